@@ -1096,6 +1096,7 @@ pub enum SkillScope {
     User,
     Repo,
     System,
+    Bundle,
     Admin,
 }
 
@@ -1108,6 +1109,12 @@ pub struct SkillMetadata {
     #[ts(optional)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub short_description: Option<String>,
+    #[ts(optional)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[ts(optional)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
     pub path: PathBuf,
     pub scope: SkillScope,
 }
@@ -1135,6 +1142,8 @@ impl From<CoreSkillMetadata> for SkillMetadata {
             name: value.name,
             description: value.description,
             short_description: value.short_description,
+            version: value.version,
+            license: value.license,
             path: value.path,
             scope: value.scope.into(),
         }
@@ -1147,6 +1156,7 @@ impl From<CoreSkillScope> for SkillScope {
             CoreSkillScope::User => Self::User,
             CoreSkillScope::Repo => Self::Repo,
             CoreSkillScope::System => Self::System,
+            CoreSkillScope::Bundle => Self::Bundle,
             CoreSkillScope::Admin => Self::Admin,
         }
     }
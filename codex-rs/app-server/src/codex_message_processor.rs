@@ -3322,6 +3322,8 @@ fn skills_to_info(
             name: skill.name.clone(),
             description: skill.description.clone(),
             short_description: skill.short_description.clone(),
+            version: skill.version.clone(),
+            license: skill.license.clone(),
             path: skill.path.clone(),
             scope: skill.scope.into(),
         })
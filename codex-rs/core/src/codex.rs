@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -60,6 +61,7 @@ use serde_json::Value;
 use tokio::sync::Mutex;
 use tokio::sync::RwLock;
 use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 use tracing::Instrument;
 use tracing::debug;
@@ -126,9 +128,11 @@ use crate::shell;
 use crate::shell_snapshot::ShellSnapshot;
 use crate::skills::SkillError;
 use crate::skills::SkillInjections;
+use crate::skills::SkillLoadOutcome;
 use crate::skills::SkillMetadata;
 use crate::skills::SkillsManager;
 use crate::skills::build_skill_injections;
+use crate::skills::loader::duplicate_description_warnings;
 use crate::state::ActiveTurn;
 use crate::state::SessionServices;
 use crate::state::SessionState;
@@ -218,28 +222,17 @@ impl Codex {
         let (tx_sub, rx_sub) = async_channel::bounded(SUBMISSION_CHANNEL_CAPACITY);
         let (tx_event, rx_event) = async_channel::unbounded();
 
-        let loaded_skills = config
-            .features
-            .enabled(Feature::Skills)
-            .then(|| skills_manager.skills_for_cwd(&config.cwd));
-
-        if let Some(outcome) = &loaded_skills {
-            for err in &outcome.errors {
-                error!(
-                    "failed to load skill {}: {}",
-                    err.path.display(),
-                    err.message
-                );
-            }
-        }
+        skills_manager.set_write_diagnostics(config.features.enabled(Feature::SkillsDiagnostics));
+        skills_manager.set_bundles(config.skills_bundles.clone());
+        // Skills are discovered in the background so a slow filesystem does not hold up
+        // `SessionConfigured`; the session task waits for them before handling submissions.
+        let skills_load = config.features.enabled(Feature::Skills).then(|| {
+            let skills_manager = Arc::clone(&skills_manager);
+            let cwd = config.cwd.clone();
+            tokio::spawn(async move { skills_manager.skills_for_cwd_async(&cwd, false).await })
+        });
 
-        let user_instructions = get_user_instructions(
-            &config,
-            loaded_skills
-                .as_ref()
-                .map(|outcome| outcome.skills.as_slice()),
-        )
-        .await;
+        let user_instructions = get_user_instructions(&config, None).await;
 
         let exec_policy = ExecPolicyManager::load(&config.features, &config.config_layer_stack)
             .await
@@ -280,7 +273,7 @@ impl Codex {
             models_manager.clone(),
             exec_policy,
             tx_event.clone(),
-            conversation_history,
+            &conversation_history,
             session_source_clone,
             skills_manager,
         )
@@ -292,7 +285,12 @@ impl Codex {
         let conversation_id = session.conversation_id;
 
         // This task will run until Op::Shutdown is received.
-        tokio::spawn(submission_loop(session, config, rx_sub));
+        tokio::spawn(async move {
+            session
+                .finish_startup(conversation_history, skills_load)
+                .await;
+            submission_loop(session, config, rx_sub).await;
+        });
         let codex = Codex {
             next_id: AtomicU64::new(0),
             tx_sub,
@@ -545,7 +543,7 @@ impl Session {
         models_manager: Arc<ModelsManager>,
         exec_policy: ExecPolicyManager,
         tx_event: Sender<Event>,
-        initial_history: InitialHistory,
+        initial_history: &InitialHistory,
         session_source: SessionSource,
         skills_manager: Arc<SkillsManager>,
     ) -> anyhow::Result<Arc<Self>> {
@@ -560,7 +558,7 @@ impl Session {
             ));
         }
 
-        let (conversation_id, rollout_params) = match &initial_history {
+        let (conversation_id, rollout_params) = match initial_history {
             InitialHistory::New | InitialHistory::Forked(_) => {
                 let conversation_id = ConversationId::default();
                 (
@@ -726,10 +724,47 @@ impl Session {
             )
             .await;
 
+        Ok(sess)
+    }
+
+    /// Completes startup once `SessionConfigured` has been emitted: waits for the background
+    /// skills load (a new session's user instructions list the discovered skills), then records
+    /// the initial history.
+    async fn finish_startup(
+        &self,
+        initial_history: InitialHistory,
+        skills_load: Option<JoinHandle<SkillLoadOutcome>>,
+    ) {
+        if let Some(skills_load) = skills_load {
+            match skills_load.await {
+                Ok(outcome) => self.apply_loaded_skills(&outcome).await,
+                Err(err) => error!("skills load task failed: {err}"),
+            }
+        }
+
         // record_initial_history can emit events. We record only after the SessionConfiguredEvent is emitted.
-        sess.record_initial_history(initial_history).await;
+        self.record_initial_history(initial_history).await;
+    }
 
-        Ok(sess)
+    async fn apply_loaded_skills(&self, outcome: &SkillLoadOutcome) {
+        for err in &outcome.errors {
+            error!(
+                "failed to load skill {}: {}",
+                err.path.display(),
+                err.message
+            );
+        }
+        if outcome.skills.is_empty() {
+            return;
+        }
+
+        let config = {
+            let state = self.state.lock().await;
+            Arc::clone(&state.session_configuration.original_config_do_not_use)
+        };
+        let user_instructions = get_user_instructions(&config, Some(&outcome.skills)).await;
+        let mut state = self.state.lock().await;
+        state.session_configuration.user_instructions = user_instructions;
     }
 
     pub(crate) fn get_tx_event(&self) -> Sender<Event> {
@@ -1978,18 +2013,24 @@ mod handlers {
         };
         let skills = if sess.enabled(Feature::Skills) {
             let skills_manager = &sess.services.skills_manager;
-            cwds.into_iter()
-                .map(|cwd| {
-                    let outcome = skills_manager.skills_for_cwd_with_options(&cwd, force_reload);
-                    let errors = super::errors_to_info(&outcome.errors);
-                    let skills = super::skills_to_info(&outcome.skills);
-                    SkillsListEntry {
-                        cwd,
-                        skills,
-                        errors,
-                    }
-                })
-                .collect()
+            let mut entries = Vec::with_capacity(cwds.len());
+            for cwd in cwds {
+                let outcome = skills_manager
+                    .skills_for_cwd_async(&cwd, force_reload)
+                    .await;
+                let mut errors = super::errors_to_info(&outcome.errors);
+                if sess.enabled(Feature::SkillsLint) {
+                    errors.extend(super::errors_to_info(&duplicate_description_warnings(
+                        &outcome.skills,
+                    )));
+                }
+                entries.push(SkillsListEntry {
+                    errors,
+                    skills: super::skills_to_info(&outcome.skills),
+                    cwd,
+                });
+            }
+            entries
         } else {
             cwds.into_iter()
                 .map(|cwd| SkillsListEntry {
@@ -2189,6 +2230,8 @@ fn skills_to_info(skills: &[SkillMetadata]) -> Vec<ProtocolSkillMetadata> {
             name: skill.name.clone(),
             description: skill.description.clone(),
             short_description: skill.short_description.clone(),
+            version: skill.version.clone(),
+            license: skill.license.clone(),
             path: skill.path.clone(),
             scope: skill.scope,
         })
@@ -2243,16 +2286,39 @@ pub(crate) async fn run_task(
     });
     sess.send_event(&turn_context, event).await;
 
-    let skills_outcome = sess.enabled(Feature::Skills).then(|| {
-        sess.services
-            .skills_manager
-            .skills_for_cwd(&turn_context.cwd)
-    });
+    let skill_index = if sess.enabled(Feature::Skills) {
+        Some(
+            sess.services
+                .skills_manager
+                .skill_index_for_cwd_async(&turn_context.cwd)
+                .await,
+        )
+    } else {
+        None
+    };
+
+    // Only wait for the MCP tool list when a skill could be checked against it.
+    let available_tools = if skill_index.as_ref().is_some_and(|index| {
+        index
+            .skills()
+            .iter()
+            .any(|skill| !skill.allowed_tools.is_empty())
+    }) {
+        Some(available_tool_names(&sess, &turn_context).await)
+    } else {
+        None
+    };
 
     let SkillInjections {
         items: skill_items,
         warnings: skill_warnings,
-    } = build_skill_injections(&input, skills_outcome.as_ref()).await;
+    } = build_skill_injections(
+        &input,
+        skill_index.as_deref(),
+        &turn_context.cwd,
+        available_tools.as_ref(),
+    )
+    .await;
 
     for message in skill_warnings {
         sess.send_event(&turn_context, EventMsg::Warning(WarningEvent { message }))
@@ -2371,6 +2437,31 @@ async fn run_auto_compact(sess: &Arc<Session>, turn_context: &Arc<TurnContext>)
     }
 }
 
+/// Names of every tool the model can call in this turn, including qualified MCP tool names.
+async fn available_tool_names(sess: &Session, turn_context: &TurnContext) -> HashSet<String> {
+    let mcp_tools = sess
+        .services
+        .mcp_connection_manager
+        .read()
+        .await
+        .list_all_tools()
+        .await;
+    let router = ToolRouter::from_config(
+        &turn_context.tools_config,
+        Some(
+            mcp_tools
+                .into_iter()
+                .map(|(name, tool)| (name, tool.tool))
+                .collect(),
+        ),
+    );
+    router
+        .specs()
+        .iter()
+        .map(|spec| spec.name().to_string())
+        .collect()
+}
+
 #[instrument(level = "trace",
     skip_all,
     fields(
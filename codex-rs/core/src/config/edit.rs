@@ -28,12 +28,16 @@ pub enum ConfigEdit {
     SetNoticeHideWorldWritableWarning(bool),
     /// Toggle the rate limit model nudge acknowledgement flag.
     SetNoticeHideRateLimitModelNudge(bool),
+    /// Toggle the context-nearly-full compaction prompt acknowledgement flag.
+    SetNoticeHideCompactPrompt(bool),
     /// Toggle the Windows onboarding acknowledgement flag.
     SetWindowsWslSetupAcknowledged(bool),
     /// Toggle the model migration prompt acknowledgement flag.
     SetNoticeHideModelMigrationPrompt(String, bool),
     /// Record that a migration prompt was shown for an old->new model mapping.
     RecordModelMigrationSeen { from: String, to: String },
+    /// Record that the `tui.model_switch_notes` note for a model was shown.
+    RecordModelSwitchNoteSeen(String),
     /// Replace the entire `[mcp_servers]` table.
     ReplaceMcpServers(BTreeMap<String, McpServerConfig>),
     /// Set trust_level under `[projects."<path>"]`,
@@ -280,6 +284,11 @@ impl ConfigDocument {
                 &[Notice::TABLE_KEY, "hide_rate_limit_model_nudge"],
                 value(*acknowledged),
             )),
+            ConfigEdit::SetNoticeHideCompactPrompt(acknowledged) => Ok(self.write_value(
+                Scope::Global,
+                &[Notice::TABLE_KEY, "hide_compact_prompt"],
+                value(*acknowledged),
+            )),
             ConfigEdit::SetNoticeHideModelMigrationPrompt(migration_config, acknowledged) => {
                 Ok(self.write_value(
                     Scope::Global,
@@ -292,6 +301,11 @@ impl ConfigDocument {
                 &[Notice::TABLE_KEY, "model_migrations", from.as_str()],
                 value(to.clone()),
             )),
+            ConfigEdit::RecordModelSwitchNoteSeen(model) => Ok(self.write_value(
+                Scope::Global,
+                &[Notice::TABLE_KEY, "model_switch_notes_seen", model.as_str()],
+                value(true),
+            )),
             ConfigEdit::SetWindowsWslSetupAcknowledged(acknowledged) => Ok(self.write_value(
                 Scope::Global,
                 &["windows_wsl_setup_acknowledged"],
@@ -614,6 +628,12 @@ impl ConfigEditsBuilder {
         self
     }
 
+    pub fn set_hide_compact_prompt(mut self, acknowledged: bool) -> Self {
+        self.edits
+            .push(ConfigEdit::SetNoticeHideCompactPrompt(acknowledged));
+        self
+    }
+
     pub fn set_hide_model_migration_prompt(mut self, model: &str, acknowledged: bool) -> Self {
         self.edits
             .push(ConfigEdit::SetNoticeHideModelMigrationPrompt(
@@ -631,6 +651,12 @@ impl ConfigEditsBuilder {
         self
     }
 
+    pub fn record_model_switch_note_seen(mut self, model: &str) -> Self {
+        self.edits
+            .push(ConfigEdit::RecordModelSwitchNoteSeen(model.to_string()));
+        self
+    }
+
     pub fn set_windows_wsl_setup_acknowledged(mut self, acknowledged: bool) -> Self {
         self.edits
             .push(ConfigEdit::SetWindowsWslSetupAcknowledged(acknowledged));
@@ -664,6 +690,26 @@ impl ConfigEditsBuilder {
         self
     }
 
+    /// Persist the TUI2 scroll settings tuned at runtime with `/scroll`.
+    pub fn set_tui_scroll_tuning(
+        mut self,
+        events_per_tick: u16,
+        wheel_lines: u16,
+        invert: bool,
+    ) -> Self {
+        for (key, item) in [
+            ("scroll_events_per_tick", value(i64::from(events_per_tick))),
+            ("scroll_wheel_lines", value(i64::from(wheel_lines))),
+            ("scroll_invert", value(invert)),
+        ] {
+            self.edits.push(ConfigEdit::SetPath {
+                segments: vec!["tui".to_string(), key.to_string()],
+                value: item,
+            });
+        }
+        self
+    }
+
     pub fn with_edits<I>(mut self, edits: I) -> Self
     where
         I: IntoIterator<Item = ConfigEdit>,
@@ -1,5 +1,7 @@
 use crate::auth::AuthCredentialsStoreMode;
+use crate::config::types::CopyPillPosition;
 use crate::config::types::DEFAULT_OTEL_ENVIRONMENT;
+use crate::config::types::EscBehavior;
 use crate::config::types::History;
 use crate::config::types::McpServerConfig;
 use crate::config::types::Notice;
@@ -7,12 +9,18 @@ use crate::config::types::Notifications;
 use crate::config::types::OtelConfig;
 use crate::config::types::OtelConfigToml;
 use crate::config::types::OtelExporterKind;
+use crate::config::types::PromptTemplate;
 use crate::config::types::SandboxWorkspaceWrite;
 use crate::config::types::ScrollInputMode;
+use crate::config::types::SelectionHighlight;
 use crate::config::types::ShellEnvironmentPolicy;
 use crate::config::types::ShellEnvironmentPolicyToml;
+use crate::config::types::StreamReveal;
+use crate::config::types::TrackpadAccelCurve;
 use crate::config::types::Tui;
+use crate::config::types::TuiTheme;
 use crate::config::types::UriBasedFileOpener;
+use crate::config::types::WrapAlgorithm;
 use crate::config_loader::ConfigLayerStack;
 use crate::config_loader::ConfigRequirements;
 use crate::config_loader::LoaderOverrides;
@@ -236,6 +244,202 @@ pub struct Config {
     /// consistently to both mouse wheels and trackpads.
     pub tui_scroll_invert: bool,
 
+    /// Override the transcript gutter width (in columns) for TUI2.
+    ///
+    /// This is the same `tui.transcript_gutter_width` value from `config.toml` (see [`Tui`]).
+    pub tui_transcript_gutter_width: Option<u16>,
+
+    /// Render transcript line numbers in the TUI2 gutter.
+    ///
+    /// This is the same `tui.transcript_line_numbers` value from `config.toml` (see [`Tui`]).
+    pub tui_transcript_line_numbers: bool,
+
+    /// Expand `@path` file mentions into file contents on submit in TUI2.
+    ///
+    /// This is the same `tui.expand_file_mentions` value from `config.toml` (see [`Tui`]).
+    pub tui_expand_file_mentions: bool,
+
+    /// Override the number of lines of context kept when paging the TUI2 transcript.
+    ///
+    /// This is the same `tui.page_overlap` value from `config.toml` (see [`Tui`]).
+    pub tui_page_overlap: Option<u16>,
+
+    /// Number of blank lines between transcript history cells.
+    ///
+    /// This is the same `tui.cell_spacing` value from `config.toml` (see [`Tui`]).
+    pub tui_cell_spacing: Option<u16>,
+
+    /// Whether the TUI mirrors the transcript to `$CODEX_HOME/transcript-<conversation id>.log`.
+    ///
+    /// This is the same `tui.mirror_transcript` value from `config.toml` (see [`Tui`]).
+    pub tui_mirror_transcript: bool,
+
+    /// Maximum number of history cells rendered in the inline transcript; `None` is unlimited.
+    ///
+    /// This is the same `tui.max_transcript_cells` value from `config.toml` (see [`Tui`]).
+    pub tui_max_transcript_cells: Option<usize>,
+
+    /// Whether transcript selection copies include ANSI styling by default.
+    ///
+    /// This is the same `tui.copy_ansi` value from `config.toml` (see [`Tui`]).
+    pub tui_copy_ansi: bool,
+
+    /// Shape of the TUI2 trackpad acceleration curve.
+    ///
+    /// This is the same `tui.scroll_trackpad_accel_curve` value from `config.toml` (see [`Tui`]).
+    pub tui_scroll_trackpad_accel_curve: TrackpadAccelCurve,
+
+    /// Spaces inserted by Tab in the composer.
+    ///
+    /// This is the same `tui.composer_tab_width` value from `config.toml` (see [`Tui`]).
+    /// TUI2 defaults to 4 when unset.
+    pub tui_composer_tab_width: Option<u16>,
+
+    /// Whether Tab inserts a literal tab character instead of spaces in the composer.
+    ///
+    /// This is the same `tui.composer_literal_tab` value from `config.toml` (see [`Tui`]).
+    pub tui_composer_literal_tab: bool,
+
+    /// Color theme for TUI2.
+    ///
+    /// This is the same `tui.theme` table from `config.toml` (see [`Tui`]).
+    pub tui_theme: TuiTheme,
+
+    /// How selected transcript text is highlighted in TUI2.
+    ///
+    /// This is the same `tui.selection_highlight` value from `config.toml` (see [`Tui`]).
+    pub tui_selection_highlight: SelectionHighlight,
+
+    /// Whether selection copies from a single-file cell get a path comment header.
+    ///
+    /// This is the same `tui.copy_path_header` value from `config.toml` (see [`Tui`]).
+    pub tui_copy_path_header: bool,
+
+    /// Line-breaking algorithm used when wrapping the TUI2 composer and transcript.
+    ///
+    /// This is the same `tui.wrap_algorithm` value from `config.toml` (see [`Tui`]).
+    pub tui_wrap_algorithm: WrapAlgorithm,
+
+    /// Context-window usage percent at which TUI2 offers to compact the conversation.
+    ///
+    /// This is the same `tui.compact_prompt_threshold` value from `config.toml` (see [`Tui`]).
+    pub tui_compact_prompt_threshold: Option<u8>,
+
+    /// Whether the composer marks trailing spaces and tabs with visible glyphs.
+    ///
+    /// This is the same `tui.composer_show_whitespace` value from `config.toml` (see [`Tui`]).
+    pub tui_composer_show_whitespace: bool,
+
+    /// Character count above which a paste is shown as a placeholder in the composer.
+    ///
+    /// This is the same `tui.paste_placeholder_threshold` value from `config.toml` (see [`Tui`]).
+    pub tui_paste_placeholder_threshold: Option<usize>,
+
+    /// Whether agent messages are rendered as Markdown rather than shown verbatim.
+    ///
+    /// This is the same `tui.render_markdown` value from `config.toml` (see [`Tui`]).
+    pub tui_render_markdown: bool,
+
+    /// Idle delay before an `@` file search starts, in milliseconds.
+    ///
+    /// This is the same `tui.file_search_debounce_ms` value from `config.toml` (see [`Tui`]).
+    pub tui_file_search_debounce_ms: Option<u64>,
+
+    /// Local-time window (`HH:MM-HH:MM`) during which desktop notifications are suppressed.
+    ///
+    /// This is the same `tui.notification_quiet_hours` value from `config.toml` (see [`Tui`]).
+    pub tui_notification_quiet_hours: Option<String>,
+
+    /// Whether the transcript reserves a column for the conversation minimap.
+    ///
+    /// This is the same `tui.transcript_minimap` value from `config.toml` (see [`Tui`]).
+    pub tui_transcript_minimap: bool,
+
+    /// Whether the composer collapses to one line when empty and unfocused.
+    ///
+    /// This is the same `tui.compact_composer` value from `config.toml` (see [`Tui`]).
+    pub tui_compact_composer: bool,
+
+    /// Messages shorter than this many characters ask for confirmation before sending.
+    ///
+    /// This is the same `tui.confirm_short_messages` value from `config.toml` (see [`Tui`]).
+    pub tui_confirm_short_messages: Option<usize>,
+
+    /// Strip one trailing newline from pasted text.
+    ///
+    /// This is the same `tui.paste_strip_trailing_newline` value from `config.toml` (see [`Tui`]).
+    pub tui_paste_strip_trailing_newline: bool,
+
+    /// Maximum width transcript content is wrapped at.
+    ///
+    /// This is the same `tui.max_content_width` value from `config.toml` (see [`Tui`]).
+    pub tui_max_content_width: Option<u16>,
+
+    /// Whether the session starts on the terminal's alternate screen.
+    ///
+    /// This is the same `tui.alternate_screen` value from `config.toml` (see [`Tui`]).
+    pub tui_alternate_screen: bool,
+
+    /// What Esc does while the composer has text.
+    ///
+    /// This is the same `tui.esc_behavior` value from `config.toml` (see [`Tui`]).
+    pub tui_esc_behavior: EscBehavior,
+
+    /// How streamed agent output is revealed.
+    ///
+    /// This is the same `tui.stream_reveal` value from `config.toml` (see [`Tui`]).
+    pub tui_stream_reveal: StreamReveal,
+
+    /// Show character/word counts and reading time under long agent messages in TUI2.
+    ///
+    /// This is the same `tui.message_stats` value from `config.toml` (see [`Tui`]).
+    pub tui_message_stats: bool,
+
+    /// Composer line count at which TUI2 suggests sending or attaching the draft.
+    ///
+    /// This is the same `tui.composer_line_warning` value from `config.toml` (see [`Tui`]).
+    pub tui_composer_line_warning: Option<usize>,
+
+    /// Whether TUI2 summarizes the previous session when a new one starts.
+    ///
+    /// This is the same `tui.show_session_summary_on_new` value from `config.toml` (see [`Tui`]).
+    pub tui_show_session_summary_on_new: bool,
+
+    /// Label of the TUI2 transcript "copy" pill.
+    ///
+    /// This is the same `tui.copy_pill_label` value from `config.toml` (see [`Tui`]).
+    pub tui_copy_pill_label: Option<String>,
+
+    /// Placement of the TUI2 transcript "copy" pill.
+    ///
+    /// This is the same `tui.copy_pill_position` value from `config.toml` (see [`Tui`]).
+    pub tui_copy_pill_position: CopyPillPosition,
+
+    /// Whether TUI2 reduces frame scheduling while idle.
+    ///
+    /// This is the same `tui.low_power_idle` value from `config.toml` (see [`Tui`]).
+    pub tui_low_power_idle: bool,
+
+    /// Whether TUI2 strips ANSI/control sequences from pasted text.
+    ///
+    /// This is the same `tui.paste_strip_ansi` value from `config.toml` (see [`Tui`]).
+    pub tui_paste_strip_ansi: bool,
+
+    /// Models that show a one-time cost/speed note when switched to mid-session.
+    ///
+    /// This is the same `tui.model_switch_notes` value from `config.toml` (see [`Tui`]).
+    pub tui_model_switch_notes: Vec<String>,
+
+    /// Custom glyph for the TUI2 composer gutter.
+    ///
+    /// This is the same `tui.composer_prompt` value from `config.toml` (see [`Tui`]).
+    pub tui_composer_prompt: Option<String>,
+
+    /// Whether the TUI2 composer shows the active model and reasoning effort.
+    ///
+    /// This is the same `tui.composer_prompt_model` value from `config.toml` (see [`Tui`]).
+    pub tui_composer_prompt_model: bool,
+
     /// The directory that should be treated as the current working directory
     /// for the session. All relative paths inside the business-logic layer are
     /// resolved against this path.
@@ -279,6 +483,9 @@ pub struct Config {
     /// Settings that govern if and what will be written to `~/.codex/history.jsonl`.
     pub history: History,
 
+    /// Prefix/suffix wrapped around every message the TUI sends (the `[prompt]` table).
+    pub prompt_template: PromptTemplate,
+
     /// Optional URI-based file opener. If set, citations to files in the model
     /// output will be hyperlinked using the specified URI scheme.
     pub file_opener: UriBasedFileOpener,
@@ -353,6 +560,10 @@ pub struct Config {
     /// or placeholder replacement will occur for fast keypress bursts.
     pub disable_paste_burst: bool,
 
+    /// Skill archives (`.zip`, `.tar.gz`, `.tgz`) extracted into the skills cache and loaded as
+    /// bundle skills.
+    pub skills_bundles: Vec<PathBuf>,
+
     /// OTEL configuration (exporter type, endpoint, headers, etc.).
     pub otel: crate::config::types::OtelConfig,
 }
@@ -759,6 +970,10 @@ pub struct ConfigToml {
     #[serde(default)]
     pub history: Option<History>,
 
+    /// Prefix/suffix wrapped around every message sent from the TUI.
+    #[serde(default)]
+    pub prompt: Option<PromptTemplate>,
+
     /// Optional URI-based file opener. If set, citations to files in the model
     /// output will be hyperlinked using the specified URI scheme.
     pub file_opener: Option<UriBasedFileOpener>,
@@ -813,6 +1028,10 @@ pub struct ConfigToml {
     /// or placeholder replacement will occur for fast keypress bursts.
     pub disable_paste_burst: Option<bool>,
 
+    /// Skill archives (`.zip`, `.tar.gz`, `.tgz`) to extract and load as bundle skills. Relative
+    /// paths are resolved against the directory containing `config.toml`.
+    pub skills_bundles: Option<Vec<AbsolutePathBuf>>,
+
     /// OTEL configuration.
     pub otel: Option<crate::config::types::OtelConfigToml>,
 
@@ -1356,6 +1575,7 @@ impl Config {
             codex_home,
             config_layer_stack,
             history,
+            prompt_template: cfg.prompt.unwrap_or_default(),
             file_opener: cfg.file_opener.unwrap_or(UriBasedFileOpener::VsCode),
             codex_linux_sandbox_exe,
 
@@ -1390,6 +1610,12 @@ impl Config {
             notices: cfg.notice.unwrap_or_default(),
             check_for_update_on_startup,
             disable_paste_burst: cfg.disable_paste_burst.unwrap_or(false),
+            skills_bundles: cfg
+                .skills_bundles
+                .unwrap_or_default()
+                .into_iter()
+                .map(AbsolutePathBuf::into_path_buf)
+                .collect(),
             tui_notifications: cfg
                 .tui
                 .as_ref()
@@ -1418,6 +1644,113 @@ impl Config {
                 .as_ref()
                 .and_then(|t| t.scroll_wheel_like_max_duration_ms),
             tui_scroll_invert: cfg.tui.as_ref().map(|t| t.scroll_invert).unwrap_or(false),
+            tui_transcript_gutter_width: cfg.tui.as_ref().and_then(|t| t.transcript_gutter_width),
+            tui_transcript_line_numbers: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.transcript_line_numbers)
+                .unwrap_or(false),
+            tui_expand_file_mentions: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.expand_file_mentions)
+                .unwrap_or(false),
+            tui_page_overlap: cfg.tui.as_ref().and_then(|t| t.page_overlap),
+            tui_cell_spacing: cfg.tui.as_ref().and_then(|t| t.cell_spacing),
+            tui_mirror_transcript: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.mirror_transcript)
+                .unwrap_or(false),
+            tui_max_transcript_cells: cfg.tui.as_ref().and_then(|t| t.max_transcript_cells),
+            tui_copy_ansi: cfg.tui.as_ref().is_some_and(|t| t.copy_ansi),
+            tui_scroll_trackpad_accel_curve: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.scroll_trackpad_accel_curve)
+                .unwrap_or_default(),
+            tui_composer_tab_width: cfg.tui.as_ref().and_then(|t| t.composer_tab_width),
+            tui_composer_literal_tab: cfg.tui.as_ref().is_some_and(|t| t.composer_literal_tab),
+            tui_theme: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.theme.clone())
+                .unwrap_or_default(),
+            tui_selection_highlight: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.selection_highlight)
+                .unwrap_or_default(),
+            tui_copy_path_header: cfg.tui.as_ref().is_some_and(|t| t.copy_path_header),
+            tui_wrap_algorithm: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.wrap_algorithm)
+                .unwrap_or_default(),
+            tui_compact_prompt_threshold: cfg.tui.as_ref().and_then(|t| t.compact_prompt_threshold),
+            tui_composer_show_whitespace: cfg
+                .tui
+                .as_ref()
+                .is_some_and(|t| t.composer_show_whitespace),
+            tui_paste_placeholder_threshold: cfg
+                .tui
+                .as_ref()
+                .and_then(|t| t.paste_placeholder_threshold),
+            tui_render_markdown: cfg.tui.as_ref().is_none_or(|t| t.render_markdown),
+            tui_file_search_debounce_ms: cfg.tui.as_ref().and_then(|t| t.file_search_debounce_ms),
+            tui_notification_quiet_hours: cfg
+                .tui
+                .as_ref()
+                .and_then(|t| t.notification_quiet_hours.clone()),
+            tui_transcript_minimap: cfg.tui.as_ref().is_some_and(|t| t.transcript_minimap),
+            tui_compact_composer: cfg.tui.as_ref().is_some_and(|t| t.compact_composer),
+            tui_confirm_short_messages: cfg.tui.as_ref().and_then(|t| t.confirm_short_messages),
+            tui_paste_strip_trailing_newline: cfg
+                .tui
+                .as_ref()
+                .is_some_and(|t| t.paste_strip_trailing_newline),
+            tui_max_content_width: cfg.tui.as_ref().and_then(|t| t.max_content_width),
+            tui_alternate_screen: cfg.tui.as_ref().is_none_or(|t| t.alternate_screen),
+            tui_esc_behavior: cfg.tui.as_ref().map(|t| t.esc_behavior).unwrap_or_default(),
+            tui_stream_reveal: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.stream_reveal)
+                .unwrap_or_default(),
+            tui_message_stats: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.message_stats)
+                .unwrap_or_default(),
+            tui_composer_line_warning: cfg.tui.as_ref().and_then(|t| t.composer_line_warning),
+            tui_show_session_summary_on_new: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.show_session_summary_on_new)
+                .unwrap_or(true),
+            tui_copy_pill_label: cfg.tui.as_ref().and_then(|t| t.copy_pill_label.clone()),
+            tui_copy_pill_position: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.copy_pill_position)
+                .unwrap_or_default(),
+            tui_low_power_idle: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.low_power_idle)
+                .unwrap_or_default(),
+            tui_paste_strip_ansi: cfg.tui.as_ref().map(|t| t.paste_strip_ansi).unwrap_or(true),
+            tui_model_switch_notes: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.model_switch_notes.clone())
+                .unwrap_or_default(),
+            tui_composer_prompt: cfg.tui.as_ref().and_then(|t| t.composer_prompt.clone()),
+            tui_composer_prompt_model: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.composer_prompt_model)
+                .unwrap_or(false),
             otel: {
                 let t: OtelConfigToml = cfg.otel.unwrap_or_default();
                 let log_user_prompt = t.log_user_prompt.unwrap_or(false);
@@ -1605,6 +1938,45 @@ persistence = "none"
                 scroll_wheel_tick_detect_max_ms: None,
                 scroll_wheel_like_max_duration_ms: None,
                 scroll_invert: false,
+                transcript_gutter_width: None,
+                transcript_line_numbers: false,
+                expand_file_mentions: false,
+                page_overlap: None,
+                cell_spacing: None,
+                mirror_transcript: false,
+                max_transcript_cells: None,
+                copy_ansi: false,
+                scroll_trackpad_accel_curve: TrackpadAccelCurve::Linear,
+                composer_tab_width: None,
+                composer_literal_tab: false,
+                theme: TuiTheme::default(),
+                selection_highlight: SelectionHighlight::Reverse,
+                copy_path_header: false,
+                wrap_algorithm: WrapAlgorithm::FirstFit,
+                compact_prompt_threshold: None,
+                composer_show_whitespace: false,
+                paste_placeholder_threshold: None,
+                render_markdown: true,
+                file_search_debounce_ms: None,
+                notification_quiet_hours: None,
+                transcript_minimap: false,
+                compact_composer: false,
+                confirm_short_messages: None,
+                paste_strip_trailing_newline: false,
+                max_content_width: None,
+                alternate_screen: true,
+                esc_behavior: EscBehavior::Backtrack,
+                stream_reveal: StreamReveal::Line,
+                message_stats: false,
+                composer_line_warning: None,
+                show_session_summary_on_new: true,
+                copy_pill_label: None,
+                copy_pill_position: CopyPillPosition::Right,
+                low_power_idle: false,
+                paste_strip_ansi: true,
+                model_switch_notes: Vec::new(),
+                composer_prompt: None,
+                composer_prompt_model: false,
             }
         );
     }
@@ -3174,6 +3546,7 @@ model_verbosity = "high"
                 codex_home: fixture.codex_home(),
                 config_layer_stack: Default::default(),
                 history: History::default(),
+                prompt_template: PromptTemplate::default(),
                 file_opener: UriBasedFileOpener::VsCode,
                 codex_linux_sandbox_exe: None,
                 hide_agent_reasoning: false,
@@ -3199,6 +3572,7 @@ model_verbosity = "high"
                 notices: Default::default(),
                 check_for_update_on_startup: true,
                 disable_paste_burst: false,
+                skills_bundles: Vec::new(),
                 tui_notifications: Default::default(),
                 animations: true,
                 show_tooltips: true,
@@ -3211,6 +3585,45 @@ model_verbosity = "high"
                 tui_scroll_wheel_tick_detect_max_ms: None,
                 tui_scroll_wheel_like_max_duration_ms: None,
                 tui_scroll_invert: false,
+                tui_transcript_gutter_width: None,
+                tui_transcript_line_numbers: false,
+                tui_expand_file_mentions: false,
+                tui_page_overlap: None,
+                tui_cell_spacing: None,
+                tui_mirror_transcript: false,
+                tui_max_transcript_cells: None,
+                tui_copy_ansi: false,
+                tui_scroll_trackpad_accel_curve: TrackpadAccelCurve::Linear,
+                tui_composer_tab_width: None,
+                tui_composer_literal_tab: false,
+                tui_theme: TuiTheme::default(),
+                tui_selection_highlight: SelectionHighlight::Reverse,
+                tui_copy_path_header: false,
+                tui_wrap_algorithm: WrapAlgorithm::FirstFit,
+                tui_compact_prompt_threshold: None,
+                tui_composer_show_whitespace: false,
+                tui_paste_placeholder_threshold: None,
+                tui_render_markdown: true,
+                tui_file_search_debounce_ms: None,
+                tui_notification_quiet_hours: None,
+                tui_transcript_minimap: false,
+                tui_compact_composer: false,
+                tui_confirm_short_messages: None,
+                tui_paste_strip_trailing_newline: false,
+                tui_max_content_width: None,
+                tui_alternate_screen: true,
+                tui_esc_behavior: EscBehavior::Backtrack,
+                tui_stream_reveal: StreamReveal::Line,
+                tui_message_stats: false,
+                tui_composer_line_warning: None,
+                tui_show_session_summary_on_new: true,
+                tui_copy_pill_label: None,
+                tui_copy_pill_position: CopyPillPosition::Right,
+                tui_low_power_idle: false,
+                tui_paste_strip_ansi: true,
+                tui_model_switch_notes: Vec::new(),
+                tui_composer_prompt: None,
+                tui_composer_prompt_model: false,
                 otel: OtelConfig::default(),
             },
             o3_profile_config
@@ -3257,6 +3670,7 @@ model_verbosity = "high"
             codex_home: fixture.codex_home(),
             config_layer_stack: Default::default(),
             history: History::default(),
+            prompt_template: PromptTemplate::default(),
             file_opener: UriBasedFileOpener::VsCode,
             codex_linux_sandbox_exe: None,
             hide_agent_reasoning: false,
@@ -3282,6 +3696,7 @@ model_verbosity = "high"
             notices: Default::default(),
             check_for_update_on_startup: true,
             disable_paste_burst: false,
+            skills_bundles: Vec::new(),
             tui_notifications: Default::default(),
             animations: true,
             show_tooltips: true,
@@ -3294,6 +3709,45 @@ model_verbosity = "high"
             tui_scroll_wheel_tick_detect_max_ms: None,
             tui_scroll_wheel_like_max_duration_ms: None,
             tui_scroll_invert: false,
+            tui_transcript_gutter_width: None,
+            tui_transcript_line_numbers: false,
+            tui_expand_file_mentions: false,
+            tui_page_overlap: None,
+            tui_cell_spacing: None,
+            tui_mirror_transcript: false,
+            tui_max_transcript_cells: None,
+            tui_copy_ansi: false,
+            tui_scroll_trackpad_accel_curve: TrackpadAccelCurve::Linear,
+            tui_composer_tab_width: None,
+            tui_composer_literal_tab: false,
+            tui_theme: TuiTheme::default(),
+            tui_selection_highlight: SelectionHighlight::Reverse,
+            tui_copy_path_header: false,
+            tui_wrap_algorithm: WrapAlgorithm::FirstFit,
+            tui_compact_prompt_threshold: None,
+            tui_composer_show_whitespace: false,
+            tui_paste_placeholder_threshold: None,
+            tui_render_markdown: true,
+            tui_file_search_debounce_ms: None,
+            tui_notification_quiet_hours: None,
+            tui_transcript_minimap: false,
+            tui_compact_composer: false,
+            tui_confirm_short_messages: None,
+            tui_paste_strip_trailing_newline: false,
+            tui_max_content_width: None,
+            tui_alternate_screen: true,
+            tui_esc_behavior: EscBehavior::Backtrack,
+            tui_stream_reveal: StreamReveal::Line,
+            tui_message_stats: false,
+            tui_composer_line_warning: None,
+            tui_show_session_summary_on_new: true,
+            tui_copy_pill_label: None,
+            tui_copy_pill_position: CopyPillPosition::Right,
+            tui_low_power_idle: false,
+            tui_paste_strip_ansi: true,
+            tui_model_switch_notes: Vec::new(),
+            tui_composer_prompt: None,
+            tui_composer_prompt_model: false,
             otel: OtelConfig::default(),
         };
 
@@ -3355,6 +3809,7 @@ model_verbosity = "high"
             codex_home: fixture.codex_home(),
             config_layer_stack: Default::default(),
             history: History::default(),
+            prompt_template: PromptTemplate::default(),
             file_opener: UriBasedFileOpener::VsCode,
             codex_linux_sandbox_exe: None,
             hide_agent_reasoning: false,
@@ -3380,6 +3835,7 @@ model_verbosity = "high"
             notices: Default::default(),
             check_for_update_on_startup: true,
             disable_paste_burst: false,
+            skills_bundles: Vec::new(),
             tui_notifications: Default::default(),
             animations: true,
             show_tooltips: true,
@@ -3392,6 +3848,45 @@ model_verbosity = "high"
             tui_scroll_wheel_tick_detect_max_ms: None,
             tui_scroll_wheel_like_max_duration_ms: None,
             tui_scroll_invert: false,
+            tui_transcript_gutter_width: None,
+            tui_transcript_line_numbers: false,
+            tui_expand_file_mentions: false,
+            tui_page_overlap: None,
+            tui_cell_spacing: None,
+            tui_mirror_transcript: false,
+            tui_max_transcript_cells: None,
+            tui_copy_ansi: false,
+            tui_scroll_trackpad_accel_curve: TrackpadAccelCurve::Linear,
+            tui_composer_tab_width: None,
+            tui_composer_literal_tab: false,
+            tui_theme: TuiTheme::default(),
+            tui_selection_highlight: SelectionHighlight::Reverse,
+            tui_copy_path_header: false,
+            tui_wrap_algorithm: WrapAlgorithm::FirstFit,
+            tui_compact_prompt_threshold: None,
+            tui_composer_show_whitespace: false,
+            tui_paste_placeholder_threshold: None,
+            tui_render_markdown: true,
+            tui_file_search_debounce_ms: None,
+            tui_notification_quiet_hours: None,
+            tui_transcript_minimap: false,
+            tui_compact_composer: false,
+            tui_confirm_short_messages: None,
+            tui_paste_strip_trailing_newline: false,
+            tui_max_content_width: None,
+            tui_alternate_screen: true,
+            tui_esc_behavior: EscBehavior::Backtrack,
+            tui_stream_reveal: StreamReveal::Line,
+            tui_message_stats: false,
+            tui_composer_line_warning: None,
+            tui_show_session_summary_on_new: true,
+            tui_copy_pill_label: None,
+            tui_copy_pill_position: CopyPillPosition::Right,
+            tui_low_power_idle: false,
+            tui_paste_strip_ansi: true,
+            tui_model_switch_notes: Vec::new(),
+            tui_composer_prompt: None,
+            tui_composer_prompt_model: false,
             otel: OtelConfig::default(),
         };
 
@@ -3439,6 +3934,7 @@ model_verbosity = "high"
             codex_home: fixture.codex_home(),
             config_layer_stack: Default::default(),
             history: History::default(),
+            prompt_template: PromptTemplate::default(),
             file_opener: UriBasedFileOpener::VsCode,
             codex_linux_sandbox_exe: None,
             hide_agent_reasoning: false,
@@ -3464,6 +3960,7 @@ model_verbosity = "high"
             notices: Default::default(),
             check_for_update_on_startup: true,
             disable_paste_burst: false,
+            skills_bundles: Vec::new(),
             tui_notifications: Default::default(),
             animations: true,
             show_tooltips: true,
@@ -3476,6 +3973,45 @@ model_verbosity = "high"
             tui_scroll_wheel_tick_detect_max_ms: None,
             tui_scroll_wheel_like_max_duration_ms: None,
             tui_scroll_invert: false,
+            tui_transcript_gutter_width: None,
+            tui_transcript_line_numbers: false,
+            tui_expand_file_mentions: false,
+            tui_page_overlap: None,
+            tui_cell_spacing: None,
+            tui_mirror_transcript: false,
+            tui_max_transcript_cells: None,
+            tui_copy_ansi: false,
+            tui_scroll_trackpad_accel_curve: TrackpadAccelCurve::Linear,
+            tui_composer_tab_width: None,
+            tui_composer_literal_tab: false,
+            tui_theme: TuiTheme::default(),
+            tui_selection_highlight: SelectionHighlight::Reverse,
+            tui_copy_path_header: false,
+            tui_wrap_algorithm: WrapAlgorithm::FirstFit,
+            tui_compact_prompt_threshold: None,
+            tui_composer_show_whitespace: false,
+            tui_paste_placeholder_threshold: None,
+            tui_render_markdown: true,
+            tui_file_search_debounce_ms: None,
+            tui_notification_quiet_hours: None,
+            tui_transcript_minimap: false,
+            tui_compact_composer: false,
+            tui_confirm_short_messages: None,
+            tui_paste_strip_trailing_newline: false,
+            tui_max_content_width: None,
+            tui_alternate_screen: true,
+            tui_esc_behavior: EscBehavior::Backtrack,
+            tui_stream_reveal: StreamReveal::Line,
+            tui_message_stats: false,
+            tui_composer_line_warning: None,
+            tui_show_session_summary_on_new: true,
+            tui_copy_pill_label: None,
+            tui_copy_pill_position: CopyPillPosition::Right,
+            tui_low_power_idle: false,
+            tui_paste_strip_ansi: true,
+            tui_model_switch_notes: Vec::new(),
+            tui_composer_prompt: None,
+            tui_composer_prompt_model: false,
             otel: OtelConfig::default(),
         };
 
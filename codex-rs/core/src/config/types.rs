@@ -377,6 +377,8 @@ pub enum ScrollInputMode {
     Wheel,
     /// Always treat scroll events as trackpad input (fractional accumulation).
     Trackpad,
+    /// Scroll exactly one line per raw scroll event, with no coalescing or acceleration.
+    Precise,
 }
 
 impl Default for ScrollInputMode {
@@ -385,6 +387,150 @@ impl Default for ScrollInputMode {
     }
 }
 
+/// What Esc does in TUI2 when the composer has text and nothing else (popup, modal, running
+/// task) claims the key.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EscBehavior {
+    /// Hand Esc to the composer as usual; backtracking still arms once the composer is empty.
+    Backtrack,
+    /// Clear the composer in one press. The cleared draft is recalled with Up.
+    Clear,
+    /// Ignore Esc while the composer has text.
+    None,
+}
+
+impl Default for EscBehavior {
+    fn default() -> Self {
+        Self::Backtrack
+    }
+}
+
+/// How TUI2 reveals streamed agent output on each animation tick.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StreamReveal {
+    /// Reveal the line being streamed one character at a time.
+    Char,
+    /// Reveal the line being streamed one word at a time.
+    Word,
+    /// Commit one completed line per tick.
+    Line,
+    /// Show completed lines as soon as they arrive, with no animation.
+    Instant,
+}
+
+impl Default for StreamReveal {
+    fn default() -> Self {
+        Self::Line
+    }
+}
+
+/// Shape of the TUI2 trackpad acceleration multiplier.
+///
+/// All curves are a function of `x = abs(events) / scroll_trackpad_accel_events`, start at 1x,
+/// and are capped at `scroll_trackpad_accel_max`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TrackpadAccelCurve {
+    /// `1 + x`.
+    Linear,
+    /// `1 + x^2`: gentler than linear for short swipes, steeper for long ones.
+    Quadratic,
+    /// `e^x`: the most aggressive curve for big swipes.
+    Exponential,
+}
+
+impl Default for TrackpadAccelCurve {
+    fn default() -> Self {
+        Self::Linear
+    }
+}
+
+/// How TUI2 highlights selected transcript text.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum SelectionHighlight {
+    /// Reverse video (swap the cell's foreground and background).
+    #[default]
+    Reverse,
+    /// Explicit `tui.theme.selection_fg` / `tui.theme.selection_bg` colors, for terminals and
+    /// themes where reverse video is hard to read.
+    HighContrast,
+    /// Underline the selection instead of changing its colors.
+    Underline,
+}
+
+/// Where TUI2 places the "copy" pill on the row below a transcript selection.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum CopyPillPosition {
+    /// Just after the end of the selection.
+    #[default]
+    Right,
+    /// At the left edge of the transcript content.
+    Left,
+}
+
+/// Line-breaking algorithm used by TUI2 when wrapping the composer and transcript.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum WrapAlgorithm {
+    /// Greedily fill each line; fast and predictable while typing.
+    #[default]
+    FirstFit,
+    /// Minimize raggedness across the whole paragraph.
+    OptimalFit,
+}
+
+/// Color overrides for the TUI2 `[tui.theme]` table.
+///
+/// `name` selects a built-in base theme (`dark`, `light`, or `high-contrast`); the remaining
+/// keys override individual colors on top of it. Colors are kept as strings here and resolved
+/// by the TUI, which accepts ANSI names (`red`, `light-blue`, ...), `#rrggbb`, and 256-color
+/// indices. Unknown names fall back to the base theme's color with a warning.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct TuiTheme {
+    /// Built-in base theme. Defaults to adapting to the terminal background.
+    pub name: Option<String>,
+
+    /// Background of user messages in the transcript and composer.
+    pub user_message_bg: Option<String>,
+
+    /// Foreground of agent message text.
+    pub agent_text: Option<String>,
+
+    /// Foreground of error messages.
+    pub error: Option<String>,
+
+    /// Foreground of warning messages.
+    pub warning: Option<String>,
+
+    /// Background of the transcript selection highlight. Selections are reverse-video when unset.
+    pub selection_bg: Option<String>,
+
+    /// Foreground of the transcript selection highlight in `high-contrast` selection mode.
+    pub selection_fg: Option<String>,
+
+    /// Background of the "copy" pill shown next to a transcript selection.
+    pub copy_pill_bg: Option<String>,
+
+    /// Foreground of the "copy" pill shown next to a transcript selection.
+    pub copy_pill_fg: Option<String>,
+}
+
+fn deserialize_cell_spacing<'de, D>(deserializer: D) -> Result<Option<u16>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<u16>::deserialize(deserializer)? {
+        Some(spacing) if spacing > 1 => Err(SerdeError::custom(format!(
+            "invalid tui.cell_spacing {spacing}: expected 0 or 1"
+        ))),
+        spacing => Ok(spacing),
+    }
+}
+
 /// Collection of settings that are specific to the TUI.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub struct Tui {
@@ -464,7 +610,8 @@ pub struct Tui {
     /// - `multiplier = clamp(1 + abs(events) / scroll_trackpad_accel_events, 1..scroll_trackpad_accel_max)`
     ///
     /// The multiplier is applied to the stream’s computed line delta (including any carried
-    /// fractional remainder).
+    /// fractional remainder). This is the default `linear` curve; see
+    /// [`Tui::scroll_trackpad_accel_curve`] for steeper alternatives.
     pub scroll_trackpad_accel_events: Option<u16>,
 
     /// Trackpad acceleration: maximum multiplier applied to trackpad-like streams.
@@ -479,6 +626,7 @@ pub struct Tui {
     /// - `auto` (default): infer wheel vs trackpad per scroll stream.
     /// - `wheel`: always use wheel behavior (fixed lines per wheel notch).
     /// - `trackpad`: always use trackpad behavior (fractional accumulation; wheel may feel slow).
+    /// - `precise`: one line per raw scroll event (no normalization, coalescing, or acceleration).
     #[serde(default)]
     pub scroll_mode: ScrollInputMode,
 
@@ -505,12 +653,254 @@ pub struct Tui {
     /// wheel and trackpad input.
     #[serde(default)]
     pub scroll_invert: bool,
+
+    /// Override the width (in columns) of the transcript gutter in TUI2.
+    ///
+    /// The gutter always includes the 2-column bullet/prefix area that each history cell renders.
+    /// Any extra columns are reserved to the left of that prefix (for example, for line numbers).
+    /// Values smaller than 2 are clamped to 2.
+    ///
+    /// Defaults to 2, or to a width that fits line numbers when `transcript_line_numbers` is set.
+    pub transcript_gutter_width: Option<u16>,
+
+    /// Render absolute transcript line numbers in the TUI2 transcript gutter.
+    ///
+    /// Line numbers are dim and are never included in selections or copied text.
+    #[serde(default)]
+    pub transcript_line_numbers: bool,
+
+    /// Expand `@path` file mentions into attached file contents when a message is sent from TUI2.
+    ///
+    /// Files are resolved relative to the session cwd and truncated to a fixed size. Mentions that
+    /// do not resolve to a file are sent as literal text with a warning. Defaults to `false`.
+    #[serde(default)]
+    pub expand_file_mentions: bool,
+
+    /// Number of lines kept visible across a PageUp/PageDown jump in the TUI2 transcript.
+    ///
+    /// Each page scroll moves by the visible height minus this many lines, so the lines at the
+    /// boundary stay on screen. The overlap is always capped so a page scroll moves at least one
+    /// line. Defaults to 2.
+    pub page_overlap: Option<u16>,
+
+    /// Number of blank lines inserted between history cells in the transcript: `0` renders
+    /// cells back to back, `1` separates them with one blank line. Other values are rejected.
+    /// Streaming continuations are never spaced. Defaults to `1`.
+    #[serde(default, deserialize_with = "deserialize_cell_spacing")]
+    pub cell_spacing: Option<u16>,
+
+    /// Mirror the transcript as plain text to `$CODEX_HOME/transcript-<conversation id>.log`
+    /// while the TUI runs, so it can be followed from another terminal. Cells are separated by
+    /// `cell_spacing` blank lines, like the viewport. Only the 20 most recently written logs are
+    /// kept. Defaults to `false`.
+    #[serde(default)]
+    pub mirror_transcript: bool,
+
+    /// Maximum number of history cells kept in the inline transcript view. Older cells are
+    /// folded into a single "earlier conversation" line (the transcript overlay still shows
+    /// everything). Unlimited when unset.
+    pub max_transcript_cells: Option<usize>,
+
+    /// Include ANSI color codes when copying a transcript selection in TUI2.
+    ///
+    /// Holding Alt with the copy shortcut flips this for a single copy. Defaults to `false`
+    /// (plain text).
+    #[serde(default)]
+    pub copy_ansi: bool,
+
+    /// Trackpad acceleration: shape of the multiplier curve in TUI2.
+    ///
+    /// - `linear` (default): `1 + x`
+    /// - `quadratic`: `1 + x^2`
+    /// - `exponential`: `e^x`
+    ///
+    /// where `x = abs(events) / scroll_trackpad_accel_events`. Every curve starts at 1x for small
+    /// swipes and is capped at `scroll_trackpad_accel_max`; the steeper curves only make large swipes
+    /// cover more content.
+    #[serde(default)]
+    pub scroll_trackpad_accel_curve: TrackpadAccelCurve,
+
+    /// Number of spaces Tab inserts in the TUI2 composer (and Shift+Tab removes).
+    ///
+    /// Defaults to `4`.
+    pub composer_tab_width: Option<u16>,
+
+    /// Insert a literal tab character instead of spaces when Tab is pressed in the TUI2
+    /// composer. Defaults to `false`.
+    #[serde(default)]
+    pub composer_literal_tab: bool,
+
+    /// Color theme for TUI2: a built-in `name` plus per-role color overrides.
+    #[serde(default)]
+    pub theme: TuiTheme,
+
+    /// How selected transcript text is highlighted in TUI2: `reverse` (default), `high-contrast`
+    /// (explicit theme colors), or `underline`.
+    #[serde(default)]
+    pub selection_highlight: SelectionHighlight,
+
+    /// Prepend a `// path/to/file` comment (in the file's comment syntax) when a TUI2 selection
+    /// copy lies within a cell tied to a single file, such as a patch or a file read. Defaults to
+    /// `false`.
+    #[serde(default)]
+    pub copy_path_header: bool,
+
+    /// Line-breaking algorithm for the TUI2 composer and transcript. `first-fit` (the default)
+    /// is fastest; `optimal-fit` balances line lengths for a less ragged right edge.
+    #[serde(default)]
+    pub wrap_algorithm: WrapAlgorithm,
+
+    /// Percent of the model's context window in use at which TUI2 offers to compact the
+    /// conversation. Defaults to 85; set to 0 to never offer.
+    #[serde(default)]
+    pub compact_prompt_threshold: Option<u8>,
+
+    /// Show trailing spaces as `·` and tabs as `→` in the TUI2 composer. Only rendering changes;
+    /// the submitted text is untouched. Defaults to `false`.
+    #[serde(default)]
+    pub composer_show_whitespace: bool,
+
+    /// Pastes longer than this many characters are collapsed into a `[Pasted Content …]`
+    /// placeholder in the TUI2 composer; the full text is still sent on submit. Defaults to 1000.
+    #[serde(default)]
+    pub paste_placeholder_threshold: Option<usize>,
+
+    /// Render Markdown in agent messages (bold, italic, code, links, lists). When `false`,
+    /// TUI2 shows the message source verbatim. Defaults to `true`.
+    #[serde(default = "default_true")]
+    pub render_markdown: bool,
+
+    /// Milliseconds TUI2 waits after the last keystroke in an `@` file mention before it starts a
+    /// file search. Defaults to 100.
+    #[serde(default)]
+    pub file_search_debounce_ms: Option<u64>,
+
+    /// Local-time window such as `"22:00-08:00"` during which TUI2 sends no desktop
+    /// notifications. Windows may wrap past midnight. Unset by default.
+    #[serde(default)]
+    pub notification_quiet_hours: Option<String>,
+
+    /// Draw a one-column overview of the whole conversation to the right of the TUI2 transcript
+    /// once it is much taller than the screen. Click it to jump. Defaults to `false`.
+    #[serde(default)]
+    pub transcript_minimap: bool,
+
+    /// Shrink the TUI2 composer to a single line while the terminal is unfocused and the draft is
+    /// empty, giving the transcript the freed rows. Defaults to `false`.
+    #[serde(default)]
+    pub compact_composer: bool,
+
+    /// Ask for confirmation before sending a TUI2 message shorter than this many characters (after
+    /// trimming), to catch a prematurely pressed Enter. Whitespace-only messages always ask when
+    /// this is set. Defaults to unset (no confirmation).
+    #[serde(default)]
+    pub confirm_short_messages: Option<usize>,
+
+    /// Drop a single trailing newline from pasted text in TUI2, so pasting one copied line does
+    /// not leave an empty line after it. Defaults to `false`.
+    #[serde(default)]
+    pub paste_strip_trailing_newline: bool,
+
+    /// Cap the width TUI2 wraps transcript content at, in columns (including the two-column
+    /// bullet prefix). Content stays left-aligned; wider terminals leave the extra columns empty.
+    /// Defaults to unset (use the full terminal width).
+    #[serde(default)]
+    pub max_content_width: Option<u16>,
+
+    /// Run the TUI2 session on the terminal's alternate screen (full-screen mode). When `false`,
+    /// the session draws on the normal screen instead. Switch at runtime with Alt+M. Defaults to
+    /// `true`.
+    #[serde(default = "default_true")]
+    pub alternate_screen: bool,
+
+    /// What Esc does in TUI2 while the composer has text: `backtrack` (default) keeps the usual
+    /// behavior, `clear` empties the composer in one press (Up brings the draft back), and `none`
+    /// ignores the key. Esc on an empty composer always arms backtracking.
+    #[serde(default)]
+    pub esc_behavior: EscBehavior,
+
+    /// How TUI2 reveals streamed agent output: `char` or `word` animate the line being streamed,
+    /// `line` (default) commits one completed line per tick, and `instant` shows completed lines
+    /// as soon as they arrive.
+    #[serde(default)]
+    pub stream_reveal: StreamReveal,
+
+    /// Show a dim footer under long agent messages in TUI2 with their character and word counts
+    /// and an estimated reading time. Defaults to `false`.
+    #[serde(default)]
+    pub message_stats: bool,
+
+    /// Show a TUI2 footer hint suggesting to send the draft or attach it as a file once the
+    /// composer holds at least this many lines. Input is never blocked. Defaults to 40; `0` turns
+    /// the hint off.
+    #[serde(default)]
+    pub composer_line_warning: Option<usize>,
+
+    /// When starting or resuming another session from TUI2, print the previous session's token
+    /// usage and resume command at the top of the new chat.
+    /// Defaults to `true`.
+    #[serde(default = "default_true")]
+    pub show_session_summary_on_new: bool,
+
+    /// Text of the TUI2 "copy" pill shown next to a transcript selection; the copy shortcut is
+    /// appended after it. Defaults to `copy`.
+    #[serde(default)]
+    pub copy_pill_label: Option<String>,
+
+    /// Where the TUI2 "copy" pill sits on the row below a selection: `right` (default, just
+    /// after the selection) or `left` (at the start of the transcript content).
+    #[serde(default)]
+    pub copy_pill_position: CopyPillPosition,
+
+    /// Let TUI2 back off periodic redraws while idle (no running task, scroll, or recent
+    /// input) to save CPU and battery. Any input restores full responsiveness. Defaults to
+    /// `false`.
+    #[serde(default)]
+    pub low_power_idle: bool,
+
+    /// Strip ANSI escape sequences and other control characters from text pasted into the TUI2
+    /// composer, keeping printable text and line breaks. Defaults to `true`.
+    #[serde(default = "default_true")]
+    pub paste_strip_ansi: bool,
+
+    /// Model slugs that show a one-time note with the model's description when the session
+    /// switches to them mid-session, e.g. to flag slower or more expensive models. Empty (off) by
+    /// default.
+    #[serde(default)]
+    pub model_switch_notes: Vec<String>,
+
+    /// Glyph drawn in the TUI2 composer's left gutter, in place of the default `›`. Only the
+    /// first column is shown so the text layout does not shift.
+    #[serde(default)]
+    pub composer_prompt: Option<String>,
+
+    /// Show the active model and reasoning effort above the text in the TUI2 composer.
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub composer_prompt_model: bool,
 }
 
 const fn default_true() -> bool {
     true
 }
 
+/// Boilerplate wrapped around every message sent from the TUI, from the `[prompt]` table.
+///
+/// Both parts default to empty, in which case messages are sent exactly as typed.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct PromptTemplate {
+    /// Text sent before the user's message, separated from it by a blank line.
+    pub prefix: Option<String>,
+
+    /// Text sent after the user's message, separated from it by a blank line.
+    pub suffix: Option<String>,
+
+    /// Show the full wrapped message in the transcript instead of only what was typed.
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub show_in_transcript: bool,
+}
+
 /// Settings for notices we display to users via the tui and app-server clients
 /// (primarily the Codex IDE extension). NOTE: these are different from
 /// notifications - notices are warnings, NUX screens, acknowledgements, etc.
@@ -522,6 +912,8 @@ pub struct Notice {
     pub hide_world_writable_warning: Option<bool>,
     /// Tracks whether the user opted out of the rate limit model switch reminder.
     pub hide_rate_limit_model_nudge: Option<bool>,
+    /// Tracks whether the user opted out of the prompt to compact a nearly full context window.
+    pub hide_compact_prompt: Option<bool>,
     /// Tracks whether the user has seen the model migration prompt
     pub hide_gpt5_1_migration_prompt: Option<bool>,
     /// Tracks whether the user has seen the gpt-5.1-codex-max migration prompt
@@ -530,6 +922,9 @@ pub struct Notice {
     /// Tracks acknowledged model migrations as old->new model slug mappings.
     #[serde(default)]
     pub model_migrations: BTreeMap<String, String>,
+    /// Tracks models whose `tui.model_switch_notes` note has already been shown.
+    #[serde(default)]
+    pub model_switch_notes_seen: BTreeMap<String, bool>,
 }
 
 impl Notice {
@@ -941,4 +1336,20 @@ mod tests {
             "unexpected error: {err}"
         );
     }
+
+    #[test]
+    fn tui_cell_spacing_accepts_only_zero_or_one() {
+        for spacing in [0, 1] {
+            let tui: Tui = toml::from_str(&format!("cell_spacing = {spacing}"))
+                .expect("cell_spacing 0 or 1 should parse");
+            assert_eq!(tui.cell_spacing, Some(spacing));
+        }
+
+        let err = toml::from_str::<Tui>("cell_spacing = 500")
+            .expect_err("cell_spacing above 1 should be rejected");
+        assert!(
+            err.to_string().contains("expected 0 or 1"),
+            "unexpected error: {err}"
+        );
+    }
 }
@@ -92,6 +92,10 @@ pub enum Feature {
     Tui2,
     /// Enable discovery and injection of skills.
     Skills,
+    /// Warn about skills that share the same description.
+    SkillsLint,
+    /// Write skill load errors as JSON to `CODEX_HOME/skills-diagnostics.json`.
+    SkillsDiagnostics,
     /// Enforce UTF8 output in Powershell.
     PowershellUtf8,
 }
@@ -393,6 +397,18 @@ pub const FEATURES: &[FeatureSpec] = &[
         stage: Stage::Experimental,
         default_enabled: true,
     },
+    FeatureSpec {
+        id: Feature::SkillsLint,
+        key: "skills_lint",
+        stage: Stage::Experimental,
+        default_enabled: false,
+    },
+    FeatureSpec {
+        id: Feature::SkillsDiagnostics,
+        key: "skills_diagnostics",
+        stage: Stage::Experimental,
+        default_enabled: false,
+    },
     FeatureSpec {
         id: Feature::PowershellUtf8,
         key: "powershell_utf8",
@@ -0,0 +1,537 @@
+//! Skills distributed as archives.
+//!
+//! Teams can list `.zip`, `.tar.gz`, or `.tgz` files under `skills_bundles` in `config.toml`. Each
+//! archive is extracted into `CODEX_HOME/skills/.bundles/<archive stem>` (next to the `.system`
+//! cache for embedded skills) and the extracted tree is loaded as the bundle skill root.
+//!
+//! Extraction is skipped while the archive's size and modification time match the marker written
+//! by the previous extraction. Entries that would land outside the extraction root (absolute
+//! paths, `..` components) fail the whole bundle, and links are never materialized. An archive
+//! with more than [`MAX_BUNDLE_ENTRIES`] entries or more than [`MAX_BUNDLE_BYTES`] of extracted
+//! content is rejected as well, so a single archive cannot fill the disk.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::fs::File;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::io;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::UNIX_EPOCH;
+
+use flate2::read::GzDecoder;
+use thiserror::Error;
+
+const SKILLS_DIR_NAME: &str = "skills";
+const BUNDLE_CACHE_DIR_NAME: &str = ".bundles";
+const BUNDLE_MARKER_FILENAME: &str = ".codex-skills-bundle.marker";
+const BUNDLE_MARKER_SALT: &str = "v1";
+
+/// Most entries (files, directories, skipped links) a single archive may contain.
+pub(crate) const MAX_BUNDLE_ENTRIES: usize = 10_000;
+/// Most bytes of file content a single archive may extract to.
+pub(crate) const MAX_BUNDLE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Directory archives are extracted into, typically `CODEX_HOME/skills/.bundles`.
+pub(crate) fn bundle_cache_root_dir(codex_home: &Path) -> PathBuf {
+    codex_home.join(SKILLS_DIR_NAME).join(BUNDLE_CACHE_DIR_NAME)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BundleFormat {
+    Zip,
+    TarGz,
+}
+
+impl BundleFormat {
+    /// Detects the archive format and returns it with the file name minus its extension.
+    fn from_path(path: &Path) -> Option<(Self, String)> {
+        let name = path.file_name()?.to_str()?;
+        let lower = name.to_ascii_lowercase();
+        let (format, ext_len) = if lower.ends_with(".zip") {
+            (Self::Zip, ".zip".len())
+        } else if lower.ends_with(".tar.gz") {
+            (Self::TarGz, ".tar.gz".len())
+        } else if lower.ends_with(".tgz") {
+            (Self::TarGz, ".tgz".len())
+        } else {
+            return None;
+        };
+        let stem = &name[..name.len() - ext_len];
+        (!stem.is_empty() && !stem.starts_with('.')).then(|| (format, stem.to_string()))
+    }
+}
+
+/// Extracts every archive in `archives` that changed since it was last extracted and removes
+/// extracted trees whose archive is no longer configured.
+///
+/// A bad archive is logged and skipped so it cannot prevent the other bundles from loading.
+pub(crate) fn install_skill_bundles(
+    codex_home: &Path,
+    archives: &[PathBuf],
+) -> Result<(), SkillBundleError> {
+    let cache_root = bundle_cache_root_dir(codex_home);
+    let mut installed: Vec<String> = Vec::new();
+    for archive in archives {
+        if !archive.is_file() {
+            tracing::error!("skills bundle {} is not a file", archive.display());
+            continue;
+        }
+        let Some((format, stem)) = BundleFormat::from_path(archive) else {
+            tracing::error!(
+                "skills bundle {} is not a .zip, .tar.gz, or .tgz archive",
+                archive.display()
+            );
+            continue;
+        };
+        if installed.contains(&stem) {
+            tracing::error!(
+                "skills bundle {} has the same name as another configured bundle",
+                archive.display()
+            );
+            continue;
+        }
+        match install_skill_bundle(
+            archive,
+            format,
+            &cache_root.join(&stem),
+            ExtractionLimits::default(),
+        ) {
+            Ok(()) => installed.push(stem),
+            Err(err) => {
+                tracing::error!(
+                    "failed to install skills bundle {}: {err}",
+                    archive.display()
+                );
+            }
+        }
+    }
+
+    remove_stale_bundles(&cache_root, &installed)
+}
+
+fn install_skill_bundle(
+    archive: &Path,
+    format: BundleFormat,
+    dest: &Path,
+    limits: ExtractionLimits,
+) -> Result<(), SkillBundleError> {
+    let fingerprint = archive_fingerprint(archive)?;
+    let marker_path = dest.join(BUNDLE_MARKER_FILENAME);
+    if dest.is_dir()
+        && fs::read_to_string(&marker_path).is_ok_and(|marker| marker.trim() == fingerprint)
+    {
+        return Ok(());
+    }
+
+    if dest.exists() {
+        fs::remove_dir_all(dest)
+            .map_err(|source| SkillBundleError::io("remove existing bundle dir", source))?;
+    }
+    fs::create_dir_all(dest).map_err(|source| SkillBundleError::io("create bundle dir", source))?;
+
+    let extracted = match format {
+        BundleFormat::Zip => extract_zip(archive, dest, limits),
+        BundleFormat::TarGz => extract_tar_gz(archive, dest, limits),
+    };
+    if let Err(err) = extracted {
+        // Never leave a partially extracted bundle behind to be loaded.
+        let _ = fs::remove_dir_all(dest);
+        return Err(err);
+    }
+
+    fs::write(&marker_path, format!("{fingerprint}\n"))
+        .map_err(|source| SkillBundleError::io("write bundle marker", source))?;
+    Ok(())
+}
+
+/// Size and modification time of the archive; a change to either triggers re-extraction.
+fn archive_fingerprint(archive: &Path) -> Result<String, SkillBundleError> {
+    let metadata = fs::metadata(archive)
+        .map_err(|source| SkillBundleError::io("stat skills bundle", source))?;
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map(|modified| modified.as_nanos());
+
+    let mut hasher = DefaultHasher::new();
+    BUNDLE_MARKER_SALT.hash(&mut hasher);
+    metadata.len().hash(&mut hasher);
+    modified.hash(&mut hasher);
+    Ok(format!("{:x}", hasher.finish()))
+}
+
+fn remove_stale_bundles(cache_root: &Path, installed: &[String]) -> Result<(), SkillBundleError> {
+    let entries = match fs::read_dir(cache_root) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(source) => return Err(SkillBundleError::io("read bundle cache dir", source)),
+    };
+    for entry in entries.flatten() {
+        let keep = entry
+            .file_name()
+            .to_str()
+            .is_some_and(|name| installed.iter().any(|stem| stem == name));
+        if !keep && entry.path().is_dir() {
+            fs::remove_dir_all(entry.path())
+                .map_err(|source| SkillBundleError::io("remove stale bundle dir", source))?;
+        }
+    }
+    Ok(())
+}
+
+/// Maps an archive entry name to a path relative to the extraction root, rejecting anything that
+/// could resolve outside of it (zip-slip). Entries naming the root itself (`./`) map to an empty
+/// path and are skipped by the callers.
+fn safe_entry_path(name: &Path) -> Result<PathBuf, SkillBundleError> {
+    let mut relative = PathBuf::new();
+    for component in name.components() {
+        match component {
+            Component::Normal(part) => relative.push(part),
+            Component::CurDir => {}
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => {
+                return Err(SkillBundleError::UnsafeEntry {
+                    entry: name.display().to_string(),
+                });
+            }
+        }
+    }
+    Ok(relative)
+}
+
+/// How much an archive may extract before it is rejected.
+#[derive(Debug, Clone, Copy)]
+struct ExtractionLimits {
+    max_entries: usize,
+    max_bytes: u64,
+}
+
+impl Default for ExtractionLimits {
+    fn default() -> Self {
+        Self {
+            max_entries: MAX_BUNDLE_ENTRIES,
+            max_bytes: MAX_BUNDLE_BYTES,
+        }
+    }
+}
+
+/// What is left of [`ExtractionLimits`] while an archive is being extracted.
+struct ExtractionBudget {
+    limits: ExtractionLimits,
+    entries: usize,
+    bytes: u64,
+}
+
+impl ExtractionBudget {
+    fn new(limits: ExtractionLimits) -> Self {
+        Self {
+            limits,
+            entries: limits.max_entries,
+            bytes: limits.max_bytes,
+        }
+    }
+
+    fn take_entry(&mut self) -> Result<(), SkillBundleError> {
+        self.entries = self
+            .entries
+            .checked_sub(1)
+            .ok_or(SkillBundleError::TooManyEntries {
+                limit: self.limits.max_entries,
+            })?;
+        Ok(())
+    }
+
+    fn take_bytes(&mut self, bytes: u64) -> Result<(), SkillBundleError> {
+        self.bytes = self
+            .bytes
+            .checked_sub(bytes)
+            .ok_or(SkillBundleError::TooLarge {
+                limit: self.limits.max_bytes,
+            })?;
+        Ok(())
+    }
+}
+
+fn write_entry(
+    dest: &Path,
+    relative: &Path,
+    reader: &mut impl io::Read,
+    budget: &mut ExtractionBudget,
+) -> Result<(), SkillBundleError> {
+    let path = dest.join(relative);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|source| SkillBundleError::io("create bundle file parent", source))?;
+    }
+    let mut file =
+        File::create(&path).map_err(|source| SkillBundleError::io("create bundle file", source))?;
+    // Read one byte past the budget so an entry that overruns it is detected without trusting the
+    // size recorded in the archive.
+    let written = io::copy(&mut reader.take(budget.bytes + 1), &mut file)
+        .map_err(|source| SkillBundleError::io("write bundle file", source))?;
+    budget.take_bytes(written)
+}
+
+fn extract_zip(
+    archive: &Path,
+    dest: &Path,
+    limits: ExtractionLimits,
+) -> Result<(), SkillBundleError> {
+    let file =
+        File::open(archive).map_err(|source| SkillBundleError::io("open skills bundle", source))?;
+    let mut zip = zip::ZipArchive::new(file)?;
+    if zip.len() > limits.max_entries {
+        return Err(SkillBundleError::TooManyEntries {
+            limit: limits.max_entries,
+        });
+    }
+    let mut budget = ExtractionBudget::new(limits);
+    for index in 0..zip.len() {
+        budget.take_entry()?;
+        let mut entry = zip.by_index(index)?;
+        let relative = safe_entry_path(Path::new(entry.name()))?;
+        if relative.as_os_str().is_empty() || entry.is_symlink() {
+            continue;
+        }
+        if entry.is_dir() {
+            fs::create_dir_all(dest.join(&relative))
+                .map_err(|source| SkillBundleError::io("create bundle dir entry", source))?;
+        } else {
+            write_entry(dest, &relative, &mut entry, &mut budget)?;
+        }
+    }
+    Ok(())
+}
+
+fn extract_tar_gz(
+    archive: &Path,
+    dest: &Path,
+    limits: ExtractionLimits,
+) -> Result<(), SkillBundleError> {
+    let file =
+        File::open(archive).map_err(|source| SkillBundleError::io("open skills bundle", source))?;
+    let mut tar = tar::Archive::new(GzDecoder::new(file));
+    let entries = tar
+        .entries()
+        .map_err(|source| SkillBundleError::io("read tar entries", source))?;
+    let mut budget = ExtractionBudget::new(limits);
+    for entry in entries {
+        budget.take_entry()?;
+        let mut entry = entry.map_err(|source| SkillBundleError::io("read tar entry", source))?;
+        let name = entry
+            .path()
+            .map_err(|source| SkillBundleError::io("read tar entry path", source))?
+            .into_owned();
+        let relative = safe_entry_path(&name)?;
+        if relative.as_os_str().is_empty() {
+            continue;
+        }
+        match entry.header().entry_type() {
+            tar::EntryType::Directory => {
+                fs::create_dir_all(dest.join(&relative))
+                    .map_err(|source| SkillBundleError::io("create bundle dir entry", source))?;
+            }
+            tar::EntryType::Regular | tar::EntryType::Continuous => {
+                write_entry(dest, &relative, &mut entry, &mut budget)?;
+            }
+            // Links and special files could point outside the root; skills never need them.
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub(crate) enum SkillBundleError {
+    #[error("io error while {action}: {source}")]
+    Io {
+        action: &'static str,
+        #[source]
+        source: io::Error,
+    },
+    #[error("invalid zip archive: {0}")]
+    Zip(#[from] zip::result::ZipError),
+    #[error("archive entry `{entry}` escapes the extraction root")]
+    UnsafeEntry { entry: String },
+    #[error("archive has more than {limit} entries")]
+    TooManyEntries { limit: usize },
+    #[error("archive extracts to more than {limit} bytes")]
+    TooLarge { limit: u64 },
+}
+
+impl SkillBundleError {
+    fn io(action: &'static str, source: io::Error) -> Self {
+        Self::Io { action, source }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skills::loader::SkillRoot;
+    use crate::skills::loader::load_skills_from_roots;
+    use codex_protocol::protocol::SkillScope;
+    use pretty_assertions::assert_eq;
+    use std::io::Write;
+
+    const SKILL_MD: &str =
+        "---\nname: bundled-skill\ndescription: ships in a bundle\n---\n\n# Body\n";
+
+    fn write_zip(path: &Path, entries: &[(&str, &str)]) {
+        let mut zip = zip::ZipWriter::new(File::create(path).unwrap());
+        for (name, contents) in entries {
+            zip.start_file(*name, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            zip.write_all(contents.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+    }
+
+    fn write_tar_gz(path: &Path, entries: &[(&str, &str)]) {
+        let encoder =
+            flate2::write::GzEncoder::new(File::create(path).unwrap(), flate2::Compression::fast());
+        let mut tar = tar::Builder::new(encoder);
+        for (name, contents) in entries {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            tar.append_data(&mut header, name, contents.as_bytes())
+                .unwrap();
+        }
+        tar.into_inner().unwrap().finish().unwrap();
+    }
+
+    #[test]
+    fn rejects_zip_slip_entries() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let archive = codex_home.path().join("evil.zip");
+        write_zip(
+            &archive,
+            &[
+                ("ok/SKILL.md", SKILL_MD),
+                ("../../escaped/SKILL.md", SKILL_MD),
+            ],
+        );
+
+        let dest = bundle_cache_root_dir(codex_home.path()).join("evil");
+        let err = install_skill_bundle(
+            &archive,
+            BundleFormat::Zip,
+            &dest,
+            ExtractionLimits::default(),
+        )
+        .expect_err("zip-slip entry is rejected");
+        assert!(
+            matches!(err, SkillBundleError::UnsafeEntry { .. }),
+            "unexpected error: {err}"
+        );
+        assert!(!dest.exists(), "partial extraction is cleaned up");
+        assert!(!codex_home.path().join("escaped").exists());
+        assert!(!codex_home.path().join("skills/escaped").exists());
+    }
+
+    #[test]
+    fn archives_over_the_limits_are_rejected_and_cleaned_up() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let limits = ExtractionLimits {
+            max_entries: 2,
+            max_bytes: 8,
+        };
+
+        let many = codex_home.path().join("many.tar.gz");
+        write_tar_gz(&many, &[("a", "1"), ("b", "2"), ("c", "3")]);
+        let dest = bundle_cache_root_dir(codex_home.path()).join("many");
+        let err = install_skill_bundle(&many, BundleFormat::TarGz, &dest, limits)
+            .expect_err("too many entries");
+        assert!(
+            matches!(err, SkillBundleError::TooManyEntries { limit: 2 }),
+            "unexpected error: {err}"
+        );
+        assert!(!dest.exists());
+
+        let large = codex_home.path().join("large.zip");
+        write_zip(&large, &[("a", "12345"), ("b", "6789")]);
+        let dest = bundle_cache_root_dir(codex_home.path()).join("large");
+        let err = install_skill_bundle(&large, BundleFormat::Zip, &dest, limits)
+            .expect_err("too many bytes");
+        assert!(
+            matches!(err, SkillBundleError::TooLarge { limit: 8 }),
+            "unexpected error: {err}"
+        );
+        assert!(!dest.exists());
+    }
+
+    #[test]
+    fn safe_entry_path_rejects_escapes() {
+        assert!(safe_entry_path(Path::new("../x")).is_err());
+        assert!(safe_entry_path(Path::new("a/../../x")).is_err());
+        assert!(safe_entry_path(Path::new("/etc/passwd")).is_err());
+        assert_eq!(safe_entry_path(Path::new("./")).unwrap(), PathBuf::new());
+        assert_eq!(
+            safe_entry_path(Path::new("./demo/SKILL.md")).unwrap(),
+            PathBuf::from("demo/SKILL.md")
+        );
+    }
+
+    #[test]
+    fn bundled_skills_are_discovered_after_extraction() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let archive = codex_home.path().join("team.tar.gz");
+        write_tar_gz(&archive, &[("demo/SKILL.md", SKILL_MD)]);
+
+        install_skill_bundles(codex_home.path(), &[archive]).expect("install bundles");
+
+        let outcome = load_skills_from_roots([SkillRoot {
+            path: bundle_cache_root_dir(codex_home.path()),
+            scope: SkillScope::Bundle,
+        }]);
+        assert!(
+            outcome.errors.is_empty(),
+            "unexpected errors: {:?}",
+            outcome.errors
+        );
+        let names: Vec<&str> = outcome.skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["bundled-skill"]);
+        let path = outcome.skills[0].path.to_string_lossy().replace('\\', "/");
+        assert!(
+            path.ends_with("skills/.bundles/team/demo/SKILL.md"),
+            "{path}"
+        );
+    }
+
+    #[test]
+    fn unchanged_archive_is_not_reextracted_and_removed_archive_is_pruned() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let archive = codex_home.path().join("team.zip");
+        let archives = [archive.clone()];
+        write_zip(&archive, &[("demo/SKILL.md", SKILL_MD)]);
+
+        install_skill_bundles(codex_home.path(), &archives).expect("install bundles");
+        let extracted = bundle_cache_root_dir(codex_home.path()).join("team/demo/SKILL.md");
+        fs::remove_file(&extracted).unwrap();
+
+        install_skill_bundles(codex_home.path(), &archives).expect("reinstall bundles");
+        assert!(
+            !extracted.exists(),
+            "marker matched, so nothing was rewritten"
+        );
+
+        write_zip(
+            &archive,
+            &[("demo/SKILL.md", SKILL_MD), ("demo/notes.md", "more")],
+        );
+        install_skill_bundles(codex_home.path(), &archives).expect("reinstall changed bundle");
+        assert!(extracted.exists(), "a changed archive is extracted again");
+
+        install_skill_bundles(codex_home.path(), &[]).expect("prune bundles");
+        assert!(
+            !bundle_cache_root_dir(codex_home.path())
+                .join("team")
+                .exists()
+        );
+    }
+}
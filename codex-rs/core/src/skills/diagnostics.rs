@@ -0,0 +1,70 @@
+//! Machine-readable skill load diagnostics (`skills_diagnostics` feature).
+//!
+//! The TUI shows skill load errors as transcript warnings, which CI and other automation cannot
+//! read. With the feature enabled, every skill (re)load also writes its errors as JSON to
+//! [`SKILLS_DIAGNOSTICS_FILE`] under `CODEX_HOME`, replacing the previous report.
+
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+use tempfile::NamedTempFile;
+
+use crate::skills::SkillError;
+
+/// Diagnostics file name, relative to `CODEX_HOME`.
+pub const SKILLS_DIAGNOSTICS_FILE: &str = "skills-diagnostics.json";
+
+/// Contents of [`SKILLS_DIAGNOSTICS_FILE`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkillsDiagnostics {
+    /// Working directory the skills were loaded for.
+    pub cwd: PathBuf,
+    pub errors: Vec<SkillErrorDiagnostic>,
+}
+
+/// One skill that failed to load.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkillErrorDiagnostic {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+impl From<&SkillError> for SkillErrorDiagnostic {
+    fn from(error: &SkillError) -> Self {
+        Self {
+            path: error.path.clone(),
+            message: error.message.clone(),
+        }
+    }
+}
+
+/// Path of the diagnostics file for `codex_home`.
+pub fn skills_diagnostics_path(codex_home: &Path) -> PathBuf {
+    codex_home.join(SKILLS_DIAGNOSTICS_FILE)
+}
+
+/// Replace the diagnostics file with `errors` from loading skills for `cwd`.
+///
+/// The report is written to a temporary file first and renamed into place, so readers never
+/// observe a partially written file.
+pub fn write_skills_diagnostics(
+    codex_home: &Path,
+    cwd: &Path,
+    errors: &[SkillError],
+) -> io::Result<PathBuf> {
+    let diagnostics = SkillsDiagnostics {
+        cwd: cwd.to_path_buf(),
+        errors: errors.iter().map(SkillErrorDiagnostic::from).collect(),
+    };
+    let json = serde_json::to_string_pretty(&diagnostics).map_err(io::Error::other)?;
+
+    std::fs::create_dir_all(codex_home)?;
+    let tmp = NamedTempFile::new_in(codex_home)?;
+    std::fs::write(tmp.path(), json)?;
+    let path = skills_diagnostics_path(codex_home);
+    tmp.persist(&path).map_err(|err| err.error)?;
+    Ok(path)
+}
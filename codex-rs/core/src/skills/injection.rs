@@ -1,11 +1,15 @@
 use std::collections::HashSet;
+use std::path::Path;
 
-use crate::skills::SkillLoadOutcome;
+use crate::skills::SkillLookup;
 use crate::skills::SkillMetadata;
+use crate::skills::SkillNameIndex;
 use crate::user_instructions::SkillInstructions;
+use codex_protocol::models::ContentItem;
 use codex_protocol::models::ResponseItem;
 use codex_protocol::user_input::UserInput;
 use tokio::fs;
+use wildmatch::WildMatch;
 
 #[derive(Debug, Default)]
 pub(crate) struct SkillInjections {
@@ -13,35 +17,69 @@ pub(crate) struct SkillInjections {
     pub(crate) warnings: Vec<String>,
 }
 
+/// Builds the instruction items for the skills mentioned in `inputs`.
+///
+/// A mention whose name matches several skills once normalized is skipped with a warning listing
+/// the candidates. Skills whose `metadata.applies_to` markers match nothing in `cwd` are skipped with a warning.
+/// When `available_tools` is known, a skill listing `allowed-tools` the session does not offer is
+/// still injected, with a warning naming the missing tools.
 pub(crate) async fn build_skill_injections(
     inputs: &[UserInput],
-    skills: Option<&SkillLoadOutcome>,
+    skills: Option<&SkillNameIndex>,
+    cwd: &Path,
+    available_tools: Option<&HashSet<String>>,
 ) -> SkillInjections {
     if inputs.is_empty() {
         return SkillInjections::default();
     }
 
-    let Some(outcome) = skills else {
+    let Some(index) = skills else {
         return SkillInjections::default();
     };
 
-    let mentioned_skills = collect_explicit_skill_mentions(inputs, &outcome.skills);
-    if mentioned_skills.is_empty() {
+    let (mentioned_skills, warnings) = collect_explicit_skill_mentions(inputs, index);
+    if mentioned_skills.is_empty() && warnings.is_empty() {
         return SkillInjections::default();
     }
 
     let mut result = SkillInjections {
         items: Vec::with_capacity(mentioned_skills.len()),
-        warnings: Vec::new(),
+        warnings,
     };
 
     for skill in mentioned_skills {
+        if !skill_applies_to_project(&skill, cwd).await {
+            result.warnings.push(format!(
+                "Skipped skill {}: it only applies to projects containing {}",
+                skill.name,
+                skill.applies_to.join(", ")
+            ));
+            continue;
+        }
+        if let Some(available_tools) = available_tools {
+            let missing: Vec<&str> = skill
+                .allowed_tools
+                .iter()
+                .filter(|tool| !available_tools.contains(tool.as_str()))
+                .map(String::as_str)
+                .collect();
+            if !missing.is_empty() {
+                result.warnings.push(format!(
+                    "Skill {} expects tools that are not available in this session: {}",
+                    skill.name,
+                    missing.join(", ")
+                ));
+            }
+        }
         match fs::read_to_string(&skill.path).await {
             Ok(contents) => {
                 result.items.push(ResponseItem::from(SkillInstructions {
+                    invocation_hint: skill.invocation_hint(),
                     name: skill.name,
                     path: skill.path.to_string_lossy().into_owned(),
                     contents,
+                    allowed_tools: skill.allowed_tools,
+                    version: skill.version,
                 }));
             }
             Err(err) => {
@@ -58,21 +96,295 @@ pub(crate) async fn build_skill_injections(
     result
 }
 
+/// Whether `cwd` contains one of the skill's `applies_to` markers (always true without markers).
+///
+/// Plain markers are paths relative to `cwd`; markers with `*` or `?` are matched against the
+/// names of the entries directly in `cwd`.
+async fn skill_applies_to_project(skill: &SkillMetadata, cwd: &Path) -> bool {
+    if skill.applies_to.is_empty() {
+        return true;
+    }
+    let mut entry_names: Option<Vec<String>> = None;
+    for marker in &skill.applies_to {
+        if !marker.contains(['*', '?']) {
+            if fs::try_exists(cwd.join(marker)).await.unwrap_or(false) {
+                return true;
+            }
+            continue;
+        }
+        if entry_names.is_none() {
+            entry_names = Some(read_entry_names(cwd).await);
+        }
+        let pattern = WildMatch::new(marker);
+        if entry_names
+            .iter()
+            .flatten()
+            .any(|name| pattern.matches(name))
+        {
+            return true;
+        }
+    }
+    false
+}
+
+async fn read_entry_names(dir: &Path) -> Vec<String> {
+    let mut names = Vec::new();
+    let Ok(mut entries) = fs::read_dir(dir).await else {
+        return names;
+    };
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        names.push(entry.file_name().to_string_lossy().into_owned());
+    }
+    names
+}
+
+/// Renders what [`build_skill_injections`] adds to a turn in `cwd` that mentions every skill in
+/// `skills`: the exact text of each injected item, separated by blank lines, followed by the
+/// warnings the turn would surface, except for unavailable `allowed-tools`, which depend on the
+/// session. Backs the TUI's `/skills-preview`.
+pub async fn preview_skill_injections(skills: &[SkillMetadata], cwd: &Path) -> String {
+    let inputs: Vec<UserInput> = skills
+        .iter()
+        .map(|skill| UserInput::Skill {
+            name: skill.name.clone(),
+            path: skill.path.clone(),
+        })
+        .collect();
+    let index = SkillNameIndex::new(skills);
+    let SkillInjections { items, warnings } =
+        build_skill_injections(&inputs, Some(&index), cwd, None).await;
+
+    let mut sections: Vec<String> = items.iter().filter_map(injected_text).collect();
+    sections.extend(
+        warnings
+            .into_iter()
+            .map(|warning| format!("warning: {warning}")),
+    );
+    sections.join("\n\n")
+}
+
+fn injected_text(item: &ResponseItem) -> Option<String> {
+    let ResponseItem::Message { content, .. } = item else {
+        return None;
+    };
+    let text: Vec<&str> = content
+        .iter()
+        .filter_map(|content| match content {
+            ContentItem::InputText { text } => Some(text.as_str()),
+            _ => None,
+        })
+        .collect();
+    Some(text.join("\n"))
+}
+
+/// Resolves the skills named in `inputs`, returning them with a warning per ambiguous mention.
 fn collect_explicit_skill_mentions(
     inputs: &[UserInput],
-    skills: &[SkillMetadata],
-) -> Vec<SkillMetadata> {
+    index: &SkillNameIndex,
+) -> (Vec<SkillMetadata>, Vec<String>) {
     let mut selected: Vec<SkillMetadata> = Vec::new();
+    let mut warnings: Vec<String> = Vec::new();
     let mut seen: HashSet<String> = HashSet::new();
 
     for input in inputs {
-        if let UserInput::Skill { name, path } = input
-            && seen.insert(name.clone())
-            && let Some(skill) = skills.iter().find(|s| s.name == *name && s.path == *path)
-        {
-            selected.push(skill.clone());
+        let UserInput::Skill { name, .. } = input else {
+            continue;
+        };
+        // An exact canonical name wins; otherwise accept a name that differs only in case or
+        // separators (`$My_Skill` for `my-skill`), as long as it is unambiguous.
+        match index.lookup(name) {
+            SkillLookup::Found(skill) => {
+                if seen.insert(skill.name.clone()) {
+                    selected.push(skill);
+                }
+            }
+            SkillLookup::Ambiguous(candidates) => {
+                if seen.insert(name.clone()) {
+                    warnings.push(format!(
+                        "Skipped skill ${name}: it matches several skills ({}); use the exact name",
+                        candidates.join(", ")
+                    ));
+                }
+            }
+            SkillLookup::NotFound => {}
         }
     }
 
-    selected
+    (selected, warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::protocol::SkillScope;
+    use pretty_assertions::assert_eq;
+    use std::path::Path;
+
+    fn skill_at(root: &Path, name: &str, body: Option<&str>) -> SkillMetadata {
+        let path = root.join(name).join("SKILL.md");
+        if let Some(body) = body {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, body).unwrap();
+        }
+        SkillMetadata {
+            name: name.to_string(),
+            description: format!("{name} description"),
+            short_description: None,
+            allowed_tools: Vec::new(),
+            priority: 0,
+            version: None,
+            license: None,
+            applies_to: Vec::new(),
+            path,
+            scope: SkillScope::User,
+        }
+    }
+
+    #[tokio::test]
+    async fn preview_matches_injected_items_and_warnings() {
+        let root = tempfile::tempdir().expect("tempdir");
+        let skills = vec![
+            skill_at(root.path(), "alpha", Some("alpha body")),
+            skill_at(root.path(), "beta", Some("beta body")),
+            skill_at(root.path(), "missing", None),
+        ];
+        let inputs: Vec<UserInput> = skills
+            .iter()
+            .map(|skill| UserInput::Skill {
+                name: skill.name.clone(),
+                path: skill.path.clone(),
+            })
+            .collect();
+        let index = SkillNameIndex::new(&skills);
+        let injections = build_skill_injections(&inputs, Some(&index), root.path(), None).await;
+        assert_eq!(injections.items.len(), 2);
+        assert_eq!(injections.warnings.len(), 1);
+
+        let mut expected: Vec<String> = injections.items.iter().filter_map(injected_text).collect();
+        expected.push(format!("warning: {}", injections.warnings[0]));
+
+        let preview = preview_skill_injections(&skills, root.path()).await;
+        assert_eq!(preview, expected.join("\n\n"));
+        assert!(preview.starts_with("<skill>\n<name>alpha</name>"));
+        assert!(preview.contains("beta body"));
+        assert!(preview.contains("warning: Failed to load skill missing"));
+    }
+
+    #[test]
+    fn explicit_mentions_resolve_case_and_separator_variants() {
+        let root = tempfile::tempdir().expect("tempdir");
+        let my_skill = skill_at(root.path(), "my-skill", None);
+        let skills = vec![
+            my_skill.clone(),
+            skill_at(root.path(), "dup-name", None),
+            skill_at(root.path(), "dup_name", None),
+        ];
+        let mention = |name: &str| UserInput::Skill {
+            name: name.to_string(),
+            path: root.path().join("unknown").join("SKILL.md"),
+        };
+
+        let inputs = vec![
+            mention("My_Skill"),
+            mention("my skill"),
+            mention("Dup-Name"),
+        ];
+        assert_eq!(
+            collect_explicit_skill_mentions(&inputs, &SkillNameIndex::new(&skills)),
+            (
+                vec![my_skill],
+                vec![
+                    "Skipped skill $Dup-Name: it matches several skills (dup-name, dup_name); \
+                     use the exact name"
+                        .to_string()
+                ]
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn warns_about_allowed_tools_missing_from_the_session() {
+        let root = tempfile::tempdir().expect("tempdir");
+        let mut skill = skill_at(root.path(), "deploy", Some("deploy body"));
+        skill.allowed_tools = vec![
+            "shell".to_string(),
+            "mcp__k8s__apply".to_string(),
+            "web_search".to_string(),
+        ];
+        let inputs = vec![UserInput::Skill {
+            name: skill.name.clone(),
+            path: skill.path.clone(),
+        }];
+        let index = SkillNameIndex::new(&[skill]);
+        let available_tools: HashSet<String> = ["shell", "apply_patch"]
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+
+        let injections =
+            build_skill_injections(&inputs, Some(&index), root.path(), Some(&available_tools))
+                .await;
+        assert_eq!(injections.items.len(), 1);
+        assert_eq!(
+            injections.warnings,
+            vec![
+                "Skill deploy expects tools that are not available in this session: \
+                 mcp__k8s__apply, web_search"
+                    .to_string()
+            ]
+        );
+
+        let unknown_tools = build_skill_injections(&inputs, Some(&index), root.path(), None).await;
+        assert_eq!(unknown_tools.warnings, Vec::<String>::new());
+    }
+
+    #[tokio::test]
+    async fn preview_is_empty_without_skills() {
+        let cwd = tempfile::tempdir().expect("tempdir");
+        assert_eq!(preview_skill_injections(&[], cwd.path()).await, "");
+    }
+
+    #[tokio::test]
+    async fn rust_only_skill_is_injected_only_in_cargo_projects() {
+        let root = tempfile::tempdir().expect("tempdir");
+        let mut skill = skill_at(root.path(), "rust-review", Some("rust body"));
+        skill.applies_to = vec!["Cargo.toml".to_string()];
+        let inputs = vec![UserInput::Skill {
+            name: skill.name.clone(),
+            path: skill.path.clone(),
+        }];
+        let index = SkillNameIndex::new(&[skill]);
+
+        let rust_repo = tempfile::tempdir().expect("tempdir");
+        std::fs::write(rust_repo.path().join("Cargo.toml"), "[package]\n").unwrap();
+        let injections =
+            build_skill_injections(&inputs, Some(&index), rust_repo.path(), None).await;
+        assert_eq!(injections.items.len(), 1);
+        assert_eq!(injections.warnings, Vec::<String>::new());
+
+        let node_repo = tempfile::tempdir().expect("tempdir");
+        std::fs::write(node_repo.path().join("package.json"), "{}\n").unwrap();
+        let injections =
+            build_skill_injections(&inputs, Some(&index), node_repo.path(), None).await;
+        assert_eq!(injections.items.len(), 0);
+        assert_eq!(
+            injections.warnings,
+            vec![
+                "Skipped skill rust-review: it only applies to projects containing Cargo.toml"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn glob_markers_match_entries_in_cwd() {
+        let root = tempfile::tempdir().expect("tempdir");
+        let mut skill = skill_at(root.path(), "haskell", Some("haskell body"));
+        skill.applies_to = vec!["stack.yaml".to_string(), "*.cabal".to_string()];
+
+        let project = tempfile::tempdir().expect("tempdir");
+        assert!(!skill_applies_to_project(&skill, project.path()).await);
+        std::fs::write(project.path().join("demo.cabal"), "").unwrap();
+        assert!(skill_applies_to_project(&skill, project.path()).await);
+    }
 }
@@ -1,5 +1,6 @@
 use crate::config::Config;
 use crate::git_info::resolve_root_git_project_for_trust;
+use crate::skills::bundle::bundle_cache_root_dir;
 use crate::skills::model::SkillError;
 use crate::skills::model::SkillLoadOutcome;
 use crate::skills::model::SkillMetadata;
@@ -7,6 +8,8 @@ use crate::skills::system::system_cache_root_dir;
 use codex_protocol::protocol::SkillScope;
 use dunce::canonicalize as normalize_path;
 use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::error::Error;
@@ -14,7 +17,12 @@ use std::fmt;
 use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::mpsc;
+use std::sync::mpsc::RecvTimeoutError;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
 use tracing::error;
+use tracing::warn;
 
 #[derive(Debug, Deserialize)]
 struct SkillFrontmatter {
@@ -28,6 +36,18 @@ struct SkillFrontmatter {
 struct SkillFrontmatterMetadata {
     #[serde(default, rename = "short-description")]
     short_description: Option<String>,
+    #[serde(default, rename = "allowed-tools")]
+    allowed_tools: Option<Vec<String>>,
+    #[serde(default)]
+    priority: Option<i64>,
+    /// Kept as a raw YAML value so an unquoted number (`version: 1.10`, which YAML reads as
+    /// `1.1`) can be reported instead of failing the whole frontmatter.
+    #[serde(default)]
+    version: Option<serde_yaml::Value>,
+    #[serde(default)]
+    applies_to: Option<Vec<String>>,
+    #[serde(default)]
+    license: Option<String>,
 }
 
 const SKILLS_FILENAME: &str = "SKILL.md";
@@ -36,7 +56,37 @@ const REPO_ROOT_CONFIG_DIR_NAME: &str = ".codex";
 const ADMIN_SKILLS_ROOT: &str = "/etc/codex/skills";
 const MAX_NAME_LEN: usize = 64;
 const MAX_DESCRIPTION_LEN: usize = 1024;
+/// Descriptions shorter than this are too generic for the duplicate-description lint to flag.
+const MIN_LINTED_DESCRIPTION_LEN: usize = 20;
 const MAX_SHORT_DESCRIPTION_LEN: usize = MAX_DESCRIPTION_LEN;
+const MAX_PRIORITY: i32 = 1000;
+const MAX_VERSION_LEN: usize = 32;
+const MAX_LICENSE_LEN: usize = 64;
+const DEFAULT_MAX_SCAN_DEPTH: usize = 16;
+const DEFAULT_MAX_SCANNED_ENTRIES: usize = 20_000;
+/// How long skill loading waits for `git` to report the project root before falling back to the
+/// cwd-only search.
+const GIT_ROOT_RESOLVE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Bounds on how much of a skills root is walked while discovering skills, so
+/// a pathologically deep or cyclic tree (e.g. via bind mounts) cannot stall
+/// loading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SkillDiscoveryLimits {
+    /// Maximum directory depth below the root that is descended into.
+    pub(crate) max_depth: usize,
+    /// Maximum number of directory entries (files and directories) examined per root.
+    pub(crate) max_entries: usize,
+}
+
+impl Default for SkillDiscoveryLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: DEFAULT_MAX_SCAN_DEPTH,
+            max_entries: DEFAULT_MAX_SCANNED_ENTRIES,
+        }
+    }
+}
 
 #[derive(Debug)]
 enum SkillParseError {
@@ -69,20 +119,79 @@ pub fn load_skills(config: &Config) -> SkillLoadOutcome {
     load_skills_from_roots(skill_roots(config))
 }
 
+/// Async counterpart of [`load_skills`] that walks the skill roots on the blocking pool, so a
+/// slow (e.g. network) filesystem does not stall the caller's runtime thread.
+pub async fn load_skills_async(config: &Config) -> SkillLoadOutcome {
+    load_skills_for_cwd_async(config.codex_home.clone(), config.cwd.clone()).await
+}
+
+/// Load the skills visible from `cwd` on the blocking pool.
+///
+/// Dropping the returned future (e.g. when the session shuts down mid-load) cancels the walk:
+/// the blocking task stops at the next directory instead of scanning the rest of the tree.
+pub(crate) async fn load_skills_for_cwd_async(
+    codex_home: PathBuf,
+    cwd: PathBuf,
+) -> SkillLoadOutcome {
+    let cancel = CancellationToken::new();
+    let _cancel_on_drop = cancel.clone().drop_guard();
+    let task = tokio::task::spawn_blocking(move || {
+        let roots = skill_roots_for_cwd(&codex_home, &cwd);
+        load_skills_from_roots_cancellable(roots, SkillDiscoveryLimits::default(), &cancel)
+    });
+    match task.await {
+        Ok(outcome) => outcome,
+        Err(err) => {
+            error!("skill loading task failed: {err}");
+            SkillLoadOutcome::default()
+        }
+    }
+}
+
 pub(crate) struct SkillRoot {
     pub(crate) path: PathBuf,
     pub(crate) scope: SkillScope,
 }
 
 pub(crate) fn load_skills_from_roots<I>(roots: I) -> SkillLoadOutcome
+where
+    I: IntoIterator<Item = SkillRoot>,
+{
+    load_skills_from_roots_with_limits(roots, SkillDiscoveryLimits::default())
+}
+
+pub(crate) fn load_skills_from_roots_with_limits<I>(
+    roots: I,
+    limits: SkillDiscoveryLimits,
+) -> SkillLoadOutcome
+where
+    I: IntoIterator<Item = SkillRoot>,
+{
+    load_skills_from_roots_cancellable(roots, limits, &CancellationToken::new())
+}
+
+fn load_skills_from_roots_cancellable<I>(
+    roots: I,
+    limits: SkillDiscoveryLimits,
+    cancel: &CancellationToken,
+) -> SkillLoadOutcome
 where
     I: IntoIterator<Item = SkillRoot>,
 {
     let mut outcome = SkillLoadOutcome::default();
     for root in roots {
-        discover_skills_under_root(&root.path, root.scope, &mut outcome);
+        if cancel.is_cancelled() {
+            break;
+        }
+        discover_skills_under_root(&root.path, root.scope, limits, cancel, &mut outcome);
     }
 
+    // Higher `metadata.priority` wins a name clash. The sort is stable, so skills with equal
+    // priority keep root order (repo, user, system, bundles, admin) and the first one is kept below.
+    outcome
+        .skills
+        .sort_by_key(|skill| std::cmp::Reverse(skill.priority));
+
     let mut seen: HashSet<String> = HashSet::new();
     outcome
         .skills
@@ -95,6 +204,44 @@ where
     outcome
 }
 
+/// Opt-in lint (`features.skills_lint`) for skills that share a description.
+///
+/// Identical descriptions are usually a copy-paste mistake and leave the model unable to tell the
+/// skills apart. Descriptions are compared ignoring case and whitespace, short ones are skipped,
+/// and each group of duplicates yields a single warning; the skills themselves still load.
+pub(crate) fn duplicate_description_warnings(skills: &[SkillMetadata]) -> Vec<SkillError> {
+    let mut groups: BTreeMap<String, Vec<&SkillMetadata>> = BTreeMap::new();
+    for skill in skills {
+        let normalized = skill
+            .description
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        if normalized.chars().count() >= MIN_LINTED_DESCRIPTION_LEN {
+            groups.entry(normalized).or_default().push(skill);
+        }
+    }
+
+    groups
+        .into_values()
+        .filter(|group| group.len() > 1)
+        .map(|group| {
+            let names = group
+                .iter()
+                .map(|skill| format!("`{}`", skill.name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            SkillError {
+                path: group[0].path.clone(),
+                message: format!(
+                    "skills {names} share the same description; give each one a distinct description so the model can tell them apart"
+                ),
+            }
+        })
+        .collect()
+}
+
 pub(crate) fn user_skills_root(codex_home: &Path) -> SkillRoot {
     SkillRoot {
         path: codex_home.join(SKILLS_DIR_NAME),
@@ -109,6 +256,17 @@ pub(crate) fn system_skills_root(codex_home: &Path) -> SkillRoot {
     }
 }
 
+/// Skills extracted from the configured `skills_bundles` archives (see [`crate::skills::bundle`]).
+///
+/// Unlike the embedded system skills, bundles are authored by users, so their parse errors are
+/// reported.
+pub(crate) fn bundle_skills_root(codex_home: &Path) -> SkillRoot {
+    SkillRoot {
+        path: bundle_cache_root_dir(codex_home),
+        scope: SkillScope::Bundle,
+    }
+}
+
 pub(crate) fn admin_skills_root() -> SkillRoot {
     SkillRoot {
         path: PathBuf::from(ADMIN_SKILLS_ROOT),
@@ -117,11 +275,65 @@ pub(crate) fn admin_skills_root() -> SkillRoot {
 }
 
 pub(crate) fn repo_skills_root(cwd: &Path) -> Option<SkillRoot> {
+    repo_skills_root_with_resolver(
+        cwd,
+        GIT_ROOT_RESOLVE_TIMEOUT,
+        resolve_root_git_project_for_trust,
+    )
+}
+
+/// Outcome of looking up the git project root for a skills search.
+enum RepoRootLookup {
+    Found(PathBuf),
+    NotARepo,
+    TimedOut,
+}
+
+/// Runs `resolve` on a helper thread and gives up after `timeout`.
+///
+/// The resolver shells out to git, which can hang on broken repositories or network mounts. On
+/// timeout the helper thread is left to finish (or hang) on its own; its result is discarded.
+fn resolve_repo_root_with_timeout<F>(base: &Path, timeout: Duration, resolve: F) -> RepoRootLookup
+where
+    F: FnOnce(&Path) -> Option<PathBuf> + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    let base_for_thread = base.to_path_buf();
+    let spawned = std::thread::Builder::new()
+        .name("skills-git-root".to_string())
+        .spawn(move || {
+            let _ = tx.send(resolve(&base_for_thread));
+        });
+    if let Err(err) = spawned {
+        warn!("failed to spawn git root lookup for skills: {err}");
+        return RepoRootLookup::NotARepo;
+    }
+
+    match rx.recv_timeout(timeout) {
+        Ok(Some(root)) => RepoRootLookup::Found(root),
+        Ok(None) | Err(RecvTimeoutError::Disconnected) => RepoRootLookup::NotARepo,
+        Err(RecvTimeoutError::Timeout) => RepoRootLookup::TimedOut,
+    }
+}
+
+fn repo_skills_root_with_resolver<F>(cwd: &Path, timeout: Duration, resolve: F) -> Option<SkillRoot>
+where
+    F: FnOnce(&Path) -> Option<PathBuf> + Send + 'static,
+{
     let base = if cwd.is_dir() { cwd } else { cwd.parent()? };
     let base = normalize_path(base).unwrap_or_else(|_| base.to_path_buf());
 
-    let repo_root =
-        resolve_root_git_project_for_trust(&base).map(|root| normalize_path(&root).unwrap_or(root));
+    let repo_root = match resolve_repo_root_with_timeout(&base, timeout, resolve) {
+        RepoRootLookup::Found(root) => Some(normalize_path(&root).unwrap_or(root)),
+        RepoRootLookup::NotARepo => None,
+        RepoRootLookup::TimedOut => {
+            warn!(
+                "git root lookup for {} timed out after {timeout:?}; loading repo skills from cwd only",
+                base.display()
+            );
+            None
+        }
+    };
 
     let scope = SkillScope::Repo;
     if let Some(repo_root) = repo_root.as_deref() {
@@ -156,9 +368,10 @@ pub(crate) fn skill_roots_for_cwd(codex_home: &Path, cwd: &Path) -> Vec<SkillRoo
     }
 
     // Load order matters: we dedupe by name, keeping the first occurrence.
-    // Priority order: repo, user, system, then admin.
+    // Priority order: repo, user, system, bundles, then admin.
     roots.push(user_skills_root(codex_home));
     roots.push(system_skills_root(codex_home));
+    roots.push(bundle_skills_root(codex_home));
     if cfg!(unix) {
         roots.push(admin_skills_root());
     }
@@ -170,7 +383,13 @@ fn skill_roots(config: &Config) -> Vec<SkillRoot> {
     skill_roots_for_cwd(&config.codex_home, &config.cwd)
 }
 
-fn discover_skills_under_root(root: &Path, scope: SkillScope, outcome: &mut SkillLoadOutcome) {
+fn discover_skills_under_root(
+    root: &Path,
+    scope: SkillScope,
+    limits: SkillDiscoveryLimits,
+    cancel: &CancellationToken,
+    outcome: &mut SkillLoadOutcome,
+) {
     let Ok(root) = normalize_path(root) else {
         return;
     };
@@ -179,8 +398,14 @@ fn discover_skills_under_root(root: &Path, scope: SkillScope, outcome: &mut Skil
         return;
     }
 
-    let mut queue: VecDeque<PathBuf> = VecDeque::from([root]);
-    while let Some(dir) = queue.pop_front() {
+    let first_skill = outcome.skills.len();
+    let mut scanned_entries = 0usize;
+    let mut depth_limited = false;
+    let mut queue: VecDeque<(PathBuf, usize)> = VecDeque::from([(root.clone(), 0)]);
+    'scan: while let Some((dir, depth)) = queue.pop_front() {
+        if cancel.is_cancelled() {
+            break;
+        }
         let entries = match fs::read_dir(&dir) {
             Ok(entries) => entries,
             Err(e) => {
@@ -190,6 +415,18 @@ fn discover_skills_under_root(root: &Path, scope: SkillScope, outcome: &mut Skil
         };
 
         for entry in entries.flatten() {
+            if scanned_entries >= limits.max_entries {
+                let max_entries = limits.max_entries;
+                outcome.errors.push(SkillError {
+                    path: root.clone(),
+                    message: format!(
+                        "stopped scanning for skills after {max_entries} entries; some skills may not be loaded"
+                    ),
+                });
+                break 'scan;
+            }
+            scanned_entries += 1;
+
             let path = entry.path();
             let file_name = match path.file_name().and_then(|f| f.to_str()) {
                 Some(name) => name,
@@ -209,13 +446,26 @@ fn discover_skills_under_root(root: &Path, scope: SkillScope, outcome: &mut Skil
             }
 
             if file_type.is_dir() {
-                queue.push_back(path);
+                if depth < limits.max_depth {
+                    queue.push_back((path, depth + 1));
+                } else {
+                    depth_limited = true;
+                }
                 continue;
             }
 
             if file_type.is_file() && file_name == SKILLS_FILENAME {
-                match parse_skill_file(&path, scope) {
+                let mut warnings = Vec::new();
+                match parse_skill_file(&path, scope, &mut warnings) {
                     Ok(skill) => {
+                        if scope != SkillScope::System {
+                            outcome
+                                .errors
+                                .extend(warnings.into_iter().map(|warning| SkillError {
+                                    path: path.clone(),
+                                    message: warning.to_string(),
+                                }));
+                        }
                         outcome.skills.push(skill);
                     }
                     Err(err) => {
@@ -230,9 +480,71 @@ fn discover_skills_under_root(root: &Path, scope: SkillScope, outcome: &mut Skil
             }
         }
     }
+
+    if depth_limited {
+        let max_depth = limits.max_depth;
+        outcome.errors.push(SkillError {
+            path: root,
+            message: format!(
+                "skipped directories nested more than {max_depth} levels deep while scanning for skills"
+            ),
+        });
+    }
+
+    // `read_dir` order is unspecified; sort this root's skills by path so the winner of a name
+    // clash within the root does not depend on the filesystem.
+    let root_skills = &mut outcome.skills[first_skill..];
+    root_skills.sort_by(|a, b| a.path.cmp(&b.path));
+    report_duplicate_names_in_root(root_skills, &mut outcome.errors);
 }
 
-fn parse_skill_file(path: &Path, scope: SkillScope) -> Result<SkillMetadata, SkillParseError> {
+/// Records an error for each skill shadowed by a same-named skill from the same root.
+///
+/// Duplicates within one root are almost always a copy-paste mistake, unlike cross-root
+/// duplicates, which intentionally override by scope. The winner matches the dedup in
+/// [`load_skills_from_roots`]: highest `priority`, then the first path.
+fn report_duplicate_names_in_root(skills: &[SkillMetadata], errors: &mut Vec<SkillError>) {
+    let mut by_name: HashMap<&str, Vec<&SkillMetadata>> = HashMap::new();
+    for skill in skills {
+        by_name.entry(skill.name.as_str()).or_default().push(skill);
+    }
+
+    let mut duplicates: Vec<(&str, Vec<&SkillMetadata>)> = by_name
+        .into_iter()
+        .filter(|(_, group)| group.len() > 1)
+        .collect();
+    duplicates.sort_by_key(|(name, _)| *name);
+
+    for (name, group) in duplicates {
+        let Some(winner) = group
+            .iter()
+            .copied()
+            .min_by_key(|skill| std::cmp::Reverse(skill.priority))
+        else {
+            continue;
+        };
+        for skill in group {
+            if std::ptr::eq(skill, winner) {
+                continue;
+            }
+            errors.push(SkillError {
+                path: skill.path.clone(),
+                message: format!(
+                    "duplicate skill name `{name}` in the same skills root; ignoring this file in favor of {}",
+                    winner.path.display()
+                ),
+            });
+        }
+    }
+}
+
+/// Parses a `SKILL.md`. Problems with optional metadata that shouldn't drop the skill (such as an
+/// invalid `metadata.version`) are pushed onto `warnings` and the field is left unset.
+fn parse_skill_file(
+    path: &Path,
+    scope: SkillScope,
+    warnings: &mut Vec<SkillParseError>,
+) -> Result<SkillMetadata, SkillParseError> {
     let contents = fs::read_to_string(path).map_err(SkillParseError::Read)?;
 
     let frontmatter = extract_frontmatter(&contents).ok_or(SkillParseError::MissingFrontmatter)?;
@@ -240,6 +552,13 @@ fn parse_skill_file(path: &Path, scope: SkillScope) -> Result<SkillMetadata, Ski
     let parsed: SkillFrontmatter =
         serde_yaml::from_str(&frontmatter).map_err(SkillParseError::InvalidYaml)?;
 
+    if parsed.name.contains(is_bidi_control) {
+        return Err(SkillParseError::InvalidField {
+            field: "name",
+            reason: "must not contain bidirectional control characters".to_string(),
+        });
+    }
+    validate_skill_name(parsed.name.trim())?;
     let name = sanitize_single_line(&parsed.name);
     let description = sanitize_single_line(&parsed.description);
     let short_description = parsed
@@ -259,19 +578,218 @@ fn parse_skill_file(path: &Path, scope: SkillScope) -> Result<SkillMetadata, Ski
         )?;
     }
 
+    let allowed_tools = parse_allowed_tools(parsed.metadata.allowed_tools.as_deref())?;
+    let priority = parse_priority(parsed.metadata.priority)?;
+    let applies_to = parse_applies_to(parsed.metadata.applies_to.as_deref())?;
+    let version = parse_version(parsed.metadata.version.as_ref()).unwrap_or_else(|err| {
+        warnings.push(err);
+        None
+    });
+    let license = parse_license(parsed.metadata.license.as_deref()).unwrap_or_else(|err| {
+        warnings.push(err);
+        None
+    });
+
     let resolved_path = normalize_path(path).unwrap_or_else(|_| path.to_path_buf());
 
     Ok(SkillMetadata {
         name,
         description,
         short_description,
+        allowed_tools,
+        priority,
+        version,
+        license,
+        applies_to,
         path: resolved_path,
         scope,
     })
 }
 
+fn parse_priority(raw: Option<i64>) -> Result<i32, SkillParseError> {
+    let Some(raw) = raw else {
+        return Ok(0);
+    };
+    i32::try_from(raw)
+        .ok()
+        .filter(|priority| (-MAX_PRIORITY..=MAX_PRIORITY).contains(priority))
+        .ok_or_else(|| SkillParseError::InvalidField {
+            field: "metadata.priority",
+            reason: format!("must be between -{MAX_PRIORITY} and {MAX_PRIORITY}"),
+        })
+}
+
+/// Validates `metadata.version`: a single semver-ish token such as `1.2.0`, `v2`, or
+/// `0.3.0-beta.1`.
+fn parse_version(raw: Option<&serde_yaml::Value>) -> Result<Option<String>, SkillParseError> {
+    let invalid = |reason: String| SkillParseError::InvalidField {
+        field: "metadata.version",
+        reason,
+    };
+    let version = match raw {
+        None | Some(serde_yaml::Value::Null) => return Ok(None),
+        Some(serde_yaml::Value::String(value)) => value.clone(),
+        Some(serde_yaml::Value::Number(value)) => {
+            return Err(invalid(format!(
+                "must be a quoted string; YAML reads the unquoted `{value}` as a number"
+            )));
+        }
+        Some(_) => return Err(invalid("must be a string".to_string())),
+    };
+    if version.contains(['\n', '\r']) {
+        return Err(invalid("must be a single line".to_string()));
+    }
+    let version = version.trim();
+    if version.is_empty() {
+        return Ok(None);
+    }
+    if version.chars().count() > MAX_VERSION_LEN {
+        return Err(invalid(format!(
+            "exceeds maximum length of {MAX_VERSION_LEN} characters"
+        )));
+    }
+    let valid = version.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+' | '_'));
+    if !valid {
+        return Err(invalid(format!(
+            "`{version}` may only contain letters, digits, `.`, `-`, `+`, and `_`"
+        )));
+    }
+    Ok(Some(version.to_string()))
+}
+
+/// Validates `metadata.license`: a single line of at most [`MAX_LICENSE_LEN`] characters, such as
+/// `MIT` or `Apache-2.0`.
+fn parse_license(raw: Option<&str>) -> Result<Option<String>, SkillParseError> {
+    let invalid = |reason: String| SkillParseError::InvalidField {
+        field: "metadata.license",
+        reason,
+    };
+    let Some(raw) = raw else {
+        return Ok(None);
+    };
+    if raw.contains(['\n', '\r']) {
+        return Err(invalid("must be a single line".to_string()));
+    }
+    let license = sanitize_single_line(raw);
+    if license.is_empty() {
+        return Ok(None);
+    }
+    if license.chars().count() > MAX_LICENSE_LEN {
+        return Err(invalid(format!(
+            "exceeds maximum length of {MAX_LICENSE_LEN} characters"
+        )));
+    }
+    Ok(Some(license))
+}
+
+fn parse_allowed_tools(raw: Option<&[String]>) -> Result<Vec<String>, SkillParseError> {
+    let mut allowed_tools: Vec<String> = Vec::new();
+    for tool in raw.unwrap_or_default() {
+        if tool.contains(['\n', '\r']) {
+            return Err(SkillParseError::InvalidField {
+                field: "metadata.allowed-tools",
+                reason: format!("tool name `{}` must be a single line", tool.trim()),
+            });
+        }
+        let tool = tool.trim();
+        if tool.is_empty() {
+            return Err(SkillParseError::InvalidField {
+                field: "metadata.allowed-tools",
+                reason: "tool names must not be empty".to_string(),
+            });
+        }
+        if !allowed_tools.iter().any(|existing| existing == tool) {
+            allowed_tools.push(tool.to_string());
+        }
+    }
+    Ok(allowed_tools)
+}
+
+/// Validates `metadata.applies_to`: single-line markers relative to the cwd.
+fn parse_applies_to(raw: Option<&[String]>) -> Result<Vec<String>, SkillParseError> {
+    let invalid = |reason: String| SkillParseError::InvalidField {
+        field: "metadata.applies_to",
+        reason,
+    };
+    let mut markers: Vec<String> = Vec::new();
+    for marker in raw.unwrap_or_default() {
+        if marker.contains(['\n', '\r']) {
+            return Err(invalid(format!(
+                "marker `{}` must be a single line",
+                marker.trim()
+            )));
+        }
+        let marker = marker.trim();
+        if marker.is_empty() {
+            return Err(invalid("markers must not be empty".to_string()));
+        }
+        let marker_path = Path::new(marker);
+        if marker_path.is_absolute()
+            || marker_path
+                .components()
+                .any(|component| matches!(component, std::path::Component::ParentDir))
+        {
+            return Err(invalid(format!(
+                "marker `{marker}` must be relative to the project root"
+            )));
+        }
+        if !markers.iter().any(|existing| existing == marker) {
+            markers.push(marker.to_string());
+        }
+    }
+    Ok(markers)
+}
+
+/// Collapses whitespace to single spaces and drops control and invisible format characters
+/// (zero-width spaces, BOMs, soft hyphens, bidi controls) that would glitch or spoof the
+/// rendered label.
 fn sanitize_single_line(raw: &str) -> String {
-    raw.split_whitespace().collect::<Vec<_>>().join(" ")
+    raw.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|c| !c.is_control() && !is_invisible_format_char(*c))
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Zero-width joiners (U+200C/U+200D) are deliberately kept: emoji sequences and several scripts
+/// depend on them.
+fn is_invisible_format_char(c: char) -> bool {
+    matches!(c, '\u{00AD}' | '\u{200B}' | '\u{2060}' | '\u{FEFF}') || is_bidi_control(c)
+}
+
+/// Characters that override or isolate text direction and can make a name render differently
+/// from how it compares.
+fn is_bidi_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+/// Rejects names that could be mistaken for a path or hide characters when injected or rendered:
+/// path separators, control characters, and leading or trailing dots.
+fn validate_skill_name(name: &str) -> Result<(), SkillParseError> {
+    let invalid = |reason: &str| SkillParseError::InvalidField {
+        field: "name",
+        reason: reason.to_string(),
+    };
+    if name.contains(['/', '\\']) {
+        return Err(invalid("must not contain path separators (`/` or `\\`)"));
+    }
+    if name.contains(char::is_control) {
+        return Err(invalid("must not contain control characters"));
+    }
+    if name.starts_with('.') || name.ends_with('.') {
+        return Err(invalid("must not start or end with `.`"));
+    }
+    Ok(())
 }
 
 fn validate_field(
@@ -389,6 +907,40 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn duplicate_descriptions_produce_one_lint_warning() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        write_skill(&codex_home, "a", "alpha", "Extract tables from PDF reports");
+        write_skill(&codex_home, "b", "beta", "extract tables from  pdf reports");
+        write_skill(&codex_home, "c", "gamma", "Summarize meeting notes");
+        // Short, generic descriptions are not worth flagging.
+        write_skill(&codex_home, "d", "delta", "helper");
+        write_skill(&codex_home, "e", "epsilon", "helper");
+        let cfg = make_config(&codex_home).await;
+
+        let outcome = load_skills(&cfg);
+        assert_eq!(outcome.skills.len(), 5, "lint does not drop skills");
+        let warnings = duplicate_description_warnings(&outcome.skills);
+        assert_eq!(warnings.len(), 1, "{warnings:?}");
+        assert!(
+            warnings[0].message.contains("`alpha`, `beta`"),
+            "{}",
+            warnings[0].message
+        );
+        assert_eq!(warnings[0].path, outcome.skills[0].path);
+    }
+
+    #[tokio::test]
+    async fn distinct_descriptions_produce_no_lint_warnings() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        write_skill(&codex_home, "a", "alpha", "Extract tables from PDF reports");
+        write_skill(&codex_home, "b", "beta", "Extract charts from PDF reports");
+        let cfg = make_config(&codex_home).await;
+
+        let outcome = load_skills(&cfg);
+        assert_eq!(duplicate_description_warnings(&outcome.skills), Vec::new());
+    }
+
     #[tokio::test]
     async fn loads_short_description_from_metadata() {
         let codex_home = tempfile::tempdir().expect("tempdir");
@@ -411,6 +963,178 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn loads_allowed_tools_from_metadata() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let skill_dir = codex_home.path().join("skills/demo");
+        fs::create_dir_all(&skill_dir).unwrap();
+        let contents = "---\nname: demo-skill\ndescription: long description\nmetadata:\n  allowed-tools:\n    - shell\n    - apply_patch\n    - shell\n---\n\n# Body\n";
+        fs::write(skill_dir.join(SKILLS_FILENAME), contents).unwrap();
+
+        let cfg = make_config(&codex_home).await;
+        let outcome = load_skills(&cfg);
+        assert!(
+            outcome.errors.is_empty(),
+            "unexpected errors: {:?}",
+            outcome.errors
+        );
+        assert_eq!(outcome.skills.len(), 1);
+        assert_eq!(
+            outcome.skills[0].allowed_tools,
+            vec!["shell".to_string(), "apply_patch".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn loads_applies_to_and_rejects_markers_outside_the_project() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let skill_dir = codex_home.path().join("skills/demo");
+        fs::create_dir_all(&skill_dir).unwrap();
+        let path = skill_dir.join(SKILLS_FILENAME);
+        fs::write(
+            &path,
+            "---\nname: demo-skill\ndescription: long description\nmetadata:\n  applies_to:\n    - Cargo.toml\n    - \"*.cabal\"\n---\n\n# Body\n",
+        )
+        .unwrap();
+        let skill = parse_skill_file(&path, SkillScope::User, &mut Vec::new()).expect("skill");
+        assert_eq!(
+            skill.applies_to,
+            vec!["Cargo.toml".to_string(), "*.cabal".to_string()]
+        );
+
+        fs::write(
+            &path,
+            "---\nname: demo-skill\ndescription: long description\nmetadata:\n  applies_to:\n    - ../Cargo.toml\n---\n\n# Body\n",
+        )
+        .unwrap();
+        let err =
+            parse_skill_file(&path, SkillScope::User, &mut Vec::new()).expect_err("parent marker");
+        assert!(
+            matches!(
+                err,
+                SkillParseError::InvalidField {
+                    field: "metadata.applies_to",
+                    ..
+                }
+            ),
+            "expected InvalidField, got: {err:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_empty_allowed_tool_name() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let skill_dir = codex_home.path().join("skills/demo");
+        fs::create_dir_all(&skill_dir).unwrap();
+        let contents = "---\nname: demo-skill\ndescription: long description\nmetadata:\n  allowed-tools:\n    - shell\n    - \"\"\n---\n\n# Body\n";
+        let path = skill_dir.join(SKILLS_FILENAME);
+        fs::write(&path, contents).unwrap();
+
+        let err = parse_skill_file(&path, SkillScope::User, &mut Vec::new())
+            .expect_err("empty tool name");
+        assert!(
+            matches!(
+                err,
+                SkillParseError::InvalidField {
+                    field: "metadata.allowed-tools",
+                    ..
+                }
+            ),
+            "expected InvalidField, got: {err:?}"
+        );
+
+        let cfg = make_config(&codex_home).await;
+        let outcome = load_skills(&cfg);
+        assert_eq!(outcome.skills.len(), 0);
+        assert_eq!(outcome.errors.len(), 1);
+        assert!(
+            outcome.errors[0]
+                .message
+                .contains("invalid metadata.allowed-tools"),
+            "expected allowed-tools error, got: {:?}",
+            outcome.errors
+        );
+    }
+
+    #[test]
+    fn strips_zero_width_characters() {
+        let root = tempfile::tempdir().expect("tempdir");
+        let path = write_skill_at(
+            root.path(),
+            "demo",
+            "demo\u{200B}-skill",
+            "does\u{2060} thi\u{00AD}ngs",
+        );
+
+        let skill =
+            parse_skill_file(&path, SkillScope::User, &mut Vec::new()).expect("valid skill");
+        assert_eq!(skill.name, "demo-skill");
+        assert_eq!(skill.description, "does things");
+    }
+
+    #[test]
+    fn rejects_path_like_names() {
+        let root = tempfile::tempdir().expect("tempdir");
+        for (dir, name, reason) in [
+            ("slash", "foo/bar", "path separators"),
+            ("backslash", "'foo\\bar'", "path separators"),
+            ("dots", "..evil", "start or end with `.`"),
+            ("trailing", "demo.", "start or end with `.`"),
+            ("control", "\"demo\\u0007bell\"", "control characters"),
+        ] {
+            let path = write_skill_at(root.path(), dir, name, "does things");
+            let err = parse_skill_file(&path, SkillScope::User, &mut Vec::new()).expect_err(name);
+            let SkillParseError::InvalidField {
+                field: "name",
+                reason: message,
+            } = &err
+            else {
+                panic!("expected InvalidField for {name}, got: {err:?}");
+            };
+            assert!(message.contains(reason), "{name}: {message}");
+        }
+    }
+
+    #[test]
+    fn accepts_hyphenated_and_unicode_names() {
+        let root = tempfile::tempdir().expect("tempdir");
+        for (dir, name) in [("demo", "demo-skill"), ("unicode", "レビュー助手")] {
+            let path = write_skill_at(root.path(), dir, name, "does things");
+            let skill = parse_skill_file(&path, SkillScope::User, &mut Vec::new())
+                .unwrap_or_else(|err| panic!("{name}: {err}"));
+            assert_eq!(skill.name, name);
+        }
+    }
+
+    #[test]
+    fn rejects_bidi_override_in_name() {
+        let root = tempfile::tempdir().expect("tempdir");
+        let path = write_skill_at(root.path(), "demo", "demo\u{202E}lliks", "does things");
+
+        let err = parse_skill_file(&path, SkillScope::User, &mut Vec::new())
+            .expect_err("bidi override in name");
+        assert!(
+            matches!(err, SkillParseError::InvalidField { field: "name", .. }),
+            "expected InvalidField, got: {err:?}"
+        );
+    }
+
+    #[test]
+    fn multilingual_names_are_unchanged() {
+        let root = tempfile::tempdir().expect("tempdir");
+        for (dir, name) in [
+            ("cjk", "日本語スキル"),
+            ("emoji", "deploy 🚀"),
+            ("family", "team 👨\u{200D}👩\u{200D}👧"),
+        ] {
+            let path = write_skill_at(root.path(), dir, name, "説明文");
+            let skill =
+                parse_skill_file(&path, SkillScope::User, &mut Vec::new()).expect("valid skill");
+            assert_eq!(skill.name, name);
+            assert_eq!(skill.description, "説明文");
+        }
+    }
+
     #[tokio::test]
     async fn enforces_short_description_length_limits() {
         let codex_home = tempfile::tempdir().expect("tempdir");
@@ -435,6 +1159,230 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn loads_version_from_metadata() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let skill_dir = codex_home.path().join("skills/demo");
+        fs::create_dir_all(&skill_dir).unwrap();
+        let contents = "---\nname: demo-skill\ndescription: long description\nmetadata:\n  version: \" 1.2.0-beta.1 \"\n---\n\n# Body\n";
+        fs::write(skill_dir.join(SKILLS_FILENAME), contents).unwrap();
+
+        let cfg = make_config(&codex_home).await;
+        let outcome = load_skills(&cfg);
+        assert!(
+            outcome.errors.is_empty(),
+            "unexpected errors: {:?}",
+            outcome.errors
+        );
+        let versions: Vec<(&str, Option<&str>)> = outcome
+            .skills
+            .iter()
+            .map(|skill| (skill.name.as_str(), skill.version.as_deref()))
+            .collect();
+        assert_eq!(versions, vec![("demo-skill", Some("1.2.0-beta.1"))]);
+
+        let rendered = crate::skills::render_skills_section(&outcome.skills).expect("section");
+        assert!(
+            rendered.contains("- $demo-skill: long description (version: 1.2.0-beta.1, file: "),
+            "version missing from rendered section: {rendered}"
+        );
+    }
+
+    #[tokio::test]
+    async fn over_long_version_is_reported_but_skill_still_loads() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let skill_dir = codex_home.path().join("skills/demo");
+        fs::create_dir_all(&skill_dir).unwrap();
+        let too_long = "1".repeat(MAX_VERSION_LEN + 1);
+        let contents = format!(
+            "---\nname: demo-skill\ndescription: long description\nmetadata:\n  version: \"{too_long}\"\n---\n\n# Body\n"
+        );
+        fs::write(skill_dir.join(SKILLS_FILENAME), contents).unwrap();
+
+        let cfg = make_config(&codex_home).await;
+        let outcome = load_skills(&cfg);
+        assert_eq!(outcome.skills.len(), 1);
+        assert_eq!(outcome.skills[0].version, None);
+        assert_eq!(outcome.errors.len(), 1);
+        assert!(
+            outcome.errors[0]
+                .message
+                .contains("invalid metadata.version: exceeds maximum length"),
+            "expected version error, got: {:?}",
+            outcome.errors
+        );
+    }
+
+    #[tokio::test]
+    async fn unquoted_numeric_version_is_reported_but_skill_still_loads() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let skill_dir = codex_home.path().join("skills/demo");
+        fs::create_dir_all(&skill_dir).unwrap();
+        let contents = "---\nname: demo-skill\ndescription: long description\nmetadata:\n  version: 1.10\n---\n\n# Body\n";
+        fs::write(skill_dir.join(SKILLS_FILENAME), contents).unwrap();
+
+        let cfg = make_config(&codex_home).await;
+        let outcome = load_skills(&cfg);
+        assert_eq!(outcome.skills.len(), 1);
+        assert_eq!(outcome.skills[0].version, None);
+        assert_eq!(outcome.errors.len(), 1);
+        assert!(
+            outcome.errors[0]
+                .message
+                .contains("invalid metadata.version: must be a quoted string"),
+            "expected version error, got: {:?}",
+            outcome.errors
+        );
+    }
+
+    #[tokio::test]
+    async fn loads_license_and_renders_it() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let skill_dir = codex_home.path().join("skills/demo");
+        fs::create_dir_all(&skill_dir).unwrap();
+        let contents = "---\nname: demo-skill\ndescription: long description\nmetadata:\n  version: 1.0.0\n  license: \" Apache-2.0 \"\n---\n\n# Body\n";
+        fs::write(skill_dir.join(SKILLS_FILENAME), contents).unwrap();
+
+        let cfg = make_config(&codex_home).await;
+        let outcome = load_skills(&cfg);
+        assert!(
+            outcome.errors.is_empty(),
+            "unexpected errors: {:?}",
+            outcome.errors
+        );
+        assert_eq!(outcome.skills[0].license, Some("Apache-2.0".to_string()));
+
+        let rendered = crate::skills::render_skills_section(&outcome.skills).expect("section");
+        assert!(
+            rendered.contains(
+                "- $demo-skill: long description (version: 1.0.0, license: Apache-2.0, file: "
+            ),
+            "license missing from rendered section: {rendered}"
+        );
+    }
+
+    #[tokio::test]
+    async fn over_long_license_is_reported_but_skill_still_loads() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let skill_dir = codex_home.path().join("skills/demo");
+        fs::create_dir_all(&skill_dir).unwrap();
+        let too_long = "L".repeat(MAX_LICENSE_LEN + 1);
+        let contents = format!(
+            "---\nname: demo-skill\ndescription: long description\nmetadata:\n  license: \"{too_long}\"\n---\n\n# Body\n"
+        );
+        fs::write(skill_dir.join(SKILLS_FILENAME), contents).unwrap();
+
+        let cfg = make_config(&codex_home).await;
+        let outcome = load_skills(&cfg);
+        assert_eq!(outcome.skills.len(), 1);
+        assert_eq!(outcome.skills[0].license, None);
+        assert_eq!(outcome.errors.len(), 1);
+        assert!(
+            outcome.errors[0]
+                .message
+                .contains("invalid metadata.license: exceeds maximum length"),
+            "expected license error, got: {:?}",
+            outcome.errors
+        );
+        assert!(parse_license(Some("MIT\nGPL")).is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_versions() {
+        for raw in ["1.0\n2.0", "1.0 beta", "-1.0", "[1]"] {
+            let value: serde_yaml::Value = if raw == "[1]" {
+                serde_yaml::from_str(raw).unwrap()
+            } else {
+                serde_yaml::Value::String(raw.to_string())
+            };
+            assert!(
+                parse_version(Some(&value)).is_err(),
+                "expected {raw:?} to be rejected"
+            );
+        }
+        assert_eq!(
+            parse_version(Some(&serde_yaml::Value::String("v3.1+build.7".to_string()))).unwrap(),
+            Some("v3.1+build.7".to_string())
+        );
+    }
+
+    #[test]
+    fn stops_descending_past_max_depth() {
+        let root = tempfile::tempdir().expect("tempdir");
+        write_skill_at(root.path(), "shallow", "shallow-skill", "near the root");
+        write_skill_at(root.path(), "a/b/c/deep", "deep-skill", "far from the root");
+        let limits = SkillDiscoveryLimits {
+            max_depth: 2,
+            ..SkillDiscoveryLimits::default()
+        };
+
+        let outcome = load_skills_from_roots_with_limits(
+            [SkillRoot {
+                path: root.path().to_path_buf(),
+                scope: SkillScope::User,
+            }],
+            limits,
+        );
+
+        let names: Vec<&str> = outcome.skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["shallow-skill"]);
+        assert_eq!(outcome.errors.len(), 1);
+        assert!(
+            outcome.errors[0]
+                .message
+                .contains("nested more than 2 levels deep"),
+            "expected depth error, got: {:?}",
+            outcome.errors
+        );
+    }
+
+    #[test]
+    fn stops_scanning_after_max_entries() {
+        let root = tempfile::tempdir().expect("tempdir");
+        for idx in 0..5 {
+            fs::write(root.path().join(format!("note-{idx}.txt")), "x").unwrap();
+        }
+        let limits = SkillDiscoveryLimits {
+            max_entries: 3,
+            ..SkillDiscoveryLimits::default()
+        };
+
+        let outcome = load_skills_from_roots_with_limits(
+            [SkillRoot {
+                path: root.path().to_path_buf(),
+                scope: SkillScope::User,
+            }],
+            limits,
+        );
+
+        assert_eq!(outcome.errors.len(), 1);
+        assert!(
+            outcome.errors[0].message.contains("after 3 entries"),
+            "expected entry limit error, got: {:?}",
+            outcome.errors
+        );
+    }
+
+    #[test]
+    fn shallow_tree_loads_fully_with_default_limits() {
+        let root = tempfile::tempdir().expect("tempdir");
+        write_skill_at(root.path(), "one", "one", "first");
+        write_skill_at(root.path(), "group/two", "two", "second");
+
+        let outcome = load_skills_from_roots([SkillRoot {
+            path: root.path().to_path_buf(),
+            scope: SkillScope::User,
+        }]);
+
+        assert!(
+            outcome.errors.is_empty(),
+            "unexpected errors: {:?}",
+            outcome.errors
+        );
+        let names: Vec<&str> = outcome.skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["one", "two"]);
+    }
+
     #[tokio::test]
     async fn skips_hidden_and_invalid() {
         let codex_home = tempfile::tempdir().expect("tempdir");
@@ -636,6 +1584,182 @@ mod tests {
         assert_eq!(outcome.skills[0].scope, SkillScope::Repo);
     }
 
+    #[test]
+    fn duplicate_names_within_one_root_report_both_paths() {
+        let root = tempfile::tempdir().expect("tempdir");
+        let first = write_skill_at(root.path(), "a", "dupe-skill", "first copy");
+        let second = write_skill_at(root.path(), "b", "dupe-skill", "second copy");
+
+        let outcome = load_skills_from_roots([SkillRoot {
+            path: root.path().to_path_buf(),
+            scope: SkillScope::User,
+        }]);
+
+        assert_eq!(outcome.skills.len(), 1);
+        assert_eq!(outcome.skills[0].description, "first copy");
+        assert_eq!(outcome.errors.len(), 1);
+        let error = &outcome.errors[0];
+        assert_eq!(
+            normalize_path(&error.path).unwrap(),
+            normalize_path(&second).unwrap()
+        );
+        let first = normalize_path(&first).unwrap();
+        assert!(
+            error.message.contains(&first.display().to_string()),
+            "message should name the kept file: {}",
+            error.message
+        );
+    }
+
+    #[test]
+    fn duplicate_names_across_roots_do_not_warn() {
+        let repo_root = tempfile::tempdir().expect("tempdir");
+        let user_root = tempfile::tempdir().expect("tempdir");
+        write_skill_at(repo_root.path(), "repo", "dupe-skill", "from repo");
+        write_skill_at(user_root.path(), "user", "dupe-skill", "from user");
+
+        let outcome = load_skills_from_roots([
+            SkillRoot {
+                path: repo_root.path().to_path_buf(),
+                scope: SkillScope::Repo,
+            },
+            SkillRoot {
+                path: user_root.path().to_path_buf(),
+                scope: SkillScope::User,
+            },
+        ]);
+
+        assert_eq!(outcome.errors, Vec::new());
+        assert_eq!(outcome.skills.len(), 1);
+        assert_eq!(outcome.skills[0].scope, SkillScope::Repo);
+    }
+
+    fn write_skill_with_priority(root: &Path, dir: &str, name: &str, priority: i64) {
+        let skill_dir = root.join(dir);
+        fs::create_dir_all(&skill_dir).unwrap();
+        let content = format!(
+            "---\nname: {name}\ndescription: from {dir}\nmetadata:\n  priority: {priority}\n---\n\n# Body\n"
+        );
+        fs::write(skill_dir.join(SKILLS_FILENAME), content).unwrap();
+    }
+
+    #[test]
+    fn higher_priority_user_skill_shadows_repo_skill() {
+        let repo_root = tempfile::tempdir().expect("tempdir");
+        let user_root = tempfile::tempdir().expect("tempdir");
+        write_skill_at(repo_root.path(), "repo", "dupe-skill", "from repo");
+        write_skill_with_priority(user_root.path(), "user", "dupe-skill", 10);
+
+        let outcome = load_skills_from_roots([
+            SkillRoot {
+                path: repo_root.path().to_path_buf(),
+                scope: SkillScope::Repo,
+            },
+            SkillRoot {
+                path: user_root.path().to_path_buf(),
+                scope: SkillScope::User,
+            },
+        ]);
+        assert!(
+            outcome.errors.is_empty(),
+            "unexpected errors: {:?}",
+            outcome.errors
+        );
+        assert_eq!(outcome.skills.len(), 1);
+        assert_eq!(outcome.skills[0].scope, SkillScope::User);
+        assert_eq!(outcome.skills[0].priority, 10);
+    }
+
+    #[test]
+    fn equal_priorities_keep_scope_order() {
+        let repo_root = tempfile::tempdir().expect("tempdir");
+        let user_root = tempfile::tempdir().expect("tempdir");
+        write_skill_with_priority(repo_root.path(), "repo", "dupe-skill", 5);
+        write_skill_with_priority(user_root.path(), "user", "dupe-skill", 5);
+        write_skill_at(repo_root.path(), "plain-repo", "plain-skill", "from repo");
+        write_skill_at(user_root.path(), "plain-user", "plain-skill", "from user");
+
+        let outcome = load_skills_from_roots([
+            SkillRoot {
+                path: repo_root.path().to_path_buf(),
+                scope: SkillScope::Repo,
+            },
+            SkillRoot {
+                path: user_root.path().to_path_buf(),
+                scope: SkillScope::User,
+            },
+        ]);
+        let kept: Vec<(&str, SkillScope)> = outcome
+            .skills
+            .iter()
+            .map(|skill| (skill.name.as_str(), skill.scope))
+            .collect();
+        assert_eq!(
+            kept,
+            vec![
+                ("dupe-skill", SkillScope::Repo),
+                ("plain-skill", SkillScope::Repo)
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_out_of_range_priority() {
+        let root = tempfile::tempdir().expect("tempdir");
+        write_skill_with_priority(root.path(), "demo", "demo-skill", 1_000_000);
+
+        let outcome = load_skills_from_roots([SkillRoot {
+            path: root.path().to_path_buf(),
+            scope: SkillScope::User,
+        }]);
+        assert_eq!(outcome.skills.len(), 0);
+        assert_eq!(outcome.errors.len(), 1);
+        assert!(
+            outcome.errors[0]
+                .message
+                .contains("invalid metadata.priority"),
+            "expected priority error, got: {:?}",
+            outcome.errors
+        );
+    }
+
+    #[tokio::test]
+    async fn async_loader_matches_sync_loader() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        write_skill(&codex_home, "demo", "demo-skill", "does things");
+        write_skill(&codex_home, "nested/deeper", "nested-skill", "nested");
+        write_system_skill(&codex_home, "system", "system-skill", "from system");
+        let broken_dir = codex_home.path().join("skills/broken");
+        fs::create_dir_all(&broken_dir).unwrap();
+        fs::write(broken_dir.join(SKILLS_FILENAME), "no frontmatter").unwrap();
+        let cfg = make_config(&codex_home).await;
+
+        let sync_outcome = load_skills(&cfg);
+        let async_outcome = load_skills_async(&cfg).await;
+
+        assert_eq!(async_outcome, sync_outcome);
+        assert_eq!(async_outcome.skills.len(), 3);
+        assert_eq!(async_outcome.errors.len(), 1);
+    }
+
+    #[test]
+    fn cancelled_load_stops_scanning() {
+        let root = tempfile::tempdir().expect("tempdir");
+        write_skill_at(root.path(), "demo", "demo-skill", "does things");
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+
+        let outcome = load_skills_from_roots_cancellable(
+            [SkillRoot {
+                path: root.path().to_path_buf(),
+                scope: SkillScope::User,
+            }],
+            SkillDiscoveryLimits::default(),
+            &cancel,
+        );
+        assert_eq!(outcome, SkillLoadOutcome::default());
+    }
+
     #[tokio::test]
     async fn loads_system_skills_when_present() {
         let codex_home = tempfile::tempdir().expect("tempdir");
@@ -758,6 +1882,67 @@ mod tests {
         assert_eq!(outcome.skills.len(), 0);
     }
 
+    #[test]
+    fn slow_git_root_lookup_times_out_and_falls_back_to_cwd() {
+        let outer_dir = tempfile::tempdir().expect("tempdir");
+        let nested_dir = outer_dir.path().join("nested");
+        let nested_skills = nested_dir
+            .join(REPO_ROOT_CONFIG_DIR_NAME)
+            .join(SKILLS_DIR_NAME);
+        fs::create_dir_all(&nested_skills).unwrap();
+        fs::create_dir_all(
+            outer_dir
+                .path()
+                .join(REPO_ROOT_CONFIG_DIR_NAME)
+                .join(SKILLS_DIR_NAME),
+        )
+        .unwrap();
+        let repo_root = outer_dir.path().to_path_buf();
+
+        let started = std::time::Instant::now();
+        let root = repo_skills_root_with_resolver(
+            &nested_dir,
+            Duration::from_millis(50),
+            move |_base: &Path| {
+                std::thread::sleep(Duration::from_secs(5));
+                Some(repo_root)
+            },
+        )
+        .expect("cwd skills root");
+
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert_eq!(
+            normalize_path(&root.path).unwrap(),
+            normalize_path(&nested_skills).unwrap()
+        );
+        assert_eq!(root.scope, SkillScope::Repo);
+    }
+
+    #[test]
+    fn fast_git_root_lookup_walks_up_to_repo_root() {
+        let outer_dir = tempfile::tempdir().expect("tempdir");
+        let nested_dir = outer_dir.path().join("nested");
+        fs::create_dir_all(&nested_dir).unwrap();
+        let outer_skills = outer_dir
+            .path()
+            .join(REPO_ROOT_CONFIG_DIR_NAME)
+            .join(SKILLS_DIR_NAME);
+        fs::create_dir_all(&outer_skills).unwrap();
+        let repo_root = outer_dir.path().to_path_buf();
+
+        let root = repo_skills_root_with_resolver(
+            &nested_dir,
+            Duration::from_secs(5),
+            move |_base: &Path| Some(repo_root),
+        )
+        .expect("repo skills root");
+
+        assert_eq!(
+            normalize_path(&root.path).unwrap(),
+            normalize_path(&outer_skills).unwrap()
+        );
+    }
+
     #[tokio::test]
     async fn loads_skills_from_system_cache_when_present() {
         let codex_home = tempfile::tempdir().expect("tempdir");
@@ -788,7 +1973,7 @@ mod tests {
             .into_iter()
             .map(|root| root.scope)
             .collect();
-        let mut expected = vec![SkillScope::User, SkillScope::System];
+        let mut expected = vec![SkillScope::User, SkillScope::System, SkillScope::Bundle];
         if cfg!(unix) {
             expected.push(SkillScope::Admin);
         }
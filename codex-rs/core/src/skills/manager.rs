@@ -1,15 +1,65 @@
 use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::sync::RwLock;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 
 use crate::skills::SkillLoadOutcome;
+use crate::skills::SkillNameIndex;
+use crate::skills::SkillsDiff;
+use crate::skills::bundle::install_skill_bundles;
+use crate::skills::diagnostics::write_skills_diagnostics;
+use crate::skills::loader::load_skills_for_cwd_async;
 use crate::skills::loader::load_skills_from_roots;
 use crate::skills::loader::skill_roots_for_cwd;
 use crate::skills::system::install_system_skills;
+
+/// Structured observation emitted by [`SkillsManager`] as it serves skill lookups.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkillsManagerEvent {
+    /// Skills for `cwd` were served from the cache.
+    CacheHit { cwd: PathBuf },
+    /// No cached skills existed for `cwd`, so they were loaded from disk.
+    CacheMiss { cwd: PathBuf },
+    /// Skills for `cwd` were (re)loaded from disk, either after a miss or a forced reload.
+    Reloaded {
+        cwd: PathBuf,
+        skill_count: usize,
+        error_count: usize,
+    },
+    /// A reload of an already-cached `cwd` found skills that differ from the cached ones.
+    Changed { cwd: PathBuf, diff: SkillsDiff },
+}
+
+/// Callback invoked with every [`SkillsManagerEvent`]; see [`SkillsManager::with_event_hook`].
+pub type SkillsEventHook = Arc<dyn Fn(&SkillsManagerEvent) + Send + Sync>;
+
+/// A cached load plus the name index built from it, so `$mention` lookups do not rescan the list.
+struct CachedSkills {
+    outcome: SkillLoadOutcome,
+    index: Arc<SkillNameIndex>,
+}
+
+impl CachedSkills {
+    fn new(outcome: SkillLoadOutcome) -> Self {
+        let index = Arc::new(SkillNameIndex::new(&outcome.skills));
+        Self { outcome, index }
+    }
+}
+
 pub struct SkillsManager {
     codex_home: PathBuf,
-    cache_by_cwd: RwLock<HashMap<PathBuf, SkillLoadOutcome>>,
+    cache_by_cwd: RwLock<HashMap<PathBuf, CachedSkills>>,
+    event_hook: Option<SkillsEventHook>,
+    /// Whether each load also writes its errors to the diagnostics file (`skills_diagnostics`).
+    write_diagnostics: AtomicBool,
+    /// Skill archives from `skills_bundles`, extracted into the bundle cache; `None` until
+    /// [`Self::set_bundles`] is first called.
+    bundles: RwLock<Option<Vec<PathBuf>>>,
+    /// Whether the bundles changed since they were last extracted.
+    bundles_dirty: AtomicBool,
 }
 
 impl SkillsManager {
@@ -21,32 +71,434 @@ impl SkillsManager {
         Self {
             codex_home,
             cache_by_cwd: RwLock::new(HashMap::new()),
+            event_hook: None,
+            write_diagnostics: AtomicBool::new(false),
+            bundles: RwLock::new(None),
+            bundles_dirty: AtomicBool::new(false),
         }
     }
 
+    /// Use `bundles` (`skills_bundles` in `config.toml`) as the skill archives to load.
+    ///
+    /// Cached skills are dropped, and the next load extracts changed archives (and prunes removed
+    /// ones) before scanning, so it sees the new bundle contents.
+    pub fn set_bundles(&self, bundles: Vec<PathBuf>) {
+        {
+            let mut current = match self.bundles.write() {
+                Ok(current) => current,
+                Err(err) => err.into_inner(),
+            };
+            if current.as_ref() == Some(&bundles) {
+                return;
+            }
+            *current = Some(bundles);
+        }
+        self.bundles_dirty.store(true, Ordering::Relaxed);
+        match self.cache_by_cwd.write() {
+            Ok(mut cache) => cache.clear(),
+            Err(err) => err.into_inner().clear(),
+        }
+    }
+
+    /// Mirror the errors of every subsequent load to the JSON diagnostics file under
+    /// `CODEX_HOME` (see [`crate::skills::diagnostics`]).
+    pub fn set_write_diagnostics(&self, enabled: bool) {
+        self.write_diagnostics.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Forward cache and reload events to `hook` so embedders can feed them into their own
+    /// telemetry. Events are also emitted as `trace` level tracing events either way.
+    pub fn with_event_hook(mut self, hook: SkillsEventHook) -> Self {
+        self.event_hook = Some(hook);
+        self
+    }
+
     pub fn skills_for_cwd(&self, cwd: &Path) -> SkillLoadOutcome {
         self.skills_for_cwd_with_options(cwd, false)
     }
 
     pub fn skills_for_cwd_with_options(&self, cwd: &Path, force_reload: bool) -> SkillLoadOutcome {
-        let cached = match self.cache_by_cwd.read() {
-            Ok(cache) => cache.get(cwd).cloned(),
-            Err(err) => err.into_inner().get(cwd).cloned(),
-        };
-        if !force_reload && let Some(outcome) = cached {
+        if !force_reload && let Some(outcome) = self.cached(cwd) {
             return outcome;
         }
+        if (force_reload || self.bundles_dirty.swap(false, Ordering::Relaxed))
+            && let Some(bundles) = self.bundles()
+        {
+            install_bundles(&self.codex_home, &bundles);
+        }
 
         let roots = skill_roots_for_cwd(&self.codex_home, cwd);
         let outcome = load_skills_from_roots(roots);
-        match self.cache_by_cwd.write() {
-            Ok(mut cache) => {
-                cache.insert(cwd.to_path_buf(), outcome.clone());
-            }
-            Err(err) => {
-                err.into_inner().insert(cwd.to_path_buf(), outcome.clone());
+        let previous = self.store(cwd, &outcome);
+        self.emit_reloaded(cwd, &outcome);
+        self.emit_changed(cwd, previous.as_ref(), &outcome);
+        outcome
+    }
+
+    /// Like [`Self::skills_for_cwd_with_options`], but loads uncached skills on the blocking
+    /// pool so the caller's runtime thread is never stalled by filesystem access.
+    pub async fn skills_for_cwd_async(&self, cwd: &Path, force_reload: bool) -> SkillLoadOutcome {
+        if !force_reload && let Some(outcome) = self.cached(cwd) {
+            return outcome;
+        }
+        if (force_reload || self.bundles_dirty.swap(false, Ordering::Relaxed))
+            && let Some(bundles) = self.bundles()
+        {
+            let codex_home = self.codex_home.clone();
+            if let Err(err) =
+                tokio::task::spawn_blocking(move || install_bundles(&codex_home, &bundles)).await
+            {
+                tracing::error!("skills bundle install task failed: {err}");
             }
         }
+
+        let outcome = load_skills_for_cwd_async(self.codex_home.clone(), cwd.to_path_buf()).await;
+        let previous = self.store(cwd, &outcome);
+        self.emit_reloaded(cwd, &outcome);
+        self.emit_changed(cwd, previous.as_ref(), &outcome);
         outcome
     }
+
+    /// The [`SkillNameIndex`] for the skills in `cwd`, loading them like
+    /// [`Self::skills_for_cwd_async`] when they are not cached. The index is built once per load
+    /// and shared by every lookup until the next reload.
+    pub async fn skill_index_for_cwd_async(&self, cwd: &Path) -> Arc<SkillNameIndex> {
+        let outcome = self.skills_for_cwd_async(cwd, false).await;
+        let index = match self.cache_by_cwd.read() {
+            Ok(cache) => cache.get(cwd).map(|cached| Arc::clone(&cached.index)),
+            Err(err) => err
+                .into_inner()
+                .get(cwd)
+                .map(|cached| Arc::clone(&cached.index)),
+        };
+        index.unwrap_or_else(|| Arc::new(SkillNameIndex::new(&outcome.skills)))
+    }
+
+    fn bundles(&self) -> Option<Vec<PathBuf>> {
+        match self.bundles.read() {
+            Ok(bundles) => bundles.clone(),
+            Err(err) => err.into_inner().clone(),
+        }
+    }
+
+    fn cached(&self, cwd: &Path) -> Option<SkillLoadOutcome> {
+        let cached = match self.cache_by_cwd.read() {
+            Ok(cache) => cache.get(cwd).map(|cached| cached.outcome.clone()),
+            Err(err) => err
+                .into_inner()
+                .get(cwd)
+                .map(|cached| cached.outcome.clone()),
+        };
+        let cwd = cwd.to_path_buf();
+        self.emit(if cached.is_some() {
+            SkillsManagerEvent::CacheHit { cwd }
+        } else {
+            SkillsManagerEvent::CacheMiss { cwd }
+        });
+        cached
+    }
+
+    fn emit_reloaded(&self, cwd: &Path, outcome: &SkillLoadOutcome) {
+        if self.write_diagnostics.load(Ordering::Relaxed)
+            && let Err(err) = write_skills_diagnostics(&self.codex_home, cwd, &outcome.errors)
+        {
+            tracing::error!("failed to write skills diagnostics: {err}");
+        }
+        self.emit(SkillsManagerEvent::Reloaded {
+            cwd: cwd.to_path_buf(),
+            skill_count: outcome.skills.len(),
+            error_count: outcome.errors.len(),
+        });
+    }
+
+    fn emit_changed(
+        &self,
+        cwd: &Path,
+        previous: Option<&SkillLoadOutcome>,
+        outcome: &SkillLoadOutcome,
+    ) {
+        let Some(previous) = previous else {
+            return;
+        };
+        let diff = SkillsDiff::between(&previous.skills, &outcome.skills);
+        if !diff.is_empty() {
+            self.emit(SkillsManagerEvent::Changed {
+                cwd: cwd.to_path_buf(),
+                diff,
+            });
+        }
+    }
+
+    fn emit(&self, event: SkillsManagerEvent) {
+        match &event {
+            SkillsManagerEvent::CacheHit { cwd } => {
+                tracing::trace!(cwd = %cwd.display(), "skills.cache_hit");
+            }
+            SkillsManagerEvent::CacheMiss { cwd } => {
+                tracing::trace!(cwd = %cwd.display(), "skills.cache_miss");
+            }
+            SkillsManagerEvent::Reloaded {
+                cwd,
+                skill_count,
+                error_count,
+            } => {
+                tracing::trace!(
+                    cwd = %cwd.display(),
+                    skill_count,
+                    error_count,
+                    "skills.reloaded"
+                );
+            }
+            SkillsManagerEvent::Changed { cwd, diff } => {
+                tracing::trace!(
+                    cwd = %cwd.display(),
+                    added = diff.added.len(),
+                    removed = diff.removed.len(),
+                    changed = diff.changed.len(),
+                    "skills.changed"
+                );
+            }
+        }
+        if let Some(hook) = &self.event_hook {
+            hook(&event);
+        }
+    }
+
+    /// Caches `outcome` for `cwd`, returning the outcome it replaced.
+    fn store(&self, cwd: &Path, outcome: &SkillLoadOutcome) -> Option<SkillLoadOutcome> {
+        let cached = CachedSkills::new(outcome.clone());
+        let previous = match self.cache_by_cwd.write() {
+            Ok(mut cache) => cache.insert(cwd.to_path_buf(), cached),
+            Err(err) => err.into_inner().insert(cwd.to_path_buf(), cached),
+        };
+        previous.map(|previous| previous.outcome)
+    }
+}
+
+fn install_bundles(codex_home: &Path, bundles: &[PathBuf]) {
+    if let Err(err) = install_skill_bundles(codex_home, bundles) {
+        tracing::error!("failed to install skills bundles: {err}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skills::SkillLookup;
+    use crate::skills::diagnostics::SkillErrorDiagnostic;
+    use crate::skills::diagnostics::SkillsDiagnostics;
+    use crate::skills::diagnostics::skills_diagnostics_path;
+    use pretty_assertions::assert_eq;
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+    use tempfile::tempdir;
+    use tracing_test::traced_test;
+
+    #[test]
+    #[traced_test]
+    fn cache_miss_then_hit_emits_events() {
+        let codex_home = tempdir().expect("codex home");
+        let cwd = tempdir().expect("cwd");
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&events);
+        let manager = SkillsManager::new(codex_home.path().to_path_buf()).with_event_hook(
+            Arc::new(move |event: &SkillsManagerEvent| {
+                recorded.lock().expect("events lock").push(event.clone());
+            }),
+        );
+
+        let first = manager.skills_for_cwd(cwd.path());
+        manager.skills_for_cwd(cwd.path());
+
+        let cwd = cwd.path().to_path_buf();
+        assert_eq!(
+            *events.lock().expect("events lock"),
+            vec![
+                SkillsManagerEvent::CacheMiss { cwd: cwd.clone() },
+                SkillsManagerEvent::Reloaded {
+                    cwd: cwd.clone(),
+                    skill_count: first.skills.len(),
+                    error_count: first.errors.len(),
+                },
+                SkillsManagerEvent::CacheHit { cwd },
+            ]
+        );
+        assert!(logs_contain("skills.cache_miss"));
+        assert!(logs_contain("skills.reloaded"));
+        assert!(logs_contain("skills.cache_hit"));
+    }
+
+    #[test]
+    fn diagnostics_file_mirrors_load_errors() {
+        let codex_home = tempdir().expect("codex home");
+        let cwd = tempdir().expect("cwd");
+        let invalid_dir = codex_home.path().join("skills/invalid");
+        std::fs::create_dir_all(&invalid_dir).expect("skill dir");
+        std::fs::write(invalid_dir.join("SKILL.md"), "---\nname: bad").expect("write skill");
+        let manager = SkillsManager::new(codex_home.path().to_path_buf());
+
+        manager.skills_for_cwd(cwd.path());
+        let diagnostics_path = skills_diagnostics_path(codex_home.path());
+        assert!(!diagnostics_path.exists(), "off by default");
+
+        manager.set_write_diagnostics(true);
+        let outcome = manager.skills_for_cwd_with_options(cwd.path(), true);
+        assert_eq!(outcome.errors.len(), 1);
+
+        let contents = std::fs::read_to_string(&diagnostics_path).expect("diagnostics file");
+        let diagnostics: SkillsDiagnostics =
+            serde_json::from_str(&contents).expect("diagnostics json");
+        assert_eq!(
+            diagnostics,
+            SkillsDiagnostics {
+                cwd: cwd.path().to_path_buf(),
+                errors: vec![SkillErrorDiagnostic {
+                    path: outcome.errors[0].path.clone(),
+                    message: outcome.errors[0].message.clone(),
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn forced_reload_skips_cache_lookup() {
+        let codex_home = tempdir().expect("codex home");
+        let cwd = tempdir().expect("cwd");
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&events);
+        let manager = SkillsManager::new(codex_home.path().to_path_buf()).with_event_hook(
+            Arc::new(move |event: &SkillsManagerEvent| {
+                recorded.lock().expect("events lock").push(event.clone());
+            }),
+        );
+
+        manager.skills_for_cwd_with_options(cwd.path(), true);
+
+        let events = events.lock().expect("events lock");
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], SkillsManagerEvent::Reloaded { .. }));
+    }
+
+    fn write_skill(root: &Path, description: &str) {
+        write_named_skill(root, "demo", description);
+    }
+
+    fn write_named_skill(root: &Path, name: &str, description: &str) {
+        let skill_dir = root.join("skills").join(name);
+        std::fs::create_dir_all(&skill_dir).expect("skill dir");
+        std::fs::write(
+            skill_dir.join("SKILL.md"),
+            format!("---\nname: {name}\ndescription: {description}\n---\n\nBody\n"),
+        )
+        .expect("write skill");
+    }
+
+    fn recording_manager(
+        codex_home: &Path,
+    ) -> (SkillsManager, Arc<Mutex<Vec<SkillsManagerEvent>>>) {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&events);
+        let manager = SkillsManager::new(codex_home.to_path_buf()).with_event_hook(Arc::new(
+            move |event: &SkillsManagerEvent| {
+                recorded.lock().expect("events lock").push(event.clone());
+            },
+        ));
+        (manager, events)
+    }
+
+    #[test]
+    fn reloading_unchanged_tree_yields_equal_skills() {
+        let codex_home = tempdir().expect("codex home");
+        let cwd = tempdir().expect("cwd");
+        write_skill(codex_home.path(), "does the demo");
+        let (manager, events) = recording_manager(codex_home.path());
+
+        let first = manager.skills_for_cwd(cwd.path());
+        let second = manager.skills_for_cwd_with_options(cwd.path(), true);
+
+        assert_eq!(first.skills, second.skills);
+        let first_set: HashSet<_> = first.skills.iter().collect();
+        let second_set: HashSet<_> = second.skills.iter().collect();
+        assert_eq!(first_set, second_set);
+        assert!(
+            !events
+                .lock()
+                .expect("events lock")
+                .iter()
+                .any(|event| matches!(event, SkillsManagerEvent::Changed { .. }))
+        );
+    }
+
+    #[test]
+    fn edited_description_is_reported_as_changed() {
+        let codex_home = tempdir().expect("codex home");
+        let cwd = tempdir().expect("cwd");
+        write_skill(codex_home.path(), "does the demo");
+        let (manager, events) = recording_manager(codex_home.path());
+
+        let first = manager.skills_for_cwd(cwd.path());
+        write_skill(codex_home.path(), "does the demo, better");
+        let second = manager.skills_for_cwd_with_options(cwd.path(), true);
+
+        assert_ne!(first.skills, second.skills);
+        assert_eq!(
+            events.lock().expect("events lock").last(),
+            Some(&SkillsManagerEvent::Changed {
+                cwd: cwd.path().to_path_buf(),
+                diff: SkillsDiff {
+                    added: Vec::new(),
+                    removed: Vec::new(),
+                    changed: vec!["demo".to_string()],
+                },
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn skill_index_resolves_ignoring_case_and_separators() {
+        let codex_home = tempdir().expect("codex home");
+        let cwd = tempdir().expect("cwd");
+        write_named_skill(codex_home.path(), "demo-skill", "does the demo");
+        let manager = SkillsManager::new(codex_home.path().to_path_buf());
+        let index = manager.skill_index_for_cwd_async(cwd.path()).await;
+
+        let SkillLookup::Found(skill) = index.lookup("Demo_Skill") else {
+            panic!("expected Demo_Skill to resolve");
+        };
+        assert_eq!(skill.name, "demo-skill");
+        assert_eq!(index.lookup("missing"), SkillLookup::NotFound);
+        assert!(
+            Arc::ptr_eq(&index, &manager.skill_index_for_cwd_async(cwd.path()).await),
+            "the cached index is shared until the next reload"
+        );
+    }
+
+    #[tokio::test]
+    async fn ambiguous_normalized_name_reports_candidates() {
+        let codex_home = tempdir().expect("codex home");
+        let cwd = tempdir().expect("cwd");
+        write_named_skill(codex_home.path(), "demo-skill", "hyphenated");
+        write_named_skill(codex_home.path(), "demo_skill", "underscored");
+        let manager = SkillsManager::new(codex_home.path().to_path_buf());
+
+        // Dedup keys on the canonical name, so both skills load.
+        let outcome = manager.skills_for_cwd(cwd.path());
+        assert_eq!(
+            outcome
+                .skills
+                .iter()
+                .filter(|skill| skill.name.starts_with("demo"))
+                .count(),
+            2
+        );
+        let index = manager.skill_index_for_cwd_async(cwd.path()).await;
+        assert_eq!(
+            index.lookup("Demo Skill"),
+            SkillLookup::Ambiguous(vec!["demo-skill".to_string(), "demo_skill".to_string()])
+        );
+        let SkillLookup::Found(skill) = index.lookup("demo_skill") else {
+            panic!("exact canonical name should win");
+        };
+        assert_eq!(skill.description, "underscored");
+    }
 }
@@ -1,3 +1,5 @@
+pub mod bundle;
+pub mod diagnostics;
 pub mod injection;
 pub mod loader;
 pub mod manager;
@@ -7,9 +9,17 @@ pub mod system;
 
 pub(crate) use injection::SkillInjections;
 pub(crate) use injection::build_skill_injections;
+pub use injection::preview_skill_injections;
 pub use loader::load_skills;
+pub use loader::load_skills_async;
+pub use manager::SkillsEventHook;
 pub use manager::SkillsManager;
+pub use manager::SkillsManagerEvent;
 pub use model::SkillError;
 pub use model::SkillLoadOutcome;
+pub use model::SkillLookup;
 pub use model::SkillMetadata;
+pub use model::SkillNameIndex;
+pub use model::SkillsDiff;
+pub use model::normalize_skill_name;
 pub use render::render_skills_section;
@@ -1,24 +1,261 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::path::Path;
 use std::path::PathBuf;
 
 use codex_protocol::protocol::SkillScope;
 
+/// Equality compares every field, so two loads of an unchanged `SKILL.md` compare equal (paths
+/// are normalized by the loader) and any edit makes them differ.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SkillMetadata {
     pub name: String,
     pub description: String,
     pub short_description: Option<String>,
+    /// Tools the skill expects to use, from `metadata.allowed-tools`. Empty
+    /// when the skill does not restrict tools.
+    pub allowed_tools: Vec<String>,
+    /// Precedence when skills from different roots share a name, from
+    /// `metadata.priority` (0 when absent). Higher wins; scope order breaks ties.
+    pub priority: i32,
+    /// Skill version from `metadata.version`, when present and valid.
+    pub version: Option<String>,
+    /// License name or SPDX identifier from `metadata.license`, when present and valid.
+    pub license: Option<String>,
+    /// Project markers from `metadata.applies_to`: file names or `*`/`?` globs matched against the
+    /// cwd. The skill is only injected when one of them matches; empty means every project.
+    pub applies_to: Vec<String>,
     pub path: PathBuf,
     pub scope: SkillScope,
 }
 
+impl SkillMetadata {
+    /// Canonical "how to invoke this skill" line: the `$name` mention followed
+    /// by the short description when present, otherwise the full description.
+    pub fn invocation_hint(&self) -> String {
+        let name = self.name.as_str();
+        let summary = self
+            .short_description
+            .as_deref()
+            .unwrap_or(self.description.as_str());
+        format!("${name}: {summary}")
+    }
+}
+
+/// Hashes the identifying fields (name, descriptions, scope, and path). Equal skills hash equally
+/// because [`PartialEq`] compares a superset of these fields.
+impl Hash for SkillMetadata {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.description.hash(state);
+        self.short_description.hash(state);
+        std::mem::discriminant(&self.scope).hash(state);
+        self.path.hash(state);
+    }
+}
+
+/// How the skills for a cwd changed between two loads, by skill name. Skills are matched by
+/// `SKILL.md` path, so renaming a skill shows up as `changed`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SkillsDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl SkillsDiff {
+    pub fn between(previous: &[SkillMetadata], current: &[SkillMetadata]) -> Self {
+        let previous_by_path: HashMap<&Path, &SkillMetadata> = previous
+            .iter()
+            .map(|skill| (skill.path.as_path(), skill))
+            .collect();
+        let current_by_path: HashMap<&Path, &SkillMetadata> = current
+            .iter()
+            .map(|skill| (skill.path.as_path(), skill))
+            .collect();
+
+        let mut diff = Self::default();
+        for skill in current {
+            match previous_by_path.get(skill.path.as_path()) {
+                None => diff.added.push(skill.name.clone()),
+                Some(old) if *old != skill => diff.changed.push(skill.name.clone()),
+                Some(_) => {}
+            }
+        }
+        for skill in previous {
+            if !current_by_path.contains_key(skill.path.as_path()) {
+                diff.removed.push(skill.name.clone());
+            }
+        }
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Folds a skill name for lookups: lowercase, with `_` and spaces treated like `-`, so
+/// `Demo_Skill` finds `demo-skill`. Canonical names are still what gets displayed and deduped.
+pub fn normalize_skill_name(name: &str) -> String {
+    name.trim()
+        .chars()
+        .map(|c| match c {
+            '_' | ' ' => '-',
+            c => c,
+        })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Result of looking a skill up by a user-typed name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkillLookup {
+    Found(SkillMetadata),
+    /// Several skills share the normalized name; holds their canonical names, sorted.
+    Ambiguous(Vec<String>),
+    NotFound,
+}
+
+/// Skills indexed by canonical and [`normalize_skill_name`] form, for resolving user-typed names
+/// (`$mentions`, completions) without scanning the whole list.
+#[derive(Debug, Clone, Default)]
+pub struct SkillNameIndex {
+    skills: Vec<SkillMetadata>,
+    by_name: HashMap<String, usize>,
+    by_normalized_name: HashMap<String, Vec<usize>>,
+}
+
+impl SkillNameIndex {
+    pub fn new(skills: &[SkillMetadata]) -> Self {
+        let mut by_name = HashMap::new();
+        let mut by_normalized_name: HashMap<String, Vec<usize>> = HashMap::new();
+        for (index, skill) in skills.iter().enumerate() {
+            by_name.entry(skill.name.clone()).or_insert(index);
+            by_normalized_name
+                .entry(normalize_skill_name(&skill.name))
+                .or_default()
+                .push(index);
+        }
+        Self {
+            skills: skills.to_vec(),
+            by_name,
+            by_normalized_name,
+        }
+    }
+
+    /// The indexed skills, in load order.
+    pub fn skills(&self) -> &[SkillMetadata] {
+        &self.skills
+    }
+
+    /// An exact canonical match wins; otherwise the normalized name must pick out a single skill.
+    pub fn lookup(&self, name: &str) -> SkillLookup {
+        if let Some(index) = self.by_name.get(name) {
+            return SkillLookup::Found(self.skills[*index].clone());
+        }
+        match self
+            .by_normalized_name
+            .get(&normalize_skill_name(name))
+            .map(Vec::as_slice)
+        {
+            None | Some([]) => SkillLookup::NotFound,
+            Some([index]) => SkillLookup::Found(self.skills[*index].clone()),
+            Some(indices) => {
+                let mut names: Vec<String> = indices
+                    .iter()
+                    .map(|index| self.skills[*index].name.clone())
+                    .collect();
+                names.sort();
+                SkillLookup::Ambiguous(names)
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SkillError {
     pub path: PathBuf,
     pub message: String,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct SkillLoadOutcome {
     pub skills: Vec<SkillMetadata>,
     pub errors: Vec<SkillError>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn skill(short_description: Option<&str>) -> SkillMetadata {
+        SkillMetadata {
+            name: "pdf-processing".to_string(),
+            description: "extract text and tables from pdfs".to_string(),
+            short_description: short_description.map(str::to_string),
+            allowed_tools: Vec::new(),
+            priority: 0,
+            version: None,
+            license: None,
+            applies_to: Vec::new(),
+            path: PathBuf::from("/skills/pdf/SKILL.md"),
+            scope: SkillScope::User,
+        }
+    }
+
+    #[test]
+    fn normalized_names_ignore_case_and_separator_style() {
+        assert_eq!(normalize_skill_name("Demo_Skill"), "demo-skill");
+        assert_eq!(normalize_skill_name(" PDF processing "), "pdf-processing");
+        assert_eq!(normalize_skill_name("demo-skill"), "demo-skill");
+    }
+
+    #[test]
+    fn diff_reports_added_removed_and_changed_skills() {
+        let unchanged = skill(None);
+        let mut edited = skill(None);
+        edited.path = PathBuf::from("/skills/edited/SKILL.md");
+        edited.name = "edited".to_string();
+        let mut removed = edited.clone();
+        removed.path = PathBuf::from("/skills/removed/SKILL.md");
+        removed.name = "removed".to_string();
+        let mut added = edited.clone();
+        added.path = PathBuf::from("/skills/added/SKILL.md");
+        added.name = "added".to_string();
+
+        let previous = vec![unchanged.clone(), edited.clone(), removed];
+        edited.description = "a new description".to_string();
+        let current = vec![unchanged, edited, added];
+
+        assert_eq!(
+            SkillsDiff::between(&previous, &current),
+            SkillsDiff {
+                added: vec!["added".to_string()],
+                removed: vec!["removed".to_string()],
+                changed: vec!["edited".to_string()],
+            }
+        );
+        assert!(SkillsDiff::between(&current, &current).is_empty());
+    }
+
+    #[test]
+    fn invocation_hint_uses_description_without_short_description() {
+        let skill = skill(None);
+        assert_eq!(
+            skill.invocation_hint(),
+            "$pdf-processing: extract text and tables from pdfs"
+        );
+        assert_eq!(skill.invocation_hint(), skill.clone().invocation_hint());
+    }
+
+    #[test]
+    fn invocation_hint_prefers_short_description() {
+        assert_eq!(
+            skill(Some("read pdfs")).invocation_hint(),
+            "$pdf-processing: read pdfs"
+        );
+    }
+}
@@ -7,13 +7,20 @@ pub fn render_skills_section(skills: &[SkillMetadata]) -> Option<String> {
 
     let mut lines: Vec<String> = Vec::new();
     lines.push("## Skills".to_string());
-    lines.push("These skills are discovered at startup from multiple local sources. Each entry includes a name, description, and file path so you can open the source for full instructions.".to_string());
+    lines.push("These skills are discovered at startup from multiple local sources. Each entry includes an invocation hint (`$name` plus a description) and file path so you can open the source for full instructions.".to_string());
 
     for skill in skills {
         let path_str = skill.path.to_string_lossy().replace('\\', "/");
-        let name = skill.name.as_str();
-        let description = skill.description.as_str();
-        lines.push(format!("- {name}: {description} (file: {path_str})"));
+        let hint = skill.invocation_hint();
+        let mut details: Vec<String> = Vec::new();
+        if let Some(version) = skill.version.as_deref() {
+            details.push(format!("version: {version}"));
+        }
+        if let Some(license) = skill.license.as_deref() {
+            details.push(format!("license: {license}"));
+        }
+        details.push(format!("file: {path_str}"));
+        lines.push(format!("- {hint} ({})", details.join(", ")));
     }
 
     lines.push(
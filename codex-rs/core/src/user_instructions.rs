@@ -48,6 +48,12 @@ pub(crate) struct SkillInstructions {
     pub name: String,
     pub path: String,
     pub contents: String,
+    /// Canonical invocation line from [`crate::skills::SkillMetadata::invocation_hint`].
+    pub invocation_hint: String,
+    /// Tools the skill is restricted to; omitted from the rendered item when empty.
+    pub allowed_tools: Vec<String>,
+    /// Skill version from `metadata.version`; omitted from the rendered item when absent.
+    pub version: Option<String>,
 }
 
 impl SkillInstructions {
@@ -62,13 +68,25 @@ impl SkillInstructions {
 
 impl From<SkillInstructions> for ResponseItem {
     fn from(si: SkillInstructions) -> Self {
+        let allowed_tools = if si.allowed_tools.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "<allowed-tools>{}</allowed-tools>\n",
+                si.allowed_tools.join(", ")
+            )
+        };
+        let version = si
+            .version
+            .map(|version| format!("<version>{version}</version>\n"))
+            .unwrap_or_default();
         ResponseItem::Message {
             id: None,
             role: "user".to_string(),
             content: vec![ContentItem::InputText {
                 text: format!(
-                    "<skill>\n<name>{}</name>\n<path>{}</path>\n{}\n</skill>",
-                    si.name, si.path, si.contents
+                    "<skill>\n<name>{}</name>\n<path>{}</path>\n<invocation>{}</invocation>\n{version}{allowed_tools}{}\n</skill>",
+                    si.name, si.path, si.invocation_hint, si.contents
                 ),
             }],
         }
@@ -157,6 +175,9 @@ mod tests {
             name: "demo-skill".to_string(),
             path: "skills/demo/SKILL.md".to_string(),
             contents: "body".to_string(),
+            invocation_hint: "$demo-skill: does demo things".to_string(),
+            allowed_tools: Vec::new(),
+            version: None,
         };
         let response_item: ResponseItem = skill_instructions.into();
 
@@ -172,7 +193,32 @@ mod tests {
 
         assert_eq!(
             text,
-            "<skill>\n<name>demo-skill</name>\n<path>skills/demo/SKILL.md</path>\nbody\n</skill>",
+            "<skill>\n<name>demo-skill</name>\n<path>skills/demo/SKILL.md</path>\n<invocation>$demo-skill: does demo things</invocation>\nbody\n</skill>",
+        );
+    }
+
+    #[test]
+    fn test_skill_instructions_with_allowed_tools() {
+        let response_item: ResponseItem = SkillInstructions {
+            name: "demo-skill".to_string(),
+            path: "skills/demo/SKILL.md".to_string(),
+            contents: "body".to_string(),
+            invocation_hint: "$demo-skill: does demo things".to_string(),
+            allowed_tools: vec!["shell".to_string(), "apply_patch".to_string()],
+            version: Some("1.2.0".to_string()),
+        }
+        .into();
+
+        let ResponseItem::Message { content, .. } = response_item else {
+            panic!("expected ResponseItem::Message");
+        };
+        let [ContentItem::InputText { text }] = content.as_slice() else {
+            panic!("expected one InputText content item");
+        };
+
+        assert_eq!(
+            text,
+            "<skill>\n<name>demo-skill</name>\n<path>skills/demo/SKILL.md</path>\n<invocation>$demo-skill: does demo things</invocation>\n<version>1.2.0</version>\n<allowed-tools>shell, apply_patch</allowed-tools>\nbody\n</skill>",
         );
     }
 
@@ -1743,6 +1743,8 @@ pub enum SkillScope {
     User,
     Repo,
     System,
+    /// Extracted from a configured skills archive (`skills_bundles`).
+    Bundle,
     Admin,
 }
 
@@ -1753,6 +1755,12 @@ pub struct SkillMetadata {
     #[ts(optional)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub short_description: Option<String>,
+    #[ts(optional)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[ts(optional)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
     pub path: PathBuf,
     pub scope: SkillScope,
 }
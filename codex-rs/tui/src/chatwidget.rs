@@ -3735,6 +3735,11 @@ fn skills_for_cwd(cwd: &Path, skills_entries: &[SkillsListEntry]) -> Vec<SkillMe
                     name: skill.name.clone(),
                     description: skill.description.clone(),
                     short_description: skill.short_description.clone(),
+                    allowed_tools: Vec::new(),
+                    priority: 0,
+                    version: skill.version.clone(),
+                    license: skill.license.clone(),
+                    applies_to: Vec::new(),
                     path: skill.path.clone(),
                     scope: skill.scope,
                 })
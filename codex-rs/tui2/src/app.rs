@@ -17,11 +17,18 @@ use crate::pager_overlay::Overlay;
 use crate::render::highlight::highlight_bash_to_lines;
 use crate::render::renderable::Renderable;
 use crate::resume_picker::ResumeSelection;
+use crate::theme::Theme;
+use crate::transcript_collapse::CollapsedTranscript;
 use crate::transcript_copy_ui::TranscriptCopyUi;
+use crate::transcript_minimap;
+use crate::transcript_mirror::TranscriptMirror;
 use crate::transcript_multi_click::TranscriptMultiClick;
-use crate::transcript_selection::TRANSCRIPT_GUTTER_COLS;
+use crate::transcript_render::DEFAULT_CELL_SPACING;
+use crate::transcript_selection::TranscriptContentAnchor;
+use crate::transcript_selection::TranscriptGutter;
 use crate::transcript_selection::TranscriptSelection;
 use crate::transcript_selection::TranscriptSelectionPoint;
+use crate::transcript_view_cache::TranscriptRowDiff;
 use crate::transcript_view_cache::TranscriptViewCache;
 use crate::tui;
 use crate::tui::TuiEvent;
@@ -29,7 +36,9 @@ use crate::tui::scrolling::MouseScrollState;
 use crate::tui::scrolling::ScrollConfig;
 use crate::tui::scrolling::ScrollConfigOverrides;
 use crate::tui::scrolling::ScrollDirection;
+use crate::tui::scrolling::ScrollOverrideAdjustment;
 use crate::tui::scrolling::ScrollUpdate;
+use crate::tui::scrolling::TranscriptEdge;
 use crate::tui::scrolling::TranscriptScroll;
 use crate::update_action::UpdateAction;
 use codex_ansi_escape::ansi_escape_line;
@@ -37,6 +46,7 @@ use codex_core::AuthManager;
 use codex_core::ConversationManager;
 use codex_core::config::Config;
 use codex_core::config::edit::ConfigEditsBuilder;
+use codex_core::config::types::EscBehavior;
 #[cfg(target_os = "windows")]
 use codex_core::features::Feature;
 use codex_core::models_manager::manager::ModelsManager;
@@ -62,8 +72,11 @@ use crossterm::event::KeyEventKind;
 use crossterm::event::MouseButton;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+use ratatui::style::Style;
 use ratatui::style::Stylize;
 use ratatui::text::Line;
+use ratatui::text::Span;
 use ratatui::widgets::Clear;
 use ratatui::widgets::Paragraph;
 use ratatui::widgets::WidgetRef;
@@ -76,6 +89,7 @@ use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::thread;
 use std::time::Duration;
+use std::time::Instant;
 use tokio::select;
 use tokio::sync::mpsc::unbounded_channel;
 
@@ -114,14 +128,38 @@ fn session_summary(
     }
 
     let usage_line = FinalOutput::from(token_usage).to_string();
-    let resume_command =
-        conversation_id.map(|conversation_id| format!("codex resume {conversation_id}"));
     Some(SessionSummary {
         usage_line,
-        resume_command,
+        resume_command: resume_command(conversation_id),
     })
 }
 
+/// The `codex resume <id>` command for a conversation, if there is one yet.
+fn resume_command(conversation_id: Option<ConversationId>) -> Option<String> {
+    conversation_id.map(|conversation_id| format!("codex resume {conversation_id}"))
+}
+
+/// Whether switching sandbox policy should run the Windows world-writable scan.
+///
+/// `skip_once` is the one-shot suppression set after the user confirms the warning; it is
+/// consumed here whether or not the scan would otherwise have run.
+#[cfg(any(target_os = "windows", test))]
+fn world_writable_scan_needed(
+    skip_once: &mut bool,
+    has_platform_sandbox: bool,
+    policy_is_workspace_write_or_ro: bool,
+    warning_hidden: bool,
+) -> bool {
+    if std::mem::take(skip_once) {
+        return false;
+    }
+    has_platform_sandbox && policy_is_workspace_write_or_ro && !warning_hidden
+}
+
+fn conversation_id_text(conversation_id: Option<ConversationId>) -> Option<String> {
+    conversation_id.map(|conversation_id| conversation_id.to_string())
+}
+
 fn errors_for_cwd(cwd: &Path, response: &ListSkillsResponseEvent) -> Vec<SkillErrorInfo> {
     response
         .skills
@@ -131,27 +169,153 @@ fn errors_for_cwd(cwd: &Path, response: &ListSkillsResponseEvent) -> Vec<SkillEr
         .unwrap_or_default()
 }
 
-fn emit_skill_load_warnings(app_event_tx: &AppEventSender, errors: &[SkillErrorInfo]) {
+fn emit_skill_load_warnings(
+    app_event_tx: &AppEventSender,
+    errors: &[SkillErrorInfo],
+    theme: &Theme,
+) {
     if errors.is_empty() {
         return;
     }
 
     let error_count = errors.len();
     app_event_tx.send(AppEvent::InsertHistoryCell(Box::new(
-        crate::history_cell::new_warning_event(format!(
-            "Skipped loading {error_count} skill(s) due to invalid SKILL.md files."
-        )),
+        crate::history_cell::new_warning_event(
+            format!("Skipped loading {error_count} skill(s) due to invalid SKILL.md files."),
+            theme,
+        ),
     )));
 
     for error in errors {
         let path = error.path.display();
         let message = error.message.as_str();
         app_event_tx.send(AppEvent::InsertHistoryCell(Box::new(
-            crate::history_cell::new_warning_event(format!("{path}: {message}")),
+            crate::history_cell::new_warning_event(format!("{path}: {message}"), theme),
         )));
     }
 }
 
+/// How long the "── top ──" / "── bottom ──" marker stays visible after hitting an edge.
+const TRANSCRIPT_EDGE_FLASH_DURATION: Duration = Duration::from_millis(600);
+
+/// How long after the last input the session stays fully responsive before low-power mode
+/// (`tui.low_power_idle`) may kick in. Covers short follow-ups such as paste flushes and edge
+/// flashes.
+const LOW_POWER_INPUT_GRACE: Duration = Duration::from_secs(2);
+
+/// Default number of lines kept on screen across a page jump (`tui.page_overlap`).
+const DEFAULT_PAGE_OVERLAP: u16 = 2;
+
+/// Lines to scroll for a full-page jump (PageUp/PageDown) in a viewport `visible_lines` tall.
+///
+/// `overlap` lines carry over between pages. It is capped so a page jump always moves at least
+/// one line.
+fn page_delta(visible_lines: usize, overlap: u16) -> i32 {
+    let overlap = usize::from(overlap).min(visible_lines.saturating_sub(1));
+    i32::try_from(visible_lines.saturating_sub(overlap).max(1)).unwrap_or(i32::MAX)
+}
+
+/// Lines to scroll for a half-page jump (Ctrl+U/Ctrl+D): half of [`page_delta`], rounded up.
+fn half_page_delta(visible_lines: usize, overlap: u16) -> i32 {
+    let page = page_delta(visible_lines, overlap).unsigned_abs();
+    i32::try_from(page.div_ceil(2)).unwrap_or(i32::MAX)
+}
+
+/// Draw the dim "── top ──" / "── bottom ──" marker centered on the first/last visible row.
+///
+/// The marker is painted over already-rendered rows, so it never changes transcript layout.
+fn render_transcript_edge_marker(area: Rect, buf: &mut Buffer, edge: TranscriptEdge) {
+    if area.height == 0 {
+        return;
+    }
+    let (label, y) = match edge {
+        TranscriptEdge::Top => ("── top ──", area.y),
+        TranscriptEdge::Bottom => ("── bottom ──", area.bottom().saturating_sub(1)),
+    };
+    let marker = label.dim();
+    let width = u16::try_from(marker.width()).unwrap_or(u16::MAX);
+    if width > area.width {
+        return;
+    }
+    let x = area.x + (area.width - width) / 2;
+    buf.set_span(x, y, &marker, width);
+}
+
+/// Render the clickable "resume following" pill in the bottom-right corner of `area`, shown while
+/// output streams in but the transcript no longer auto-follows it. Returns the pill's rect for
+/// hit-testing, or `None` when it does not fit.
+fn render_resume_follow_pill(area: Rect, buf: &mut Buffer, theme: &Theme) -> Option<Rect> {
+    let style = Style::new()
+        .bg(theme.copy_pill_bg)
+        .fg(theme.copy_pill_fg)
+        .add_modifier(Modifier::BOLD);
+    let pill = Span::styled(" ↓ following paused · End to resume ", style);
+    let width = u16::try_from(pill.width()).ok()?;
+    if area.height == 0 || width > area.width {
+        return None;
+    }
+    let rect = Rect::new(
+        area.right() - width,
+        area.bottom().saturating_sub(1),
+        width,
+        1,
+    );
+    buf.set_span(rect.x, rect.y, &pill, width);
+    Some(rect)
+}
+
+/// Render the clickable "stop" pill in the top-right corner of `area`, shown while a task is
+/// running. Returns the pill's rect for hit-testing, or `None` when it does not fit.
+fn render_stop_pill(area: Rect, buf: &mut Buffer, theme: &Theme) -> Option<Rect> {
+    let style = Style::new()
+        .bg(theme.copy_pill_bg)
+        .fg(theme.copy_pill_fg)
+        .add_modifier(Modifier::BOLD);
+    let pill = Span::styled(" ⏹ stop · Alt+X ", style);
+    let width = u16::try_from(pill.width()).ok()?;
+    if area.height == 0 || width > area.width {
+        return None;
+    }
+    let rect = Rect::new(area.right() - width, area.y, width, 1);
+    buf.set_span(rect.x, rect.y, &pill, width);
+    Some(rect)
+}
+
+/// Inline transcript geometry for the current terminal size (see [`App::transcript_viewport`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TranscriptViewport {
+    /// Rows above the bottom pane, anchored at the top-left of the terminal.
+    area: Rect,
+    /// Width history cells are wrapped at (terminal width minus any gutter margin).
+    content_width: u16,
+}
+
+/// Which terminal screen buffer the session draws on (`tui.alternate_screen`, Alt+M).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ScreenMode {
+    /// Full-screen on the terminal's alternate screen, leaving the shell's output untouched.
+    AltScreen,
+    /// On the normal screen buffer, drawn over the shell's output.
+    Inline,
+}
+
+impl ScreenMode {
+    pub(crate) fn from_config(alternate_screen: bool) -> Self {
+        if alternate_screen {
+            Self::AltScreen
+        } else {
+            Self::Inline
+        }
+    }
+
+    fn toggled(self) -> Self {
+        match self {
+            Self::AltScreen => Self::Inline,
+            Self::Inline => Self::AltScreen,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct SessionSummary {
     usage_line: String,
@@ -204,6 +368,10 @@ fn migration_prompt_hidden(config: &Config, migration_config_key: &str) -> bool
     }
 }
 
+fn find_model_preset<'a>(presets: &'a [ModelPreset], model: &str) -> Option<&'a ModelPreset> {
+    presets.iter().find(|preset| preset.model == model)
+}
+
 async fn handle_model_migration_prompt_if_needed(
     tui: &mut tui::Tui,
     config: &mut Config,
@@ -212,10 +380,8 @@ async fn handle_model_migration_prompt_if_needed(
     models_manager: Arc<ModelsManager>,
 ) -> Option<AppExitInfo> {
     let available_models = models_manager.list_models(config).await;
-    let upgrade = available_models
-        .iter()
-        .find(|preset| preset.model == model)
-        .and_then(|preset| preset.upgrade.as_ref());
+    let upgrade =
+        find_model_preset(&available_models, model).and_then(|preset| preset.upgrade.as_ref());
 
     if let Some(ModelUpgrade {
         id: target_model,
@@ -238,10 +404,8 @@ async fn handle_model_migration_prompt_if_needed(
             return None;
         }
 
-        let current_preset = available_models.iter().find(|preset| preset.model == model);
-        let target_preset = available_models
-            .iter()
-            .find(|preset| preset.model == target_model);
+        let current_preset = find_model_preset(&available_models, model);
+        let target_preset = find_model_preset(&available_models, &target_model);
         let target_display_name = target_preset
             .map(|preset| preset.display_name.clone())
             .unwrap_or_else(|| target_model.clone());
@@ -326,7 +490,16 @@ pub(crate) struct App {
     pub(crate) file_search: FileSearchManager,
 
     pub(crate) transcript_cells: Vec<Arc<dyn HistoryCell>>,
+    /// Folds the oldest cells into a summary once `tui.max_transcript_cells` is exceeded.
+    transcript_collapse: CollapsedTranscript,
     transcript_view_cache: TranscriptViewCache,
+    /// Per-row dirty tracking so unchanged transcript rows are not re-rendered each frame.
+    transcript_row_diff: TranscriptRowDiff,
+    /// Recent raw protocol events for the F11 debug overlay.
+    #[cfg(feature = "debug-logs")]
+    protocol_event_log: crate::protocol_event_log::ProtocolEventLog,
+    /// Plain-text copy of inserted history cells (`tui.mirror_transcript`).
+    transcript_mirror: TranscriptMirror,
 
     #[allow(dead_code)]
     transcript_scroll: TranscriptScroll,
@@ -334,6 +507,15 @@ pub(crate) struct App {
     transcript_multi_click: TranscriptMultiClick,
     transcript_view_top: usize,
     transcript_total_lines: usize,
+    transcript_gutter: TranscriptGutter,
+    /// Set when a scroll attempt could not move past an edge; drives a brief edge marker.
+    transcript_edge_flash: Option<(TranscriptEdge, Instant)>,
+    /// Hit target of the "resume following" pill from the last frame, if it was shown.
+    resume_follow_rect: Option<Rect>,
+    /// Hit target of the "stop" pill from the last frame, if it was shown.
+    stop_rect: Option<Rect>,
+    /// Hit target of the transcript minimap from the last frame, if it was shown.
+    transcript_minimap_rect: Option<Rect>,
     transcript_copy_ui: TranscriptCopyUi,
 
     // Pager overlay state (Transcript or Static like Diff)
@@ -345,9 +527,32 @@ pub(crate) struct App {
 
     /// Controls the animation thread that sends CommitTick events.
     pub(crate) commit_anim_running: Arc<AtomicBool>,
+    /// Set when the commit animation was stopped (or refused to start) because the terminal lost
+    /// focus; it restarts on focus gain.
+    commit_anim_paused: bool,
+    /// Last focus state reported by the terminal (`FocusGained`/`FocusLost`).
+    terminal_focused: bool,
 
     scroll_config: ScrollConfig,
     scroll_state: MouseScrollState,
+    /// Whether to back off redraws while idle (`tui.low_power_idle`).
+    low_power_idle: bool,
+    /// When the last key, mouse, or paste event arrived.
+    last_input_at: Instant,
+    /// Lines kept on screen across PageUp/PageDown and half-page jumps.
+    page_overlap: u16,
+    /// Blank lines between non-continuation history cells (`tui.cell_spacing`).
+    cell_spacing: u16,
+    /// Resolved `tui.theme`, handed to the transcript views and pills.
+    theme: Theme,
+    /// Whether selection copies include ANSI styling by default (`tui.copy_ansi`).
+    copy_ansi: bool,
+    /// Whether single-file selection copies get a path comment header (`tui.copy_path_header`).
+    copy_path_header: bool,
+    /// Screen buffer the session currently draws on.
+    screen_mode: ScreenMode,
+    /// What Esc does while the composer has text (`tui.esc_behavior`).
+    esc_behavior: EscBehavior,
 
     // Esc-backtracking state grouped
     pub(crate) backtrack: crate::app_backtrack::BacktrackState,
@@ -387,6 +592,14 @@ impl App {
         let (app_event_tx, mut app_event_rx) = unbounded_channel();
         let app_event_tx = AppEventSender::new(app_event_tx);
 
+        crate::wrapping::set_wrap_algorithm(config.tui_wrap_algorithm);
+        let (theme, theme_warnings) = Theme::from_config(&config);
+        for warning in theme_warnings {
+            app_event_tx.send(AppEvent::InsertHistoryCell(Box::new(
+                crate::history_cell::new_warning_event(warning, &theme),
+            )));
+        }
+
         let conversation_manager = Arc::new(ConversationManager::new(
             auth_manager.clone(),
             SessionSource::Cli,
@@ -462,25 +675,45 @@ impl App {
 
         chat_widget.maybe_prompt_windows_sandbox_enable();
 
-        let file_search = FileSearchManager::new(config.cwd.clone(), app_event_tx.clone());
+        let file_search = FileSearchManager::new(config.cwd.clone(), app_event_tx.clone())
+            .with_debounce_ms(config.tui_file_search_debounce_ms);
         #[cfg(not(debug_assertions))]
         let upgrade_version = crate::updates::get_upgrade_version(&config);
-        let scroll_config = ScrollConfig::from_terminal(
-            &terminal_info(),
-            ScrollConfigOverrides {
-                events_per_tick: config.tui_scroll_events_per_tick,
-                wheel_lines_per_tick: config.tui_scroll_wheel_lines,
-                trackpad_lines_per_tick: config.tui_scroll_trackpad_lines,
-                trackpad_accel_events: config.tui_scroll_trackpad_accel_events,
-                trackpad_accel_max: config.tui_scroll_trackpad_accel_max,
-                mode: Some(config.tui_scroll_mode),
-                wheel_tick_detect_max_ms: config.tui_scroll_wheel_tick_detect_max_ms,
-                wheel_like_max_duration_ms: config.tui_scroll_wheel_like_max_duration_ms,
-                invert_direction: config.tui_scroll_invert,
-            },
-        );
+        let (scroll_overrides, scroll_adjustments) = ScrollConfigOverrides {
+            events_per_tick: config.tui_scroll_events_per_tick,
+            wheel_lines_per_tick: config.tui_scroll_wheel_lines,
+            trackpad_lines_per_tick: config.tui_scroll_trackpad_lines,
+            trackpad_accel_events: config.tui_scroll_trackpad_accel_events,
+            trackpad_accel_max: config.tui_scroll_trackpad_accel_max,
+            trackpad_accel_curve: Some(config.tui_scroll_trackpad_accel_curve),
+            mode: Some(config.tui_scroll_mode),
+            wheel_tick_detect_max_ms: config.tui_scroll_wheel_tick_detect_max_ms,
+            wheel_like_max_duration_ms: config.tui_scroll_wheel_like_max_duration_ms,
+            invert_direction: config.tui_scroll_invert,
+        }
+        .validated();
+        let scroll_config = ScrollConfig::from_terminal(&terminal_info(), scroll_overrides);
 
         let copy_selection_shortcut = crate::transcript_copy_ui::detect_copy_selection_shortcut();
+        let transcript_gutter = TranscriptGutter::new(
+            config.tui_transcript_gutter_width,
+            config.tui_transcript_line_numbers,
+        )
+        .with_minimap(config.tui_transcript_minimap)
+        .with_max_content_width(config.tui_max_content_width);
+        let page_overlap = config.tui_page_overlap.unwrap_or(DEFAULT_PAGE_OVERLAP);
+        let cell_spacing = config.tui_cell_spacing.unwrap_or(DEFAULT_CELL_SPACING);
+        let copy_ansi = config.tui_copy_ansi;
+        let copy_path_header = config.tui_copy_path_header;
+        let screen_mode = ScreenMode::from_config(config.tui_alternate_screen);
+        let esc_behavior = config.tui_esc_behavior;
+        let low_power_idle = config.tui_low_power_idle;
+        let transcript_collapse = CollapsedTranscript::new(config.tui_max_transcript_cells);
+        let transcript_mirror = if config.tui_mirror_transcript {
+            TranscriptMirror::new(&config.codex_home, cell_spacing)
+        } else {
+            TranscriptMirror::disabled()
+        };
 
         let mut app = Self {
             server: conversation_manager.clone(),
@@ -493,25 +726,57 @@ impl App {
             file_search,
             enhanced_keys_supported,
             transcript_cells: Vec::new(),
+            transcript_collapse,
             transcript_view_cache: TranscriptViewCache::new(),
+            transcript_row_diff: TranscriptRowDiff::new(),
+            #[cfg(feature = "debug-logs")]
+            protocol_event_log: Default::default(),
+            transcript_mirror,
             transcript_scroll: TranscriptScroll::default(),
             transcript_selection: TranscriptSelection::default(),
             transcript_multi_click: TranscriptMultiClick::default(),
             transcript_view_top: 0,
             transcript_total_lines: 0,
+            transcript_gutter,
+            transcript_edge_flash: None,
+            resume_follow_rect: None,
+            stop_rect: None,
+            transcript_minimap_rect: None,
             transcript_copy_ui: TranscriptCopyUi::new_with_shortcut(copy_selection_shortcut),
             overlay: None,
             deferred_history_lines: Vec::new(),
             has_emitted_history_lines: false,
             commit_anim_running: Arc::new(AtomicBool::new(false)),
+            commit_anim_paused: false,
+            terminal_focused: true,
             scroll_config,
             scroll_state: MouseScrollState::default(),
+            low_power_idle,
+            last_input_at: Instant::now(),
+            page_overlap,
+            cell_spacing,
+            theme,
+            copy_ansi,
+            copy_path_header,
+            screen_mode,
+            esc_behavior,
             backtrack: BacktrackState::default(),
             feedback: feedback.clone(),
             pending_update_action: None,
             suppress_shutdown_complete: false,
             skip_world_writable_scan_once: false,
         };
+        app.transcript_view_cache.set_cell_spacing(cell_spacing);
+        app.transcript_view_cache.set_theme(theme);
+        app.transcript_multi_click.set_cell_spacing(cell_spacing);
+        app.transcript_copy_ui.set_theme(theme);
+        app.transcript_copy_ui.set_pill_appearance(
+            app.config.tui_copy_pill_label.as_deref(),
+            app.config.tui_copy_pill_position,
+        );
+        if let Some(message) = ScrollOverrideAdjustment::summary(&scroll_adjustments) {
+            app.chat_widget.add_info_message(message, None);
+        }
 
         // On startup, if Agent mode (workspace-write) or ReadOnly is active, warn about world-writable dirs on Windows.
         #[cfg(target_os = "windows")]
@@ -561,20 +826,31 @@ impl App {
             Some(event) = tui_events.next() => {
                 app.handle_tui_event(tui, event).await?
             }
-        } {}
+        } {
+            app.update_low_power(&tui.frame_requester());
+        }
         let width = tui.terminal.last_known_screen_size.width;
         let session_lines = if width == 0 {
             Vec::new()
         } else {
-            let transcript =
-                crate::transcript_render::build_transcript_lines(&app.transcript_cells, width);
+            let transcript = crate::transcript_render::build_transcript_lines(
+                &app.transcript_cells,
+                width,
+                app.cell_spacing,
+            );
             let (lines, line_meta) = (transcript.lines, transcript.meta);
             let is_user_cell: Vec<bool> = app
                 .transcript_cells
                 .iter()
                 .map(|cell| cell.as_any().is::<UserHistoryCell>())
                 .collect();
-            crate::transcript_render::render_lines_to_ansi(&lines, &line_meta, &is_user_cell, width)
+            crate::transcript_render::render_lines_to_ansi(
+                &lines,
+                &line_meta,
+                &is_user_cell,
+                width,
+                &app.theme,
+            )
         };
 
         tui.terminal.clear()?;
@@ -586,14 +862,88 @@ impl App {
         })
     }
 
+    /// Clean up pasted text before it reaches the composer: drop ANSI/control sequences
+    /// (`tui.paste_strip_ansi`) and normalize line endings.
+    fn sanitize_paste(&self, pasted: &str) -> String {
+        let strip_trailing_newline = self.config.tui_paste_strip_trailing_newline;
+        if self.config.tui_paste_strip_ansi {
+            let stripped = crate::clipboard_paste::strip_ansi_sequences(pasted);
+            crate::clipboard_paste::normalize_pasted_text(&stripped, strip_trailing_newline)
+        } else {
+            crate::clipboard_paste::normalize_pasted_text(pasted, strip_trailing_newline)
+        }
+    }
+
+    /// Whether nothing needs periodic redraws: no running task, no scroll stream, and no input
+    /// within [`LOW_POWER_INPUT_GRACE`].
+    fn is_idle(&self) -> bool {
+        !self.chat_widget.is_task_running()
+            && self.scroll_state.is_idle()
+            && self.last_input_at.elapsed() >= LOW_POWER_INPUT_GRACE
+    }
+
+    /// Move `frame_requester` in or out of low-power mode (`tui.low_power_idle`) to match the
+    /// current activity.
+    ///
+    /// Entering low power also stops the commit-tick thread; it only has work to do while a task
+    /// streams output. An unfocused terminal always runs in low power; see
+    /// [`App::on_focus_changed`].
+    fn update_low_power(&self, frame_requester: &crate::tui::FrameRequester) {
+        let idle = self.low_power_idle && self.is_idle();
+        if idle {
+            self.commit_anim_running.store(false, Ordering::Release);
+        }
+        frame_requester.set_low_power(idle || !self.terminal_focused);
+    }
+
+    /// React to the terminal gaining or losing focus.
+    ///
+    /// Losing focus pauses the commit animation and periodic frames, and lets desktop
+    /// notifications through. Gaining focus resumes both and drops any notification that was
+    /// still waiting to be posted.
+    fn on_focus_changed(&mut self, focused: bool, frame_requester: &crate::tui::FrameRequester) {
+        self.terminal_focused = focused;
+        self.chat_widget.set_terminal_focused(focused);
+        if focused {
+            self.chat_widget.clear_pending_notification();
+            frame_requester.set_low_power(false);
+            if std::mem::take(&mut self.commit_anim_paused) {
+                self.app_event_tx.send(AppEvent::StartCommitAnimation);
+            }
+        } else {
+            if self.commit_anim_running.swap(false, Ordering::AcqRel) {
+                self.commit_anim_paused = true;
+            }
+            frame_requester.set_low_power(true);
+        }
+        // Redraw so focus-dependent layout (`tui.compact_composer`) updates.
+        frame_requester.schedule_frame();
+    }
+
+    /// Record user input and restore full responsiveness right away.
+    fn note_input(&mut self, frame_requester: &crate::tui::FrameRequester) {
+        self.last_input_at = Instant::now();
+        frame_requester.set_low_power(false);
+    }
+
     pub(crate) async fn handle_tui_event(
         &mut self,
         tui: &mut tui::Tui,
         event: TuiEvent,
     ) -> Result<bool> {
+        if matches!(
+            &event,
+            TuiEvent::Key(_) | TuiEvent::Mouse(_) | TuiEvent::Paste(_)
+        ) {
+            self.note_input(&tui.frame_requester());
+        }
         if matches!(&event, TuiEvent::Draw) {
             self.handle_scroll_tick(tui);
         }
+        if let TuiEvent::FocusChanged(focused) = event {
+            self.on_focus_changed(focused, &tui.frame_requester());
+            return Ok(true);
+        }
 
         if self.overlay.is_some() {
             let _ = self.handle_backtrack_overlay_event(tui, event).await?;
@@ -606,11 +956,7 @@ impl App {
                     self.handle_mouse_event(tui, mouse_event);
                 }
                 TuiEvent::Paste(pasted) => {
-                    // Many terminals convert newlines to \r when pasting (e.g., iTerm2),
-                    // but tui-textarea expects \n. Normalize CR to LF.
-                    // [tui-textarea]: https://github.com/rhysd/tui-textarea/blob/4d18622eeac13b309e0ff6a55a46ac6706da68cf/src/textarea.rs#L782-L783
-                    // [iTerm2]: https://github.com/gnachman/iTerm2/blob/5d0c0d9f68523cbd0494dad5422998964a2ecd8d/sources/iTermPasteHelper.m#L206-L216
-                    let pasted = pasted.replace("\r", "\n");
+                    let pasted = self.sanitize_paste(&pasted);
                     self.chat_widget.handle_paste(pasted);
                 }
                 TuiEvent::Draw => {
@@ -621,7 +967,12 @@ impl App {
                     {
                         return Ok(true);
                     }
-                    let cells = self.transcript_cells.clone();
+                    let cells = self
+                        .transcript_collapse
+                        .view(&self.transcript_cells)
+                        .to_vec();
+                    self.chat_widget
+                        .set_terminal_focused(tui.is_terminal_focused());
                     tui.draw(tui.terminal.size()?.height, |frame| {
                         let chat_height = self.chat_widget.desired_height(frame.area().width);
                         let chat_top = self.render_transcript_cells(frame, &cells, chat_height);
@@ -674,11 +1025,32 @@ impl App {
                         self.copy_selection_key(),
                     );
                 }
+                // Handled above, before overlay dispatch.
+                TuiEvent::FocusChanged(_) => {}
             }
         }
         Ok(true)
     }
 
+    /// Collapse `cell` into the newest transcript cell when both are the same error, bumping its
+    /// `(×N)` count instead of appending a duplicate. Returns whether it merged.
+    ///
+    /// Only the immediately preceding cell is considered, so any other cell in between starts a
+    /// new group. The append-only transcript mirror keeps the first occurrence.
+    fn merge_into_last_transcript_cell(&mut self, cell: &dyn HistoryCell) -> bool {
+        let Some(merged) = self
+            .transcript_cells
+            .last()
+            .and_then(|last| crate::history_cell::merge_repeated_error(last.as_ref(), cell))
+        else {
+            return false;
+        };
+        if let Some(last) = self.transcript_cells.last_mut() {
+            *last = Arc::new(merged);
+        }
+        true
+    }
+
     pub(crate) fn render_transcript_cells(
         &mut self,
         frame: &mut Frame,
@@ -686,7 +1058,8 @@ impl App {
         chat_height: u16,
     ) -> u16 {
         let area = frame.area();
-        if area.width == 0 || area.height == 0 {
+        self.transcript_minimap_rect = None;
+        if self.transcript_gutter.content_width(area.width) == 0 || area.height == 0 {
             self.transcript_scroll = TranscriptScroll::default();
             self.transcript_view_top = 0;
             self.transcript_total_lines = 0;
@@ -709,8 +1082,8 @@ impl App {
             height: max_transcript_height,
         };
 
-        self.transcript_view_cache
-            .ensure_wrapped(cells, transcript_area.width);
+        let content_width = self.transcript_gutter.content_width(transcript_area.width);
+        self.wrap_transcript_preserving_selection(cells, content_width);
         let total_lines = self.transcript_view_cache.lines().len();
         if total_lines == 0 {
             Clear.render_ref(transcript_area, frame.buffer);
@@ -760,27 +1133,55 @@ impl App {
             width: area.width,
             height: transcript_visible_height,
         };
+        let content_area = self.transcript_gutter.content_area(transcript_area);
 
         // Cache a few viewports worth of rasterized rows so redraws during streaming can cheaply
         // copy already-rendered `Cell`s instead of re-running grapheme segmentation.
         self.transcript_view_cache
             .set_raster_capacity(max_visible.saturating_mul(4).max(256));
 
-        for (row_index, line_index) in (top_offset..total_lines).enumerate() {
-            if row_index >= max_visible {
-                break;
+        // Rows that show the same line as last frame are restored from the recorded snapshot;
+        // only dirty rows (new streamed lines, scrolled rows) are rendered again. Track against
+        // the full transcript height so rows stay clean while short transcripts grow.
+        self.transcript_row_diff.begin_frame(Rect {
+            height: max_transcript_height,
+            ..transcript_area
+        });
+        let row_keys: Vec<_> = (top_offset..total_lines)
+            .take(max_visible)
+            .map(|line_index| self.transcript_view_cache.row_key(line_index))
+            .collect();
+        let dirty_rows = self.transcript_row_diff.dirty_rows(&row_keys);
+        for (row_index, row_key) in row_keys.into_iter().enumerate() {
+            if dirty_rows.binary_search(&row_index).is_err()
+                && self
+                    .transcript_row_diff
+                    .restore_row(row_index, frame.buffer)
+            {
+                continue;
             }
 
+            let line_index = row_key.line_index();
             let y = transcript_area.y + row_index as u16;
             let row_area = Rect {
-                x: transcript_area.x,
+                x: content_area.x,
                 y,
-                width: transcript_area.width,
+                width: content_area.width,
                 height: 1,
             };
 
             self.transcript_view_cache
                 .render_row_index_into(line_index, row_area, frame.buffer);
+            if let Some(label) = self.transcript_gutter.line_number_label(line_index) {
+                frame.buffer.set_span(
+                    transcript_area.x,
+                    y,
+                    &label.dim(),
+                    self.transcript_gutter.margin_cols(),
+                );
+            }
+            self.transcript_row_diff
+                .record_row(row_index, row_key, frame.buffer);
         }
 
         self.apply_transcript_selection(transcript_area, frame.buffer);
@@ -790,7 +1191,7 @@ impl App {
         ) && anchor != head
         {
             self.transcript_copy_ui.render_copy_pill(
-                transcript_area,
+                content_area,
                 frame.buffer,
                 (anchor.line_index, anchor.column),
                 (head.line_index, head.column),
@@ -800,6 +1201,42 @@ impl App {
         } else {
             self.transcript_copy_ui.clear_affordance();
         }
+
+        if let Some((edge, started)) = self.transcript_edge_flash {
+            if started.elapsed() >= TRANSCRIPT_EDGE_FLASH_DURATION {
+                self.transcript_edge_flash = None;
+            } else if TranscriptEdge::at(top_offset, total_lines, max_visible) == Some(edge) {
+                render_transcript_edge_marker(content_area, frame.buffer, edge);
+            }
+        }
+        self.resume_follow_rect = if self.chat_widget.is_task_running()
+            && !matches!(self.transcript_scroll, TranscriptScroll::ToBottom)
+        {
+            render_resume_follow_pill(content_area, frame.buffer, &self.theme)
+        } else {
+            None
+        };
+        self.stop_rect = if self.chat_widget.is_task_running() {
+            render_stop_pill(content_area, frame.buffer, &self.theme)
+        } else {
+            None
+        };
+        self.transcript_minimap_rect = self
+            .transcript_gutter
+            .minimap_area(transcript_area)
+            .filter(|_| transcript_minimap::should_show(total_lines, max_visible));
+        if let Some(rect) = self.transcript_minimap_rect {
+            let shades = transcript_minimap::row_shades(total_lines, rect.height, |line| {
+                self.transcript_view_cache.is_user_row(line)
+            });
+            let viewport = transcript_minimap::viewport_rows(
+                top_offset,
+                max_visible,
+                total_lines,
+                rect.height,
+            );
+            transcript_minimap::render(rect, frame.buffer, &shades, viewport);
+        }
         chat_top
     }
 
@@ -830,32 +1267,29 @@ impl App {
             return;
         }
 
-        let size = tui.terminal.last_known_screen_size;
-        let width = size.width;
-        let height = size.height;
-        if width == 0 || height == 0 {
-            return;
-        }
-
-        let chat_height = self.chat_widget.desired_height(width);
-        if chat_height >= height {
+        // Pointer movement only drives hover affordances in the bottom pane (e.g. skill
+        // description tooltips); the transcript does not react to it.
+        if mouse_event.kind == MouseEventKind::Moved {
+            self.chat_widget
+                .handle_mouse_moved(mouse_event.column, mouse_event.row);
             return;
         }
 
         // Only handle events over the transcript area above the composer.
-        let transcript_height = height.saturating_sub(chat_height);
-        if transcript_height == 0 {
+        let size = tui.terminal.last_known_screen_size;
+        let Some(TranscriptViewport {
+            area: transcript_area,
+            content_width,
+        }) = self.transcript_viewport(size.width, size.height)
+        else {
             return;
-        }
-
-        let transcript_area = Rect {
-            x: 0,
-            y: 0,
-            width,
-            height: transcript_height,
         };
-        let base_x = transcript_area.x.saturating_add(TRANSCRIPT_GUTTER_COLS);
-        let max_x = transcript_area.right().saturating_sub(1);
+        let base_x = self.transcript_gutter.base_x(transcript_area);
+        let max_x = self
+            .transcript_gutter
+            .content_area(transcript_area)
+            .right()
+            .saturating_sub(1);
 
         // Treat the transcript as the only interactive region for transcript selection.
         //
@@ -888,12 +1322,51 @@ impl App {
 
         let streaming = self.chat_widget.is_task_running();
 
+        if matches!(mouse_event.kind, MouseEventKind::Down(MouseButton::Left))
+            && let Some(rect) = self.transcript_minimap_rect
+            && rect.contains(ratatui::layout::Position::new(
+                mouse_event.column,
+                mouse_event.row,
+            ))
+        {
+            let target = transcript_minimap::line_for_row(
+                mouse_event.row - rect.y,
+                rect.height,
+                self.transcript_total_lines,
+            );
+            self.jump_transcript_to_line(target, transcript_area.height as usize, content_width);
+            self.transcript_selection = TranscriptSelection::default();
+            tui.frame_requester().schedule_frame();
+            return;
+        }
+
+        if matches!(mouse_event.kind, MouseEventKind::Down(MouseButton::Left))
+            && self.resume_follow_rect.is_some_and(|rect| {
+                rect.contains(ratatui::layout::Position::new(
+                    mouse_event.column,
+                    mouse_event.row,
+                ))
+            })
+        {
+            self.resume_transcript_follow();
+            tui.frame_requester().schedule_frame();
+            return;
+        }
+
+        if matches!(mouse_event.kind, MouseEventKind::Down(MouseButton::Left))
+            && self.stop_hit_test(mouse_event.column, mouse_event.row)
+        {
+            self.stop_generation();
+            tui.frame_requester().schedule_frame();
+            return;
+        }
+
         if matches!(mouse_event.kind, MouseEventKind::Down(MouseButton::Left))
             && self
                 .transcript_copy_ui
                 .hit_test(mouse_event.column, mouse_event.row)
         {
-            self.copy_transcript_selection(tui);
+            self.copy_transcript_selection(tui, self.copy_ansi);
             return;
         }
 
@@ -904,7 +1377,7 @@ impl App {
                     tui,
                     scroll_update,
                     transcript_area.height as usize,
-                    transcript_area.width,
+                    content_width,
                     true,
                 );
             }
@@ -914,7 +1387,7 @@ impl App {
                     tui,
                     scroll_update,
                     transcript_area.height as usize,
-                    transcript_area.width,
+                    content_width,
                     true,
                 );
             }
@@ -929,8 +1402,8 @@ impl App {
                 );
                 if self.transcript_multi_click.on_mouse_down(
                     &mut self.transcript_selection,
-                    &self.transcript_cells,
-                    transcript_area.width,
+                    self.transcript_collapse.view(&self.transcript_cells),
+                    content_width,
                     point,
                 ) {
                     tui.frame_requester().schedule_frame();
@@ -954,7 +1427,7 @@ impl App {
                 if outcome.lock_scroll {
                     self.lock_transcript_scroll_to_current_view(
                         transcript_area.height as usize,
-                        transcript_area.width,
+                        content_width,
                     );
                 }
                 if outcome.changed {
@@ -1050,26 +1523,36 @@ impl App {
     /// querying the terminal during non-draw events.
     ///
     /// Returns `(visible_lines, width)` or `None` when the terminal is not yet sized or the chat
-    /// area consumes the full height.
+    /// area consumes the full height. `width` is the width history cells are wrapped at, i.e. the
+    /// terminal width minus any gutter margin.
     fn transcript_scroll_dimensions(&self, tui: &tui::Tui) -> Option<(usize, u16)> {
         let size = tui.terminal.last_known_screen_size;
-        let width = size.width;
-        let height = size.height;
+        let viewport = self.transcript_viewport(size.width, size.height)?;
+        Some((viewport.area.height as usize, viewport.content_width))
+    }
+
+    /// Inline transcript geometry for a `width` x `height` terminal.
+    ///
+    /// This is the single guard for degenerate terminal sizes outside of drawing: it returns
+    /// `None` when the terminal is not yet sized, the bottom pane takes every row, or the gutter
+    /// leaves no content columns. Scrolling, hit-testing, and copy all go through it so none of
+    /// them does layout arithmetic on an empty area.
+    fn transcript_viewport(&self, width: u16, height: u16) -> Option<TranscriptViewport> {
         if width == 0 || height == 0 {
             return None;
         }
 
         let chat_height = self.chat_widget.desired_height(width);
-        if chat_height >= height {
-            return None;
-        }
-
-        let transcript_height = height.saturating_sub(chat_height);
-        if transcript_height == 0 {
+        let transcript_height = height.checked_sub(chat_height).filter(|h| *h > 0)?;
+        let content_width = self.transcript_gutter.content_width(width);
+        if content_width == 0 {
             return None;
         }
 
-        Some((transcript_height as usize, width))
+        Some(TranscriptViewport {
+            area: Rect::new(0, 0, width, transcript_height),
+            content_width,
+        })
     }
 
     /// Scroll the transcript by a number of visual lines.
@@ -1094,11 +1577,23 @@ impl App {
         }
 
         self.transcript_view_cache
-            .ensure_wrapped(&self.transcript_cells, width);
+            .ensure_wrapped(self.transcript_collapse.view(&self.transcript_cells), width);
         let line_meta = self.transcript_view_cache.line_meta();
-        self.transcript_scroll =
-            self.transcript_scroll
-                .scrolled_by(delta_lines, line_meta, visible_lines);
+        let before = self.transcript_scroll;
+        self.transcript_scroll = before.scrolled_by(delta_lines, line_meta, visible_lines);
+
+        // A scroll attempt that produced no movement hit an edge: flash a marker there. The
+        // delayed frame clears the marker once the flash has expired.
+        if delta_lines != 0 && self.transcript_scroll == before && line_meta.len() > visible_lines {
+            let edge = if delta_lines < 0 {
+                TranscriptEdge::Top
+            } else {
+                TranscriptEdge::Bottom
+            };
+            self.transcript_edge_flash = Some((edge, Instant::now()));
+            tui.frame_requester()
+                .schedule_frame_in(TRANSCRIPT_EDGE_FLASH_DURATION);
+        }
 
         if schedule_frame {
             // Request a redraw; the frame scheduler coalesces bursts and clamps to 60fps.
@@ -1106,6 +1601,103 @@ impl App {
         }
     }
 
+    /// Keep the viewport on the same content after more cells were folded into the summary cell.
+    ///
+    /// Scroll anchors are viewport cell indices, so they are remapped to the new view; an anchor
+    /// that now falls inside the summary lands on the summary itself. Selection endpoints are line
+    /// indices into the old view and cannot be remapped cheaply, so the selection is cleared.
+    fn remap_transcript_after_collapse(&mut self, previous_collapsed: usize) {
+        self.transcript_scroll = match self.transcript_scroll {
+            TranscriptScroll::ToBottom => TranscriptScroll::ToBottom,
+            TranscriptScroll::Scrolled {
+                cell_index,
+                line_in_cell,
+            } => match self
+                .transcript_collapse
+                .remap_cell_index(previous_collapsed, cell_index)
+            {
+                Some(cell_index) => TranscriptScroll::Scrolled {
+                    cell_index,
+                    line_in_cell,
+                },
+                None => TranscriptScroll::Scrolled {
+                    cell_index: 0,
+                    line_in_cell: 0,
+                },
+            },
+            TranscriptScroll::ScrolledSpacerBeforeCell { cell_index } => match self
+                .transcript_collapse
+                .remap_cell_index(previous_collapsed, cell_index)
+            {
+                Some(cell_index) => TranscriptScroll::ScrolledSpacerBeforeCell { cell_index },
+                None => TranscriptScroll::Scrolled {
+                    cell_index: 0,
+                    line_in_cell: 0,
+                },
+            },
+        };
+        self.transcript_selection = TranscriptSelection::default();
+    }
+
+    /// Anchor the transcript viewport at the top of the viewport cell `cell_index`.
+    ///
+    /// Any selection is cleared, since it refers to rows of the previous view.
+    fn scroll_transcript_to_cell(&mut self, cell_index: usize) {
+        let view_len = self.transcript_collapse.view(&self.transcript_cells).len();
+        if cell_index >= view_len {
+            return;
+        }
+        self.transcript_scroll = TranscriptScroll::Scrolled {
+            cell_index,
+            line_in_cell: 0,
+        };
+        self.transcript_selection = TranscriptSelection::default();
+    }
+
+    /// Jump to the next (`Alt+N`) or previous (`Alt+P`) error cell relative to the top of the
+    /// viewport, reporting its position among all errors. Returns the cell jumped to.
+    fn jump_to_error(&mut self, direction: ScrollDirection) -> Option<usize> {
+        let entries = crate::transcript_errors::error_entries(
+            self.transcript_collapse.view(&self.transcript_cells),
+        );
+        let current = match self.transcript_scroll {
+            TranscriptScroll::Scrolled { cell_index, .. }
+            | TranscriptScroll::ScrolledSpacerBeforeCell { cell_index } => Some(cell_index),
+            TranscriptScroll::ToBottom => None,
+        };
+        let Some(position) = crate::transcript_errors::adjacent_error(&entries, current, direction)
+        else {
+            self.chat_widget
+                .add_info_message("No errors in this session.".to_string(), None);
+            return None;
+        };
+        let cell_index = entries[position].cell_index;
+        self.scroll_transcript_to_cell(cell_index);
+        self.chat_widget.add_info_message(
+            format!("Error {} of {}.", position + 1, entries.len()),
+            None,
+        );
+        Some(cell_index)
+    }
+
+    /// Scroll the transcript by half of the visible transcript height (`Ctrl+U`/`Ctrl+D`).
+    ///
+    /// This is a no-op when the whole transcript fits in the viewport.
+    fn scroll_transcript_half_page(&mut self, tui: &mut tui::Tui, direction: ScrollDirection) {
+        let Some((visible_lines, width)) = self.transcript_scroll_dimensions(tui) else {
+            return;
+        };
+        if self.transcript_total_lines <= visible_lines {
+            return;
+        }
+        let delta = half_page_delta(visible_lines, self.page_overlap);
+        let delta = match direction {
+            ScrollDirection::Up => -delta,
+            ScrollDirection::Down => delta,
+        };
+        self.scroll_transcript(tui, delta, visible_lines, width, true);
+    }
+
     /// Convert a `ToBottom` (auto-follow) scroll state into a fixed anchor at the current view.
     ///
     /// When the user begins a mouse selection while new output is streaming in, the view
@@ -1119,7 +1711,7 @@ impl App {
         }
 
         self.transcript_view_cache
-            .ensure_wrapped(&self.transcript_cells, width);
+            .ensure_wrapped(self.transcript_collapse.view(&self.transcript_cells), width);
         let lines = self.transcript_view_cache.lines();
         let line_meta = self.transcript_view_cache.line_meta();
         if lines.is_empty() || line_meta.is_empty() {
@@ -1147,6 +1739,85 @@ impl App {
         }
     }
 
+    /// Wrap `cells` at `width`, re-anchoring the selection when the width changed.
+    ///
+    /// Selection endpoints are wrapped line/column indices, so after a reflow they would point at
+    /// different content. Each endpoint is converted to a cell-relative content offset against the
+    /// old wrapping and resolved again against the new one; if a cell disappeared in the meantime
+    /// the selection is dropped.
+    fn wrap_transcript_preserving_selection(&mut self, cells: &[Arc<dyn HistoryCell>], width: u16) {
+        let previous_width = self.transcript_view_cache.width();
+        let selection = self.transcript_selection;
+        let reflowing = previous_width != 0
+            && previous_width != width
+            && (selection.anchor.is_some() || selection.head.is_some());
+        if !reflowing {
+            self.transcript_view_cache.ensure_wrapped(cells, width);
+            return;
+        }
+
+        let to_anchor = |point: Option<TranscriptSelectionPoint>, cache: &TranscriptViewCache| {
+            point.map(|point| {
+                crate::transcript_selection::content_anchor_for(
+                    cache.lines(),
+                    cache.line_meta(),
+                    point,
+                )
+            })
+        };
+        let anchor = to_anchor(selection.anchor, &self.transcript_view_cache);
+        let head = to_anchor(selection.head, &self.transcript_view_cache);
+
+        self.transcript_view_cache.ensure_wrapped(cells, width);
+
+        let cache = &self.transcript_view_cache;
+        let resolve = |anchor: Option<Option<TranscriptContentAnchor>>| match anchor {
+            None => Some(None),
+            Some(anchor) => anchor
+                .and_then(|anchor| {
+                    crate::transcript_selection::point_for_content_anchor(
+                        cache.lines(),
+                        cache.line_meta(),
+                        anchor,
+                    )
+                })
+                .map(Some),
+        };
+        self.transcript_selection = match (resolve(anchor), resolve(head)) {
+            (Some(anchor), Some(head)) => TranscriptSelection { anchor, head },
+            _ => TranscriptSelection::default(),
+        };
+    }
+
+    /// Scroll so flattened transcript line `target` sits in the middle of the viewport (clicking
+    /// the minimap). Landing on the last page resumes auto-follow.
+    fn jump_transcript_to_line(&mut self, target: usize, visible_lines: usize, width: u16) {
+        if self.transcript_cells.is_empty() || visible_lines == 0 || width == 0 {
+            return;
+        }
+
+        self.transcript_view_cache
+            .ensure_wrapped(self.transcript_collapse.view(&self.transcript_cells), width);
+        let line_meta = self.transcript_view_cache.line_meta();
+        let total_lines = line_meta.len();
+        let max_visible = std::cmp::min(visible_lines, total_lines);
+        let max_start = total_lines.saturating_sub(max_visible);
+        let top_offset = target.saturating_sub(max_visible / 2).min(max_start);
+
+        if top_offset >= max_start {
+            self.transcript_scroll = TranscriptScroll::ToBottom;
+        } else if let Some(scroll_state) = TranscriptScroll::anchor_for(line_meta, top_offset) {
+            self.transcript_scroll = scroll_state;
+        }
+    }
+
+    /// Jump back to the newest output and auto-follow it again (End, or clicking the
+    /// "resume following" pill).
+    fn resume_transcript_follow(&mut self) {
+        self.transcript_scroll = TranscriptScroll::ToBottom;
+        self.resume_follow_rect = None;
+    }
+
     /// Apply the current transcript selection to the given buffer.
     ///
     /// The selection is defined in terms of flattened wrapped transcript line
@@ -1167,8 +1838,12 @@ impl App {
             return;
         }
 
-        let base_x = area.x.saturating_add(TRANSCRIPT_GUTTER_COLS);
-        let max_x = area.right().saturating_sub(1);
+        let base_x = self.transcript_gutter.base_x(area);
+        let max_x = self
+            .transcript_gutter
+            .content_area(area)
+            .right()
+            .saturating_sub(1);
 
         let (start, end) = crate::transcript_selection::ordered_endpoints(anchor, head);
 
@@ -1234,7 +1909,7 @@ impl App {
             for x in from_x..=to_x {
                 let cell = &mut buf[(x, y)];
                 let style = cell.style();
-                cell.set_style(style.add_modifier(ratatui::style::Modifier::REVERSED));
+                cell.set_style(crate::style::selection_style(&self.theme, style));
             }
         }
     }
@@ -1251,42 +1926,221 @@ impl App {
     /// region (for example, by scrolling after selecting, or by selecting while
     /// autoscrolling), and we still want the clipboard payload to reflect the
     /// entire selected transcript.
-    fn copy_transcript_selection(&mut self, tui: &tui::Tui) {
+    ///
+    /// With `ansi`, the clipboard receives the selection with its styling as ANSI escape codes
+    /// instead of plain text.
+    fn copy_transcript_selection(&mut self, tui: &tui::Tui, ansi: bool) {
         let size = tui.terminal.last_known_screen_size;
-        let width = size.width;
-        let height = size.height;
-        if width == 0 || height == 0 {
+        let Some(mut text) = self.transcript_selection_text(size.width, size.height, ansi) else {
             return;
+        };
+        if self.copy_path_header
+            && let Some(path) = self.transcript_selection_source_path(size.width, size.height)
+        {
+            text = crate::transcript_copy::with_path_header(&text, &path);
+        }
+        if let Err(err) = clipboard_copy::copy_text(text) {
+            tracing::error!(error = %err, "failed to copy selection to clipboard");
         }
+    }
 
-        let chat_height = self.chat_widget.desired_height(width);
-        if chat_height >= height {
-            return;
+    /// Copy the current conversation's resume command to the clipboard and confirm in history.
+    ///
+    /// Returns whether anything was copied; before the session is configured there is no
+    /// conversation id yet and only an informational message is shown.
+    fn copy_resume_command(&mut self) -> bool {
+        let Some(command) = resume_command(self.chat_widget.conversation_id()) else {
+            self.chat_widget.add_info_message(
+                "No conversation to resume yet.".to_string(),
+                Some("Send a message first, then try again.".to_string()),
+            );
+            return false;
+        };
+        match clipboard_copy::copy_text(command.clone()) {
+            Ok(()) => {
+                self.chat_widget
+                    .add_info_message(format!("Copied `{command}` to the clipboard."), None);
+                true
+            }
+            Err(err) => {
+                tracing::error!(error = %err, "failed to copy resume command to clipboard");
+                self.chat_widget
+                    .add_error_message(format!("Failed to copy resume command: {err}"));
+                false
+            }
         }
+    }
 
-        let transcript_height = height.saturating_sub(chat_height);
-        if transcript_height == 0 {
-            return;
+    /// Copy the contents of the most recent code block in agent output to the clipboard (`Alt+C`).
+    fn copy_last_code_block(&mut self) -> bool {
+        let Some(code) = crate::transcript_copy::last_code_block_text(&self.transcript_cells)
+        else {
+            self.chat_widget
+                .add_info_message("No code block to copy yet.".to_string(), None);
+            return false;
+        };
+        let line_count = code.lines().count();
+        match clipboard_copy::copy_text(code) {
+            Ok(()) => {
+                let noun = if line_count == 1 { "line" } else { "lines" };
+                self.chat_widget.add_info_message(
+                    format!("Copied the last code block ({line_count} {noun}) to the clipboard."),
+                    None,
+                );
+                true
+            }
+            Err(err) => {
+                tracing::error!(error = %err, "failed to copy code block to clipboard");
+                self.chat_widget
+                    .add_error_message(format!("Failed to copy code block: {err}"));
+                false
+            }
         }
+    }
 
-        let Some(text) = crate::transcript_copy::selection_to_copy_text_for_cells(
-            &self.transcript_cells,
-            self.transcript_selection,
-            width,
-        ) else {
-            return;
+    /// Copy the output of the commands run in the current turn to the clipboard (`Alt+O`).
+    fn copy_last_turn_output(&mut self) -> bool {
+        let Some(output) = crate::transcript_copy::last_turn_command_output(&self.transcript_cells)
+        else {
+            self.chat_widget
+                .add_info_message("No command output in this turn.".to_string(), None);
+            return false;
         };
-        if let Err(err) = clipboard_copy::copy_text(text) {
-            tracing::error!(error = %err, "failed to copy selection to clipboard");
+        let line_count = output.lines().count();
+        match clipboard_copy::copy_text(output) {
+            Ok(()) => {
+                let noun = if line_count == 1 { "line" } else { "lines" };
+                self.chat_widget.add_info_message(
+                    format!("Copied command output ({line_count} {noun}) to the clipboard."),
+                    None,
+                );
+                true
+            }
+            Err(err) => {
+                tracing::error!(error = %err, "failed to copy command output to clipboard");
+                self.chat_widget
+                    .add_error_message(format!("Failed to copy command output: {err}"));
+                false
+            }
         }
     }
 
-    fn copy_selection_key(&self) -> crate::key_hint::KeyBinding {
-        self.transcript_copy_ui.key_binding()
+    /// Copy the current conversation id to the clipboard, e.g. to quote it in a support request.
+    fn copy_conversation_id(&mut self) -> bool {
+        let Some(id) = conversation_id_text(self.chat_widget.conversation_id()) else {
+            self.chat_widget.add_info_message(
+                "No conversation id yet.".to_string(),
+                Some("Send a message first, then try again.".to_string()),
+            );
+            return false;
+        };
+        match clipboard_copy::copy_text(id.clone()) {
+            Ok(()) => {
+                self.chat_widget.add_info_message(
+                    format!("Copied conversation id `{id}` to the clipboard."),
+                    None,
+                );
+                true
+            }
+            Err(err) => {
+                tracing::error!(error = %err, "failed to copy conversation id to clipboard");
+                self.chat_widget
+                    .add_error_message(format!("Failed to copy conversation id {id}: {err}"));
+                false
+            }
+        }
     }
 
-    /// Map a mouse position in the transcript area to a content-relative
-    /// selection point, if there is transcript content to select.
+    /// Persist `title` for the current conversation under `codex_home` and confirm in history.
+    ///
+    /// The title shows up in the session header the next time the conversation is resumed and in
+    /// the resume picker in place of the first-message preview.
+    fn set_conversation_title(&mut self, title: &str) {
+        let Some(conversation_id) = self.chat_widget.conversation_id() else {
+            return;
+        };
+        match crate::conversation_titles::save_title(
+            &self.config.codex_home,
+            conversation_id,
+            title,
+        ) {
+            Ok(saved) => {
+                let message = match &saved {
+                    Some(title) => format!("Chat title set to \"{title}\"."),
+                    None => "Chat title cleared.".to_string(),
+                };
+                self.chat_widget.set_conversation_title(saved);
+                self.chat_widget.add_info_message(message, None);
+            }
+            Err(err) => {
+                tracing::error!(error = %err, "failed to save conversation title");
+                self.chat_widget
+                    .add_error_message(format!("Failed to save chat title: {err}"));
+            }
+        }
+    }
+
+    /// Seed the composer with the current selection as a quoted block and clear the selection.
+    ///
+    /// The quote is inserted at the composer cursor, leaving the cursor just after the block so
+    /// the user can type a question about it.
+    fn quote_transcript_selection(&mut self, tui: &mut tui::Tui) {
+        let size = tui.terminal.last_known_screen_size;
+        if self.quote_transcript_selection_at_size(size.width, size.height) {
+            tui.frame_requester().schedule_frame();
+        }
+    }
+
+    fn quote_transcript_selection_at_size(&mut self, width: u16, height: u16) -> bool {
+        let Some(text) = self.transcript_selection_text(width, height, false) else {
+            return false;
+        };
+        let quoted = crate::transcript_copy::quote_selection_for_prompt(&text);
+        self.chat_widget.insert_str(&quoted);
+        self.transcript_selection = TranscriptSelection::default();
+        self.transcript_copy_ui.clear_affordance();
+        true
+    }
+
+    /// Clipboard text for the current selection, built with the same layout as the viewport of a
+    /// `width` x `height` terminal. `ansi` keeps span styling as ANSI escape codes.
+    fn transcript_selection_text(&mut self, width: u16, height: u16, ansi: bool) -> Option<String> {
+        let content_width = self.transcript_viewport(width, height)?.content_width;
+        let cells = self.transcript_collapse.view(&self.transcript_cells);
+        if ansi {
+            crate::transcript_copy::selection_to_ansi_text_for_cells(
+                cells,
+                self.transcript_selection,
+                content_width,
+                self.cell_spacing,
+            )
+        } else {
+            crate::transcript_copy::selection_to_copy_text_for_cells(
+                cells,
+                self.transcript_selection,
+                content_width,
+                self.cell_spacing,
+            )
+        }
+    }
+
+    /// Display path of the single file behind the current selection, if any.
+    fn transcript_selection_source_path(&mut self, width: u16, height: u16) -> Option<String> {
+        let content_width = self.transcript_viewport(width, height)?.content_width;
+        crate::transcript_copy::selection_source_path(
+            self.transcript_collapse.view(&self.transcript_cells),
+            self.transcript_selection,
+            content_width,
+            self.cell_spacing,
+        )
+    }
+
+    fn copy_selection_key(&self) -> crate::key_hint::KeyBinding {
+        self.transcript_copy_ui.key_binding()
+    }
+
+    /// Map a mouse position in the transcript area to a content-relative
+    /// selection point, if there is transcript content to select.
     fn transcript_point_from_coordinates(
         &self,
         transcript_area: Rect,
@@ -1311,18 +2165,54 @@ impl App {
             .transcript_view_top
             .saturating_add(usize::from(row_index))
             .min(max_line);
-        let column = x.saturating_sub(base_x);
+        let mut column = x.saturating_sub(base_x);
+        if let Some(line) = self.transcript_view_cache.lines().get(line_index) {
+            column = crate::transcript_selection::snap_column_to_grapheme_start(line, column);
+        }
 
         Some(TranscriptSelectionPoint { line_index, column })
     }
 
+    /// Show a computed diff in the pager overlay, clearing the in-progress diff state.
+    fn open_diff_overlay(&mut self, text: String) {
+        self.chat_widget.on_diff_complete();
+        let pager_lines: Vec<ratatui::text::Line<'static>> = if text.trim().is_empty() {
+            vec!["No changes detected.".italic().into()]
+        } else {
+            text.lines().map(ansi_escape_line).collect()
+        };
+        self.overlay = Some(if text.trim().is_empty() {
+            Overlay::new_static_with_lines(pager_lines, "D I F F".to_string())
+        } else {
+            Overlay::new_diff(pager_lines, text, self.config.cwd.clone())
+        });
+    }
+
+    /// Lines summarizing the outgoing session, shown at the top of the next one.
+    ///
+    /// Empty when the session used no tokens or `tui.show_session_summary_on_new` is off.
+    fn outgoing_session_summary_lines(&self) -> Vec<Line<'static>> {
+        if !self.config.tui_show_session_summary_on_new {
+            return Vec::new();
+        }
+        let Some(summary) = session_summary(
+            self.chat_widget.token_usage(),
+            self.chat_widget.conversation_id(),
+        ) else {
+            return Vec::new();
+        };
+        let mut lines: Vec<Line<'static>> = vec![summary.usage_line.into()];
+        if let Some(command) = summary.resume_command {
+            let spans = vec!["To continue this session, run ".into(), command.cyan()];
+            lines.push(spans.into());
+        }
+        lines
+    }
+
     async fn handle_event(&mut self, tui: &mut tui::Tui, event: AppEvent) -> Result<bool> {
         match event {
-            AppEvent::NewSession => {
-                let summary = session_summary(
-                    self.chat_widget.token_usage(),
-                    self.chat_widget.conversation_id(),
-                );
+            AppEvent::NewSession { draft } => {
+                let summary_lines = self.outgoing_session_summary_lines();
                 self.shutdown_current_conversation().await;
                 let init = crate::chatwidget::ChatWidgetInit {
                     config: self.config.clone(),
@@ -1338,16 +2228,31 @@ impl App {
                     model: self.current_model.clone(),
                 };
                 self.chat_widget = ChatWidget::new(init, self.server.clone());
-                if let Some(summary) = summary {
-                    let mut lines: Vec<Line<'static>> = vec![summary.usage_line.clone().into()];
-                    if let Some(command) = summary.resume_command {
-                        let spans = vec!["To continue this session, run ".into(), command.cyan()];
-                        lines.push(spans.into());
-                    }
-                    self.chat_widget.add_plain_history_lines(lines);
+                if !summary_lines.is_empty() {
+                    self.chat_widget.add_plain_history_lines(summary_lines);
+                }
+                if let Some(draft) = draft {
+                    self.chat_widget.set_composer_text(draft);
                 }
                 tui.frame_requester().schedule_frame();
             }
+            AppEvent::CopyResumeCommand => {
+                self.copy_resume_command();
+            }
+            AppEvent::CopyConversationId => {
+                self.copy_conversation_id();
+            }
+            AppEvent::RetryWithHigherEffort => {
+                match crate::app_backtrack::last_user_message(&self.transcript_cells) {
+                    Some(prompt) => self.chat_widget.retry_with_higher_effort(prompt),
+                    None => self
+                        .chat_widget
+                        .add_info_message("No message to retry yet.".to_string(), None),
+                }
+            }
+            AppEvent::SetConversationTitle(title) => {
+                self.set_conversation_title(&title);
+            }
             AppEvent::OpenResumePicker => {
                 match crate::resume_picker::run_resume_picker(
                     tui,
@@ -1358,10 +2263,7 @@ impl App {
                 .await?
                 {
                     ResumeSelection::Resume(path) => {
-                        let summary = session_summary(
-                            self.chat_widget.token_usage(),
-                            self.chat_widget.conversation_id(),
-                        );
+                        let summary_lines = self.outgoing_session_summary_lines();
                         match self
                             .server
                             .resume_conversation_from_rollout(
@@ -1391,17 +2293,8 @@ impl App {
                                     resumed.conversation,
                                     resumed.session_configured,
                                 );
-                                if let Some(summary) = summary {
-                                    let mut lines: Vec<Line<'static>> =
-                                        vec![summary.usage_line.clone().into()];
-                                    if let Some(command) = summary.resume_command {
-                                        let spans = vec![
-                                            "To continue this session, run ".into(),
-                                            command.cyan(),
-                                        ];
-                                        lines.push(spans.into());
-                                    }
-                                    self.chat_widget.add_plain_history_lines(lines);
+                                if !summary_lines.is_empty() {
+                                    self.chat_widget.add_plain_history_lines(summary_lines);
                                 }
                             }
                             Err(err) => {
@@ -1420,19 +2313,41 @@ impl App {
             }
             AppEvent::InsertHistoryCell(cell) => {
                 let cell: Arc<dyn HistoryCell> = cell.into();
+                if self.merge_into_last_transcript_cell(cell.as_ref()) {
+                    if let Some(Overlay::Transcript(transcript)) = &mut self.overlay
+                        && let Some(last) = self.transcript_cells.last()
+                    {
+                        transcript.replace_last_cell(last.clone());
+                    }
+                    tui.frame_requester().schedule_frame();
+                    return Ok(true);
+                }
                 if let Some(Overlay::Transcript(transcript)) = &mut self.overlay {
                     transcript.insert_cell(cell.clone());
                     tui.frame_requester().schedule_frame();
                 }
                 self.transcript_cells.push(cell.clone());
-                let mut display = cell.display_lines(tui.terminal.last_known_screen_size.width);
+                let previous_collapsed = self.transcript_collapse.sync(&self.transcript_cells);
+                if previous_collapsed != self.transcript_collapse.collapsed() {
+                    self.remap_transcript_after_collapse(previous_collapsed);
+                }
+                let width = tui.terminal.last_known_screen_size.width;
+                self.transcript_mirror.append_cell(
+                    self.chat_widget.conversation_id(),
+                    cell.as_ref(),
+                    width,
+                );
+                let mut display = cell.display_lines(width);
                 if !display.is_empty() {
-                    // Only insert a separating blank line for new cells that are not
-                    // part of an ongoing stream. Streaming continuations should not
-                    // accrue extra blank lines between chunks.
+                    // Only insert separating blank lines (`tui.cell_spacing`) for new cells
+                    // that are not part of an ongoing stream. Streaming continuations should
+                    // not accrue extra blank lines between chunks.
                     if !cell.is_stream_continuation() {
                         if self.has_emitted_history_lines {
-                            display.insert(0, Line::from(""));
+                            display.splice(
+                                0..0,
+                                std::iter::repeat_n(Line::from(""), self.cell_spacing as usize),
+                            );
                         } else {
                             self.has_emitted_history_lines = true;
                         }
@@ -1443,7 +2358,9 @@ impl App {
                 }
             }
             AppEvent::StartCommitAnimation => {
-                if self
+                if !self.terminal_focused {
+                    self.commit_anim_paused = true;
+                } else if self
                     .commit_anim_running
                     .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
                     .is_ok()
@@ -1459,12 +2376,16 @@ impl App {
                 }
             }
             AppEvent::StopCommitAnimation => {
+                self.commit_anim_paused = false;
                 self.commit_anim_running.store(false, Ordering::Release);
             }
             AppEvent::CommitTick => {
                 self.chat_widget.on_commit_tick();
             }
             AppEvent::CodexEvent(event) => {
+                #[cfg(feature = "debug-logs")]
+                self.protocol_event_log
+                    .push(chrono::Local::now(), &event.msg);
                 if self.suppress_shutdown_complete
                     && matches!(event.msg, EventMsg::ShutdownComplete)
                 {
@@ -1474,7 +2395,7 @@ impl App {
                 if let EventMsg::ListSkillsResponse(response) = &event.msg {
                     let cwd = self.chat_widget.config_ref().cwd.clone();
                     let errors = errors_for_cwd(&cwd, response);
-                    emit_skill_load_warnings(&self.app_event_tx, &errors);
+                    emit_skill_load_warnings(&self.app_event_tx, &errors, &self.theme);
                 }
                 self.chat_widget.handle_codex_event(event);
             }
@@ -1486,18 +2407,69 @@ impl App {
             }
             AppEvent::CodexOp(op) => self.chat_widget.submit_op(op),
             AppEvent::DiffResult(text) => {
-                // Clear the in-progress state in the bottom pane
-                self.chat_widget.on_diff_complete();
-                // Enter alternate screen using TUI helper and build pager lines
+                // Enter alternate screen using TUI helper, then show the diff pager
+                let _ = tui.enter_alt_screen();
+                self.open_diff_overlay(text);
+                tui.frame_requester().schedule_frame();
+            }
+            AppEvent::SubmitConfirmedMessage(text) => {
+                self.chat_widget.submit_confirmed_message(text);
+            }
+            AppEvent::RevertLastPatch => {
+                self.chat_widget.revert_last_patch();
+            }
+            AppEvent::OpenScrollTuning { selected } => {
+                self.chat_widget
+                    .open_scroll_tuning_popup(self.scroll_config.tuning_summary(), selected);
+            }
+            AppEvent::TuneScroll { tuning, selected } => {
+                self.scroll_config.tune(tuning);
+                self.chat_widget
+                    .open_scroll_tuning_popup(self.scroll_config.tuning_summary(), Some(selected));
+            }
+            AppEvent::PersistScrollTuning => {
+                let builder = ConfigEditsBuilder::new(&self.config.codex_home)
+                    .with_profile(self.active_profile.as_deref());
+                match self.scroll_config.with_tuning_edits(builder).apply().await {
+                    Ok(()) => self.chat_widget.add_info_message(
+                        format!(
+                            "Saved scroll settings ({}).",
+                            self.scroll_config.tuning_summary()
+                        ),
+                        None,
+                    ),
+                    Err(err) => {
+                        tracing::error!(error = %err, "failed to persist scroll settings");
+                        self.chat_widget
+                            .add_error_message(format!("Failed to save scroll settings: {err}"));
+                    }
+                }
+            }
+            AppEvent::PreviewSkillInjections => {
+                // Go through the shared manager so the preview reuses (and warms) the session's
+                // skills cache instead of rescanning the skill roots.
+                let skills_manager = self.server.skills_manager();
+                let cwd = self.chat_widget.config_ref().cwd.clone();
+                let tx = self.app_event_tx.clone();
+                tokio::spawn(async move {
+                    let outcome = skills_manager.skills_for_cwd_async(&cwd, false).await;
+                    let text =
+                        codex_core::skills::preview_skill_injections(&outcome.skills, &cwd).await;
+                    tx.send(AppEvent::SkillInjectionPreview(text));
+                });
+            }
+            AppEvent::SkillInjectionPreview(text) => {
                 let _ = tui.enter_alt_screen();
-                let pager_lines: Vec<ratatui::text::Line<'static>> = if text.trim().is_empty() {
-                    vec!["No changes detected.".italic().into()]
+                let pager_lines: Vec<Line<'static>> = if text.is_empty() {
+                    vec!["No skills are available in this directory.".italic().into()]
                 } else {
-                    text.lines().map(ansi_escape_line).collect()
+                    text.lines()
+                        .map(|line| Line::from(line.to_string()))
+                        .collect()
                 };
                 self.overlay = Some(Overlay::new_static_with_lines(
                     pager_lines,
-                    "D I F F".to_string(),
+                    "S K I L L S".to_string(),
                 ));
                 tui.frame_requester().schedule_frame();
             }
@@ -1517,8 +2489,12 @@ impl App {
             }
             AppEvent::UpdateModel(model) => {
                 self.chat_widget.set_model(&model);
+                self.show_model_switch_note_if_needed(&model);
                 self.current_model = model;
             }
+            AppEvent::OpenModelPopup => {
+                self.chat_widget.open_model_popup();
+            }
             AppEvent::OpenReasoningPopup { model } => {
                 self.chat_widget.open_reasoning_popup(model);
             }
@@ -1685,15 +2661,12 @@ impl App {
                 // If sandbox policy becomes workspace-write or read-only, run the Windows world-writable scan.
                 #[cfg(target_os = "windows")]
                 {
-                    // One-shot suppression if the user just confirmed continue.
-                    if self.skip_world_writable_scan_once {
-                        self.skip_world_writable_scan_once = false;
-                        return Ok(true);
-                    }
-
-                    let should_check = codex_core::get_platform_sandbox().is_some()
-                        && policy_is_workspace_write_or_ro
-                        && !self.chat_widget.world_writable_warning_hidden();
+                    let should_check = world_writable_scan_needed(
+                        &mut self.skip_world_writable_scan_once,
+                        codex_core::get_platform_sandbox().is_some(),
+                        policy_is_workspace_write_or_ro,
+                        self.chat_widget.world_writable_warning_hidden(),
+                    );
                     if should_check {
                         let cwd = self.config.cwd.clone();
                         let env_map: std::collections::HashMap<String, String> =
@@ -1724,6 +2697,12 @@ impl App {
             AppEvent::UpdateRateLimitSwitchPromptHidden(hidden) => {
                 self.chat_widget.set_rate_limit_switch_prompt_hidden(hidden);
             }
+            AppEvent::UpdateCompactPromptHidden(hidden) => {
+                self.chat_widget.set_compact_prompt_hidden(hidden);
+            }
+            AppEvent::SnoozeCompactPrompt => {
+                self.chat_widget.snooze_compact_prompt();
+            }
             AppEvent::PersistFullAccessWarningAcknowledged => {
                 if let Err(err) = ConfigEditsBuilder::new(&self.config.codex_home)
                     .set_hide_full_access_warning(true)
@@ -1769,6 +2748,21 @@ impl App {
                     ));
                 }
             }
+            AppEvent::PersistCompactPromptHidden => {
+                if let Err(err) = ConfigEditsBuilder::new(&self.config.codex_home)
+                    .set_hide_compact_prompt(true)
+                    .apply()
+                    .await
+                {
+                    tracing::error!(
+                        error = %err,
+                        "failed to persist compaction prompt preference"
+                    );
+                    self.chat_widget.add_error_message(format!(
+                        "Failed to save compaction reminder preference: {err}"
+                    ));
+                }
+            }
             AppEvent::PersistModelMigrationPromptAcknowledged {
                 from_model,
                 to_model,
@@ -1787,9 +2781,56 @@ impl App {
                     ));
                 }
             }
+            AppEvent::PersistModelSwitchNoteSeen { model } => {
+                if let Err(err) = ConfigEditsBuilder::new(&self.config.codex_home)
+                    .record_model_switch_note_seen(model.as_str())
+                    .apply()
+                    .await
+                {
+                    tracing::error!(
+                        error = %err,
+                        "failed to persist model switch note acknowledgement"
+                    );
+                    self.chat_widget.add_error_message(format!(
+                        "Failed to save model switch note preference: {err}"
+                    ));
+                }
+            }
             AppEvent::OpenApprovalsPopup => {
                 self.chat_widget.open_approvals_popup();
             }
+            AppEvent::OpenErrorList => {
+                let entries = crate::transcript_errors::error_entries(
+                    self.transcript_collapse.view(&self.transcript_cells),
+                );
+                self.chat_widget.open_error_list(entries);
+            }
+            AppEvent::OpenCodeFencePopup => {
+                self.chat_widget.open_code_fence_popup();
+            }
+            AppEvent::FenceComposerLine { language } => {
+                self.chat_widget.fence_composer_line(&language);
+            }
+            AppEvent::OpenApprovalHistory => {
+                let entries = crate::approval_history::approval_history(
+                    self.transcript_collapse.view(&self.transcript_cells),
+                );
+                self.chat_widget.open_approval_history(entries);
+            }
+            AppEvent::OpenPromptHistory => {
+                let prompts = crate::prompt_history::prompt_history(&self.transcript_cells);
+                self.chat_widget.open_prompt_history(prompts);
+            }
+            AppEvent::PromptHistorySelected(prompt) => {
+                self.chat_widget.open_prompt_history_actions(prompt);
+            }
+            AppEvent::ReusePrompt { text, send } => {
+                self.chat_widget.reuse_prompt(text, send);
+            }
+            AppEvent::ScrollTranscriptToCell(cell_index) => {
+                self.scroll_transcript_to_cell(cell_index);
+                tui.frame_requester().schedule_frame();
+            }
             AppEvent::OpenReviewBranchPicker(cwd) => {
                 self.chat_widget.show_review_branch_picker(&cwd).await;
             }
@@ -1839,6 +2880,60 @@ impl App {
         Ok(true)
     }
 
+    /// Esc primes/advances backtracking only with the composer focused and empty.
+    fn esc_arms_backtrack(&self) -> bool {
+        self.chat_widget.is_normal_backtrack_mode() && self.chat_widget.composer_is_empty()
+    }
+
+    /// Esc while the composer holds a draft and nothing else claims the key
+    /// (`tui.esc_behavior`).
+    fn handle_esc_with_draft(&mut self, key_event: KeyEvent) {
+        match self.esc_behavior {
+            EscBehavior::Backtrack => self.chat_widget.handle_key_event(key_event),
+            EscBehavior::Clear => self.chat_widget.clear_composer(),
+            EscBehavior::None => {}
+        }
+    }
+
+    /// Whether a click at (`x`, `y`) lands on the "stop" pill drawn last frame.
+    fn stop_hit_test(&self, x: u16, y: u16) -> bool {
+        self.stop_rect
+            .is_some_and(|rect| rect.contains(ratatui::layout::Position::new(x, y)))
+    }
+
+    /// Interrupt the running task (stop pill, Alt+X) and hide the pill until the next frame.
+    fn stop_generation(&mut self) {
+        if self.chat_widget.stop_generation() {
+            self.stop_rect = None;
+        }
+    }
+
+    /// Move the session between the alternate screen and the normal screen (Alt+M).
+    fn toggle_screen_mode(&mut self, tui: &mut tui::Tui) {
+        let mode = self.switch_screen_mode();
+        let _ = match mode {
+            ScreenMode::AltScreen => tui.enter_alt_screen(),
+            ScreenMode::Inline => tui.leave_alt_screen(),
+        };
+        // The buffer we switched to still shows whatever was last drawn there; repaint it all.
+        let _ = tui.terminal.clear();
+        let message = match mode {
+            ScreenMode::AltScreen => "Switched to full-screen mode.",
+            ScreenMode::Inline => "Switched to inline mode.",
+        };
+        self.chat_widget.add_info_message(message.to_string(), None);
+        tui.frame_requester().schedule_frame();
+    }
+
+    /// Flip [`ScreenMode`] and return the new mode.
+    ///
+    /// Only the terminal buffer changes: the transcript, its scroll position, and any selection
+    /// stay as they are, so the view looks the same after the next frame.
+    fn switch_screen_mode(&mut self) -> ScreenMode {
+        self.screen_mode = self.screen_mode.toggled();
+        self.screen_mode
+    }
+
     fn reasoning_label(reasoning_effort: Option<ReasoningEffortConfig>) -> &'static str {
         match reasoning_effort {
             Some(ReasoningEffortConfig::Minimal) => "minimal",
@@ -1861,11 +2956,98 @@ impl App {
         self.chat_widget.token_usage()
     }
 
+    /// Show the one-time `tui.model_switch_notes` note when switching to a flagged model, using
+    /// the preset description as the cost/speed hint.
+    fn show_model_switch_note_if_needed(&mut self, model: &str) {
+        if model == self.current_model
+            || !self
+                .config
+                .tui_model_switch_notes
+                .iter()
+                .any(|m| m == model)
+            || self
+                .config
+                .notices
+                .model_switch_notes_seen
+                .contains_key(model)
+        {
+            return;
+        }
+        let Ok(presets) = self
+            .server
+            .get_models_manager()
+            .try_list_models(&self.config)
+        else {
+            return;
+        };
+        let Some(preset) = find_model_preset(&presets, model) else {
+            return;
+        };
+        if preset.description.is_empty() {
+            return;
+        }
+
+        self.chat_widget.add_info_message(
+            format!(
+                "Switched to {}: {}",
+                preset.display_name, preset.description
+            ),
+            Some("This note is only shown once.".to_string()),
+        );
+        self.config
+            .notices
+            .model_switch_notes_seen
+            .insert(model.to_string(), true);
+        self.app_event_tx
+            .send(AppEvent::PersistModelSwitchNoteSeen {
+                model: model.to_string(),
+            });
+    }
+
     fn on_update_reasoning_effort(&mut self, effort: Option<ReasoningEffortConfig>) {
         self.chat_widget.set_reasoning_effort(effort);
         self.config.model_reasoning_effort = effort;
     }
 
+    /// Show the wrapped transcript annotated with its line metadata (F12, `debug-logs` only).
+    ///
+    /// Uses the same cached wrapping as the viewport, so the labels are exactly what scroll
+    /// anchoring and selection see.
+    #[cfg(feature = "debug-logs")]
+    fn open_line_map_overlay(&mut self, tui: &mut tui::Tui) {
+        let width = match self.transcript_view_cache.width() {
+            0 => tui.terminal.last_known_screen_size.width,
+            width => width,
+        };
+        self.transcript_view_cache
+            .ensure_wrapped(self.transcript_collapse.view(&self.transcript_cells), width);
+        let is_user_row: Vec<bool> = (0..self.transcript_view_cache.lines().len())
+            .map(|line| self.transcript_view_cache.is_user_row(line))
+            .collect();
+        let lines = crate::transcript_render::annotate_transcript_lines(
+            self.transcript_view_cache.lines(),
+            self.transcript_view_cache.line_meta(),
+            &is_user_row,
+        );
+        let _ = tui.enter_alt_screen();
+        self.overlay = Some(Overlay::new_static_with_lines(
+            lines,
+            "L I N E   M A P".to_string(),
+        ));
+        tui.frame_requester().schedule_frame();
+    }
+
+    /// Show the recent raw protocol events, newest at the bottom (F11, `debug-logs` only).
+    #[cfg(feature = "debug-logs")]
+    fn open_protocol_event_overlay(&mut self, tui: &mut tui::Tui) {
+        let _ = tui.enter_alt_screen();
+        self.overlay = Some(Overlay::new_static_tail(
+            self.protocol_event_log.lines(),
+            "P R O T O C O L   E V E N T S".to_string(),
+        ));
+        tui.frame_requester().schedule_frame();
+    }
+
     async fn handle_key_event(&mut self, tui: &mut tui::Tui, key_event: KeyEvent) {
         match key_event {
             KeyEvent {
@@ -1876,9 +3058,28 @@ impl App {
             } => {
                 // Enter alternate screen and set viewport to full size.
                 let _ = tui.enter_alt_screen();
-                self.overlay = Some(Overlay::new_transcript(self.transcript_cells.clone()));
+                self.overlay = Some(Overlay::new_transcript(
+                    self.transcript_cells.clone(),
+                    self.theme,
+                ));
                 tui.frame_requester().schedule_frame();
             }
+            #[cfg(feature = "debug-logs")]
+            KeyEvent {
+                code: KeyCode::F(12),
+                kind: KeyEventKind::Press,
+                ..
+            } => {
+                self.open_line_map_overlay(tui);
+            }
+            #[cfg(feature = "debug-logs")]
+            KeyEvent {
+                code: KeyCode::F(11),
+                kind: KeyEventKind::Press,
+                ..
+            } => {
+                self.open_protocol_event_overlay(tui);
+            }
             // Esc primes/advances backtracking only in normal (not working) mode
             // with the composer focused and empty. In any other state, forward
             // Esc so the active UI (e.g. status indicator, modals, popups)
@@ -1888,10 +3089,10 @@ impl App {
                 kind: KeyEventKind::Press | KeyEventKind::Repeat,
                 ..
             } => {
-                if self.chat_widget.is_normal_backtrack_mode()
-                    && self.chat_widget.composer_is_empty()
-                {
+                if self.esc_arms_backtrack() {
                     self.handle_backtrack_esc_key(tui);
+                } else if self.chat_widget.is_normal_backtrack_mode() {
+                    self.handle_esc_with_draft(key_event);
                 } else {
                     self.chat_widget.handle_key_event(key_event);
                 }
@@ -1901,106 +3102,201 @@ impl App {
                 modifiers,
                 kind: KeyEventKind::Press | KeyEventKind::Repeat,
                 ..
-            } if self.transcript_copy_ui.is_copy_key(ch, modifiers) => {
-                self.copy_transcript_selection(tui);
+            } if self.transcript_copy_ui.is_copy_key(
+                ch,
+                modifiers.difference(crossterm::event::KeyModifiers::ALT),
+            ) =>
+            {
+                // Holding Alt flips `tui.copy_ansi` for this copy.
+                let ansi =
+                    self.copy_ansi != modifiers.contains(crossterm::event::KeyModifiers::ALT);
+                self.copy_transcript_selection(tui, ansi);
             }
             KeyEvent {
-                code: KeyCode::PageUp,
-                kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                code: KeyCode::Char(ch),
+                modifiers,
+                kind: KeyEventKind::Press,
+                ..
+            } if crate::transcript_copy_ui::is_quote_selection_key(ch, modifiers)
+                && self.transcript_selection.anchor != self.transcript_selection.head =>
+            {
+                self.quote_transcript_selection(tui);
+            }
+            KeyEvent {
+                code: KeyCode::Char('r'),
+                modifiers: crossterm::event::KeyModifiers::ALT,
+                kind: KeyEventKind::Press,
                 ..
             } => {
-                let size = tui.terminal.last_known_screen_size;
-                let width = size.width;
-                let height = size.height;
-                if width > 0 && height > 0 {
-                    let chat_height = self.chat_widget.desired_height(width);
-                    if chat_height < height {
-                        let transcript_height = height.saturating_sub(chat_height);
-                        if transcript_height > 0 {
-                            let delta = -i32::from(transcript_height);
-                            self.scroll_transcript(
-                                tui,
-                                delta,
-                                usize::from(transcript_height),
-                                width,
-                                true,
-                            );
-                        }
-                    }
-                }
+                self.copy_resume_command();
             }
             KeyEvent {
-                code: KeyCode::PageDown,
-                kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                code: KeyCode::Char('c'),
+                modifiers: crossterm::event::KeyModifiers::ALT,
+                kind: KeyEventKind::Press,
                 ..
             } => {
-                let size = tui.terminal.last_known_screen_size;
-                let width = size.width;
-                let height = size.height;
-                if width > 0 && height > 0 {
-                    let chat_height = self.chat_widget.desired_height(width);
-                    if chat_height < height {
-                        let transcript_height = height.saturating_sub(chat_height);
-                        if transcript_height > 0 {
-                            let delta = i32::from(transcript_height);
-                            self.scroll_transcript(
-                                tui,
-                                delta,
-                                usize::from(transcript_height),
-                                width,
-                                true,
-                            );
-                        }
-                    }
-                }
+                self.copy_last_code_block();
             }
             KeyEvent {
-                code: KeyCode::Home,
+                code: KeyCode::Char('d'),
+                modifiers: crossterm::event::KeyModifiers::ALT,
+                kind: KeyEventKind::Press,
+                ..
+            } => {
+                self.chat_widget.request_git_diff();
+            }
+            KeyEvent {
+                code: KeyCode::Char('o'),
+                modifiers: crossterm::event::KeyModifiers::ALT,
+                kind: KeyEventKind::Press,
+                ..
+            } => {
+                self.copy_last_turn_output();
+            }
+            KeyEvent {
+                code: KeyCode::Char('n'),
+                modifiers: crossterm::event::KeyModifiers::ALT,
                 kind: KeyEventKind::Press | KeyEventKind::Repeat,
                 ..
             } => {
-                if !self.transcript_cells.is_empty() {
-                    self.transcript_scroll = TranscriptScroll::Scrolled {
-                        cell_index: 0,
-                        line_in_cell: 0,
-                    };
-                    tui.frame_requester().schedule_frame();
-                }
+                self.jump_to_error(ScrollDirection::Down);
+                tui.frame_requester().schedule_frame();
             }
             KeyEvent {
-                code: KeyCode::End,
+                code: KeyCode::Char('p'),
+                modifiers: crossterm::event::KeyModifiers::ALT,
                 kind: KeyEventKind::Press | KeyEventKind::Repeat,
                 ..
             } => {
-                self.transcript_scroll = TranscriptScroll::ToBottom;
+                self.jump_to_error(ScrollDirection::Up);
                 tui.frame_requester().schedule_frame();
             }
-            // Enter confirms backtrack when primed + count > 0. Otherwise pass to widget.
             KeyEvent {
-                code: KeyCode::Enter,
+                code: KeyCode::Char('e'),
+                modifiers: crossterm::event::KeyModifiers::ALT,
                 kind: KeyEventKind::Press,
                 ..
-            } if self.backtrack.primed
-                && self.backtrack.nth_user_message != usize::MAX
-                && self.chat_widget.composer_is_empty() =>
-            {
-                // Delegate to helper for clarity; preserves behavior.
-                self.confirm_backtrack_from_main();
+            } => {
+                if let Some(effort) = self.chat_widget.cycle_reasoning_effort() {
+                    let label = Self::reasoning_label(Some(effort));
+                    self.chat_widget
+                        .add_info_message(format!("Reasoning effort set to {label}."), None);
+                }
             }
             KeyEvent {
-                kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                code: KeyCode::Char('s'),
+                modifiers: crossterm::event::KeyModifiers::ALT,
+                kind: KeyEventKind::Press,
+                ..
+            } if !self.chat_widget.is_task_running() => {
+                self.chat_widget.cycle_sandbox_policy();
+            }
+            KeyEvent {
+                code: KeyCode::Char('m'),
+                modifiers: crossterm::event::KeyModifiers::ALT,
+                kind: KeyEventKind::Press,
                 ..
             } => {
-                // Any non-Esc key press should cancel a primed backtrack.
-                // This avoids stale "Esc-primed" state after the user starts typing
-                // (even if they later backspace to empty).
-                if key_event.code != KeyCode::Esc && self.backtrack.primed {
-                    self.reset_backtrack_state();
-                }
-                self.chat_widget.handle_key_event(key_event);
+                self.toggle_screen_mode(tui);
             }
-            _ => {
-                // Ignore Release key events.
+            KeyEvent {
+                code: KeyCode::Char('x'),
+                modifiers: crossterm::event::KeyModifiers::ALT,
+                kind: KeyEventKind::Press,
+                ..
+            } if self.chat_widget.is_task_running() => {
+                self.stop_generation();
+                tui.frame_requester().schedule_frame();
+            }
+            KeyEvent {
+                code: KeyCode::PageUp,
+                kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                ..
+            } => {
+                if let Some((visible_lines, width)) = self.transcript_scroll_dimensions(tui) {
+                    let delta = -page_delta(visible_lines, self.page_overlap);
+                    self.scroll_transcript(tui, delta, visible_lines, width, true);
+                }
+            }
+            KeyEvent {
+                code: KeyCode::PageDown,
+                kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                ..
+            } => {
+                if let Some((visible_lines, width)) = self.transcript_scroll_dimensions(tui) {
+                    let delta = page_delta(visible_lines, self.page_overlap);
+                    self.scroll_transcript(tui, delta, visible_lines, width, true);
+                }
+            }
+            // Vim-style half-page scrolling. These only apply with an empty composer, where
+            // Ctrl+U has nothing to kill. Ctrl+D additionally requires the view to be scrolled
+            // up, so at the bottom it still falls through to the composer's "exit" behavior.
+            KeyEvent {
+                code: KeyCode::Char('u'),
+                modifiers: crossterm::event::KeyModifiers::CONTROL,
+                kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                ..
+            } if self.chat_widget.composer_is_empty() => {
+                self.scroll_transcript_half_page(tui, ScrollDirection::Up);
+            }
+            KeyEvent {
+                code: KeyCode::Char('d'),
+                modifiers: crossterm::event::KeyModifiers::CONTROL,
+                kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                ..
+            } if self.chat_widget.composer_is_empty()
+                && !matches!(self.transcript_scroll, TranscriptScroll::ToBottom) =>
+            {
+                self.scroll_transcript_half_page(tui, ScrollDirection::Down);
+            }
+            KeyEvent {
+                code: KeyCode::Home,
+                kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                ..
+            } => {
+                if !self.transcript_cells.is_empty() {
+                    self.transcript_scroll = TranscriptScroll::Scrolled {
+                        cell_index: 0,
+                        line_in_cell: 0,
+                    };
+                    tui.frame_requester().schedule_frame();
+                }
+            }
+            KeyEvent {
+                code: KeyCode::End,
+                kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                ..
+            } => {
+                self.resume_transcript_follow();
+                tui.frame_requester().schedule_frame();
+            }
+            // Enter confirms backtrack when primed + count > 0. Otherwise pass to widget.
+            KeyEvent {
+                code: KeyCode::Enter,
+                kind: KeyEventKind::Press,
+                ..
+            } if self.backtrack.primed
+                && self.backtrack.nth_user_message != usize::MAX
+                && self.chat_widget.composer_is_empty() =>
+            {
+                // Delegate to helper for clarity; preserves behavior.
+                self.confirm_backtrack_from_main();
+            }
+            KeyEvent {
+                kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                ..
+            } => {
+                // Any non-Esc key press should cancel a primed backtrack.
+                // This avoids stale "Esc-primed" state after the user starts typing
+                // (even if they later backspace to empty).
+                if key_event.code != KeyCode::Esc && self.backtrack.primed {
+                    self.reset_backtrack_state();
+                }
+                self.chat_widget.handle_key_event(key_event);
+            }
+            _ => {
+                // Ignore Release key events.
             }
         };
     }
@@ -2044,6 +3340,7 @@ mod tests {
     use crate::history_cell::AgentMessageCell;
     use crate::history_cell::HistoryCell;
     use crate::history_cell::UserHistoryCell;
+    use crate::history_cell::new_error_event;
     use crate::history_cell::new_session_info;
     use crate::transcript_copy_ui::CopySelectionShortcut;
     use crate::tui::scrolling::TranscriptLineMeta;
@@ -2055,6 +3352,8 @@ mod tests {
     use codex_core::protocol::EventMsg;
     use codex_core::protocol::SandboxPolicy;
     use codex_core::protocol::SessionConfiguredEvent;
+    use codex_core::protocol::TaskStartedEvent;
+    use codex_core::protocol::TokenUsageInfo;
     use codex_protocol::ConversationId;
     use pretty_assertions::assert_eq;
     use ratatui::prelude::Line;
@@ -2084,12 +3383,22 @@ mod tests {
             active_profile: None,
             file_search,
             transcript_cells: Vec::new(),
+            transcript_collapse: CollapsedTranscript::new(None),
             transcript_view_cache: TranscriptViewCache::new(),
+            transcript_row_diff: TranscriptRowDiff::new(),
+            #[cfg(feature = "debug-logs")]
+            protocol_event_log: Default::default(),
+            transcript_mirror: TranscriptMirror::disabled(),
             transcript_scroll: TranscriptScroll::default(),
             transcript_selection: TranscriptSelection::default(),
             transcript_multi_click: TranscriptMultiClick::default(),
             transcript_view_top: 0,
             transcript_total_lines: 0,
+            transcript_gutter: TranscriptGutter::default(),
+            transcript_edge_flash: None,
+            resume_follow_rect: None,
+            stop_rect: None,
+            transcript_minimap_rect: None,
             transcript_copy_ui: TranscriptCopyUi::new_with_shortcut(
                 CopySelectionShortcut::CtrlShiftC,
             ),
@@ -2098,8 +3407,19 @@ mod tests {
             has_emitted_history_lines: false,
             enhanced_keys_supported: false,
             commit_anim_running: Arc::new(AtomicBool::new(false)),
+            commit_anim_paused: false,
+            terminal_focused: true,
             scroll_config: ScrollConfig::default(),
             scroll_state: MouseScrollState::default(),
+            low_power_idle: false,
+            last_input_at: Instant::now(),
+            page_overlap: DEFAULT_PAGE_OVERLAP,
+            cell_spacing: DEFAULT_CELL_SPACING,
+            theme: Theme::default(),
+            copy_ansi: false,
+            copy_path_header: false,
+            screen_mode: ScreenMode::AltScreen,
+            esc_behavior: EscBehavior::Backtrack,
             backtrack: BacktrackState::default(),
             feedback: codex_feedback::CodexFeedback::new(),
             pending_update_action: None,
@@ -2135,12 +3455,22 @@ mod tests {
                 active_profile: None,
                 file_search,
                 transcript_cells: Vec::new(),
+                transcript_collapse: CollapsedTranscript::new(None),
                 transcript_view_cache: TranscriptViewCache::new(),
+                transcript_row_diff: TranscriptRowDiff::new(),
+                #[cfg(feature = "debug-logs")]
+                protocol_event_log: Default::default(),
+                transcript_mirror: TranscriptMirror::disabled(),
                 transcript_scroll: TranscriptScroll::default(),
                 transcript_selection: TranscriptSelection::default(),
                 transcript_multi_click: TranscriptMultiClick::default(),
                 transcript_view_top: 0,
                 transcript_total_lines: 0,
+                transcript_gutter: TranscriptGutter::default(),
+                transcript_edge_flash: None,
+                resume_follow_rect: None,
+                stop_rect: None,
+                transcript_minimap_rect: None,
                 transcript_copy_ui: TranscriptCopyUi::new_with_shortcut(
                     CopySelectionShortcut::CtrlShiftC,
                 ),
@@ -2149,8 +3479,19 @@ mod tests {
                 has_emitted_history_lines: false,
                 enhanced_keys_supported: false,
                 commit_anim_running: Arc::new(AtomicBool::new(false)),
+                commit_anim_paused: false,
+                terminal_focused: true,
                 scroll_config: ScrollConfig::default(),
                 scroll_state: MouseScrollState::default(),
+                low_power_idle: false,
+                last_input_at: Instant::now(),
+                page_overlap: DEFAULT_PAGE_OVERLAP,
+                cell_spacing: DEFAULT_CELL_SPACING,
+                theme: Theme::default(),
+                copy_ansi: false,
+                copy_path_header: false,
+                screen_mode: ScreenMode::AltScreen,
+                esc_behavior: EscBehavior::Backtrack,
                 backtrack: BacktrackState::default(),
                 feedback: codex_feedback::CodexFeedback::new(),
                 pending_update_action: None,
@@ -2212,6 +3553,7 @@ mod tests {
                 Line::from("four"),
             ],
             true,
+            &Theme::default(),
         ))];
 
         app.transcript_view_top = 2;
@@ -2228,11 +3570,65 @@ mod tests {
             &app.transcript_cells,
             app.transcript_selection,
             40,
+            app.cell_spacing,
         )
         .expect("expected text");
         assert_eq!(text, "one\ntwo\nthree\nfour");
     }
 
+    #[tokio::test]
+    async fn transcript_selection_reanchors_after_width_change() {
+        let mut app = make_test_app().await;
+        app.transcript_cells = vec![Arc::new(AgentMessageCell::new(
+            vec![Line::from("alpha beta gamma delta epsilon")],
+            true,
+            &Theme::default(),
+        ))];
+        let cells = app.transcript_cells.clone();
+
+        // At width 40 everything fits on one line; `gamma` spans content columns 11..=15.
+        app.wrap_transcript_preserving_selection(&cells, 40);
+        app.transcript_selection = TranscriptSelection {
+            anchor: Some(TranscriptSelectionPoint::new(0, 11)),
+            head: Some(TranscriptSelectionPoint::new(0, 15)),
+        };
+        assert_eq!(
+            crate::transcript_copy::selection_to_copy_text_for_cells(
+                &cells,
+                app.transcript_selection,
+                40,
+                app.cell_spacing,
+            )
+            .as_deref(),
+            Some("gamma")
+        );
+
+        // Narrowing pushes `gamma` onto a later wrapped line.
+        app.wrap_transcript_preserving_selection(&cells, 12);
+        let anchor = app.transcript_selection.anchor.expect("anchor kept");
+        assert!(anchor.line_index > 0, "{anchor:?}");
+        assert_eq!(
+            crate::transcript_copy::selection_to_copy_text_for_cells(
+                &cells,
+                app.transcript_selection,
+                12,
+                app.cell_spacing,
+            )
+            .as_deref(),
+            Some("gamma")
+        );
+
+        // Widening again restores the original endpoints.
+        app.wrap_transcript_preserving_selection(&cells, 40);
+        assert_eq!(
+            app.transcript_selection,
+            TranscriptSelection {
+                anchor: Some(TranscriptSelectionPoint::new(0, 11)),
+                head: Some(TranscriptSelectionPoint::new(0, 15)),
+            }
+        );
+    }
+
     #[tokio::test]
     async fn model_migration_prompt_respects_hide_flag_and_self_target() {
         let mut seen = BTreeMap::new();
@@ -2277,12 +3673,14 @@ mod tests {
         let user_cell = |text: &str| -> Arc<dyn HistoryCell> {
             Arc::new(UserHistoryCell {
                 message: text.to_string(),
+                theme: Theme::default(),
             }) as Arc<dyn HistoryCell>
         };
         let agent_cell = |text: &str| -> Arc<dyn HistoryCell> {
             Arc::new(AgentMessageCell::new(
                 vec![Line::from(text.to_string())],
                 true,
+                &Theme::default(),
             )) as Arc<dyn HistoryCell>
         };
 
@@ -2305,6 +3703,7 @@ mod tests {
                 app.current_model.as_str(),
                 event,
                 is_first,
+                None,
             )) as Arc<dyn HistoryCell>
         };
 
@@ -2337,6 +3736,32 @@ mod tests {
         assert_eq!(prefill, "follow-up (edited)");
     }
 
+    #[tokio::test]
+    async fn quote_selection_seeds_composer_and_clears_selection() {
+        use crate::history_cell::PlainHistoryCell;
+
+        let mut app = make_test_app().await;
+        app.transcript_cells = vec![Arc::new(PlainHistoryCell::new(vec![
+            Line::from("• first line"),
+            Line::from("  second line"),
+        ])) as Arc<dyn HistoryCell>];
+        app.transcript_selection = TranscriptSelection {
+            anchor: Some(TranscriptSelectionPoint::new(0, 0)),
+            head: Some(TranscriptSelectionPoint::new(1, 20)),
+        };
+
+        assert!(app.quote_transcript_selection_at_size(80, 40));
+
+        let expected = "> first line\n> second line\n\n";
+        assert_eq!(app.chat_widget.composer_text(), expected);
+        assert_eq!(app.chat_widget.composer_cursor(), expected.len());
+        assert_eq!(app.transcript_selection, TranscriptSelection::default());
+
+        // Without a selection there is nothing to quote.
+        assert!(!app.quote_transcript_selection_at_size(80, 40));
+        assert_eq!(app.chat_widget.composer_text(), expected);
+    }
+
     #[tokio::test]
     async fn transcript_selection_moves_with_scroll() {
         use ratatui::buffer::Buffer;
@@ -2399,6 +3824,344 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn message_stats_footer_adds_one_transcript_line() {
+        use crate::history_cell::MESSAGE_STATS_MIN_WORDS;
+        use crate::history_cell::new_agent_message_stats;
+
+        let mut app = make_test_app().await;
+        let message = "word ".repeat(MESSAGE_STATS_MIN_WORDS);
+        let agent: Arc<dyn HistoryCell> = Arc::new(AgentMessageCell::new(
+            vec![Line::from("first"), Line::from("second")],
+            true,
+            &Theme::default(),
+        ));
+        let next: Arc<dyn HistoryCell> = Arc::new(AgentMessageCell::new(
+            vec![Line::from("next")],
+            true,
+            &Theme::default(),
+        ));
+        let mut total_lines = |cells: Vec<Arc<dyn HistoryCell>>| {
+            app.transcript_cells = cells;
+            app.transcript_view_cache = TranscriptViewCache::new();
+            app.transcript_view_cache
+                .ensure_wrapped(app.transcript_collapse.view(&app.transcript_cells), 40);
+            app.transcript_view_cache.lines().len()
+        };
+
+        let without = total_lines(vec![agent.clone(), next.clone()]);
+        let footer: Arc<dyn HistoryCell> =
+            Arc::new(new_agent_message_stats(&message).expect("footer"));
+        let with = total_lines(vec![agent, footer, next]);
+        assert_eq!(with, without + 1);
+    }
+
+    #[tokio::test]
+    async fn clicking_inside_wide_glyph_snaps_selection_and_copy_matches_highlight() {
+        use crate::history_cell::PlainHistoryCell;
+        use ratatui::buffer::Buffer;
+        use ratatui::layout::Rect;
+        use ratatui::style::Modifier;
+        use ratatui::widgets::Widget;
+
+        let mut app = make_test_app().await;
+        app.transcript_cells = vec![
+            Arc::new(PlainHistoryCell::new(vec![Line::from("• 中文字")])) as Arc<dyn HistoryCell>,
+        ];
+        let area = Rect::new(0, 0, 20, 1);
+        app.transcript_view_cache.ensure_wrapped(
+            app.transcript_collapse.view(&app.transcript_cells),
+            area.width,
+        );
+        app.transcript_total_lines = app.transcript_view_cache.lines().len();
+        app.transcript_view_top = 0;
+        let base_x = app.transcript_gutter.base_x(area);
+
+        // `文` covers content columns 2..=3; a click on its right half snaps to column 2.
+        let anchor = app
+            .transcript_point_from_coordinates(area, base_x, base_x, 0)
+            .expect("anchor");
+        let head = app
+            .transcript_point_from_coordinates(area, base_x, base_x + 3, 0)
+            .expect("head");
+        assert_eq!(head, TranscriptSelectionPoint::new(0, 2));
+        app.transcript_selection = TranscriptSelection {
+            anchor: Some(anchor),
+            head: Some(head),
+        };
+
+        let mut buf = Buffer::empty(area);
+        Line::from("• 中文字").render(area, &mut buf);
+        app.apply_transcript_selection(area, &mut buf);
+        let highlighted: String = (area.left()..area.right())
+            .map(|x| &buf[(x, 0)])
+            .filter(|cell| cell.style().add_modifier.contains(Modifier::REVERSED))
+            .map(|cell| cell.symbol().trim().to_string())
+            .collect();
+
+        let copied = crate::transcript_copy::selection_to_copy_text_for_cells(
+            app.transcript_collapse.view(&app.transcript_cells),
+            app.transcript_selection,
+            area.width,
+            app.cell_spacing,
+        )
+        .expect("copy text");
+        assert_eq!(highlighted, "中文");
+        assert_eq!(copied, highlighted);
+    }
+
+    #[tokio::test]
+    async fn degenerate_terminal_sizes_yield_no_viewport_or_copy() {
+        use crate::history_cell::PlainHistoryCell;
+
+        let mut app = make_test_app().await;
+        app.transcript_cells = vec![
+            Arc::new(PlainHistoryCell::new(vec![Line::from("• 中文字")])) as Arc<dyn HistoryCell>,
+        ];
+        app.transcript_selection = TranscriptSelection {
+            anchor: Some(TranscriptSelectionPoint::new(0, 0)),
+            head: Some(TranscriptSelectionPoint::new(0, 4)),
+        };
+
+        assert_eq!(app.transcript_viewport(0, 40), None);
+        assert_eq!(app.transcript_viewport(80, 0), None);
+        for width in [0, 1, 2] {
+            assert_eq!(app.transcript_selection_text(width, 40, false), None);
+            assert_eq!(app.transcript_selection_text(width, 40, true), None);
+        }
+
+        let viewport = app.transcript_viewport(80, 40).expect("viewport");
+        assert_eq!(
+            viewport.content_width,
+            app.transcript_gutter.content_width(80)
+        );
+        assert!(viewport.area.height > 0 && viewport.area.height < 40);
+    }
+
+    #[tokio::test]
+    async fn transcript_gutter_width_shifts_selection_columns() {
+        use ratatui::buffer::Buffer;
+        use ratatui::layout::Rect;
+
+        let mut app = make_test_app().await;
+        app.transcript_gutter = TranscriptGutter::new(Some(6), true);
+        app.transcript_total_lines = 1;
+        app.transcript_view_top = 0;
+
+        let area = Rect {
+            x: 0,
+            y: 0,
+            width: 20,
+            height: 1,
+        };
+        let base_x = app.transcript_gutter.base_x(area);
+        assert_eq!(base_x, 6);
+
+        // A click two columns into the content maps to content column 2, regardless of the
+        // gutter width.
+        let point = app
+            .transcript_point_from_coordinates(area, base_x, base_x + 2, 0)
+            .expect("point");
+        assert_eq!(point, TranscriptSelectionPoint::new(0, 2));
+
+        app.transcript_selection = TranscriptSelection {
+            anchor: Some(point),
+            head: Some(TranscriptSelectionPoint::new(0, 4)),
+        };
+
+        let mut buf = Buffer::empty(area);
+        buf.set_string(0, 0, "   1 ", ratatui::style::Style::default());
+        for x in base_x..area.width {
+            buf[(x, 0)].set_symbol("X");
+        }
+        app.apply_transcript_selection(area, &mut buf);
+
+        let highlighted: Vec<u16> = (0..area.width)
+            .filter(|x| {
+                buf[(*x, 0)]
+                    .style()
+                    .add_modifier
+                    .contains(ratatui::style::Modifier::REVERSED)
+            })
+            .collect();
+        assert_eq!(highlighted, vec![8, 9, 10]);
+    }
+
+    #[tokio::test]
+    async fn esc_behavior_controls_esc_with_a_draft() {
+        let esc = KeyEvent::new(KeyCode::Esc, crossterm::event::KeyModifiers::NONE);
+        let mut app = make_test_app().await;
+
+        app.esc_behavior = EscBehavior::Backtrack;
+        app.chat_widget.set_composer_text("draft".to_string());
+        assert!(!app.esc_arms_backtrack());
+        app.handle_esc_with_draft(esc);
+        assert_eq!(app.chat_widget.composer_text(), "draft");
+
+        app.esc_behavior = EscBehavior::None;
+        app.handle_esc_with_draft(esc);
+        assert_eq!(app.chat_widget.composer_text(), "draft");
+
+        app.esc_behavior = EscBehavior::Clear;
+        app.handle_esc_with_draft(esc);
+        assert_eq!(app.chat_widget.composer_text(), "");
+        // The cleared draft is one Up away.
+        app.chat_widget.handle_key_event(KeyEvent::new(
+            KeyCode::Up,
+            crossterm::event::KeyModifiers::NONE,
+        ));
+        assert_eq!(app.chat_widget.composer_text(), "draft");
+    }
+
+    #[tokio::test]
+    async fn esc_on_empty_composer_arms_backtrack_in_every_mode() {
+        let mut app = make_test_app().await;
+        for behavior in [
+            EscBehavior::Backtrack,
+            EscBehavior::Clear,
+            EscBehavior::None,
+        ] {
+            app.esc_behavior = behavior;
+            assert!(app.esc_arms_backtrack(), "{behavior:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn screen_mode_toggle_preserves_transcript_scroll() {
+        let mut app = make_test_app().await;
+        assert_eq!(app.screen_mode, ScreenMode::AltScreen);
+        let scrolled = TranscriptScroll::Scrolled {
+            cell_index: 2,
+            line_in_cell: 1,
+        };
+        app.transcript_scroll = scrolled;
+        app.transcript_selection = TranscriptSelection {
+            anchor: Some(TranscriptSelectionPoint::new(3, 0)),
+            head: Some(TranscriptSelectionPoint::new(4, 5)),
+        };
+        let selection = app.transcript_selection;
+
+        assert_eq!(app.switch_screen_mode(), ScreenMode::Inline);
+        assert_eq!(app.screen_mode, ScreenMode::Inline);
+        assert_eq!(app.transcript_scroll, scrolled);
+        assert_eq!(app.transcript_selection, selection);
+
+        assert_eq!(app.switch_screen_mode(), ScreenMode::AltScreen);
+        assert_eq!(app.transcript_scroll, scrolled);
+        assert_eq!(app.transcript_selection, selection);
+
+        assert_eq!(ScreenMode::from_config(false), ScreenMode::Inline);
+    }
+
+    #[tokio::test]
+    async fn max_content_width_caps_wrapping_and_selection() {
+        let mut app = make_test_app().await;
+        app.transcript_gutter = TranscriptGutter::default().with_max_content_width(Some(80));
+        let words: Vec<String> = (0..40).map(|idx| format!("word{idx}")).collect();
+        app.transcript_cells = vec![Arc::new(AgentMessageCell::new(
+            vec![Line::from(words.join(" "))],
+            true,
+            &Theme::default(),
+        ))];
+
+        let viewport = app.transcript_viewport(200, 40).expect("viewport");
+        assert_eq!(viewport.content_width, 80);
+
+        let cells = app.transcript_cells.clone();
+        app.transcript_view_cache
+            .ensure_wrapped(&cells, viewport.content_width);
+        let lines: Vec<String> = app
+            .transcript_view_cache
+            .lines()
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .collect();
+        assert!(lines.len() > 1, "{lines:?}");
+        assert!(
+            lines.iter().all(|line| line.len() <= 80),
+            "wrapped at the cap: {lines:?}"
+        );
+        app.transcript_total_lines = lines.len();
+
+        // A click five columns into the content of the second row maps to content column 5.
+        let base_x = app.transcript_gutter.base_x(viewport.area);
+        let point = app
+            .transcript_point_from_coordinates(viewport.area, base_x, base_x + 5, 1)
+            .expect("point");
+        assert_eq!(point, TranscriptSelectionPoint::new(1, 5));
+
+        app.transcript_selection = TranscriptSelection {
+            anchor: Some(TranscriptSelectionPoint::new(1, 0)),
+            head: Some(TranscriptSelectionPoint::new(1, u16::MAX)),
+        };
+        assert_eq!(
+            app.transcript_selection_text(200, 40, false),
+            Some(
+                lines[1][crate::transcript_selection::TRANSCRIPT_GUTTER_COLS as usize..]
+                    .trim_end()
+                    .to_string()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn max_transcript_cells_collapses_oldest_cells_in_viewport() {
+        use crate::custom_terminal::Frame;
+        use crate::history_cell::PlainHistoryCell;
+        use ratatui::buffer::Buffer;
+        use ratatui::layout::Rect;
+
+        let mut app = make_test_app().await;
+        app.transcript_collapse = CollapsedTranscript::new(Some(2));
+        app.transcript_cells = (0..5)
+            .map(|idx| {
+                Arc::new(PlainHistoryCell::new(vec![Line::from(format!(
+                    "cell {idx}"
+                ))])) as Arc<dyn HistoryCell>
+            })
+            .collect();
+
+        let area = Rect::new(0, 0, 40, 12);
+        let mut buf = Buffer::empty(area);
+        let mut frame = Frame {
+            cursor_position: None,
+            viewport_area: area,
+            buffer: &mut buf,
+        };
+        let cells = app.transcript_collapse.view(&app.transcript_cells).to_vec();
+        app.render_transcript_cells(&mut frame, &cells, 2);
+
+        // Summary + the newest two cells, separated by spacer rows.
+        assert_eq!(app.transcript_collapse.collapsed(), 3);
+        assert_eq!(app.transcript_total_lines, 5);
+        let rows: Vec<String> = (0..5)
+            .map(|y| {
+                (0..area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                "… earlier conversation (collapsed) …".to_string(),
+                String::new(),
+                "cell 3".to_string(),
+                String::new(),
+                "cell 4".to_string(),
+            ]
+        );
+        // The full list is untouched for the overlay and backtracking.
+        assert_eq!(app.transcript_cells.len(), 5);
+    }
+
     #[tokio::test]
     async fn transcript_selection_renders_copy_affordance() {
         use ratatui::buffer::Buffer;
@@ -2613,6 +4376,191 @@ mod tests {
         }
     }
 
+    #[test]
+    fn half_page_delta_is_half_the_visible_height() {
+        // 24-row terminal with a 4-row composer: 20 visible transcript lines.
+        assert_eq!(half_page_delta(20, 0), 10);
+        // 40-row terminal with a 9-row composer: 31 visible lines, rounded up.
+        assert_eq!(half_page_delta(31, 0), 16);
+        assert_eq!(half_page_delta(1, 0), 1);
+        assert_eq!(page_delta(20, 0), 20);
+    }
+
+    #[test]
+    fn page_delta_keeps_overlap_lines() {
+        assert_eq!(page_delta(20, 2), 18);
+        assert_eq!(half_page_delta(20, 2), 9);
+
+        // The overlap never swallows the whole page.
+        assert_eq!(page_delta(2, 2), 1);
+        assert_eq!(page_delta(1, 5), 1);
+        assert_eq!(page_delta(20, u16::MAX), 1);
+    }
+
+    #[tokio::test]
+    async fn pasted_color_codes_are_stripped_before_insertion() {
+        let mut app = make_test_app().await;
+        let pasted = "\u{1b}[31mfailed\u{1b}[0m\r\n  at \u{1b}[1mmain.rs\u{1b}[22m";
+
+        let sanitized = app.sanitize_paste(pasted);
+        app.chat_widget.handle_paste(sanitized);
+        assert_eq!(app.chat_widget.composer_text(), "failed\n  at main.rs");
+
+        app.config.tui_paste_strip_ansi = false;
+        assert_eq!(
+            app.sanitize_paste("\u{1b}[31mred\u{1b}[0m"),
+            "\u{1b}[31mred\u{1b}[0m"
+        );
+    }
+
+    #[tokio::test]
+    async fn low_power_mode_follows_idle_state_and_input() {
+        let mut app = make_test_app().await;
+        let requester = crate::tui::FrameRequester::test_dummy();
+        app.low_power_idle = true;
+        app.last_input_at = Instant::now()
+            .checked_sub(LOW_POWER_INPUT_GRACE)
+            .expect("instant in range");
+        app.commit_anim_running.store(true, Ordering::Release);
+
+        app.update_low_power(&requester);
+        assert!(requester.is_low_power());
+        assert!(!app.commit_anim_running.load(Ordering::Acquire));
+
+        app.note_input(&requester);
+        assert!(!requester.is_low_power());
+        app.update_low_power(&requester);
+        assert!(!requester.is_low_power(), "recent input keeps full power");
+
+        app.last_input_at = Instant::now()
+            .checked_sub(LOW_POWER_INPUT_GRACE)
+            .expect("instant in range");
+        app.low_power_idle = false;
+        app.update_low_power(&requester);
+        assert!(!requester.is_low_power(), "disabled by config");
+    }
+
+    #[tokio::test]
+    async fn focus_changes_toggle_unfocused_state_and_commit_animation() {
+        let (mut app, mut app_event_rx, _op_rx) = make_test_app_with_channels().await;
+        let requester = crate::tui::FrameRequester::test_dummy();
+        app.commit_anim_running.store(true, Ordering::Release);
+
+        app.on_focus_changed(false, &requester);
+        assert!(!app.terminal_focused);
+        assert!(requester.is_low_power());
+        assert!(!app.commit_anim_running.load(Ordering::Acquire));
+        assert!(app.commit_anim_paused);
+        app.update_low_power(&requester);
+        assert!(requester.is_low_power(), "unfocused stays in low power");
+
+        app.on_focus_changed(true, &requester);
+        assert!(app.terminal_focused);
+        assert!(!requester.is_low_power());
+        assert!(!app.commit_anim_paused);
+        assert!(matches!(
+            app_event_rx.try_recv(),
+            Ok(AppEvent::StartCommitAnimation)
+        ));
+    }
+
+    #[tokio::test]
+    async fn diff_result_opens_diff_overlay() {
+        let mut app = make_test_app().await;
+        assert!(app.overlay.is_none());
+
+        app.open_diff_overlay("diff --git a/a.txt b/a.txt\n+added\n".to_string());
+        assert!(matches!(app.overlay, Some(Overlay::Static(_))));
+
+        app.overlay = None;
+        app.open_diff_overlay(String::new());
+        assert!(matches!(app.overlay, Some(Overlay::Static(_))));
+    }
+
+    #[tokio::test]
+    async fn closing_overlay_flushes_deferred_history_and_requests_frame() {
+        let mut app = make_test_app().await;
+        app.overlay = Some(Overlay::new_transcript(Vec::new(), Theme::default()));
+        app.backtrack.overlay_preview_active = true;
+        app.backtrack.nth_user_message = 2;
+        app.deferred_history_lines = vec![Line::from("arrived while open")];
+
+        let (draw_tx, mut draw_rx) = tokio::sync::broadcast::channel(4);
+        let deferred = app.finish_overlay_close(&crate::tui::FrameRequester::new(draw_tx));
+
+        assert!(app.overlay.is_none());
+        assert!(!app.backtrack.overlay_preview_active);
+        assert_eq!(app.backtrack.nth_user_message, usize::MAX);
+        assert_eq!(deferred, vec![Line::from("arrived while open")]);
+        assert!(app.deferred_history_lines.is_empty());
+        tokio::time::timeout(std::time::Duration::from_secs(1), draw_rx.recv())
+            .await
+            .expect("closing the overlay should schedule a frame")
+            .expect("draw channel open");
+    }
+
+    #[tokio::test]
+    async fn consecutive_identical_errors_collapse_into_one_cell() {
+        let mut app = make_test_app().await;
+        let push = |app: &mut App, cell: Arc<dyn HistoryCell>| {
+            if !app.merge_into_last_transcript_cell(cell.as_ref()) {
+                app.transcript_cells.push(cell);
+            }
+        };
+        let rendered = |app: &App| -> Vec<String> {
+            app.transcript_cells
+                .iter()
+                .map(|cell| {
+                    cell.display_lines(80)
+                        .iter()
+                        .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                })
+                .collect()
+        };
+
+        for _ in 0..3 {
+            push(
+                &mut app,
+                Arc::new(new_error_event("boom".to_string(), &Theme::default())),
+            );
+        }
+        assert_eq!(rendered(&app), vec!["■ boom (×3)".to_string()]);
+
+        push(
+            &mut app,
+            Arc::new(new_error_event("other".to_string(), &Theme::default())),
+        );
+        push(
+            &mut app,
+            Arc::new(new_error_event("boom".to_string(), &Theme::default())),
+        );
+        assert_eq!(
+            rendered(&app),
+            vec![
+                "■ boom (×3)".to_string(),
+                "■ other".to_string(),
+                "■ boom".to_string(),
+            ]
+        );
+
+        // A non-error cell in between also breaks the group.
+        push(
+            &mut app,
+            Arc::new(AgentMessageCell::new(
+                vec![Line::from("retrying")],
+                true,
+                &Theme::default(),
+            )),
+        );
+        push(
+            &mut app,
+            Arc::new(new_error_event("boom".to_string(), &Theme::default())),
+        );
+        assert_eq!(app.transcript_cells.len(), 5);
+    }
+
     #[tokio::test]
     async fn session_summary_skip_zero_usage() {
         assert!(session_summary(TokenUsage::default(), None).is_none());
@@ -2634,11 +4582,353 @@ mod tests {
             &line_meta,
             &is_user_cell,
             width,
+            &Theme::default(),
         );
         assert_eq!(rendered.len(), 1);
         assert!(rendered[0].contains("hi"));
     }
 
+    #[test]
+    fn resume_command_uses_conversation_id() {
+        let conversation =
+            ConversationId::from_string("123e4567-e89b-12d3-a456-426614174000").unwrap();
+        assert_eq!(
+            resume_command(Some(conversation)),
+            Some("codex resume 123e4567-e89b-12d3-a456-426614174000".to_string())
+        );
+        assert_eq!(resume_command(None), None);
+    }
+
+    #[tokio::test]
+    async fn minimap_jump_centers_target_and_resumes_follow_at_bottom() {
+        use crate::history_cell::PlainHistoryCell;
+
+        let mut app = make_test_app().await;
+        app.transcript_cells = (0..30)
+            .map(|i| {
+                Arc::new(PlainHistoryCell::new(vec![Line::from(format!(
+                    "• line {i}"
+                ))])) as Arc<dyn HistoryCell>
+            })
+            .collect();
+
+        app.jump_transcript_to_line(20, 6, 40);
+        let line_meta = app.transcript_view_cache.line_meta();
+        let max_start = line_meta.len().saturating_sub(6);
+        let (_, top) = app.transcript_scroll.resolve_top(line_meta, max_start);
+        assert_eq!(top, 17);
+
+        app.jump_transcript_to_line(usize::MAX, 6, 40);
+        assert_eq!(app.transcript_scroll, TranscriptScroll::ToBottom);
+    }
+
+    #[tokio::test]
+    async fn scroll_transcript_to_cell_anchors_viewport_at_approval() {
+        use crate::history_cell::PlainHistoryCell;
+        use crate::history_cell::new_approval_decision_cell;
+        use codex_core::protocol::ReviewDecision;
+
+        let mut app = make_test_app().await;
+        app.transcript_cells = vec![
+            Arc::new(PlainHistoryCell::new(vec![Line::from("• hello")])) as Arc<dyn HistoryCell>,
+            new_approval_decision_cell(vec!["ls".to_string()], ReviewDecision::Denied).into(),
+        ];
+
+        let entries = crate::approval_history::approval_history(
+            app.transcript_collapse.view(&app.transcript_cells),
+        );
+        assert_eq!(entries.len(), 1);
+
+        app.scroll_transcript_to_cell(entries[0].cell_index);
+        assert_eq!(
+            app.transcript_scroll,
+            TranscriptScroll::Scrolled {
+                cell_index: 1,
+                line_in_cell: 0
+            }
+        );
+
+        // Out-of-range indices (e.g. after the transcript was trimmed) leave the view alone.
+        app.scroll_transcript_to_cell(5);
+        assert_eq!(
+            app.transcript_scroll,
+            TranscriptScroll::Scrolled {
+                cell_index: 1,
+                line_in_cell: 0
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn error_navigation_lands_on_error_cells() {
+        use crate::history_cell::PlainHistoryCell;
+        use crate::history_cell::new_error_event;
+
+        let mut app = make_test_app().await;
+        app.transcript_cells = vec![
+            Arc::new(new_error_event(
+                "first failure".to_string(),
+                &Theme::default(),
+            )) as Arc<dyn HistoryCell>,
+            Arc::new(PlainHistoryCell::new(vec![Line::from("• working")])),
+            Arc::new(PlainHistoryCell::new(vec![Line::from("• still working")])),
+            Arc::new(new_error_event(
+                "second failure".to_string(),
+                &Theme::default(),
+            )),
+            Arc::new(PlainHistoryCell::new(vec![Line::from("• done")])),
+        ];
+        let anchor = |cell_index| TranscriptScroll::Scrolled {
+            cell_index,
+            line_in_cell: 0,
+        };
+
+        assert_eq!(app.jump_to_error(ScrollDirection::Up), Some(3));
+        assert_eq!(app.transcript_scroll, anchor(3));
+        assert_eq!(app.jump_to_error(ScrollDirection::Up), Some(0));
+        assert_eq!(app.transcript_scroll, anchor(0));
+        assert_eq!(app.jump_to_error(ScrollDirection::Down), Some(3));
+        assert_eq!(app.transcript_scroll, anchor(3));
+
+        app.transcript_cells.retain(|cell| {
+            cell.as_any()
+                .downcast_ref::<crate::history_cell::ErrorHistoryCell>()
+                .is_none()
+        });
+        app.transcript_scroll = TranscriptScroll::ToBottom;
+        assert_eq!(app.jump_to_error(ScrollDirection::Down), None);
+        assert_eq!(app.transcript_scroll, TranscriptScroll::ToBottom);
+    }
+
+    #[tokio::test]
+    async fn selection_during_streaming_pauses_follow_until_resumed() {
+        use crate::history_cell::PlainHistoryCell;
+
+        let mut app = make_test_app().await;
+        app.transcript_cells = (0..10)
+            .map(|idx| {
+                Arc::new(PlainHistoryCell::new(vec![Line::from(format!(
+                    "line {idx}"
+                ))])) as Arc<dyn HistoryCell>
+            })
+            .collect();
+
+        let anchor = TranscriptSelectionPoint::new(8, 1);
+        app.transcript_selection = TranscriptSelection {
+            anchor: Some(anchor),
+            head: None,
+        };
+        let outcome = crate::transcript_selection::on_mouse_drag(
+            &mut app.transcript_selection,
+            &app.transcript_scroll,
+            Some(anchor),
+            true,
+        );
+        assert!(outcome.lock_scroll);
+        app.lock_transcript_scroll_to_current_view(4, 40);
+        assert_ne!(app.transcript_scroll, TranscriptScroll::ToBottom);
+
+        let area = Rect::new(0, 0, 40, 4);
+        let mut buf = Buffer::empty(area);
+        app.resume_follow_rect = render_resume_follow_pill(area, &mut buf, &Theme::default());
+        let pill = app.resume_follow_rect.expect("pill fits");
+        assert_eq!(pill.y, 3);
+        assert_eq!(pill.right(), 40);
+
+        app.resume_transcript_follow();
+        assert_eq!(app.transcript_scroll, TranscriptScroll::ToBottom);
+        assert_eq!(app.resume_follow_rect, None);
+    }
+
+    #[tokio::test]
+    async fn stop_pill_hit_region_interrupts_running_task() {
+        let (mut app, _app_event_rx, mut op_rx) = make_test_app_with_channels().await;
+
+        let area = Rect::new(0, 0, 40, 4);
+        let mut buf = Buffer::empty(area);
+        app.stop_rect = render_stop_pill(area, &mut buf, &Theme::default());
+        let pill = app.stop_rect.expect("pill fits");
+        assert_eq!((pill.y, pill.right()), (0, 40));
+        assert!(app.stop_hit_test(pill.x, 0));
+        assert!(app.stop_hit_test(39, 0));
+        assert!(!app.stop_hit_test(pill.x - 1, 0));
+        assert!(!app.stop_hit_test(39, 1));
+
+        // Nothing to stop while idle.
+        app.stop_generation();
+        assert!(op_rx.try_recv().is_err());
+        assert!(app.stop_rect.is_some());
+
+        app.chat_widget.handle_codex_event(Event {
+            id: String::new(),
+            msg: EventMsg::TaskStarted(TaskStartedEvent {
+                model_context_window: None,
+            }),
+        });
+        assert!(app.chat_widget.is_task_running());
+        app.stop_generation();
+        assert!(matches!(op_rx.try_recv(), Ok(Op::Interrupt)));
+        assert_eq!(app.stop_rect, None);
+    }
+
+    #[test]
+    fn stop_pill_is_skipped_when_too_narrow() {
+        let area = Rect::new(0, 0, 10, 2);
+        let mut buf = Buffer::empty(area);
+        assert_eq!(render_stop_pill(area, &mut buf, &Theme::default()), None);
+    }
+
+    #[test]
+    fn resume_follow_pill_is_skipped_when_too_narrow() {
+        let area = Rect::new(0, 0, 10, 2);
+        let mut buf = Buffer::empty(area);
+        assert_eq!(
+            render_resume_follow_pill(area, &mut buf, &Theme::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn world_writable_scan_respects_one_shot_suppression() {
+        let mut skip_once = true;
+        assert!(!world_writable_scan_needed(
+            &mut skip_once,
+            true,
+            true,
+            false
+        ));
+        assert!(!skip_once);
+        assert!(world_writable_scan_needed(
+            &mut skip_once,
+            true,
+            true,
+            false
+        ));
+
+        assert!(!world_writable_scan_needed(
+            &mut skip_once,
+            false,
+            true,
+            false
+        ));
+        assert!(!world_writable_scan_needed(
+            &mut skip_once,
+            true,
+            false,
+            false
+        ));
+        assert!(!world_writable_scan_needed(
+            &mut skip_once,
+            true,
+            true,
+            true
+        ));
+    }
+
+    #[test]
+    fn conversation_id_text_is_the_bare_id() {
+        let conversation =
+            ConversationId::from_string("123e4567-e89b-12d3-a456-426614174000").unwrap();
+        assert_eq!(
+            conversation_id_text(Some(conversation)),
+            Some("123e4567-e89b-12d3-a456-426614174000".to_string())
+        );
+        assert_eq!(conversation_id_text(None), None);
+    }
+
+    #[tokio::test]
+    async fn copy_conversation_id_is_noop_without_conversation() {
+        let mut app = make_test_app().await;
+        assert_eq!(app.chat_widget.conversation_id(), None);
+        assert!(!app.copy_conversation_id());
+    }
+
+    #[tokio::test]
+    async fn copy_resume_command_is_noop_without_conversation() {
+        let mut app = make_test_app().await;
+        assert_eq!(app.chat_widget.conversation_id(), None);
+        assert!(!app.copy_resume_command());
+    }
+
+    #[tokio::test]
+    async fn session_summary_on_new_follows_config() {
+        let mut app = make_test_app().await;
+        let usage = TokenUsage {
+            input_tokens: 10,
+            output_tokens: 2,
+            total_tokens: 12,
+            ..Default::default()
+        };
+        app.chat_widget.set_token_info(Some(TokenUsageInfo {
+            total_token_usage: usage.clone(),
+            last_token_usage: usage,
+            model_context_window: None,
+        }));
+
+        app.config.tui_show_session_summary_on_new = true;
+        let lines: Vec<String> = app
+            .outgoing_session_summary_lines()
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        assert_eq!(lines, vec!["Token usage: total=12 input=10 output=2"]);
+
+        app.config.tui_show_session_summary_on_new = false;
+        assert!(app.outgoing_session_summary_lines().is_empty());
+    }
+
+    #[tokio::test]
+    async fn model_switch_note_shows_once_for_flagged_models() {
+        let (mut app, mut app_event_rx, _op_rx) = make_test_app_with_channels().await;
+        let presets = app
+            .server
+            .get_models_manager()
+            .try_list_models(&app.config)
+            .expect("models");
+        let target = presets
+            .iter()
+            .find(|preset| preset.model != app.current_model && !preset.description.is_empty())
+            .expect("another model")
+            .clone();
+        while app_event_rx.try_recv().is_ok() {}
+
+        app.show_model_switch_note_if_needed(&target.model);
+        assert!(app_event_rx.try_recv().is_err(), "off by default");
+
+        app.config.tui_model_switch_notes = vec![target.model.clone()];
+        app.show_model_switch_note_if_needed(&target.model);
+        let mut note = None;
+        let mut persisted = None;
+        while let Ok(event) = app_event_rx.try_recv() {
+            match event {
+                AppEvent::InsertHistoryCell(cell) => {
+                    note = Some(
+                        cell.display_lines(200)
+                            .iter()
+                            .map(|line| {
+                                line.spans
+                                    .iter()
+                                    .map(|span| span.content.as_ref())
+                                    .collect::<String>()
+                            })
+                            .collect::<Vec<_>>()
+                            .join("\n"),
+                    );
+                }
+                AppEvent::PersistModelSwitchNoteSeen { model } => persisted = Some(model),
+                _ => {}
+            }
+        }
+        assert!(
+            note.expect("note shown").contains(&target.description),
+            "note quotes the preset description"
+        );
+        assert_eq!(persisted, Some(target.model.clone()));
+
+        app.show_model_switch_note_if_needed(&target.model);
+        assert!(app_event_rx.try_recv().is_err(), "shown only once");
+    }
+
     #[tokio::test]
     async fn session_summary_includes_resume_hint() {
         let usage = TokenUsage {
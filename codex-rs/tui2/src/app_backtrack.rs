@@ -7,6 +7,7 @@ use crate::history_cell::SessionInfoCell;
 use crate::history_cell::UserHistoryCell;
 use crate::pager_overlay::Overlay;
 use crate::tui;
+use crate::tui::FrameRequester;
 use crate::tui::TuiEvent;
 use codex_core::protocol::ConversationPathResponseEvent;
 use codex_protocol::ConversationId;
@@ -14,6 +15,7 @@ use color_eyre::eyre::Result;
 use crossterm::event::KeyCode;
 use crossterm::event::KeyEvent;
 use crossterm::event::KeyEventKind;
+use ratatui::text::Line;
 
 /// Aggregates all backtrack-related state used by the App.
 #[derive(Default)]
@@ -118,24 +120,42 @@ impl App {
     /// Open transcript overlay (enters alternate screen and shows full transcript).
     pub(crate) fn open_transcript_overlay(&mut self, tui: &mut tui::Tui) {
         let _ = tui.enter_alt_screen();
-        self.overlay = Some(Overlay::new_transcript(self.transcript_cells.clone()));
+        self.overlay = Some(Overlay::new_transcript(
+            self.transcript_cells.clone(),
+            self.theme,
+        ));
         tui.frame_requester().schedule_frame();
     }
 
-    /// Close transcript overlay and restore normal UI.
-    pub(crate) fn close_transcript_overlay(&mut self, tui: &mut tui::Tui) {
+    /// Close the active overlay (transcript, diff, or any static pager) and restore the inline
+    /// view.
+    ///
+    /// Every close path goes through here so they all leave the alternate screen, flush history
+    /// that arrived while the overlay was up, and schedule a frame; the redraw puts the composer
+    /// cursor back via `cursor_pos`.
+    pub(crate) fn close_overlay(&mut self, tui: &mut tui::Tui) {
         let _ = tui.leave_alt_screen();
-        let was_backtrack = self.backtrack.overlay_preview_active;
-        if !self.deferred_history_lines.is_empty() {
-            let lines = std::mem::take(&mut self.deferred_history_lines);
-            tui.insert_history_lines(lines);
+        let deferred = self.finish_overlay_close(&tui.frame_requester());
+        if !deferred.is_empty() {
+            tui.insert_history_lines(deferred);
         }
+    }
+
+    /// Resets overlay and backtrack-preview state, schedules a redraw of the inline view, and
+    /// returns the history lines deferred while the overlay was open.
+    pub(crate) fn finish_overlay_close(
+        &mut self,
+        frame_requester: &FrameRequester,
+    ) -> Vec<Line<'static>> {
+        let was_backtrack = self.backtrack.overlay_preview_active;
         self.overlay = None;
         self.backtrack.overlay_preview_active = false;
         if was_backtrack {
             // Ensure backtrack state is fully reset when overlay closes (e.g. via 'q').
             self.reset_backtrack_state();
         }
+        frame_requester.schedule_frame();
+        std::mem::take(&mut self.deferred_history_lines)
     }
 
     /// Re-render the full transcript into the terminal scrollback in one call.
@@ -221,8 +241,7 @@ impl App {
         if let Some(overlay) = &mut self.overlay {
             overlay.handle_event(tui, event)?;
             if overlay.is_done() {
-                self.close_transcript_overlay(tui);
-                tui.frame_requester().schedule_frame();
+                self.close_overlay(tui);
             }
         }
         Ok(())
@@ -237,7 +256,7 @@ impl App {
                 .and_then(|cell| cell.as_any().downcast_ref::<UserHistoryCell>())
                 .map(|c| c.message.clone())
                 .unwrap_or_default();
-            self.close_transcript_overlay(tui);
+            self.close_overlay(tui);
             self.request_backtrack(prefill, base_id, nth_user_message);
         }
         self.reset_backtrack_state();
@@ -385,6 +404,16 @@ pub(crate) fn user_count(cells: &[Arc<dyn crate::history_cell::HistoryCell>]) ->
     user_positions_iter(cells).count()
 }
 
+/// The text of the most recent user message in the current session, if any.
+pub(crate) fn last_user_message(
+    cells: &[Arc<dyn crate::history_cell::HistoryCell>],
+) -> Option<String> {
+    user_positions_iter(cells)
+        .last()
+        .and_then(|idx| cells[idx].as_any().downcast_ref::<UserHistoryCell>())
+        .map(|cell| cell.message.clone())
+}
+
 fn nth_user_position(
     cells: &[Arc<dyn crate::history_cell::HistoryCell>],
     nth: usize,
@@ -418,6 +447,7 @@ mod tests {
     use super::*;
     use crate::history_cell::AgentMessageCell;
     use crate::history_cell::HistoryCell;
+    use crate::theme::Theme;
     use ratatui::prelude::Line;
     use std::sync::Arc;
 
@@ -426,9 +456,13 @@ mod tests {
         let mut cells: Vec<Arc<dyn HistoryCell>> = vec![
             Arc::new(UserHistoryCell {
                 message: "first user".to_string(),
+                theme: Theme::default(),
             }) as Arc<dyn HistoryCell>,
-            Arc::new(AgentMessageCell::new(vec![Line::from("assistant")], true))
-                as Arc<dyn HistoryCell>,
+            Arc::new(AgentMessageCell::new(
+                vec![Line::from("assistant")],
+                true,
+                &Theme::default(),
+            )) as Arc<dyn HistoryCell>,
         ];
         trim_transcript_cells_to_nth_user(&mut cells, 0);
 
@@ -438,13 +472,20 @@ mod tests {
     #[test]
     fn trim_transcript_preserves_cells_before_selected_user() {
         let mut cells: Vec<Arc<dyn HistoryCell>> = vec![
-            Arc::new(AgentMessageCell::new(vec![Line::from("intro")], true))
-                as Arc<dyn HistoryCell>,
+            Arc::new(AgentMessageCell::new(
+                vec![Line::from("intro")],
+                true,
+                &Theme::default(),
+            )) as Arc<dyn HistoryCell>,
             Arc::new(UserHistoryCell {
                 message: "first".to_string(),
+                theme: Theme::default(),
             }) as Arc<dyn HistoryCell>,
-            Arc::new(AgentMessageCell::new(vec![Line::from("after")], false))
-                as Arc<dyn HistoryCell>,
+            Arc::new(AgentMessageCell::new(
+                vec![Line::from("after")],
+                false,
+                &Theme::default(),
+            )) as Arc<dyn HistoryCell>,
         ];
         trim_transcript_cells_to_nth_user(&mut cells, 0);
 
@@ -466,18 +507,29 @@ mod tests {
     #[test]
     fn trim_transcript_for_later_user_keeps_prior_history() {
         let mut cells: Vec<Arc<dyn HistoryCell>> = vec![
-            Arc::new(AgentMessageCell::new(vec![Line::from("intro")], true))
-                as Arc<dyn HistoryCell>,
+            Arc::new(AgentMessageCell::new(
+                vec![Line::from("intro")],
+                true,
+                &Theme::default(),
+            )) as Arc<dyn HistoryCell>,
             Arc::new(UserHistoryCell {
                 message: "first".to_string(),
+                theme: Theme::default(),
             }) as Arc<dyn HistoryCell>,
-            Arc::new(AgentMessageCell::new(vec![Line::from("between")], false))
-                as Arc<dyn HistoryCell>,
+            Arc::new(AgentMessageCell::new(
+                vec![Line::from("between")],
+                false,
+                &Theme::default(),
+            )) as Arc<dyn HistoryCell>,
             Arc::new(UserHistoryCell {
                 message: "second".to_string(),
+                theme: Theme::default(),
             }) as Arc<dyn HistoryCell>,
-            Arc::new(AgentMessageCell::new(vec![Line::from("tail")], false))
-                as Arc<dyn HistoryCell>,
+            Arc::new(AgentMessageCell::new(
+                vec![Line::from("tail")],
+                false,
+                &Theme::default(),
+            )) as Arc<dyn HistoryCell>,
         ];
         trim_transcript_cells_to_nth_user(&mut cells, 1);
 
@@ -512,4 +564,25 @@ mod tests {
             .collect();
         assert_eq!(between_text, "  between");
     }
+
+    #[test]
+    fn last_user_message_returns_most_recent_prompt() {
+        let cells: Vec<Arc<dyn HistoryCell>> = vec![
+            Arc::new(UserHistoryCell {
+                message: "first".to_string(),
+                theme: Theme::default(),
+            }) as Arc<dyn HistoryCell>,
+            Arc::new(UserHistoryCell {
+                message: "second".to_string(),
+                theme: Theme::default(),
+            }) as Arc<dyn HistoryCell>,
+            Arc::new(AgentMessageCell::new(
+                vec![Line::from("reply")],
+                true,
+                &Theme::default(),
+            )) as Arc<dyn HistoryCell>,
+        ];
+        assert_eq!(last_user_message(&cells), Some("second".to_string()));
+        assert_eq!(last_user_message(&cells[2..]), None);
+    }
 }
@@ -9,6 +9,7 @@ use codex_protocol::openai_models::ModelPreset;
 
 use crate::bottom_pane::ApprovalRequest;
 use crate::history_cell::HistoryCell;
+use crate::tui::scrolling::ScrollTuning;
 
 use codex_core::protocol::AskForApproval;
 use codex_core::protocol::SandboxPolicy;
@@ -19,12 +20,26 @@ use codex_protocol::openai_models::ReasoningEffort;
 pub(crate) enum AppEvent {
     CodexEvent(Event),
 
-    /// Start a new session.
-    NewSession,
+    /// Start a new session, seeding its composer with `draft` when the user chose to keep it.
+    NewSession {
+        draft: Option<String>,
+    },
 
     /// Open the resume picker inside the running TUI session.
     OpenResumePicker,
 
+    /// Copy the `codex resume <id>` command for the current conversation to the clipboard.
+    CopyResumeCommand,
+
+    /// Copy the current conversation id to the clipboard (`/copy-id`).
+    CopyConversationId,
+
+    /// Resend the last user message with reasoning effort bumped one level (`/retry`).
+    RetryWithHigherEffort,
+
+    /// Persist a custom title for the current conversation (`/title`).
+    SetConversationTitle(String),
+
     /// Request to exit the application gracefully.
     ExitRequest,
 
@@ -51,6 +66,32 @@ pub(crate) enum AppEvent {
     /// Result of computing a `/diff` command.
     DiffResult(String),
 
+    /// Send a short message the user confirmed (`tui.confirm_short_messages`).
+    SubmitConfirmedMessage(String),
+
+    /// Undo the most recent applied patch, confirmed from `/revert-patch`.
+    RevertLastPatch,
+
+    /// Open the `/scroll` popup, optionally with the item at `selected` highlighted.
+    OpenScrollTuning {
+        selected: Option<usize>,
+    },
+
+    /// Apply a `/scroll` adjustment to the live scroll config, then reopen the popup on `selected`.
+    TuneScroll {
+        tuning: ScrollTuning,
+        selected: usize,
+    },
+
+    /// Save the live scroll settings to `config.toml`.
+    PersistScrollTuning,
+
+    /// Render the skill injections for the current cwd (`/skills-preview`).
+    PreviewSkillInjections,
+
+    /// Rendered skill injections for `/skills-preview`.
+    SkillInjectionPreview(String),
+
     InsertHistoryCell(Box<dyn HistoryCell>),
 
     StartCommitAnimation,
@@ -69,6 +110,9 @@ pub(crate) enum AppEvent {
         effort: Option<ReasoningEffort>,
     },
 
+    /// Open the model selection popup (e.g. from `/settings`).
+    OpenModelPopup,
+
     /// Open the reasoning selection popup after picking a model.
     OpenReasoningPopup {
         model: ModelPreset,
@@ -127,6 +171,12 @@ pub(crate) enum AppEvent {
     /// Update whether the rate limit switch prompt has been acknowledged for the session.
     UpdateRateLimitSwitchPromptHidden(bool),
 
+    /// Update whether the context-nearly-full compaction prompt is hidden.
+    UpdateCompactPromptHidden(bool),
+
+    /// Stop offering to compact the conversation for the rest of the session.
+    SnoozeCompactPrompt,
+
     /// Persist the acknowledgement flag for the full access warning prompt.
     PersistFullAccessWarningAcknowledged,
 
@@ -137,12 +187,20 @@ pub(crate) enum AppEvent {
     /// Persist the acknowledgement flag for the rate limit switch prompt.
     PersistRateLimitSwitchPromptHidden,
 
+    /// Persist the acknowledgement flag for the compaction prompt.
+    PersistCompactPromptHidden,
+
     /// Persist the acknowledgement flag for the model migration prompt.
     PersistModelMigrationPromptAcknowledged {
         from_model: String,
         to_model: String,
     },
 
+    /// Persist that the `tui.model_switch_notes` note for `model` was shown.
+    PersistModelSwitchNoteSeen {
+        model: String,
+    },
+
     /// Skip the next world-writable scan (one-shot) after a user-confirmed continue.
     #[cfg_attr(not(target_os = "windows"), allow(dead_code))]
     SkipNextWorldWritableScan,
@@ -150,6 +208,36 @@ pub(crate) enum AppEvent {
     /// Re-open the approval presets popup.
     OpenApprovalsPopup,
 
+    /// Open the list of approval requests answered in this session.
+    OpenApprovalHistory,
+
+    /// Open the list of errors in the transcript (`/errors`).
+    OpenErrorList,
+
+    /// Ask which language to tag a composer code fence with (Alt+`).
+    OpenCodeFencePopup,
+
+    /// Wrap the composer's current line in a code fence tagged with `language` (may be empty).
+    FenceComposerLine {
+        language: String,
+    },
+
+    /// Open the list of prompts sent in this session (`/history`).
+    OpenPromptHistory,
+
+    /// A prompt was picked from `/history`; ask whether to edit or resend it.
+    PromptHistorySelected(String),
+
+    /// Reuse a prompt from `/history`: resend it when `send` is set, otherwise put it in the
+    /// composer.
+    ReusePrompt {
+        text: String,
+        send: bool,
+    },
+
+    /// Scroll the inline transcript so the viewport cell at this index is at the top.
+    ScrollTranscriptToCell(usize),
+
     /// Forwarded conversation history snapshot from the current conversation.
     ConversationHistory(ConversationPathResponseEvent),
 
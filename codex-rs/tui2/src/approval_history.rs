@@ -0,0 +1,186 @@
+//! Session approval history (`/approval-history`).
+//!
+//! Every answered approval request (exec commands, patches, and MCP elicitations) leaves an
+//! [`ApprovalDecisionCell`] in the transcript. This module reconstructs the list of those
+//! decisions from the transcript itself, so it needs no separate bookkeeping and naturally
+//! follows backtracking, which trims transcript cells.
+//!
+//! The list is built from the *viewport* view of the transcript (after collapsing), so each
+//! entry's `cell_index` can be used directly as a scroll anchor. Decisions folded into the
+//! collapsed summary cell are not listed.
+
+use std::sync::Arc;
+
+use crate::history_cell::ApprovalDecisionCell;
+use crate::history_cell::HistoryCell;
+
+/// What kind of request the user answered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ApprovalKind {
+    Exec,
+    Patch,
+    McpElicitation,
+}
+
+impl ApprovalKind {
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            ApprovalKind::Exec => "command",
+            ApprovalKind::Patch => "edit",
+            ApprovalKind::McpElicitation => "MCP request",
+        }
+    }
+}
+
+/// How the user answered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ApprovalOutcome {
+    Approved,
+    ApprovedForSession,
+    ApprovedWithPolicyAmendment,
+    Denied,
+    Canceled,
+}
+
+impl ApprovalOutcome {
+    pub(crate) fn is_approved(self) -> bool {
+        matches!(
+            self,
+            ApprovalOutcome::Approved
+                | ApprovalOutcome::ApprovedForSession
+                | ApprovalOutcome::ApprovedWithPolicyAmendment
+        )
+    }
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            ApprovalOutcome::Approved => "approved",
+            ApprovalOutcome::ApprovedForSession => "approved for session",
+            ApprovalOutcome::ApprovedWithPolicyAmendment => "approved, policy amended",
+            ApprovalOutcome::Denied => "denied",
+            ApprovalOutcome::Canceled => "canceled",
+        }
+    }
+}
+
+/// A single answered approval request, as recorded on its transcript cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ApprovalRecord {
+    pub(crate) kind: ApprovalKind,
+    /// Short description of what was requested (command snippet, edited files, or MCP server).
+    pub(crate) subject: String,
+    pub(crate) outcome: ApprovalOutcome,
+}
+
+/// An approval decision and where it sits in the transcript viewport.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ApprovalHistoryEntry {
+    /// Index of the decision cell in the viewport cell list (usable as a scroll anchor).
+    pub(crate) cell_index: usize,
+    pub(crate) record: ApprovalRecord,
+}
+
+/// Collect every approval decision in `cells`, oldest first.
+pub(crate) fn approval_history(cells: &[Arc<dyn HistoryCell>]) -> Vec<ApprovalHistoryEntry> {
+    cells
+        .iter()
+        .enumerate()
+        .filter_map(|(cell_index, cell)| {
+            let decision = cell.as_any().downcast_ref::<ApprovalDecisionCell>()?;
+            Some(ApprovalHistoryEntry {
+                cell_index,
+                record: decision.record().clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history_cell::PlainHistoryCell;
+    use crate::history_cell::new_approval_decision_cell;
+    use crate::history_cell::new_elicitation_decision_cell;
+    use crate::history_cell::new_patch_decision_cell;
+    use codex_core::protocol::ElicitationAction;
+    use codex_core::protocol::ReviewDecision;
+    use pretty_assertions::assert_eq;
+    use ratatui::text::Line;
+
+    #[test]
+    fn builds_history_from_transcript_with_mixed_outcomes() {
+        let cells: Vec<Arc<dyn HistoryCell>> = vec![
+            Arc::new(PlainHistoryCell::new(vec![Line::from("• hello")])),
+            new_approval_decision_cell(
+                vec!["echo".to_string(), "hi".to_string()],
+                ReviewDecision::Approved,
+            )
+            .into(),
+            new_patch_decision_cell("src/lib.rs".to_string(), ReviewDecision::Abort).into(),
+            Arc::new(PlainHistoryCell::new(vec![Line::from("• working")])),
+            new_approval_decision_cell(
+                vec!["rm".to_string(), "-rf".to_string(), "target".to_string()],
+                ReviewDecision::Denied,
+            )
+            .into(),
+            new_elicitation_decision_cell("docs".to_string(), ElicitationAction::Accept).into(),
+            new_approval_decision_cell(vec!["ls".to_string()], ReviewDecision::ApprovedForSession)
+                .into(),
+        ];
+
+        let history = approval_history(&cells);
+        let summary: Vec<(usize, ApprovalKind, &str, ApprovalOutcome)> = history
+            .iter()
+            .map(|entry| {
+                (
+                    entry.cell_index,
+                    entry.record.kind,
+                    entry.record.subject.as_str(),
+                    entry.record.outcome,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, ApprovalKind::Exec, "echo hi", ApprovalOutcome::Approved),
+                (
+                    2,
+                    ApprovalKind::Patch,
+                    "src/lib.rs",
+                    ApprovalOutcome::Canceled
+                ),
+                (
+                    4,
+                    ApprovalKind::Exec,
+                    "rm -rf target",
+                    ApprovalOutcome::Denied
+                ),
+                (
+                    5,
+                    ApprovalKind::McpElicitation,
+                    "docs",
+                    ApprovalOutcome::Approved
+                ),
+                (
+                    6,
+                    ApprovalKind::Exec,
+                    "ls",
+                    ApprovalOutcome::ApprovedForSession
+                ),
+            ]
+        );
+        let approved = history
+            .iter()
+            .filter(|entry| entry.record.outcome.is_approved())
+            .count();
+        assert_eq!(approved, 3);
+    }
+
+    #[test]
+    fn transcript_without_decisions_has_empty_history() {
+        let cells: Vec<Arc<dyn HistoryCell>> =
+            vec![Arc::new(PlainHistoryCell::new(vec![Line::from("• hi")]))];
+        assert_eq!(approval_history(&cells), Vec::new());
+    }
+}
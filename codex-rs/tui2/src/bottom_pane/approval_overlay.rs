@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::path::Path;
 use std::path::PathBuf;
 
 use crate::app_event::AppEvent;
@@ -8,7 +9,8 @@ use crate::bottom_pane::CancellationEvent;
 use crate::bottom_pane::list_selection_view::ListSelectionView;
 use crate::bottom_pane::list_selection_view::SelectionItem;
 use crate::bottom_pane::list_selection_view::SelectionViewParams;
-use crate::diff_render::DiffSummary;
+use crate::diff_render::PatchPreview;
+use crate::diff_render::display_path_for;
 use crate::exec_command::strip_bash_lc_and_escape;
 use crate::history_cell;
 use crate::key_hint;
@@ -16,6 +18,7 @@ use crate::key_hint::KeyBinding;
 use crate::render::highlight::highlight_bash_to_lines;
 use crate::render::renderable::ColumnRenderable;
 use crate::render::renderable::Renderable;
+use crate::theme::Theme;
 use codex_core::features::Feature;
 use codex_core::features::Features;
 use codex_core::protocol::ElicitationAction;
@@ -69,6 +72,7 @@ pub(crate) struct ApprovalOverlay {
     current_complete: bool,
     done: bool,
     features: Features,
+    theme: Theme,
 }
 
 impl ApprovalOverlay {
@@ -83,11 +87,17 @@ impl ApprovalOverlay {
             current_complete: false,
             done: false,
             features,
+            theme: Theme::default(),
         };
         view.set_current(request);
         view
     }
 
+    pub(crate) fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+        self.list.set_theme(theme);
+    }
+
     pub fn enqueue_request(&mut self, req: ApprovalRequest) {
         self.queue.push(req);
     }
@@ -100,6 +110,7 @@ impl ApprovalOverlay {
         let (options, params) = Self::build_options(variant, header, &self.features);
         self.options = options;
         self.list = ListSelectionView::new(params, self.app_event_tx.clone());
+        self.list.set_theme(self.theme);
     }
 
     fn build_options(
@@ -107,29 +118,15 @@ impl ApprovalOverlay {
         header: Box<dyn Renderable>,
         features: &Features,
     ) -> (Vec<ApprovalOption>, SelectionViewParams) {
-        let (options, title) = match &variant {
+        let options = match &variant {
             ApprovalVariant::Exec {
                 proposed_execpolicy_amendment,
                 ..
-            } => (
-                exec_options(proposed_execpolicy_amendment.clone(), features),
-                "Would you like to run the following command?".to_string(),
-            ),
-            ApprovalVariant::ApplyPatch { .. } => (
-                patch_options(),
-                "Would you like to make the following edits?".to_string(),
-            ),
-            ApprovalVariant::McpElicitation { server_name, .. } => (
-                elicitation_options(),
-                format!("{server_name} needs your approval."),
-            ),
+            } => exec_options(proposed_execpolicy_amendment.clone(), features),
+            ApprovalVariant::ApplyPatch { .. } => patch_options(),
+            ApprovalVariant::McpElicitation { .. } => elicitation_options(),
         };
-
-        let header = Box::new(ColumnRenderable::with([
-            Line::from(title.bold()).into(),
-            Line::from("").into(),
-            header,
-        ]));
+        let header = titled_header(&variant, header);
 
         let items = options
             .iter()
@@ -171,8 +168,11 @@ impl ApprovalOverlay {
                 (ApprovalVariant::Exec { id, command, .. }, ApprovalDecision::Review(decision)) => {
                     self.handle_exec_decision(id, command, decision.clone());
                 }
-                (ApprovalVariant::ApplyPatch { id, .. }, ApprovalDecision::Review(decision)) => {
-                    self.handle_patch_decision(id, decision.clone());
+                (
+                    ApprovalVariant::ApplyPatch { id, files, .. },
+                    ApprovalDecision::Review(decision),
+                ) => {
+                    self.handle_patch_decision(id, files, decision.clone());
                 }
                 (
                     ApprovalVariant::McpElicitation {
@@ -200,7 +200,9 @@ impl ApprovalOverlay {
         }));
     }
 
-    fn handle_patch_decision(&self, id: &str, decision: ReviewDecision) {
+    fn handle_patch_decision(&self, id: &str, files: &str, decision: ReviewDecision) {
+        let cell = history_cell::new_patch_decision_cell(files.to_string(), decision.clone());
+        self.app_event_tx.send(AppEvent::InsertHistoryCell(cell));
         self.app_event_tx.send(AppEvent::CodexOp(Op::PatchApproval {
             id: id.to_string(),
             decision,
@@ -213,6 +215,8 @@ impl ApprovalOverlay {
         request_id: &RequestId,
         decision: ElicitationAction,
     ) {
+        let cell = history_cell::new_elicitation_decision_cell(server_name.to_string(), decision);
+        self.app_event_tx.send(AppEvent::InsertHistoryCell(cell));
         self.app_event_tx
             .send(AppEvent::CodexOp(Op::ResolveElicitation {
                 server_name: server_name.to_string(),
@@ -229,6 +233,36 @@ impl ApprovalOverlay {
         }
     }
 
+    /// Space expands/collapses the focused file of a patch preview; Tab/Shift+Tab move the
+    /// focus between files.
+    fn try_handle_patch_preview_key(&mut self, key_event: &KeyEvent) -> bool {
+        if key_event.kind != KeyEventKind::Press {
+            return false;
+        }
+        let Some(ApprovalVariant::ApplyPatch {
+            reason, preview, ..
+        }) = self.current_variant.as_mut()
+        else {
+            return false;
+        };
+        let changed = match key_event.code {
+            KeyCode::Char(' ') => {
+                preview.toggle_focused();
+                true
+            }
+            KeyCode::Tab => preview.focus_next(),
+            KeyCode::BackTab => preview.focus_prev(),
+            _ => return false,
+        };
+        if changed {
+            let header = patch_header(reason.as_deref(), preview);
+            if let Some(variant) = self.current_variant.as_ref() {
+                self.list.set_header(titled_header(variant, header));
+            }
+        }
+        true
+    }
+
     fn try_handle_shortcut(&mut self, key_event: &KeyEvent) -> bool {
         match key_event {
             KeyEvent {
@@ -263,7 +297,7 @@ impl ApprovalOverlay {
 
 impl BottomPaneView for ApprovalOverlay {
     fn handle_key_event(&mut self, key_event: KeyEvent) {
-        if self.try_handle_shortcut(&key_event) {
+        if self.try_handle_patch_preview_key(&key_event) || self.try_handle_shortcut(&key_event) {
             return;
         }
         self.list.handle_key_event(key_event);
@@ -283,8 +317,8 @@ impl BottomPaneView for ApprovalOverlay {
                 ApprovalVariant::Exec { id, command, .. } => {
                     self.handle_exec_decision(id, command, ReviewDecision::Abort);
                 }
-                ApprovalVariant::ApplyPatch { id, .. } => {
-                    self.handle_patch_decision(id, ReviewDecision::Abort);
+                ApprovalVariant::ApplyPatch { id, files, .. } => {
+                    self.handle_patch_decision(id, files, ReviewDecision::Abort);
                 }
                 ApprovalVariant::McpElicitation {
                     server_name,
@@ -370,20 +404,17 @@ impl From<ApprovalRequest> for ApprovalRequestState {
                 cwd,
                 changes,
             } => {
-                let mut header: Vec<Box<dyn Renderable>> = Vec::new();
-                if let Some(reason) = reason
-                    && !reason.is_empty()
-                {
-                    header.push(Box::new(
-                        Paragraph::new(Line::from_iter(["Reason: ".into(), reason.italic()]))
-                            .wrap(Wrap { trim: false }),
-                    ));
-                    header.push(Box::new(Line::from("")));
-                }
-                header.push(DiffSummary::new(changes, cwd).into());
+                let files = patch_files_label(&changes, &cwd);
+                let preview = PatchPreview::new(&changes, cwd);
+                let header = patch_header(reason.as_deref(), &preview);
                 Self {
-                    variant: ApprovalVariant::ApplyPatch { id },
-                    header: Box::new(ColumnRenderable::with(header)),
+                    variant: ApprovalVariant::ApplyPatch {
+                        id,
+                        files,
+                        reason,
+                        preview,
+                    },
+                    header,
                 }
             }
             ApprovalRequest::McpElicitation {
@@ -418,6 +449,11 @@ enum ApprovalVariant {
     },
     ApplyPatch {
         id: String,
+        /// Short description of the edited files, recorded in the decision history cell.
+        files: String,
+        reason: Option<String>,
+        /// Collapsible per-file preview; toggling a file rebuilds the header from it.
+        preview: PatchPreview,
     },
     McpElicitation {
         server_name: String,
@@ -425,6 +461,51 @@ enum ApprovalVariant {
     },
 }
 
+/// The request header with its title line on top.
+fn titled_header(variant: &ApprovalVariant, header: Box<dyn Renderable>) -> Box<dyn Renderable> {
+    let title = match variant {
+        ApprovalVariant::Exec { .. } => "Would you like to run the following command?".to_string(),
+        ApprovalVariant::ApplyPatch { .. } => {
+            "Would you like to make the following edits?".to_string()
+        }
+        ApprovalVariant::McpElicitation { server_name, .. } => {
+            format!("{server_name} needs your approval.")
+        }
+    };
+    Box::new(ColumnRenderable::with([
+        Line::from(title.bold()).into(),
+        Line::from("").into(),
+        header,
+    ]))
+}
+
+/// Optional reason followed by the collapsible patch preview.
+fn patch_header(reason: Option<&str>, preview: &PatchPreview) -> Box<dyn Renderable> {
+    let mut header: Vec<Box<dyn Renderable>> = Vec::new();
+    if let Some(reason) = reason
+        && !reason.is_empty()
+    {
+        header.push(Box::new(
+            Paragraph::new(Line::from_iter([
+                "Reason: ".into(),
+                reason.to_string().italic(),
+            ]))
+            .wrap(Wrap { trim: false }),
+        ));
+        header.push(Box::new(Line::from("")));
+    }
+    header.push(preview.renderable());
+    Box::new(ColumnRenderable::with(header))
+}
+
+/// The single edited path (relative to `cwd` when possible), or a file count.
+fn patch_files_label(changes: &HashMap<PathBuf, FileChange>, cwd: &Path) -> String {
+    match changes.keys().next() {
+        Some(path) if changes.len() == 1 => display_path_for(path, cwd),
+        _ => format!("{} files", changes.len()),
+    }
+}
+
 #[derive(Clone)]
 enum ApprovalDecision {
     Review(ReviewDecision),
@@ -666,6 +747,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn space_expands_patch_preview_without_answering() {
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let request = ApprovalRequest::ApplyPatch {
+            id: "patch".into(),
+            reason: None,
+            cwd: PathBuf::from("/repo"),
+            changes: HashMap::from([(
+                PathBuf::from("/repo/notes.txt"),
+                FileChange::Add {
+                    content: "hidden until expanded\n".into(),
+                },
+            )]),
+        };
+        let mut view = ApprovalOverlay::new(request, tx, Features::with_defaults());
+        let render_text = |view: &ApprovalOverlay| {
+            let area = Rect::new(0, 0, 80, view.desired_height(80));
+            let mut buf = Buffer::empty(area);
+            view.render(area, &mut buf);
+            (0..area.height)
+                .map(|row| {
+                    (0..area.width)
+                        .map(|col| buf[(col, row)].symbol().to_string())
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        let collapsed = render_text(&view);
+        assert!(collapsed.contains("1 file changed (+1 -0)"), "{collapsed}");
+        assert!(!collapsed.contains("hidden until expanded"), "{collapsed}");
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE));
+        let expanded = render_text(&view);
+        assert!(expanded.contains("hidden until expanded"), "{expanded}");
+        assert!(!view.is_complete());
+        assert!(
+            rx.try_recv().is_err(),
+            "toggling must not answer the request"
+        );
+    }
+
     #[test]
     fn exec_history_cell_wraps_with_two_space_indent() {
         let command = vec![
@@ -45,6 +45,7 @@ use crate::render::renderable::Renderable;
 use crate::slash_command::SlashCommand;
 use crate::slash_command::built_in_slash_commands;
 use crate::style::user_message_style;
+use crate::theme::Theme;
 use codex_common::fuzzy_match::fuzzy_match;
 use codex_protocol::custom_prompts::CustomPrompt;
 use codex_protocol::custom_prompts::PROMPTS_CMD_PREFIX;
@@ -55,7 +56,9 @@ use crate::bottom_pane::textarea::TextArea;
 use crate::bottom_pane::textarea::TextAreaState;
 use crate::clipboard_paste::normalize_pasted_path;
 use crate::clipboard_paste::pasted_image_format;
+use crate::file_mentions::split_range_suffix;
 use crate::history_cell;
+use crate::text_formatting::truncate_to_width;
 use crate::ui_consts::LIVE_PREFIX_COLS;
 use codex_core::skills::model::SkillMetadata;
 use codex_file_search::FileMatch;
@@ -66,10 +69,14 @@ use std::path::PathBuf;
 use std::time::Duration;
 use std::time::Instant;
 
-/// If the pasted content exceeds this number of characters, replace it with a
-/// placeholder in the UI.
+/// Default number of characters above which pasted content is replaced with a
+/// placeholder in the UI (`tui.paste_placeholder_threshold`).
 const LARGE_PASTE_CHAR_THRESHOLD: usize = 1000;
 
+/// Default number of draft lines at which the footer suggests sending the draft or attaching it
+/// as a file (`tui.composer_line_warning`).
+const DRAFT_LINE_WARNING_THRESHOLD: usize = 40;
+
 /// Result returned when the user interacts with the text area.
 #[derive(Debug, PartialEq)]
 pub enum InputResult {
@@ -107,6 +114,8 @@ pub(crate) struct ChatComposer {
     current_file_query: Option<String>,
     pending_pastes: Vec<(String, String)>,
     large_paste_counters: HashMap<usize, usize>,
+    large_paste_threshold: usize,
+    line_warning_threshold: usize,
     has_focus: bool,
     attached_images: Vec<AttachedImage>,
     placeholder_text: String,
@@ -115,6 +124,8 @@ pub(crate) struct ChatComposer {
     paste_burst: PasteBurst,
     // When true, disables paste-burst logic and inserts characters immediately.
     disable_paste_burst: bool,
+    // Whether the footer shows the "pasting…" cue; synced from the paste-burst tick.
+    paste_burst_indicator: bool,
     custom_prompts: Vec<CustomPrompt>,
     footer_mode: FooterMode,
     footer_hint_override: Option<Vec<(String, String)>>,
@@ -125,7 +136,22 @@ pub(crate) struct ChatComposer {
     transcript_scroll_position: Option<(usize, usize)>,
     transcript_copy_selection_key: KeyBinding,
     skills: Option<Vec<SkillMetadata>>,
+    /// Skills are being discovered in the background; `$` shows a placeholder popup until
+    /// they arrive.
+    skills_loading: bool,
     dismissed_skill_popup_token: Option<String>,
+    // When true, file search selections are inserted as `@path` mentions so they
+    // can be expanded into file contents on submit.
+    expand_file_mentions: bool,
+    /// File-mention elements (`@path`, as inserted) of the last submission; drained by
+    /// [`Self::take_recent_submission_file_mentions`].
+    submitted_file_mentions: Vec<String>,
+    /// Glyph drawn in the left gutter on the first text row (`tui.composer_prompt`).
+    prompt_glyph: String,
+    /// Model/effort label drawn on the padding row above the text (`tui.composer_prompt_model`).
+    prompt_context: Option<String>,
+    /// Resolved `tui.theme`; tints the composer background and error cells.
+    theme: Theme,
 }
 
 /// Popup state – at most one can be visible at any time.
@@ -137,6 +163,7 @@ enum ActivePopup {
 }
 
 const FOOTER_SPACING_HEIGHT: u16 = 0;
+const DEFAULT_PROMPT_GLYPH: &str = "›";
 
 impl ChatComposer {
     pub fn new(
@@ -161,12 +188,15 @@ impl ChatComposer {
             current_file_query: None,
             pending_pastes: Vec::new(),
             large_paste_counters: HashMap::new(),
+            large_paste_threshold: LARGE_PASTE_CHAR_THRESHOLD,
+            line_warning_threshold: DRAFT_LINE_WARNING_THRESHOLD,
             has_focus: has_input_focus,
             attached_images: Vec::new(),
             placeholder_text,
             is_task_running: false,
             paste_burst: PasteBurst::default(),
             disable_paste_burst: false,
+            paste_burst_indicator: false,
             custom_prompts: Vec::new(),
             footer_mode: FooterMode::ShortcutSummary,
             footer_hint_override: None,
@@ -177,7 +207,13 @@ impl ChatComposer {
             transcript_scroll_position: None,
             transcript_copy_selection_key: key_hint::ctrl_shift(KeyCode::Char('c')),
             skills: None,
+            skills_loading: false,
             dismissed_skill_popup_token: None,
+            expand_file_mentions: false,
+            submitted_file_mentions: Vec::new(),
+            prompt_glyph: DEFAULT_PROMPT_GLYPH.to_string(),
+            prompt_context: None,
+            theme: Theme::default(),
         };
         // Apply configuration via the setter to keep side-effects centralized.
         this.set_disable_paste_burst(disable_paste_burst);
@@ -185,7 +221,77 @@ impl ChatComposer {
     }
 
     pub fn set_skill_mentions(&mut self, skills: Option<Vec<SkillMetadata>>) {
+        if skills.is_some() {
+            self.skills_loading = false;
+        }
         self.skills = skills;
+        // Swap the placeholder for the real list if the popup is already open.
+        if let ActivePopup::Skill(popup) = &mut self.active_popup {
+            popup.set_skills(self.skills.clone().unwrap_or_default());
+            popup.set_loading(self.skills_loading);
+        }
+    }
+
+    pub(crate) fn set_skills_loading(&mut self, loading: bool) {
+        self.skills_loading = loading;
+    }
+
+    /// Use `glyph` as the gutter prompt, clipped to the gutter's single column so the text
+    /// layout never shifts; `None` (or a glyph too wide to fit) restores `›`.
+    pub(crate) fn set_prompt_glyph(&mut self, glyph: Option<&str>) {
+        let glyph = glyph
+            .map(|glyph| truncate_to_width(glyph.trim(), usize::from(LIVE_PREFIX_COLS - 1)).0)
+            .filter(|glyph| !glyph.is_empty());
+        self.prompt_glyph = glyph.unwrap_or_else(|| DEFAULT_PROMPT_GLYPH.to_string());
+    }
+
+    /// Show `context` (e.g. the active model and effort) on the padding row above the text.
+    pub(crate) fn set_prompt_context(&mut self, context: Option<String>) {
+        self.prompt_context = context;
+    }
+
+    /// Wrap the logical line under the cursor in a fenced code block tagged with `language`.
+    pub(crate) fn fence_current_line(&mut self, language: &str) {
+        self.textarea.fence_current_line(language);
+    }
+
+    #[cfg(test)]
+    pub(crate) fn prompt_context(&self) -> Option<&str> {
+        self.prompt_context.as_deref()
+    }
+
+    pub(crate) fn set_tab_behavior(&mut self, tab_width: usize, literal_tab: bool) {
+        self.textarea.set_tab_behavior(tab_width, literal_tab);
+    }
+
+    pub(crate) fn set_show_whitespace(&mut self, show_whitespace: bool) {
+        self.textarea.set_show_whitespace(show_whitespace);
+    }
+
+    /// Collapse pastes longer than `threshold` characters into a placeholder element;
+    /// `None` restores the default.
+    pub(crate) fn set_large_paste_threshold(&mut self, threshold: Option<usize>) {
+        self.large_paste_threshold = threshold.unwrap_or(LARGE_PASTE_CHAR_THRESHOLD);
+    }
+
+    /// Warn in the footer once the draft has at least `threshold` lines; `None` restores the
+    /// default and `Some(0)` disables the warning.
+    pub(crate) fn set_line_warning_threshold(&mut self, threshold: Option<usize>) {
+        self.line_warning_threshold = threshold.unwrap_or(DRAFT_LINE_WARNING_THRESHOLD);
+    }
+
+    /// The draft's logical line count when it is tall enough to warrant the footer warning.
+    fn long_draft_line_count(&self) -> Option<usize> {
+        let line_count = self.textarea.text().split('\n').count();
+        is_long_draft(line_count, self.line_warning_threshold).then_some(line_count)
+    }
+
+    pub(crate) fn set_expand_file_mentions(&mut self, enabled: bool) {
+        self.expand_file_mentions = enabled;
+    }
+
+    pub(crate) fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
     }
 
     fn layout_areas(&self, area: Rect) -> [Rect; 3] {
@@ -224,6 +330,25 @@ impl ChatComposer {
         self.textarea.is_empty()
     }
 
+    /// Draw just the prompt marker and placeholder on the first row of `area`, used while the
+    /// composer is collapsed (`tui.compact_composer`).
+    pub(crate) fn render_collapsed(&self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        let row = Rect { height: 1, ..area };
+        Block::default()
+            .style(user_message_style(&self.theme))
+            .render_ref(row, buf);
+        Line::from(vec![
+            self.prompt_glyph.as_str().bold(),
+            " ".repeat(usize::from(LIVE_PREFIX_COLS.saturating_sub(1)))
+                .into(),
+            Span::from(self.placeholder_text.as_str()).dim(),
+        ])
+        .render_ref(row, buf);
+    }
+
     /// Record the history metadata advertised by `SessionConfiguredEvent` so
     /// that the composer can navigate cross-session history.
     pub(crate) fn set_history_metadata(&mut self, log_id: u64, entry_count: usize) {
@@ -248,7 +373,7 @@ impl ChatComposer {
 
     pub fn handle_paste(&mut self, pasted: String) -> bool {
         let char_count = pasted.chars().count();
-        if char_count > LARGE_PASTE_CHAR_THRESHOLD {
+        if is_large_paste(char_count, self.large_paste_threshold) {
             let placeholder = self.next_large_paste_placeholder(char_count);
             self.textarea.insert_element(&placeholder);
             self.pending_pastes.push((placeholder, pasted));
@@ -325,6 +450,12 @@ impl ChatComposer {
         self.textarea.text().to_string()
     }
 
+    /// Byte offset of the cursor within [`Self::current_text`].
+    #[cfg(test)]
+    pub(crate) fn cursor(&self) -> usize {
+        self.textarea.cursor()
+    }
+
     /// Attempt to start a burst by retro-capturing recent chars before the cursor.
     pub fn attach_image(&mut self, path: PathBuf, width: u32, height: u32, _format_label: &str) {
         let file_label = path
@@ -344,6 +475,13 @@ impl ChatComposer {
         images.into_iter().map(|img| img.path).collect()
     }
 
+    /// The `@path` mentions inserted from the file search popup into the last submission.
+    ///
+    /// Only these are expanded into attachments; `@name` typed by hand stays plain text.
+    pub(crate) fn take_recent_submission_file_mentions(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.submitted_file_mentions)
+    }
+
     pub(crate) fn flush_paste_burst_if_due(&mut self) -> bool {
         self.handle_paste_burst_flush(Instant::now())
     }
@@ -352,6 +490,16 @@ impl ChatComposer {
         self.paste_burst.is_active()
     }
 
+    /// Show the "pasting…" footer cue while a burst is buffered and hide it once it settles.
+    ///
+    /// Returns whether the cue changed and a redraw is needed.
+    pub(crate) fn update_paste_burst_indicator(&mut self) -> bool {
+        let buffering = self.paste_burst.is_buffering();
+        let changed = buffering != self.paste_burst_indicator;
+        self.paste_burst_indicator = buffering;
+        changed
+    }
+
     pub(crate) fn recommended_paste_flush_delay() -> Duration {
         PasteBurst::recommended_flush_delay()
     }
@@ -399,6 +547,16 @@ impl ChatComposer {
         self.sync_popups();
     }
 
+    /// Track the mouse pointer for popup hover affordances (skill description tooltips).
+    ///
+    /// `(x, y)` are screen coordinates. Returns whether a redraw is needed.
+    pub(crate) fn handle_mouse_moved(&mut self, x: u16, y: u16) -> bool {
+        match &mut self.active_popup {
+            ActivePopup::Skill(popup) => popup.on_mouse_moved(x, y),
+            _ => false,
+        }
+    }
+
     /// Handle a key event coming from the main UI.
     pub fn handle_key_event(&mut self, key_event: KeyEvent) -> (InputResult, bool) {
         let result = match &mut self.active_popup {
@@ -539,7 +697,7 @@ impl ChatComposer {
                 if let Some(sel) = popup.selected_item() {
                     match sel {
                         CommandItem::Builtin(cmd) => {
-                            self.textarea.set_text("");
+                            self.clear_command_line();
                             return (InputResult::Command(cmd), true);
                         }
                         CommandItem::UserPrompt(idx) => {
@@ -572,6 +730,19 @@ impl ChatComposer {
         }
     }
 
+    /// Remove the slash-command line that is being dispatched, keeping any lines below it so a
+    /// draft written under the command survives (and can be carried into `/new`).
+    fn clear_command_line(&mut self) {
+        let rest = self
+            .textarea
+            .text()
+            .split_once('\n')
+            .map(|(_, rest)| rest.to_string())
+            .unwrap_or_default();
+        self.textarea.set_text(&rest);
+        self.textarea.set_cursor(rest.len());
+    }
+
     #[inline]
     fn clamp_to_char_boundary(text: &str, pos: usize) -> usize {
         let mut p = pos.min(text.len());
@@ -778,6 +949,10 @@ impl ChatComposer {
             KeyEvent {
                 code: KeyCode::Esc, ..
             } => {
+                // A visible description tooltip is dismissed first; the next Esc closes the popup.
+                if popup.dismiss_tooltip() {
+                    return (InputResult::None, true);
+                }
                 if let Some(tok) = self.current_skill_token() {
                     self.dismissed_skill_popup_token = Some(tok);
                 }
@@ -809,7 +984,7 @@ impl ChatComposer {
     }
 
     fn skills_enabled(&self) -> bool {
-        self.skills.as_ref().is_some_and(|s| !s.is_empty())
+        self.skills_loading || self.skills.as_ref().is_some_and(|s| !s.is_empty())
     }
 
     pub fn skills(&self) -> Option<&Vec<SkillMetadata>> {
@@ -973,6 +1148,20 @@ impl ChatComposer {
         } else {
             path.to_string()
         };
+        if self.expand_file_mentions {
+            // Insert the mention, keeping a line range the user typed after the partial path
+            // (`@src/ma:10-20`), as one element: it is edited and deleted atomically, and on
+            // submit it is what marks the token as a file to attach.
+            let mention = match split_range_suffix(&text[start_idx..end_idx]).1 {
+                Some(suffix) => format!("@{inserted}:{suffix}"),
+                None => format!("@{inserted}"),
+            };
+            self.textarea.replace_range(start_idx..end_idx, "");
+            self.textarea.set_cursor(start_idx);
+            self.textarea.insert_element(&mention);
+            self.textarea.insert_str(" ");
+            return;
+        }
 
         // Replace the slice `[start_idx, end_idx)` with the chosen path and a trailing space.
         let mut new_text =
@@ -1048,6 +1237,15 @@ impl ChatComposer {
                 self.app_event_tx.send(AppEvent::ExitRequest);
                 (InputResult::None, true)
             }
+            KeyEvent {
+                code: KeyCode::Char('`'),
+                modifiers: KeyModifiers::ALT,
+                kind: KeyEventKind::Press,
+                ..
+            } => {
+                self.app_event_tx.send(AppEvent::OpenCodeFencePopup);
+                (InputResult::None, true)
+            }
             // -------------------------------------------------------------
             // History navigation (Up / Down) – only when the composer is not
             // empty or when the cursor is at the correct position, to avoid
@@ -1099,7 +1297,7 @@ impl ChatComposer {
                         .into_iter()
                         .find(|(n, _)| *n == name)
                 {
-                    self.textarea.set_text("");
+                    self.clear_command_line();
                     return (InputResult::Command(cmd), true);
                 }
                 // If we're in a paste-like burst capture, treat Enter as part of the burst
@@ -1146,6 +1344,12 @@ impl ChatComposer {
                 let mut text = self.textarea.text().to_string();
                 let original_input = text.clone();
                 let input_starts_with_space = original_input.starts_with(' ');
+                let file_mentions: Vec<String> = self
+                    .textarea
+                    .element_texts()
+                    .filter(|element| element.starts_with('@'))
+                    .map(str::to_string)
+                    .collect();
                 self.textarea.set_text("");
 
                 // Replace all pending pastes in the text
@@ -1192,7 +1396,7 @@ impl ChatComposer {
                     Ok(expanded) => expanded,
                     Err(err) => {
                         self.app_event_tx.send(AppEvent::InsertHistoryCell(Box::new(
-                            history_cell::new_error_event(err.user_message()),
+                            history_cell::new_error_event(err.user_message(), &self.theme),
                         )));
                         self.textarea.set_text(&original_input);
                         self.textarea.set_cursor(original_input.len());
@@ -1209,6 +1413,7 @@ impl ChatComposer {
                     self.history.record_local_submission(&text);
                 }
                 // Do not clear attached_images here; ChatWidget drains them via take_recent_submission_images().
+                self.submitted_file_mentions = file_mentions;
                 (InputResult::Submitted(text), true)
             }
             input => self.handle_input_basic(input),
@@ -1318,6 +1523,29 @@ impl ChatComposer {
             return (InputResult::None, true);
         }
 
+        // Tab indents and Shift+Tab dedents; an open completion popup owns Tab instead.
+        if matches!(
+            input,
+            KeyEvent {
+                code: KeyCode::Tab,
+                modifiers: KeyModifiers::NONE,
+                ..
+            } | KeyEvent {
+                code: KeyCode::BackTab,
+                ..
+            }
+        ) {
+            if let Some(pasted) = self.paste_burst.flush_before_modified_input() {
+                self.handle_paste(pasted);
+            }
+            self.paste_burst.clear_window_after_non_char();
+            let completion_open = !matches!(self.active_popup, ActivePopup::None);
+            let edited = self
+                .textarea
+                .handle_tab(input.code == KeyCode::BackTab, completion_open);
+            return (InputResult::None, edited);
+        }
+
         // Normal input handling
         self.textarea.input(input);
         let text_after = self.textarea.text();
@@ -1545,6 +1773,9 @@ impl ChatComposer {
             transcript_selection_active: self.transcript_selection_active,
             transcript_scroll_position: self.transcript_scroll_position,
             transcript_copy_selection_key: self.transcript_copy_selection_key,
+            paste_burst_active: self.paste_burst_indicator,
+            skills_loading: self.skills_loading,
+            long_draft_lines: self.long_draft_line_count(),
         }
     }
 
@@ -1613,6 +1844,12 @@ impl ChatComposer {
         self.dismissed_skill_popup_token = None;
 
         if let Some(token) = file_token {
+            // Search on the path only; a `:START-END` range is re-attached on selection.
+            let token = if self.expand_file_mentions {
+                split_range_suffix(&token).0.to_string()
+            } else {
+                token
+            };
             self.sync_file_search_popup(token);
             return;
         }
@@ -1776,6 +2013,7 @@ impl ChatComposer {
 
         let skills = match self.skills.as_ref() {
             Some(skills) if !skills.is_empty() => skills.clone(),
+            _ if self.skills_loading => Vec::new(),
             _ => {
                 self.active_popup = ActivePopup::None;
                 return;
@@ -1786,10 +2024,12 @@ impl ChatComposer {
             ActivePopup::Skill(popup) => {
                 popup.set_query(&query);
                 popup.set_skills(skills);
+                popup.set_loading(self.skills_loading);
             }
             _ => {
                 let mut popup = SkillPopup::new(skills);
                 popup.set_query(&query);
+                popup.set_loading(self.skills_loading);
                 self.active_popup = ActivePopup::Skill(popup);
             }
         }
@@ -1899,15 +2139,27 @@ impl Renderable for ChatComposer {
                 }
             }
         }
-        let style = user_message_style();
+        let style = user_message_style(&self.theme);
         Block::default().style(style).render_ref(composer_rect, buf);
         if !textarea_rect.is_empty() {
+            let gutter_x = textarea_rect.x - LIVE_PREFIX_COLS;
             buf.set_span(
-                textarea_rect.x - LIVE_PREFIX_COLS,
+                gutter_x,
                 textarea_rect.y,
-                &"›".bold(),
-                textarea_rect.width,
+                &self.prompt_glyph.as_str().bold(),
+                LIVE_PREFIX_COLS,
             );
+            // The label sits on the top padding row, so it never moves the text or cursor.
+            if let Some(context) = &self.prompt_context
+                && textarea_rect.y > composer_rect.y
+            {
+                buf.set_span(
+                    gutter_x,
+                    composer_rect.y,
+                    &context.as_str().dim(),
+                    textarea_rect.width + LIVE_PREFIX_COLS,
+                );
+            }
         }
 
         let mut state = self.textarea_state.borrow_mut();
@@ -1967,6 +2219,18 @@ fn prompt_selection_action(
     }
 }
 
+/// Whether a paste of `char_count` characters is stashed behind a placeholder instead of
+/// being inserted inline.
+fn is_large_paste(char_count: usize, threshold: usize) -> bool {
+    char_count > threshold
+}
+
+/// Whether a draft of `line_count` logical lines gets the footer warning; a zero threshold
+/// disables it.
+fn is_long_draft(line_count: usize, threshold: usize) -> bool {
+    threshold > 0 && line_count >= threshold
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1986,6 +2250,110 @@ mod tests {
     use crate::bottom_pane::textarea::TextArea;
     use tokio::sync::mpsc::unbounded_channel;
 
+    #[test]
+    fn esc_dismisses_skill_tooltip_before_closing_popup() {
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let mut composer =
+            ChatComposer::new(true, AppEventSender::new(tx), false, String::new(), false);
+        let skill = |name: &str, description: &str| SkillMetadata {
+            name: name.to_string(),
+            description: description.to_string(),
+            short_description: None,
+            path: PathBuf::from(format!("/skills/{name}/SKILL.md")),
+            scope: codex_protocol::protocol::SkillScope::User,
+            allowed_tools: Vec::new(),
+            priority: 0,
+            version: None,
+            license: None,
+            applies_to: Vec::new(),
+        };
+        composer.set_skill_mentions(Some(vec![
+            skill("short", "fits"),
+            skill(
+                "long",
+                "a very long description that cannot fit in a narrow popup row",
+            ),
+        ]));
+        composer.insert_str("$");
+        assert!(matches!(composer.active_popup, ActivePopup::Skill(_)));
+
+        let area = Rect::new(0, 0, 30, 12);
+        let mut buf = Buffer::empty(area);
+        composer.render(area, &mut buf);
+        let popup_height = composer.desired_height(area.width);
+        let hovered = (area.y..area.bottom()).any(|y| composer.handle_mouse_moved(4, y));
+        assert!(hovered, "a truncated row should show its tooltip");
+        assert!(composer.desired_height(area.width) > popup_height);
+
+        composer.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(matches!(composer.active_popup, ActivePopup::Skill(_)));
+        assert_eq!(composer.desired_height(area.width), popup_height);
+
+        composer.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(matches!(composer.active_popup, ActivePopup::None));
+    }
+
+    #[test]
+    fn alt_backtick_opens_code_fence_popup_and_fences_current_line() {
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let mut composer =
+            ChatComposer::new(true, AppEventSender::new(tx), false, String::new(), false);
+        composer.set_text_content("explain this:\nfn main() {}".to_string());
+
+        let (result, _) =
+            composer.handle_key_event(KeyEvent::new(KeyCode::Char('`'), KeyModifiers::ALT));
+        assert_eq!(result, InputResult::None);
+        assert!(matches!(rx.try_recv(), Ok(AppEvent::OpenCodeFencePopup)));
+        assert_eq!(composer.current_text(), "explain this:\nfn main() {}");
+
+        composer.textarea.set_cursor("explain this:\nfn".len());
+        composer.fence_current_line("rust");
+        assert_eq!(
+            composer.current_text(),
+            "explain this:\n```rust\nfn main() {}\n```"
+        );
+    }
+
+    #[test]
+    fn prompt_indicator_leaves_text_layout_unchanged() {
+        let render = |configure: &dyn Fn(&mut ChatComposer)| {
+            let (tx, _rx) = unbounded_channel::<AppEvent>();
+            let mut composer =
+                ChatComposer::new(true, AppEventSender::new(tx), false, String::new(), false);
+            composer.set_text_content("a draft long enough to wrap onto a second row".to_string());
+            configure(&mut composer);
+            let area = Rect::new(0, 0, 30, 8);
+            let mut buf = Buffer::empty(area);
+            composer.render(area, &mut buf);
+            let rows: Vec<String> = (0..area.height)
+                .map(|y| {
+                    (0..area.width)
+                        .map(|x| buf[(x, y)].symbol().to_string())
+                        .collect()
+                })
+                .collect();
+            (rows, composer.cursor_pos(area))
+        };
+
+        let (plain_rows, plain_cursor) = render(&|_| {});
+        let (rows, cursor) = render(&|composer| {
+            composer.set_prompt_glyph(Some(">>"));
+            composer.set_prompt_context(Some("gpt-5.2-codex · high".to_string()));
+        });
+
+        assert_eq!(cursor, plain_cursor);
+        assert!(rows[0].starts_with("gpt-5.2-codex · high"));
+        assert!(plain_rows[1].starts_with("› "));
+        assert!(rows[1].starts_with("> "));
+        let text = |rows: &[String]| -> Vec<String> {
+            rows[1..]
+                .iter()
+                .map(|row| row.chars().skip(usize::from(LIVE_PREFIX_COLS)).collect())
+                .collect()
+        };
+        assert_eq!(text(&rows), text(&plain_rows));
+    }
+
     #[test]
     fn footer_hint_row_is_separated_from_composer() {
         let (tx, _rx) = unbounded_channel::<AppEvent>();
@@ -2506,6 +2874,180 @@ mod tests {
         assert!(composer.pending_pastes.is_empty());
     }
 
+    #[test]
+    fn large_paste_decision_respects_threshold() {
+        assert!(!is_large_paste(1000, LARGE_PASTE_CHAR_THRESHOLD));
+        assert!(is_large_paste(1001, LARGE_PASTE_CHAR_THRESHOLD));
+        assert!(!is_large_paste(200, 200));
+        assert!(is_large_paste(201, 200));
+    }
+
+    #[test]
+    fn handle_paste_uses_configured_threshold() {
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        composer.set_large_paste_threshold(Some(20));
+
+        composer.handle_paste("short paste".to_string());
+        assert_eq!(composer.textarea.text(), "short paste");
+        assert!(composer.pending_pastes.is_empty());
+
+        composer.set_text_content(String::new());
+        let pasted = "a".repeat(25);
+        composer.handle_paste(pasted.clone());
+        let placeholder = "[Pasted Content 25 chars]".to_string();
+        assert_eq!(composer.textarea.text(), placeholder);
+        assert_eq!(composer.pending_pastes, vec![(placeholder, pasted)]);
+
+        // The placeholder is an atomic element: one Backspace removes all of it.
+        composer.handle_key_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+        assert_eq!(composer.textarea.text(), "");
+    }
+
+    #[test]
+    fn long_draft_warning_fires_at_configured_line_count() {
+        assert!(!is_long_draft(4, 5));
+        assert!(is_long_draft(5, 5));
+        assert!(!is_long_draft(500, 0));
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        composer.set_line_warning_threshold(Some(3));
+
+        composer.set_text_content("one\ntwo".to_string());
+        assert_eq!(composer.footer_props().long_draft_lines, None);
+
+        composer.set_text_content("one\ntwo\n".to_string());
+        assert_eq!(composer.footer_props().long_draft_lines, Some(3));
+
+        composer.set_line_warning_threshold(Some(0));
+        assert_eq!(composer.footer_props().long_draft_lines, None);
+    }
+
+    #[test]
+    fn stale_file_search_result_is_ignored() {
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        composer.set_text_content("@alp".to_string());
+        let file_match = |path: &str| FileMatch {
+            score: 1,
+            path: path.to_string(),
+            indices: None,
+        };
+
+        composer.on_file_search_result("al".to_string(), vec![file_match("all.rs")]);
+        let ActivePopup::File(popup) = &composer.active_popup else {
+            panic!("expected file popup");
+        };
+        assert_eq!(popup.selected_match(), None);
+
+        composer.on_file_search_result("alp".to_string(), vec![file_match("alpha.rs")]);
+        let ActivePopup::File(popup) = &composer.active_popup else {
+            panic!("expected file popup");
+        };
+        assert_eq!(popup.selected_match(), Some("alpha.rs"));
+    }
+
+    #[test]
+    fn selected_path_keeps_typed_line_range_as_one_element() {
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        composer.set_expand_file_mentions(true);
+        composer.set_text_content("see @alp:10-20".to_string());
+        composer.textarea.set_cursor("see @alp".len());
+        composer.sync_popups();
+        assert_eq!(composer.current_file_query.as_deref(), Some("alp"));
+
+        composer.on_file_search_result(
+            "alp".to_string(),
+            vec![FileMatch {
+                score: 1,
+                path: "alpha.rs".to_string(),
+                indices: None,
+            }],
+        );
+        composer.handle_key_event(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+        assert_eq!(composer.textarea.text(), "see @alpha.rs:10-20 ");
+
+        // One Backspace removes the trailing space, the next the whole mention.
+        composer.handle_key_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+        composer.handle_key_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+        assert_eq!(composer.textarea.text(), "see ");
+    }
+
+    #[test]
+    fn only_popup_inserted_mentions_are_submitted_as_files() {
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        composer.set_expand_file_mentions(true);
+        composer.set_text_content("ask @alice about @alp".to_string());
+        composer.textarea.set_cursor("ask @alice about @alp".len());
+        composer.sync_popups();
+        assert_eq!(composer.current_file_query.as_deref(), Some("alp"));
+
+        composer.on_file_search_result(
+            "alp".to_string(),
+            vec![FileMatch {
+                score: 1,
+                path: "alpha.rs".to_string(),
+                indices: None,
+            }],
+        );
+        composer.handle_key_event(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+        assert_eq!(composer.textarea.text(), "ask @alice about @alpha.rs ");
+
+        let (result, _) =
+            composer.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(
+            result,
+            InputResult::Submitted("ask @alice about @alpha.rs".to_string())
+        );
+        assert_eq!(
+            composer.take_recent_submission_file_mentions(),
+            vec!["@alpha.rs".to_string()]
+        );
+        assert!(composer.take_recent_submission_file_mentions().is_empty());
+    }
+
     #[test]
     fn edit_clears_pending_paste() {
         use crossterm::event::KeyCode;
@@ -3908,6 +4450,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn paste_burst_indicator_tracks_buffering() {
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+
+        // A single held first char is not a paste yet.
+        let _ = composer.handle_key_event(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE));
+        assert!(!composer.update_paste_burst_indicator());
+        assert!(!composer.footer_props().paste_burst_active);
+
+        for _ in 0..16 {
+            let _ =
+                composer.handle_key_event(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE));
+        }
+        assert!(
+            composer.update_paste_burst_indicator(),
+            "cue should turn on"
+        );
+        assert!(composer.footer_props().paste_burst_active);
+        // Later ticks during the same burst do not request extra redraws.
+        assert!(!composer.update_paste_burst_indicator());
+
+        std::thread::sleep(ChatComposer::recommended_paste_flush_delay());
+        assert!(composer.flush_paste_burst_if_due());
+        assert!(
+            composer.update_paste_burst_indicator(),
+            "cue should turn off"
+        );
+        assert!(!composer.footer_props().paste_burst_active);
+    }
+
     #[test]
     fn burst_paste_fast_large_inserts_placeholder_on_flush() {
         use crossterm::event::KeyCode;
@@ -3996,6 +4580,56 @@ mod tests {
         assert_eq!(result, InputResult::None);
     }
 
+    #[test]
+    fn up_in_empty_composer_recalls_previous_prompts() {
+        use tokio::sync::mpsc::unbounded_channel;
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        composer.history.record_local_submission("first prompt");
+        composer.history.record_local_submission("second prompt");
+
+        let up = KeyEvent::new(KeyCode::Up, KeyModifiers::NONE);
+        let down = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
+        let _ = composer.handle_key_event(up);
+        assert_eq!(composer.current_text(), "second prompt");
+        let _ = composer.handle_key_event(up);
+        assert_eq!(composer.current_text(), "first prompt");
+        let _ = composer.handle_key_event(down);
+        assert_eq!(composer.current_text(), "second prompt");
+    }
+
+    #[test]
+    fn up_in_non_empty_composer_moves_cursor() {
+        use tokio::sync::mpsc::unbounded_channel;
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        composer.history.record_local_submission("earlier prompt");
+
+        let draft = "line one\nline two";
+        composer.set_text_content(draft.to_string());
+        composer.textarea.set_cursor(draft.len());
+
+        let _ = composer.handle_key_event(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE));
+        assert_eq!(composer.current_text(), draft);
+        assert_eq!(composer.cursor(), "line one".len());
+    }
+
     #[test]
     fn slash_popup_activated_for_bare_slash_and_valid_prefixes() {
         // use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
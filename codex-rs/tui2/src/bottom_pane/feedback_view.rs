@@ -19,6 +19,7 @@ use crate::app_event::FeedbackCategory;
 use crate::app_event_sender::AppEventSender;
 use crate::history_cell;
 use crate::render::renderable::Renderable;
+use crate::theme::Theme;
 use codex_core::protocol::SessionSource;
 
 use super::CancellationEvent;
@@ -38,6 +39,7 @@ pub(crate) struct FeedbackNoteView {
     rollout_path: Option<PathBuf>,
     app_event_tx: AppEventSender,
     include_logs: bool,
+    theme: Theme,
 
     // UI state
     textarea: TextArea,
@@ -52,6 +54,7 @@ impl FeedbackNoteView {
         rollout_path: Option<PathBuf>,
         app_event_tx: AppEventSender,
         include_logs: bool,
+        theme: Theme,
     ) -> Self {
         Self {
             category,
@@ -59,6 +62,7 @@ impl FeedbackNoteView {
             rollout_path,
             app_event_tx,
             include_logs,
+            theme,
             textarea: TextArea::new(),
             textarea_state: RefCell::new(TextAreaState::default()),
             complete: false,
@@ -127,7 +131,10 @@ impl FeedbackNoteView {
             }
             Err(e) => {
                 self.app_event_tx.send(AppEvent::InsertHistoryCell(Box::new(
-                    history_cell::new_error_event(format!("Failed to upload feedback: {e}")),
+                    history_cell::new_error_event(
+                        format!("Failed to upload feedback: {e}"),
+                        &self.theme,
+                    ),
                 )));
             }
         }
@@ -508,7 +515,7 @@ mod tests {
         let (tx_raw, _rx) = tokio::sync::mpsc::unbounded_channel::<AppEvent>();
         let tx = AppEventSender::new(tx_raw);
         let snapshot = codex_feedback::CodexFeedback::new().snapshot(None);
-        FeedbackNoteView::new(category, snapshot, None, tx, true)
+        FeedbackNoteView::new(category, snapshot, None, tx, true, Theme::default())
     }
 
     #[test]
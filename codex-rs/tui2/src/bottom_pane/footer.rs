@@ -26,6 +26,12 @@ pub(crate) struct FooterProps {
     pub(crate) transcript_selection_active: bool,
     pub(crate) transcript_scroll_position: Option<(usize, usize)>,
     pub(crate) transcript_copy_selection_key: KeyBinding,
+    /// A paste burst is being buffered; show a transient "pasting…" cue.
+    pub(crate) paste_burst_active: bool,
+    /// Skills are still being discovered at startup; show a "loading skills…" cue.
+    pub(crate) skills_loading: bool,
+    /// The draft is tall enough (`tui.composer_line_warning`) to suggest sending or attaching it.
+    pub(crate) long_draft_lines: Option<usize>,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -119,6 +125,9 @@ fn footer_lines(props: FooterProps) -> Vec<Line<'static>> {
                 line.push_span(props.transcript_copy_selection_key);
                 line.push_span(" copy selection".dim());
             }
+            push_paste_burst_indicator(&mut line, props.paste_burst_active);
+            push_skills_loading_indicator(&mut line, props.skills_loading);
+            push_long_draft_indicator(&mut line, props.long_draft_lines);
             vec![line]
         }
         FooterMode::ShortcutOverlay => {
@@ -135,10 +144,39 @@ fn footer_lines(props: FooterProps) -> Vec<Line<'static>> {
             shortcut_overlay_lines(state)
         }
         FooterMode::EscHint => vec![esc_hint_line(props.esc_backtrack_hint)],
-        FooterMode::ContextOnly => vec![context_window_line(
-            props.context_window_percent,
-            props.context_window_used_tokens,
-        )],
+        FooterMode::ContextOnly => {
+            let mut line = context_window_line(
+                props.context_window_percent,
+                props.context_window_used_tokens,
+            );
+            push_paste_burst_indicator(&mut line, props.paste_burst_active);
+            push_skills_loading_indicator(&mut line, props.skills_loading);
+            push_long_draft_indicator(&mut line, props.long_draft_lines);
+            vec![line]
+        }
+    }
+}
+
+fn push_paste_burst_indicator(line: &mut Line<'static>, paste_burst_active: bool) {
+    if paste_burst_active {
+        line.push_span(" · ".dim());
+        line.push_span("pasting…".cyan());
+    }
+}
+
+fn push_skills_loading_indicator(line: &mut Line<'static>, skills_loading: bool) {
+    if skills_loading {
+        line.push_span(" · ".dim());
+        line.push_span("loading skills…".dim());
+    }
+}
+
+fn push_long_draft_indicator(line: &mut Line<'static>, long_draft_lines: Option<usize>) {
+    if let Some(lines) = long_draft_lines {
+        line.push_span(" · ".dim());
+        line.push_span(
+            format!("{lines}-line draft: consider sending it or attaching a file").cyan(),
+        );
     }
 }
 
@@ -469,6 +507,9 @@ mod tests {
                 transcript_selection_active: false,
                 transcript_scroll_position: None,
                 transcript_copy_selection_key: key_hint::ctrl_shift(KeyCode::Char('c')),
+                paste_burst_active: false,
+                skills_loading: false,
+                long_draft_lines: None,
             },
         );
 
@@ -485,6 +526,9 @@ mod tests {
                 transcript_selection_active: true,
                 transcript_scroll_position: Some((3, 42)),
                 transcript_copy_selection_key: key_hint::ctrl_shift(KeyCode::Char('c')),
+                paste_burst_active: false,
+                skills_loading: false,
+                long_draft_lines: None,
             },
         );
 
@@ -501,6 +545,9 @@ mod tests {
                 transcript_selection_active: false,
                 transcript_scroll_position: None,
                 transcript_copy_selection_key: key_hint::ctrl_shift(KeyCode::Char('c')),
+                paste_burst_active: false,
+                skills_loading: false,
+                long_draft_lines: None,
             },
         );
 
@@ -517,6 +564,9 @@ mod tests {
                 transcript_selection_active: false,
                 transcript_scroll_position: None,
                 transcript_copy_selection_key: key_hint::ctrl_shift(KeyCode::Char('c')),
+                paste_burst_active: false,
+                skills_loading: false,
+                long_draft_lines: None,
             },
         );
 
@@ -533,6 +583,9 @@ mod tests {
                 transcript_selection_active: false,
                 transcript_scroll_position: None,
                 transcript_copy_selection_key: key_hint::ctrl_shift(KeyCode::Char('c')),
+                paste_burst_active: false,
+                skills_loading: false,
+                long_draft_lines: None,
             },
         );
 
@@ -549,6 +602,9 @@ mod tests {
                 transcript_selection_active: false,
                 transcript_scroll_position: None,
                 transcript_copy_selection_key: key_hint::ctrl_shift(KeyCode::Char('c')),
+                paste_burst_active: false,
+                skills_loading: false,
+                long_draft_lines: None,
             },
         );
 
@@ -565,6 +621,9 @@ mod tests {
                 transcript_selection_active: false,
                 transcript_scroll_position: None,
                 transcript_copy_selection_key: key_hint::ctrl_shift(KeyCode::Char('c')),
+                paste_burst_active: false,
+                skills_loading: false,
+                long_draft_lines: None,
             },
         );
 
@@ -581,6 +640,9 @@ mod tests {
                 transcript_selection_active: false,
                 transcript_scroll_position: None,
                 transcript_copy_selection_key: key_hint::ctrl_shift(KeyCode::Char('c')),
+                paste_burst_active: false,
+                skills_loading: false,
+                long_draft_lines: None,
             },
         );
 
@@ -597,6 +659,9 @@ mod tests {
                 transcript_selection_active: false,
                 transcript_scroll_position: None,
                 transcript_copy_selection_key: key_hint::ctrl_shift(KeyCode::Char('c')),
+                paste_burst_active: false,
+                skills_loading: false,
+                long_draft_lines: None,
             },
         );
     }
@@ -20,6 +20,7 @@ use crate::render::RectExt as _;
 use crate::render::renderable::ColumnRenderable;
 use crate::render::renderable::Renderable;
 use crate::style::user_message_style;
+use crate::theme::Theme;
 
 use super::CancellationEvent;
 use super::bottom_pane_view::BottomPaneView;
@@ -53,6 +54,9 @@ pub(crate) struct SelectionViewParams {
     pub items: Vec<SelectionItem>,
     pub is_searchable: bool,
     pub search_placeholder: Option<String>,
+    /// Decides whether an item's `search_value` matches the query. Defaults to a
+    /// case-insensitive substring match.
+    pub search_matcher: Option<fn(&str, &str) -> bool>,
     pub header: Box<dyn Renderable>,
     pub initial_selected_idx: Option<usize>,
 }
@@ -66,6 +70,7 @@ impl Default for SelectionViewParams {
             items: Vec::new(),
             is_searchable: false,
             search_placeholder: None,
+            search_matcher: None,
             header: Box::new(()),
             initial_selected_idx: None,
         }
@@ -81,10 +86,12 @@ pub(crate) struct ListSelectionView {
     is_searchable: bool,
     search_query: String,
     search_placeholder: Option<String>,
+    search_matcher: Option<fn(&str, &str) -> bool>,
     filtered_indices: Vec<usize>,
     last_selected_actual_idx: Option<usize>,
     header: Box<dyn Renderable>,
     initial_selected_idx: Option<usize>,
+    theme: Theme,
 }
 
 impl ListSelectionView {
@@ -112,15 +119,21 @@ impl ListSelectionView {
             } else {
                 None
             },
+            search_matcher: params.search_matcher,
             filtered_indices: Vec::new(),
             last_selected_actual_idx: None,
             header,
             initial_selected_idx: params.initial_selected_idx,
+            theme: Theme::default(),
         };
         s.apply_filter();
         s
     }
 
+    pub(crate) fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
     fn visible_len(&self) -> usize {
         self.filtered_indices.len()
     }
@@ -143,13 +156,16 @@ impl ListSelectionView {
 
         if self.is_searchable && !self.search_query.is_empty() {
             let query_lower = self.search_query.to_lowercase();
+            let matcher = self.search_matcher;
+            let query = self.search_query.as_str();
             self.filtered_indices = self
                 .items
                 .iter()
                 .positions(|item| {
-                    item.search_value
-                        .as_ref()
-                        .is_some_and(|v| v.to_lowercase().contains(&query_lower))
+                    item.search_value.as_ref().is_some_and(|v| match matcher {
+                        Some(matcher) => matcher(v, query),
+                        None => v.to_lowercase().contains(&query_lower),
+                    })
                 })
                 .collect();
         } else {
@@ -260,6 +276,11 @@ impl ListSelectionView {
         self.apply_filter();
     }
 
+    /// Replace the header above the list, e.g. after the caller changed what it shows.
+    pub(crate) fn set_header(&mut self, header: Box<dyn Renderable>) {
+        self.header = header;
+    }
+
     pub(crate) fn take_last_selected_index(&mut self) -> Option<usize> {
         self.last_selected_actual_idx.take()
     }
@@ -409,7 +430,7 @@ impl Renderable for ListSelectionView {
         .areas(area);
 
         Block::default()
-            .style(user_message_style())
+            .style(user_message_style(&self.theme))
             .render(content_area, buf);
 
         let header_height = self
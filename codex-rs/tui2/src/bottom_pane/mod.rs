@@ -53,6 +53,7 @@ pub(crate) use chat_composer::InputResult;
 use codex_protocol::custom_prompts::CustomPrompt;
 
 use crate::status_indicator_widget::StatusIndicatorWidget;
+use crate::theme::Theme;
 pub(crate) use list_selection_view::SelectionAction;
 pub(crate) use list_selection_view::SelectionItem;
 
@@ -80,6 +81,7 @@ pub(crate) struct BottomPane {
     queued_user_messages: QueuedUserMessages,
     context_window_percent: Option<i64>,
     context_window_used_tokens: Option<i64>,
+    theme: Theme,
 }
 
 pub(crate) struct BottomPaneParams {
@@ -128,14 +130,67 @@ impl BottomPane {
             animations_enabled,
             context_window_percent: None,
             context_window_used_tokens: None,
+            theme: Theme::default(),
         }
     }
 
+    pub(crate) fn set_expand_file_mentions(&mut self, enabled: bool) {
+        self.composer.set_expand_file_mentions(enabled);
+    }
+
+    /// Apply the resolved `tui.theme` to the composer and to views opened from now on.
+    pub(crate) fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+        self.composer.set_theme(theme);
+        self.request_redraw();
+    }
+
+    pub(crate) fn set_composer_prompt_glyph(&mut self, glyph: Option<&str>) {
+        self.composer.set_prompt_glyph(glyph);
+        self.request_redraw();
+    }
+
+    pub(crate) fn set_composer_prompt_context(&mut self, context: Option<String>) {
+        self.composer.set_prompt_context(context);
+        self.request_redraw();
+    }
+
+    /// Configure Tab in the composer; `tab_width` defaults to
+    /// [`textarea::DEFAULT_TAB_WIDTH`] spaces.
+    pub(crate) fn set_tab_behavior(&mut self, tab_width: Option<u16>, literal_tab: bool) {
+        let tab_width = tab_width.map_or(textarea::DEFAULT_TAB_WIDTH, usize::from);
+        self.composer.set_tab_behavior(tab_width, literal_tab);
+    }
+
+    /// Collapse pastes longer than `threshold` characters into a placeholder (default 1000).
+    pub(crate) fn set_paste_placeholder_threshold(&mut self, threshold: Option<usize>) {
+        self.composer.set_large_paste_threshold(threshold);
+    }
+
+    /// Suggest sending or attaching drafts of at least `threshold` lines (`tui.composer_line_warning`).
+    pub(crate) fn set_composer_line_warning(&mut self, threshold: Option<usize>) {
+        self.composer.set_line_warning_threshold(threshold);
+        self.request_redraw();
+    }
+
+    /// Mark trailing spaces and tabs in the composer with visible glyphs.
+    pub(crate) fn set_show_whitespace(&mut self, show_whitespace: bool) {
+        self.composer.set_show_whitespace(show_whitespace);
+        self.request_redraw();
+    }
+
     pub fn set_skills(&mut self, skills: Option<Vec<SkillMetadata>>) {
         self.composer.set_skill_mentions(skills);
         self.request_redraw();
     }
 
+    /// Show a "loading skills…" placeholder for `$` mentions until [`Self::set_skills`] is
+    /// called with the discovered skills.
+    pub(crate) fn set_skills_loading(&mut self, loading: bool) {
+        self.composer.set_skills_loading(loading);
+        self.request_redraw();
+    }
+
     pub fn status_widget(&self) -> Option<&StatusIndicatorWidget> {
         self.status.as_ref()
     }
@@ -144,6 +199,11 @@ impl BottomPane {
         self.composer.skills()
     }
 
+    #[cfg(test)]
+    pub(crate) fn composer_prompt_context(&self) -> Option<&str> {
+        self.composer.prompt_context()
+    }
+
     #[cfg(test)]
     pub(crate) fn context_window_percent(&self) -> Option<i64> {
         self.context_window_percent
@@ -250,7 +310,19 @@ impl BottomPane {
         self.request_redraw();
     }
 
+    /// Forward pointer movement to the composer while no modal view is active.
+    pub(crate) fn handle_mouse_moved(&mut self, x: u16, y: u16) {
+        if self.active_view().is_none() && self.composer.handle_mouse_moved(x, y) {
+            self.request_redraw();
+        }
+    }
+
     /// Replace the composer text with `text`.
+    pub(crate) fn fence_composer_line(&mut self, language: &str) {
+        self.composer.fence_current_line(language);
+        self.request_redraw();
+    }
+
     pub(crate) fn set_composer_text(&mut self, text: String) {
         self.composer.set_text_content(text);
         self.request_redraw();
@@ -266,6 +338,12 @@ impl BottomPane {
         self.composer.current_text()
     }
 
+    /// Byte offset of the composer cursor.
+    #[cfg(test)]
+    pub(crate) fn composer_cursor(&self) -> usize {
+        self.composer.cursor()
+    }
+
     /// Update the status indicator header (defaults to "Working") and details below it.
     ///
     /// Passing `None` clears any existing details. No-ops if the status indicator is not active.
@@ -402,7 +480,9 @@ impl BottomPane {
 
     /// Show a generic list selection view with the provided items.
     pub(crate) fn show_selection_view(&mut self, params: list_selection_view::SelectionViewParams) {
-        let view = list_selection_view::ListSelectionView::new(params, self.app_event_tx.clone());
+        let mut view =
+            list_selection_view::ListSelectionView::new(params, self.app_event_tx.clone());
+        view.set_theme(self.theme);
         self.push_view(Box::new(view));
     }
 
@@ -426,6 +506,11 @@ impl BottomPane {
         self.is_task_running
     }
 
+    /// Render the empty composer as a single prompt line (`tui.compact_composer`).
+    pub(crate) fn render_collapsed_composer(&self, area: Rect, buf: &mut Buffer) {
+        self.composer.render_collapsed(area, buf);
+    }
+
     /// Return true when the pane is in the regular composer state without any
     /// overlays or popups and not running a task. This is the safe context to
     /// use Esc-Esc for backtracking from the main view.
@@ -452,7 +537,8 @@ impl BottomPane {
         };
 
         // Otherwise create a new approval modal overlay.
-        let modal = ApprovalOverlay::new(request, self.app_event_tx.clone(), features.clone());
+        let mut modal = ApprovalOverlay::new(request, self.app_event_tx.clone(), features.clone());
+        modal.set_theme(self.theme);
         self.pause_status_timer_for_modal();
         self.push_view(Box::new(modal));
     }
@@ -496,6 +582,10 @@ impl BottomPane {
         self.composer.is_in_paste_burst()
     }
 
+    pub(crate) fn update_paste_burst_indicator(&mut self) -> bool {
+        self.composer.update_paste_burst_indicator()
+    }
+
     pub(crate) fn on_history_entry_response(
         &mut self,
         log_id: u64,
@@ -534,6 +624,10 @@ impl BottomPane {
         self.composer.take_recent_submission_images()
     }
 
+    pub(crate) fn take_recent_submission_file_mentions(&mut self) -> Vec<String> {
+        self.composer.take_recent_submission_file_mentions()
+    }
+
     fn as_renderable(&'_ self) -> RenderableItem<'_> {
         if let Some(view) = self.active_view() {
             RenderableItem::Borrowed(view)
@@ -240,6 +240,12 @@ impl PasteBurst {
         self.is_active_internal() || self.pending_first_char.is_some()
     }
 
+    /// Returns true while a burst is actually being buffered, excluding the brief hold of a
+    /// single fast first char (which ordinary typing also goes through).
+    pub fn is_buffering(&self) -> bool {
+        self.is_active_internal()
+    }
+
     fn is_active_internal(&self) -> bool {
         self.active || !self.buffer.is_empty()
     }
@@ -12,6 +12,7 @@ use unicode_width::UnicodeWidthChar;
 use unicode_width::UnicodeWidthStr;
 
 use crate::key_hint::KeyBinding;
+use crate::text_formatting::truncate_to_width;
 
 use super::scroll_state::ScrollState;
 
@@ -58,15 +59,7 @@ fn truncate_line_to_width(line: Line<'static>, max_width: usize) -> Line<'static
             continue;
         }
 
-        let mut truncated = String::new();
-        for ch in text.chars() {
-            let ch_width = UnicodeWidthChar::width(ch).unwrap_or(0);
-            if used + ch_width > max_width {
-                break;
-            }
-            truncated.push(ch);
-            used += ch_width;
-        }
+        let (truncated, _) = truncate_to_width(&text, max_width - used);
 
         if !truncated.is_empty() {
             spans_out.push(Span::styled(truncated, style));
@@ -167,16 +160,9 @@ fn build_full_line(row: &GenericDisplayRow, desc_col: usize) -> Line<'static> {
             }
         }
     } else {
-        for ch in row.name.chars() {
-            let ch_w = UnicodeWidthChar::width(ch).unwrap_or(0);
-            let next_width = used_width.saturating_add(ch_w);
-            if next_width > name_limit {
-                truncated = true;
-                break;
-            }
-            used_width = next_width;
-            name_spans.push(ch.to_string().into());
-        }
+        let (name, name_truncated) = truncate_to_width(&row.name, name_limit);
+        truncated = name_truncated;
+        name_spans.push(name.into());
     }
 
     if truncated {
@@ -289,6 +275,17 @@ pub(crate) fn render_rows(
     }
 }
 
+/// Screen bounds of one row drawn by [`render_rows_single_line_with_bounds`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct RenderedRowBounds {
+    /// Index into the `rows_all` slice that was rendered.
+    pub row_idx: usize,
+    /// The single-line area the row was drawn into.
+    pub area: Rect,
+    /// Whether the row overflowed `area` and was cut off with an ellipsis.
+    pub truncated: bool,
+}
+
 /// Render rows as a single line each (no wrapping), truncating overflow with an ellipsis.
 pub(crate) fn render_rows_single_line(
     area: Rect,
@@ -298,11 +295,24 @@ pub(crate) fn render_rows_single_line(
     max_results: usize,
     empty_message: &str,
 ) {
+    render_rows_single_line_with_bounds(area, buf, rows_all, state, max_results, empty_message);
+}
+
+/// Like [`render_rows_single_line`], but also returns where each visible row was drawn so callers
+/// can hit-test mouse positions against rows.
+pub(crate) fn render_rows_single_line_with_bounds(
+    area: Rect,
+    buf: &mut Buffer,
+    rows_all: &[GenericDisplayRow],
+    state: &ScrollState,
+    max_results: usize,
+    empty_message: &str,
+) -> Vec<RenderedRowBounds> {
     if rows_all.is_empty() {
         if area.height > 0 {
             Line::from(empty_message.dim().italic()).render(area, buf);
         }
-        return;
+        return Vec::new();
     }
 
     let visible_items = max_results
@@ -323,6 +333,7 @@ pub(crate) fn render_rows_single_line(
 
     let desc_col = compute_desc_col(rows_all, start_idx, visible_items, area.width);
 
+    let mut bounds = Vec::with_capacity(visible_items);
     let mut cur_y = area.y;
     for (i, row) in rows_all
         .iter()
@@ -341,18 +352,23 @@ pub(crate) fn render_rows_single_line(
             });
         }
 
+        let truncated = line_width(&full_line) > area.width as usize;
         let full_line = truncate_line_with_ellipsis_if_overflow(full_line, area.width as usize);
-        full_line.render(
-            Rect {
-                x: area.x,
-                y: cur_y,
-                width: area.width,
-                height: 1,
-            },
-            buf,
-        );
+        let row_area = Rect {
+            x: area.x,
+            y: cur_y,
+            width: area.width,
+            height: 1,
+        };
+        full_line.render(row_area, buf);
+        bounds.push(RenderedRowBounds {
+            row_idx: i,
+            area: row_area,
+            truncated,
+        });
         cur_y = cur_y.saturating_add(1);
     }
+    bounds
 }
 
 /// Compute the number of terminal rows required to render up to `max_results`
@@ -1,11 +1,18 @@
+use std::cell::RefCell;
+
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::Paragraph;
+use ratatui::widgets::Widget;
 use ratatui::widgets::WidgetRef;
 
 use super::popup_consts::MAX_POPUP_ROWS;
 use super::scroll_state::ScrollState;
 use super::selection_popup_common::GenericDisplayRow;
-use super::selection_popup_common::render_rows_single_line;
+use super::selection_popup_common::RenderedRowBounds;
+use super::selection_popup_common::render_rows_single_line_with_bounds;
 use crate::render::Insets;
 use crate::render::RectExt;
 use codex_common::fuzzy_match::fuzzy_match;
@@ -13,10 +20,19 @@ use codex_core::skills::model::SkillMetadata;
 
 use crate::text_formatting::truncate_text;
 
+/// Maximum number of rows used by the hover tooltip that shows a full skill description.
+const MAX_TOOLTIP_ROWS: usize = 3;
+
 pub(crate) struct SkillPopup {
     query: String,
     skills: Vec<SkillMetadata>,
     state: ScrollState,
+    /// Where each visible row was drawn during the last render, for mouse hit-testing.
+    rendered_rows: RefCell<Vec<RenderedRowBounds>>,
+    /// Skill whose full description is shown in the hover tooltip.
+    hovered_skill: Option<usize>,
+    /// Skills are still being discovered; shown in place of "no skills".
+    loading: bool,
 }
 
 impl SkillPopup {
@@ -25,32 +41,80 @@ impl SkillPopup {
             query: String::new(),
             skills,
             state: ScrollState::new(),
+            rendered_rows: RefCell::new(Vec::new()),
+            hovered_skill: None,
+            loading: false,
         }
     }
 
+    pub(crate) fn set_loading(&mut self, loading: bool) {
+        self.loading = loading;
+    }
+
     pub(crate) fn set_skills(&mut self, skills: Vec<SkillMetadata>) {
         self.skills = skills;
+        self.hovered_skill = None;
         self.clamp_selection();
     }
 
     pub(crate) fn set_query(&mut self, query: &str) {
         self.query = query.to_string();
+        self.hovered_skill = None;
         self.clamp_selection();
     }
 
-    pub(crate) fn calculate_required_height(&self, _width: u16) -> u16 {
+    pub(crate) fn calculate_required_height(&self, width: u16) -> u16 {
         let rows = self.rows_from_matches(self.filtered());
         let visible = rows.len().clamp(1, MAX_POPUP_ROWS);
-        visible as u16
+        (visible + self.tooltip_lines(width).len()) as u16
+    }
+
+    /// Skill index under the screen position `(x, y)`, based on the last render.
+    pub(crate) fn skill_index_at(&self, x: u16, y: u16) -> Option<usize> {
+        let row_idx = self
+            .rendered_rows
+            .borrow()
+            .iter()
+            .find(|row| row.area.contains((x, y).into()))?
+            .row_idx;
+        self.filtered_items().get(row_idx).copied()
+    }
+
+    /// Update the hover tooltip for a pointer at `(x, y)`.
+    ///
+    /// The tooltip only appears over rows whose description was cut off. Returns whether the
+    /// tooltip changed and a redraw is needed.
+    pub(crate) fn on_mouse_moved(&mut self, x: u16, y: u16) -> bool {
+        let truncated = self
+            .rendered_rows
+            .borrow()
+            .iter()
+            .find(|row| row.area.contains((x, y).into()))
+            .is_some_and(|row| row.truncated);
+        let hovered = if truncated {
+            self.skill_index_at(x, y)
+        } else {
+            None
+        };
+        let changed = hovered != self.hovered_skill;
+        self.hovered_skill = hovered;
+        changed
+    }
+
+    /// Hide the hover tooltip. Returns whether it was visible.
+    pub(crate) fn dismiss_tooltip(&mut self) -> bool {
+        self.hovered_skill.take().is_some()
     }
 
     pub(crate) fn move_up(&mut self) {
+        self.hovered_skill = None;
         let len = self.filtered_items().len();
         self.state.move_up_wrap(len);
         self.state.ensure_visible(len, MAX_POPUP_ROWS.min(len));
     }
 
     pub(crate) fn move_down(&mut self) {
+        self.hovered_skill = None;
         let len = self.filtered_items().len();
         self.state.move_down_wrap(len);
         self.state.ensure_visible(len, MAX_POPUP_ROWS.min(len));
@@ -63,6 +127,26 @@ impl SkillPopup {
         self.skills.get(*skill_idx)
     }
 
+    /// Full description of the hovered skill, wrapped to fit under the list at `width`.
+    fn tooltip_lines(&self, width: u16) -> Vec<Line<'static>> {
+        let Some(skill) = self.hovered_skill.and_then(|idx| self.skills.get(idx)) else {
+            return Vec::new();
+        };
+        let wrap_width = usize::from(width.saturating_sub(4)).max(1);
+        let wrapped = textwrap::wrap(&skill.description, wrap_width);
+        let mut lines: Vec<Line<'static>> = wrapped
+            .iter()
+            .take(MAX_TOOLTIP_ROWS)
+            .map(|line| Line::from(line.to_string()).dim().italic())
+            .collect();
+        if wrapped.len() > MAX_TOOLTIP_ROWS
+            && let Some(last) = lines.last_mut()
+        {
+            last.push_span("…".dim());
+        }
+        lines
+    }
+
     fn clamp_selection(&mut self) {
         let len = self.filtered_items().len();
         self.state.clamp_selection(len);
@@ -82,11 +166,14 @@ impl SkillPopup {
             .map(|(idx, indices, _score)| {
                 let skill = &self.skills[idx];
                 let name = truncate_text(&skill.name, 21);
-                let description = skill
+                let summary = skill
                     .short_description
                     .as_ref()
-                    .unwrap_or(&skill.description)
-                    .clone();
+                    .unwrap_or(&skill.description);
+                let description = [skill.version.as_deref(), skill.license.as_deref()]
+                    .into_iter()
+                    .flatten()
+                    .fold(summary.clone(), |acc, detail| format!("{acc} · {detail}"));
                 GenericDisplayRow {
                     name,
                     match_indices: indices,
@@ -130,13 +217,125 @@ impl SkillPopup {
 impl WidgetRef for SkillPopup {
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
         let rows = self.rows_from_matches(self.filtered());
-        render_rows_single_line(
-            area.inset(Insets::tlbr(0, 2, 0, 0)),
+        let tooltip = self.tooltip_lines(area.width);
+        let tooltip_height = (tooltip.len() as u16).min(area.height.saturating_sub(1));
+        let list_area = Rect {
+            height: area.height.saturating_sub(tooltip_height),
+            ..area
+        };
+        let bounds = render_rows_single_line_with_bounds(
+            list_area.inset(Insets::tlbr(0, 2, 0, 0)),
             buf,
             &rows,
             &self.state,
             MAX_POPUP_ROWS,
-            "no skills",
+            if self.loading {
+                "loading skills…"
+            } else {
+                "no skills"
+            },
+        );
+        *self.rendered_rows.borrow_mut() = bounds;
+
+        if tooltip_height > 0 {
+            let tooltip_area = Rect {
+                y: list_area.bottom(),
+                height: tooltip_height,
+                ..area
+            };
+            Paragraph::new(tooltip).render(tooltip_area.inset(Insets::tlbr(0, 4, 0, 0)), buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
+
+    fn skill(name: &str, description: &str) -> SkillMetadata {
+        SkillMetadata {
+            name: name.to_string(),
+            description: description.to_string(),
+            short_description: None,
+            path: PathBuf::from(format!("/skills/{name}/SKILL.md")),
+            scope: codex_protocol::protocol::SkillScope::User,
+            allowed_tools: Vec::new(),
+            priority: 0,
+            version: None,
+            license: None,
+            applies_to: Vec::new(),
+        }
+    }
+
+    fn render(popup: &SkillPopup, area: Rect) -> Buffer {
+        let mut buf = Buffer::empty(area);
+        popup.render_ref(area, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn loading_popup_shows_placeholder() {
+        let mut popup = SkillPopup::new(Vec::new());
+        popup.set_loading(true);
+        let area = Rect::new(0, 0, 30, 1);
+        let buf = render(&popup, area);
+        let text: String = (0..area.width)
+            .map(|x| buf[(x, 0)].symbol().to_string())
+            .collect();
+        assert!(text.contains("loading skills…"), "got: {text:?}");
+        assert_eq!(popup.selected_skill(), None);
+    }
+
+    #[test]
+    fn hovered_row_maps_to_filtered_skill_index() {
+        let mut popup = SkillPopup::new(vec![
+            skill("alpha", "first"),
+            skill("beta", "second"),
+            skill("gamma", "third"),
+        ]);
+        popup.set_query("a");
+        let area = Rect::new(0, 10, 40, 3);
+        render(&popup, area);
+
+        // Rendered rows follow the filtered order, starting at the top of the popup area.
+        let hovered: Vec<Option<usize>> = (0..3)
+            .map(|row| popup.skill_index_at(5, area.y + row))
+            .collect();
+        let expected: Vec<Option<usize>> = popup.filtered_items().into_iter().map(Some).collect();
+        assert_eq!(hovered, expected);
+
+        // The left inset and rows outside the popup do not hit any skill.
+        assert_eq!(popup.skill_index_at(0, area.y), None);
+        assert_eq!(popup.skill_index_at(5, area.y + 5), None);
+    }
+
+    #[test]
+    fn hovering_truncated_row_shows_full_description() {
+        let long = "a very long description that cannot fit in a narrow popup row";
+        let mut popup = SkillPopup::new(vec![skill("short", "fits"), skill("long", long)]);
+        let area = Rect::new(0, 0, 30, 2);
+        render(&popup, area);
+
+        // Rows that fit do not show a tooltip.
+        assert!(!popup.on_mouse_moved(4, 0));
+        assert_eq!(popup.calculate_required_height(area.width), 2);
+
+        assert!(popup.on_mouse_moved(4, 1));
+        let tooltip = popup.tooltip_lines(area.width);
+        let text: String = tooltip
+            .iter()
+            .flat_map(|line| line.spans.iter().map(|span| span.content.to_string()))
+            .collect::<Vec<_>>()
+            .join(" ");
+        assert_eq!(text, long);
+        assert_eq!(
+            popup.calculate_required_height(area.width),
+            2 + tooltip.len() as u16
         );
+
+        assert!(popup.dismiss_tooltip());
+        assert_eq!(popup.calculate_required_height(area.width), 2);
     }
 }
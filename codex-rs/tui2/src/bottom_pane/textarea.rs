@@ -5,6 +5,7 @@ use crossterm::event::KeyModifiers;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::style::Color;
+use ratatui::style::Modifier;
 use ratatui::style::Style;
 use ratatui::widgets::StatefulWidgetRef;
 use ratatui::widgets::WidgetRef;
@@ -15,6 +16,9 @@ use textwrap::Options;
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
+/// Spaces inserted by Tab (and removed by Shift+Tab) unless configured otherwise.
+pub(crate) const DEFAULT_TAB_WIDTH: usize = 4;
+
 const WORD_SEPARATORS: &str = "`~!@#$%^&*()-=+[{]}\\|;:'\",.<>/?";
 
 fn is_word_separator(ch: char) -> bool {
@@ -34,6 +38,12 @@ pub(crate) struct TextArea {
     preferred_col: Option<usize>,
     elements: Vec<TextElement>,
     kill_buffer: String,
+    /// Spaces inserted by Tab; also the most leading spaces Shift+Tab removes.
+    tab_width: usize,
+    /// Insert a literal `\t` on Tab instead of spaces.
+    literal_tab: bool,
+    /// Draw trailing spaces and tabs as visible marker glyphs (rendering only).
+    show_whitespace: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -57,9 +67,21 @@ impl TextArea {
             preferred_col: None,
             elements: Vec::new(),
             kill_buffer: String::new(),
+            tab_width: DEFAULT_TAB_WIDTH,
+            literal_tab: false,
+            show_whitespace: false,
         }
     }
 
+    pub(crate) fn set_tab_behavior(&mut self, tab_width: usize, literal_tab: bool) {
+        self.tab_width = tab_width.max(1);
+        self.literal_tab = literal_tab;
+    }
+
+    pub(crate) fn set_show_whitespace(&mut self, show_whitespace: bool) {
+        self.show_whitespace = show_whitespace;
+    }
+
     pub fn set_text(&mut self, text: &str) {
         self.text = text.to_string();
         self.cursor_pos = self.cursor_pos.clamp(0, self.text.len());
@@ -140,7 +162,7 @@ impl TextArea {
         self.wrapped_lines(width).len() as u16
     }
 
-    #[cfg_attr(not(test), allow(dead_code))]
+    #[cfg(test)]
     pub fn cursor_pos(&self, area: Rect) -> Option<(u16, u16)> {
         self.cursor_pos_with_state(area, TextAreaState::default())
     }
@@ -328,6 +350,14 @@ impl TextArea {
             } => {
                 self.kill_to_end_of_line();
             }
+            // Terminals report Ctrl+Shift+K as either `K` or `k` with SHIFT.
+            KeyEvent {
+                code: KeyCode::Char('K' | 'k'),
+                modifiers,
+                ..
+            } if modifiers == (KeyModifiers::CONTROL | KeyModifiers::SHIFT) => {
+                self.kill_whole_line();
+            }
             KeyEvent {
                 code: KeyCode::Char('y'),
                 modifiers: KeyModifiers::CONTROL,
@@ -443,6 +473,19 @@ impl TextArea {
             } => {
                 self.move_cursor_to_end_of_line(true);
             }
+            KeyEvent {
+                code: KeyCode::Tab,
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => {
+                self.handle_tab(false, false);
+            }
+            KeyEvent {
+                code: KeyCode::BackTab,
+                ..
+            } => {
+                self.handle_tab(true, false);
+            }
             _o => {
                 #[cfg(feature = "debug-logs")]
                 tracing::debug!("Unhandled key event in TextArea: {:?}", _o);
@@ -450,6 +493,41 @@ impl TextArea {
         }
     }
 
+    /// Indent at the cursor (Tab) or dedent the current line (Shift+Tab).
+    ///
+    /// When `completion_open` is set, Tab belongs to the completion popup: nothing is edited
+    /// and `false` is returned.
+    pub fn handle_tab(&mut self, dedent: bool, completion_open: bool) -> bool {
+        if completion_open {
+            return false;
+        }
+        if dedent {
+            self.dedent_current_line();
+        } else if self.literal_tab {
+            self.insert_str("\t");
+        } else {
+            self.insert_str(&" ".repeat(self.tab_width));
+        }
+        true
+    }
+
+    /// Remove one leading tab, or up to `tab_width` leading spaces, from the current line.
+    fn dedent_current_line(&mut self) {
+        let bol = self.beginning_of_current_line();
+        let line = &self.text[bol..self.end_of_current_line()];
+        let remove = if line.starts_with('\t') {
+            1
+        } else {
+            line.bytes()
+                .take(self.tab_width)
+                .take_while(|b| *b == b' ')
+                .count()
+        };
+        if remove > 0 {
+            self.replace_range(bol..bol + remove, "");
+        }
+    }
+
     // ####### Input Functions #######
     pub fn delete_backward(&mut self, n: usize) {
         if n == 0 || self.cursor_pos == 0 {
@@ -526,6 +604,30 @@ impl TextArea {
         }
     }
 
+    /// Kill the entire logical line under the cursor along with its newline.
+    ///
+    /// The trailing newline goes with the line; on the last line the preceding newline is
+    /// removed instead so no empty line is left behind. The cursor lands at the start of the
+    /// line that now occupies the killed line's position.
+    pub fn kill_whole_line(&mut self) {
+        let bol = self.beginning_of_current_line();
+        let eol = self.end_of_current_line();
+        let range = if eol < self.text.len() {
+            bol..eol + 1
+        } else if bol > 0 {
+            bol - 1..eol
+        } else {
+            bol..eol
+        };
+        if range.is_empty() {
+            return;
+        }
+        let start = range.start;
+        self.kill_range(range);
+        self.set_cursor(self.beginning_of_line(start.min(self.text.len())));
+        self.preferred_col = None;
+    }
+
     pub fn yank(&mut self) {
         if self.kill_buffer.is_empty() {
             return;
@@ -723,6 +825,13 @@ impl TextArea {
         self.set_cursor(end);
     }
 
+    /// The text of each element, in buffer order.
+    pub(crate) fn element_texts(&self) -> impl Iterator<Item = &str> {
+        self.elements
+            .iter()
+            .map(|element| &self.text[element.range.clone()])
+    }
+
     fn add_element(&mut self, range: Range<usize>) {
         let elem = TextElement { range };
         self.elements.push(elem);
@@ -915,6 +1024,110 @@ impl TextArea {
         self.adjust_pos_out_of_elements(end, false)
     }
 
+    /// Returns the word containing or touching the cursor along with its byte range.
+    ///
+    /// Words follow the same rules as [`Self::beginning_of_previous_word`] and
+    /// [`Self::end_of_next_word`]: a run of non-whitespace characters that are either all word
+    /// separators or all non-separators. An atomic element is always a word of its own. When the
+    /// cursor sits between two words the one after it wins; `None` means the cursor only touches
+    /// whitespace (or the text is empty).
+    #[cfg(test)]
+    pub fn current_word(&self) -> Option<(Range<usize>, &str)> {
+        let pos = self.cursor_pos;
+        let range = if let Some(e) = self
+            .elements
+            .iter()
+            .find(|e| pos >= e.range.start && pos < e.range.end)
+        {
+            e.range.clone()
+        } else if let Some(ch) = self.text[pos..].chars().next()
+            && !ch.is_whitespace()
+        {
+            self.word_range_around(pos, ch)
+        } else if let Some(e) = self.elements.iter().find(|e| e.range.end == pos) {
+            e.range.clone()
+        } else {
+            let ch = self.text[..pos]
+                .chars()
+                .next_back()
+                .filter(|ch| !ch.is_whitespace())?;
+            self.word_range_around(pos - ch.len_utf8(), ch)
+        };
+        Some((range.clone(), &self.text[range]))
+    }
+
+    /// Expand the character `ch` at byte `at` into its whole word, stopping at whitespace, a change
+    /// of separator class, or an element boundary.
+    #[cfg(test)]
+    fn word_range_around(&self, at: usize, ch: char) -> Range<usize> {
+        let is_separator = is_word_separator(ch);
+        let same_word = |ch: char| !ch.is_whitespace() && is_word_separator(ch) == is_separator;
+        let is_element_boundary = |pos: usize| {
+            self.elements
+                .iter()
+                .any(|e| e.range.start == pos || e.range.end == pos)
+        };
+
+        let mut start = at;
+        for (idx, ch) in self.text[..at].char_indices().rev() {
+            if is_element_boundary(start) || !same_word(ch) {
+                break;
+            }
+            start = idx;
+        }
+        let mut end = at + ch.len_utf8();
+        for ch in self.text[end..].chars() {
+            if is_element_boundary(end) || !same_word(ch) {
+                break;
+            }
+            end += ch.len_utf8();
+        }
+        start..end
+    }
+
+    /// Wrap `range` in a fenced code block, putting each fence on its own line and tagging the
+    /// opening fence with `language` (may be empty). Atomic elements that intersect the range are
+    /// fenced whole.
+    ///
+    /// An empty range becomes an empty block with the cursor on its blank line; otherwise the
+    /// cursor lands just after the closing fence.
+    pub fn fence_range(&mut self, range: Range<usize>, language: &str) {
+        let range = self.expand_range_to_element_boundaries(range);
+        let (start, end) = (range.start, range.end.min(self.text.len()));
+        let lead = if start == 0 || self.text[..start].ends_with('\n') {
+            ""
+        } else {
+            "\n"
+        };
+        let trail = if end == self.text.len() || self.text[end..].starts_with('\n') {
+            ""
+        } else {
+            "\n"
+        };
+        let open = format!("{lead}```{language}\n");
+        if start == end {
+            self.insert_str_at(start, &format!("{open}\n```{trail}"));
+            self.set_cursor(start + open.len());
+            return;
+        }
+        let close_lead = if self.text[..end].ends_with('\n') {
+            ""
+        } else {
+            "\n"
+        };
+        let close = format!("{close_lead}```");
+        // Closing fence first so `start` stays valid.
+        self.insert_str_at(end, &format!("{close}{trail}"));
+        self.insert_str_at(start, &open);
+        self.set_cursor(end + open.len() + close.len());
+    }
+
+    /// [`Self::fence_range`] over the logical line under the cursor.
+    pub fn fence_current_line(&mut self, language: &str) {
+        let range = self.beginning_of_current_line()..self.end_of_current_line();
+        self.fence_range(range, language);
+    }
+
     fn adjust_pos_out_of_elements(&self, pos: usize, prefer_start: bool) -> usize {
         if let Some(idx) = self.find_element_containing(pos) {
             let e = &self.elements[idx];
@@ -940,7 +1153,7 @@ impl TextArea {
             if needs_recalc {
                 let lines = crate::wrapping::wrap_ranges(
                     &self.text,
-                    Options::new(width as usize).wrap_algorithm(textwrap::WrapAlgorithm::FirstFit),
+                    Options::new(width as usize).wrap_algorithm(crate::wrapping::wrap_algorithm()),
                 );
                 *cache = Some(WrapCache { width, lines });
             }
@@ -1033,6 +1246,40 @@ impl TextArea {
                 let style = Style::default().fg(Color::Cyan);
                 buf.set_string(area.x + x_off, y, styled, style);
             }
+
+            if self.show_whitespace {
+                self.render_whitespace_markers(area.x, y, buf, line_range);
+            }
+        }
+    }
+
+    /// Overlay `·` on trailing spaces and `→` on tabs within one displayed slice.
+    ///
+    /// Markers replace the cell the whitespace already occupies, so columns (and the cursor
+    /// math built on `width()`) are unchanged. A tab that renders with zero width has no cell to
+    /// mark and is left alone.
+    fn render_whitespace_markers(
+        &self,
+        x: u16,
+        y: u16,
+        buf: &mut Buffer,
+        line_range: Range<usize>,
+    ) {
+        let eol = self.end_of_line(line_range.start);
+        let trailing_start = self.text[..eol].trim_end_matches([' ', '\t']).len();
+        let style = Style::default().add_modifier(Modifier::DIM);
+        for (offset, ch) in self.text[line_range.clone()].char_indices() {
+            let idx = line_range.start + offset;
+            let marker = match ch {
+                '\t' => "→",
+                ' ' if idx >= trailing_start => "·",
+                _ => continue,
+            };
+            if self.text[idx..idx + ch.len_utf8()].width() == 0 {
+                continue;
+            }
+            let x_off = self.text[line_range.start..idx].width() as u16;
+            buf.set_string(x + x_off, y, marker, style);
         }
     }
 }
@@ -1090,6 +1337,80 @@ mod tests {
         t
     }
 
+    #[test]
+    fn show_whitespace_marks_trailing_spaces_without_changing_text() {
+        let mut t = ta_with("a b  \nc");
+        t.set_show_whitespace(true);
+        let area = Rect::new(0, 0, 10, 2);
+        let mut buf = Buffer::empty(area);
+        ratatui::widgets::WidgetRef::render_ref(&(&t), area, &mut buf);
+
+        let row = |y: u16| -> String { (0..5).map(|x| buf[(x, y)].symbol().to_string()).collect() };
+        // The inner space stays blank; only the two trailing spaces get markers.
+        assert_eq!(row(0), "a b··");
+        assert_eq!(row(1), "c    ");
+        assert!(buf[(3, 0)].modifier.contains(Modifier::DIM));
+        assert_eq!(t.text(), "a b  \nc");
+
+        t.set_show_whitespace(false);
+        let mut buf = Buffer::empty(area);
+        ratatui::widgets::WidgetRef::render_ref(&(&t), area, &mut buf);
+        let plain: String = (0..5).map(|x| buf[(x, 0)].symbol().to_string()).collect();
+        assert_eq!(plain, "a b  ");
+    }
+
+    #[test]
+    fn tab_inserts_configured_spaces_or_literal_tab() {
+        let mut t = ta_with("ab");
+        t.set_cursor(1);
+        t.input(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+        assert_eq!(t.text(), "a    b");
+        assert_eq!(t.cursor(), 5);
+
+        let mut t = ta_with("");
+        t.set_tab_behavior(2, false);
+        t.input(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+        assert_eq!(t.text(), "  ");
+
+        let mut t = ta_with("");
+        t.set_tab_behavior(2, true);
+        t.input(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+        assert_eq!(t.text(), "\t");
+    }
+
+    #[test]
+    fn shift_tab_dedents_current_line() {
+        // Removes at most one indent level of spaces, keeping the cursor on the same char.
+        let mut t = ta_with("first\n      second");
+        t.input(KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT));
+        assert_eq!(t.text(), "first\n  second");
+        assert_eq!(t.cursor(), t.text().len());
+
+        // Cursor inside the removed indent lands at line start.
+        t.set_cursor("first\n ".len());
+        t.input(KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT));
+        assert_eq!(t.text(), "first\nsecond");
+        assert_eq!(t.cursor(), "first\n".len());
+
+        // A leading tab is removed on its own; unindented lines are untouched.
+        let mut t = ta_with("\t\tx");
+        t.input(KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT));
+        assert_eq!(t.text(), "\tx");
+        let mut t = ta_with("x");
+        t.input(KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT));
+        assert_eq!(t.text(), "x");
+    }
+
+    #[test]
+    fn open_completion_suppresses_tab_insertion() {
+        let mut t = ta_with("  ab");
+        assert!(!t.handle_tab(false, true));
+        assert!(!t.handle_tab(true, true));
+        assert_eq!(t.text(), "  ab");
+        assert!(t.handle_tab(false, false));
+        assert_eq!(t.text(), "  ab    ");
+    }
+
     #[test]
     fn insert_and_replace_update_cursor_and_text() {
         // insert helpers
@@ -1333,6 +1654,52 @@ mod tests {
         assert_eq!(t.cursor(), 0);
     }
 
+    #[test]
+    fn kill_whole_line_middle_last_and_single() {
+        // Middle line: removed with its newline; cursor moves to the start of the next line.
+        let mut t = ta_with("one\ntwo\nthree");
+        t.set_cursor(5);
+        t.kill_whole_line();
+        assert_eq!(t.text(), "one\nthree");
+        assert_eq!(t.cursor(), 4);
+        t.yank();
+        assert_eq!(t.text(), "one\ntwo\nthree");
+
+        // Last line: the preceding newline goes instead; cursor lands on the new last line.
+        let mut t = ta_with("one\ntwo\nthree");
+        t.set_cursor(10);
+        t.kill_whole_line();
+        assert_eq!(t.text(), "one\ntwo");
+        assert_eq!(t.cursor(), 4);
+
+        // Only line: buffer becomes empty.
+        let mut t = ta_with("solo");
+        t.set_cursor(2);
+        t.kill_whole_line();
+        assert_eq!(t.text(), "");
+        assert_eq!(t.cursor(), 0);
+        t.yank();
+        assert_eq!(t.text(), "solo");
+
+        // Empty buffer: nothing to kill.
+        let mut t = ta_with("");
+        t.kill_whole_line();
+        assert_eq!(t.text(), "");
+        assert_eq!(t.cursor(), 0);
+    }
+
+    #[test]
+    fn ctrl_shift_k_kills_whole_line() {
+        let mut t = ta_with("a\nb\nc");
+        t.set_cursor(2);
+        t.input(KeyEvent::new(
+            KeyCode::Char('K'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        ));
+        assert_eq!(t.text(), "a\nc");
+        assert_eq!(t.cursor(), 2);
+    }
+
     #[test]
     fn yank_restores_last_kill() {
         let mut t = ta_with("hello");
@@ -1586,6 +1953,82 @@ mod tests {
         assert_eq!(t.end_of_next_word(), t.text().len());
     }
 
+    #[test]
+    fn fence_range_wraps_exactly_the_range() {
+        let mut t = ta_with("see foo(bar) here");
+        t.fence_range(4..12, "rust");
+        assert_eq!(t.text(), "see \n```rust\nfoo(bar)\n```\n here");
+        assert_eq!(t.cursor(), "see \n```rust\nfoo(bar)\n```".len());
+
+        let mut t = ta_with("a\nb\nc");
+        t.fence_range(2..4, "");
+        assert_eq!(t.text(), "a\n```\nb\n```\nc");
+    }
+
+    #[test]
+    fn fence_current_line_wraps_the_logical_line() {
+        let mut t = ta_with("intro\nlet x = 1;\noutro");
+        t.set_cursor("intro\nlet".len());
+        t.fence_current_line("rust");
+        assert_eq!(t.text(), "intro\n```rust\nlet x = 1;\n```\noutro");
+        assert_eq!(t.cursor(), "intro\n```rust\nlet x = 1;\n```".len());
+
+        let mut t = ta_with("");
+        t.fence_current_line("sh");
+        assert_eq!(t.text(), "```sh\n\n```");
+        assert_eq!(t.cursor(), "```sh\n".len());
+    }
+
+    #[test]
+    fn current_word_under_cursor() {
+        let mut t = ta_with("alpha  beta path/to");
+
+        // Mid-word.
+        t.set_cursor(2);
+        assert_eq!(t.current_word(), Some((0..5, "alpha")));
+
+        // At the start of a word, and at its end before whitespace.
+        let beta_start = t.text().find("beta").unwrap();
+        t.set_cursor(beta_start);
+        assert_eq!(t.current_word(), Some((beta_start..beta_start + 4, "beta")));
+        t.set_cursor(5);
+        assert_eq!(t.current_word(), Some((0..5, "alpha")));
+
+        // Between two spaces there is no word.
+        t.set_cursor(6);
+        assert_eq!(t.current_word(), None);
+
+        // Separators form their own words, and the word after the cursor wins.
+        let slash = t.text().find('/').unwrap();
+        t.set_cursor(slash);
+        assert_eq!(t.current_word(), Some((slash..slash + 1, "/")));
+        t.set_cursor(slash + 1);
+        assert_eq!(t.current_word(), Some((slash + 1..slash + 3, "to")));
+
+        assert_eq!(ta_with("").current_word(), None);
+    }
+
+    #[test]
+    fn current_word_treats_elements_as_words() {
+        let mut t = TextArea::new();
+        t.insert_str("see");
+        t.insert_element("[Image #1]");
+        t.insert_str("now");
+        let elem = t.elements[0].range.clone();
+
+        // Inside the element.
+        t.cursor_pos = elem.start + 3;
+        assert_eq!(t.current_word(), Some((elem.clone(), "[Image #1]")));
+
+        // Adjacent words stop at the element instead of merging into it.
+        t.set_cursor(1);
+        assert_eq!(t.current_word(), Some((0..3, "see")));
+        t.set_cursor(elem.end);
+        assert_eq!(t.current_word(), Some((elem.end..elem.end + 3, "now")));
+        t.set_cursor(t.text().len());
+        assert_eq!(t.current_word(), Some((elem.end..elem.end + 3, "now")));
+    }
+
     #[test]
     fn wrapping_and_cursor_positions() {
         let mut t = ta_with("hello world here");
@@ -10,6 +10,9 @@ use codex_backend_client::Client as BackendClient;
 use codex_core::config::Config;
 use codex_core::config::ConstraintResult;
 use codex_core::config::types::Notifications;
+use codex_core::config::types::PromptTemplate;
+use codex_core::config::types::StreamReveal;
+use codex_core::features::Feature;
 use codex_core::git_info::current_branch_name;
 use codex_core::git_info::local_git_branches;
 use codex_core::models_manager::manager::ModelsManager;
@@ -32,6 +35,7 @@ use codex_core::protocol::ExecCommandBeginEvent;
 use codex_core::protocol::ExecCommandEndEvent;
 use codex_core::protocol::ExecCommandSource;
 use codex_core::protocol::ExitedReviewModeEvent;
+use codex_core::protocol::FileChange;
 use codex_core::protocol::ListCustomPromptsResponseEvent;
 use codex_core::protocol::ListSkillsResponseEvent;
 use codex_core::protocol::McpListToolsResponseEvent;
@@ -60,6 +64,8 @@ use codex_core::protocol::ViewImageToolCallEvent;
 use codex_core::protocol::WarningEvent;
 use codex_core::protocol::WebSearchBeginEvent;
 use codex_core::protocol::WebSearchEndEvent;
+use codex_core::skills::SkillLookup;
+use codex_core::skills::SkillNameIndex;
 use codex_core::skills::model::SkillMetadata;
 use codex_protocol::ConversationId;
 use codex_protocol::account::PlanType;
@@ -84,6 +90,7 @@ use tracing::debug;
 
 use crate::app_event::AppEvent;
 use crate::app_event_sender::AppEventSender;
+use crate::approval_history::ApprovalHistoryEntry;
 use crate::bottom_pane::ApprovalRequest;
 use crate::bottom_pane::BottomPane;
 use crate::bottom_pane::BottomPaneParams;
@@ -99,6 +106,8 @@ use crate::diff_render::display_path_for;
 use crate::exec_cell::CommandOutput;
 use crate::exec_cell::ExecCell;
 use crate::exec_cell::new_active_exec_command;
+use crate::file_mentions::FILE_MENTION_MAX_BYTES;
+use crate::file_mentions::read_file_mentions;
 use crate::get_git_diff::get_git_diff;
 use crate::history_cell;
 use crate::history_cell::AgentMessageCell;
@@ -106,6 +115,8 @@ use crate::history_cell::HistoryCell;
 use crate::history_cell::McpToolCallCell;
 use crate::history_cell::PlainHistoryCell;
 use crate::markdown::append_markdown;
+use crate::notifications::QuietHours;
+use crate::patch_revert::plan_revert;
 use crate::render::Insets;
 use crate::render::renderable::ColumnRenderable;
 use crate::render::renderable::FlexRenderable;
@@ -115,7 +126,10 @@ use crate::render::renderable::RenderableItem;
 use crate::slash_command::SlashCommand;
 use crate::status::RateLimitSnapshotDisplay;
 use crate::text_formatting::truncate_text;
+use crate::theme::Theme;
+use crate::transcript_errors::ErrorEntry;
 use crate::tui::FrameRequester;
+use crate::tui::scrolling::ScrollTuning;
 mod interrupts;
 use self::interrupts::InterruptManager;
 mod agent;
@@ -129,6 +143,7 @@ use std::path::Path;
 use chrono::Local;
 use codex_common::approval_presets::ApprovalPreset;
 use codex_common::approval_presets::builtin_approval_presets;
+use codex_common::sandbox_summary::summarize_sandbox_policy;
 use codex_core::AuthManager;
 use codex_core::CodexAuth;
 use codex_core::ConversationManager;
@@ -166,6 +181,22 @@ impl UnifiedExecWaitState {
 const RATE_LIMIT_WARNING_THRESHOLDS: [f64; 3] = [75.0, 90.0, 95.0];
 const NUDGE_MODEL_SLUG: &str = "gpt-5.1-codex-mini";
 const RATE_LIMIT_SWITCH_PROMPT_THRESHOLD: f64 = 90.0;
+/// Default `tui.compact_prompt_threshold`: offer to compact once this percent of the context
+/// window is in use.
+const DEFAULT_COMPACT_PROMPT_THRESHOLD: u8 = 85;
+/// Languages offered by the Alt+` code fence popup, after "No language".
+const CODE_FENCE_LANGUAGES: [&str; 10] = [
+    "bash",
+    "diff",
+    "javascript",
+    "json",
+    "python",
+    "rust",
+    "sql",
+    "toml",
+    "typescript",
+    "yaml",
+];
 
 #[derive(Default)]
 struct RateLimitWarningState {
@@ -277,12 +308,44 @@ enum RateLimitSwitchPromptState {
     Shown,
 }
 
+/// Lifecycle of the "context window nearly full" compaction prompt.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum CompactPromptState {
+    #[default]
+    Idle,
+    /// Usage crossed the threshold; the prompt opens when the current turn completes.
+    Pending,
+    /// The prompt was offered; it re-arms once usage drops back below the threshold.
+    Shown,
+    /// The user asked not to be reminded again this session.
+    Snoozed,
+}
+
+/// Whether `info` puts context-window usage at or above `threshold` percent. A threshold of 0
+/// disables the check.
+fn context_usage_reaches(info: &TokenUsageInfo, threshold: u8) -> bool {
+    if threshold == 0 {
+        return false;
+    }
+    let Some(window) = info.model_context_window else {
+        return false;
+    };
+    let used = 100
+        - info
+            .last_token_usage
+            .percent_of_context_window_remaining(window);
+    used >= i64::from(threshold.min(100))
+}
+
 pub(crate) struct ChatWidget {
     app_event_tx: AppEventSender,
     codex_op_tx: UnboundedSender<Op>,
     bottom_pane: BottomPane,
     active_cell: Option<Box<dyn HistoryCell>>,
     config: Config,
+    /// Colors resolved from `[tui.theme]`; handed to the bottom pane and the cells this widget
+    /// creates.
+    theme: Theme,
     model: String,
     auth_manager: Arc<AuthManager>,
     models_manager: Arc<ModelsManager>,
@@ -293,7 +356,15 @@ pub(crate) struct ChatWidget {
     plan_type: Option<PlanType>,
     rate_limit_warnings: RateLimitWarningState,
     rate_limit_switch_prompt: RateLimitSwitchPromptState,
+    compact_prompt: CompactPromptState,
+    /// Whether the transcript has noted (once per session) that a prompt template is applied.
+    prompt_template_noted: bool,
+    /// Whether the terminal has focus, as of the last frame; drives `tui.compact_composer`.
+    terminal_focused: bool,
     rate_limit_poller: Option<JoinHandle<()>>,
+    /// Task reading `@path` attachments for the latest submission; later submissions wait on
+    /// it so they reach the agent in order.
+    pending_user_input: Option<JoinHandle<()>>,
     // Stream lifecycle controller
     stream_controller: Option<StreamController>,
     running_commands: HashMap<String, RunningCommand>,
@@ -312,6 +383,8 @@ pub(crate) struct ChatWidget {
     // Previous status header to restore after a transient stream retry.
     retry_status_header: Option<String>,
     conversation_id: Option<ConversationId>,
+    // Custom title for the current conversation, set with `/title`.
+    conversation_title: Option<String>,
     frame_requester: FrameRequester,
     // Whether to include the initial welcome banner on session configured
     show_welcome_banner: bool,
@@ -328,6 +401,8 @@ pub(crate) struct ChatWidget {
     pre_review_token_info: Option<Option<TokenUsageInfo>>,
     // Whether to add a final message separator after the last message
     needs_final_message_separator: bool,
+    // Changes of the most recent successfully applied patch, for `/revert-patch`.
+    last_applied_patch: Option<HashMap<PathBuf, FileChange>>,
 
     last_rendered_width: std::cell::Cell<Option<usize>>,
     // Feedback sink for /feedback
@@ -339,6 +414,8 @@ pub(crate) struct ChatWidget {
 struct UserMessage {
     text: String,
     image_paths: Vec<PathBuf>,
+    /// `@path` elements inserted from the file search popup, attached on submit.
+    file_mentions: Vec<String>,
 }
 
 impl From<String> for UserMessage {
@@ -346,6 +423,7 @@ impl From<String> for UserMessage {
         Self {
             text,
             image_paths: Vec::new(),
+            file_mentions: Vec::new(),
         }
     }
 }
@@ -355,6 +433,7 @@ impl From<&str> for UserMessage {
         Self {
             text: text.to_string(),
             image_paths: Vec::new(),
+            file_mentions: Vec::new(),
         }
     }
 }
@@ -363,7 +442,11 @@ fn create_initial_user_message(text: String, image_paths: Vec<PathBuf>) -> Optio
     if text.is_empty() && image_paths.is_empty() {
         None
     } else {
-        Some(UserMessage { text, image_paths })
+        Some(UserMessage {
+            text,
+            image_paths,
+            file_mentions: Vec::new(),
+        })
     }
 }
 
@@ -400,8 +483,9 @@ impl ChatWidget {
     fn on_session_configured(&mut self, event: codex_core::protocol::SessionConfiguredEvent) {
         self.bottom_pane
             .set_history_metadata(event.history_log_id, event.history_entry_count);
-        self.set_skills(None);
         self.conversation_id = Some(event.session_id);
+        self.conversation_title =
+            crate::conversation_titles::load_title(&self.config.codex_home, event.session_id);
         self.current_rollout_path = Some(event.rollout_path.clone());
         let initial_messages = event.initial_messages.clone();
         let model_for_header = event.model.clone();
@@ -411,6 +495,7 @@ impl ChatWidget {
             &model_for_header,
             event,
             self.show_welcome_banner,
+            self.conversation_title.clone(),
         ));
         if let Some(messages) = initial_messages {
             self.replay_initial_messages(messages);
@@ -429,6 +514,13 @@ impl ChatWidget {
         }
     }
 
+    /// Show the loading-skills cue from the moment the agent starts discovering skills (when it
+    /// is spawned) until `ListSkillsResponse` delivers them.
+    fn begin_skills_load(&mut self) {
+        self.bottom_pane
+            .set_skills_loading(self.config.features.enabled(Feature::Skills));
+    }
+
     fn set_skills(&mut self, skills: Option<Vec<SkillMetadata>>) {
         self.bottom_pane.set_skills(skills);
     }
@@ -438,6 +530,16 @@ impl ChatWidget {
         self.set_skills(Some(skills));
     }
 
+    /// Load the skills for the current cwd and show exactly what mentioning each one would inject
+    /// into a turn (`/skills-preview`).
+    fn preview_skill_injections(&mut self) {
+        if !self.config.features.enabled(Feature::Skills) {
+            self.add_info_message("Skills are disabled for this session.".to_string(), None);
+            return;
+        }
+        self.app_event_tx.send(AppEvent::PreviewSkillInjections);
+    }
+
     pub(crate) fn open_feedback_note(
         &mut self,
         category: crate::app_event::FeedbackCategory,
@@ -456,6 +558,7 @@ impl ChatWidget {
             rollout,
             self.app_event_tx.clone(),
             include_logs,
+            self.theme,
         );
         self.bottom_pane.show_view(Box::new(view));
         self.request_redraw();
@@ -472,12 +575,20 @@ impl ChatWidget {
     }
 
     fn on_agent_message(&mut self, message: String) {
+        let stats_cell = if self.config.tui_message_stats {
+            history_cell::new_agent_message_stats(&message)
+        } else {
+            None
+        };
         // If we have a stream_controller, then the final agent message is redundant and will be a
         // duplicate of what has already been streamed.
         if self.stream_controller.is_none() {
             self.handle_streaming_delta(message);
         }
         self.flush_answer_stream_with_separator();
+        if let Some(cell) = stats_cell {
+            self.add_to_history(cell);
+        }
         self.handle_stream_finished();
         self.request_redraw();
     }
@@ -552,6 +663,7 @@ impl ChatWidget {
         });
 
         self.maybe_show_pending_rate_limit_prompt();
+        self.maybe_show_pending_compact_prompt();
     }
 
     pub(crate) fn set_token_info(&mut self, info: Option<TokenUsageInfo>) {
@@ -568,9 +680,121 @@ impl ChatWidget {
         let percent = self.context_remaining_percent(&info);
         let used_tokens = self.context_used_tokens(&info, percent.is_some());
         self.bottom_pane.set_context_window(percent, used_tokens);
+        self.update_compact_prompt_state(&info);
         self.token_info = Some(info);
     }
 
+    fn compact_prompt_hidden(&self) -> bool {
+        self.config.notices.hide_compact_prompt.unwrap_or(false)
+    }
+
+    fn update_compact_prompt_state(&mut self, info: &TokenUsageInfo) {
+        if self.compact_prompt_hidden() {
+            self.compact_prompt = CompactPromptState::Idle;
+            return;
+        }
+        let threshold = self
+            .config
+            .tui_compact_prompt_threshold
+            .unwrap_or(DEFAULT_COMPACT_PROMPT_THRESHOLD);
+        let near_full = context_usage_reaches(info, threshold);
+        self.compact_prompt = match (self.compact_prompt, near_full) {
+            (CompactPromptState::Snoozed, _) => CompactPromptState::Snoozed,
+            (CompactPromptState::Idle, true) => CompactPromptState::Pending,
+            (CompactPromptState::Pending | CompactPromptState::Shown, false) => {
+                CompactPromptState::Idle
+            }
+            (state, _) => state,
+        };
+    }
+
+    fn maybe_show_pending_compact_prompt(&mut self) {
+        if self.compact_prompt != CompactPromptState::Pending {
+            return;
+        }
+        if self.compact_prompt_hidden() {
+            self.compact_prompt = CompactPromptState::Idle;
+            return;
+        }
+        let used_percent = self
+            .token_info
+            .as_ref()
+            .and_then(|info| self.context_remaining_percent(info))
+            .map(|remaining| 100 - remaining);
+        self.open_compact_prompt(used_percent);
+        self.compact_prompt = CompactPromptState::Shown;
+    }
+
+    fn open_compact_prompt(&mut self, used_percent: Option<i64>) {
+        let compact_actions: Vec<SelectionAction> = vec![Box::new(|tx| {
+            tx.send(AppEvent::CodexOp(Op::Compact));
+        })];
+        let snooze_actions: Vec<SelectionAction> = vec![Box::new(|tx| {
+            tx.send(AppEvent::SnoozeCompactPrompt);
+        })];
+        let never_actions: Vec<SelectionAction> = vec![Box::new(|tx| {
+            tx.send(AppEvent::UpdateCompactPromptHidden(true));
+            tx.send(AppEvent::PersistCompactPromptHidden);
+        })];
+
+        let items = vec![
+            SelectionItem {
+                name: "Compact now".to_string(),
+                description: Some(
+                    "Summarize earlier turns to free up room in the context window.".to_string(),
+                ),
+                actions: compact_actions,
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+            SelectionItem {
+                name: "Not now".to_string(),
+                description: None,
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+            SelectionItem {
+                name: "Not again this session".to_string(),
+                description: Some("Stop offering to compact until Codex restarts.".to_string()),
+                actions: snooze_actions,
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+            SelectionItem {
+                name: "Never ask again".to_string(),
+                description: Some("Run /compact yourself when the context fills up.".to_string()),
+                actions: never_actions,
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+        ];
+
+        let subtitle = match used_percent {
+            Some(used) => {
+                format!("{used}% of the context window is in use. Compact earlier turns?")
+            }
+            None => "Compact earlier turns before the context window fills up?".to_string(),
+        };
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some("Context window nearly full".to_string()),
+            subtitle: Some(subtitle),
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            ..Default::default()
+        });
+    }
+
+    pub(crate) fn snooze_compact_prompt(&mut self) {
+        self.compact_prompt = CompactPromptState::Snoozed;
+    }
+
+    pub(crate) fn set_compact_prompt_hidden(&mut self, hidden: bool) {
+        self.config.notices.hide_compact_prompt = Some(hidden);
+        if hidden {
+            self.compact_prompt = CompactPromptState::Idle;
+        }
+    }
+
     fn context_remaining_percent(&self, info: &TokenUsageInfo) -> Option<i64> {
         info.model_context_window.map(|window| {
             info.last_token_usage
@@ -657,7 +881,7 @@ impl ChatWidget {
 
             if !warnings.is_empty() {
                 for warning in warnings {
-                    self.add_to_history(history_cell::new_warning_event(warning));
+                    self.add_to_history(history_cell::new_warning_event(warning, &self.theme));
                 }
                 self.request_redraw();
             }
@@ -680,7 +904,7 @@ impl ChatWidget {
 
     fn on_error(&mut self, message: String) {
         self.finalize_turn();
-        self.add_to_history(history_cell::new_error_event(message));
+        self.add_to_history(history_cell::new_error_event(message, &self.theme));
         self.request_redraw();
 
         // After an error ends the turn, try sending the next queued input.
@@ -688,7 +912,7 @@ impl ChatWidget {
     }
 
     fn on_warning(&mut self, message: impl Into<String>) {
-        self.add_to_history(history_cell::new_warning_event(message.into()));
+        self.add_to_history(history_cell::new_warning_event(message.into(), &self.theme));
         self.request_redraw();
     }
 
@@ -770,6 +994,7 @@ impl ChatWidget {
         if reason != TurnAbortReason::ReviewEnded {
             self.add_to_history(history_cell::new_error_event(
                 "Conversation interrupted - tell the model what to do differently. Something went wrong? Hit `/feedback` to report the issue.".to_owned(),
+                &self.theme,
             ));
         }
 
@@ -965,10 +1190,14 @@ impl ChatWidget {
     pub(crate) fn on_commit_tick(&mut self) {
         if let Some(controller) = self.stream_controller.as_mut() {
             let (cell, is_idle) = controller.on_commit_tick();
+            let revealing = controller.partial_cell().is_some();
             if let Some(cell) = cell {
                 self.bottom_pane.hide_status_indicator();
                 self.add_boxed_history(cell);
                 self.request_redraw();
+            } else if revealing {
+                self.bottom_pane.hide_status_indicator();
+                self.request_redraw();
             }
             if is_idle {
                 self.app_event_tx.send(AppEvent::StopCommitAnimation);
@@ -1023,14 +1252,26 @@ impl ChatWidget {
                 self.needs_final_message_separator = false;
                 needs_redraw = true;
             }
-            self.stream_controller = Some(StreamController::new(
-                self.last_rendered_width.get().map(|w| w.saturating_sub(2)),
-            ));
+            let mut controller =
+                StreamController::new(self.last_rendered_width.get().map(|w| w.saturating_sub(2)));
+            controller.set_render_markdown(self.config.tui_render_markdown);
+            controller.set_reveal(self.config.tui_stream_reveal);
+            controller.set_theme(self.theme);
+            self.stream_controller = Some(controller);
         }
         if let Some(controller) = self.stream_controller.as_mut()
             && controller.push(&delta)
         {
-            self.app_event_tx.send(AppEvent::StartCommitAnimation);
+            if self.config.tui_stream_reveal == StreamReveal::Instant {
+                // Skip the commit animation entirely: completed lines land in one go.
+                if let Some(cell) = controller.drain_queued() {
+                    self.bottom_pane.hide_status_indicator();
+                    self.add_boxed_history(cell);
+                    needs_redraw = true;
+                }
+            } else {
+                self.app_event_tx.send(AppEvent::StartCommitAnimation);
+            }
         }
         if needs_redraw {
             self.request_redraw();
@@ -1099,6 +1340,8 @@ impl ChatWidget {
         // Otherwise, add a failure block.
         if !event.success {
             self.add_to_history(history_cell::new_patch_apply_failure(event.stderr));
+        } else if !event.changes.is_empty() {
+            self.last_applied_patch = Some(event.changes);
         }
     }
 
@@ -1302,6 +1545,8 @@ impl ChatWidget {
                 skills: None,
             }),
             active_cell: None,
+            // Config warnings for the theme are reported once, by the app.
+            theme: Theme::from_config(&config).0,
             config,
             model: model.clone(),
             auth_manager,
@@ -1316,7 +1561,11 @@ impl ChatWidget {
             plan_type: None,
             rate_limit_warnings: RateLimitWarningState::default(),
             rate_limit_switch_prompt: RateLimitSwitchPromptState::default(),
+            compact_prompt: CompactPromptState::default(),
+            prompt_template_noted: false,
+            terminal_focused: true,
             rate_limit_poller: None,
+            pending_user_input: None,
             stream_controller: None,
             running_commands: HashMap::new(),
             suppressed_exec_calls: HashSet::new(),
@@ -1329,6 +1578,7 @@ impl ChatWidget {
             current_status_header: String::from("Working"),
             retry_status_header: None,
             conversation_id: None,
+            conversation_title: None,
             queued_user_messages: VecDeque::new(),
             show_welcome_banner: is_first_run,
             suppress_session_configured_redraw: false,
@@ -1336,11 +1586,34 @@ impl ChatWidget {
             is_review_mode: false,
             pre_review_token_info: None,
             needs_final_message_separator: false,
+            last_applied_patch: None,
             last_rendered_width: std::cell::Cell::new(None),
             feedback,
             current_rollout_path: None,
         };
 
+        widget.bottom_pane.set_theme(widget.theme);
+        widget
+            .bottom_pane
+            .set_expand_file_mentions(widget.config.tui_expand_file_mentions);
+        widget
+            .bottom_pane
+            .set_composer_prompt_glyph(widget.config.tui_composer_prompt.as_deref());
+        widget.refresh_composer_prompt_context();
+        widget.bottom_pane.set_tab_behavior(
+            widget.config.tui_composer_tab_width,
+            widget.config.tui_composer_literal_tab,
+        );
+        widget
+            .bottom_pane
+            .set_show_whitespace(widget.config.tui_composer_show_whitespace);
+        widget
+            .bottom_pane
+            .set_paste_placeholder_threshold(widget.config.tui_paste_placeholder_threshold);
+        widget
+            .bottom_pane
+            .set_composer_line_warning(widget.config.tui_composer_line_warning);
+        widget.begin_skills_load();
         widget.prefetch_rate_limits();
 
         widget
@@ -1386,6 +1659,8 @@ impl ChatWidget {
                 skills: None,
             }),
             active_cell: None,
+            // Config warnings for the theme are reported once, by the app.
+            theme: Theme::from_config(&config).0,
             config,
             model: model.clone(),
             auth_manager,
@@ -1400,7 +1675,11 @@ impl ChatWidget {
             plan_type: None,
             rate_limit_warnings: RateLimitWarningState::default(),
             rate_limit_switch_prompt: RateLimitSwitchPromptState::default(),
+            compact_prompt: CompactPromptState::default(),
+            prompt_template_noted: false,
+            terminal_focused: true,
             rate_limit_poller: None,
+            pending_user_input: None,
             stream_controller: None,
             running_commands: HashMap::new(),
             suppressed_exec_calls: HashSet::new(),
@@ -1413,6 +1692,7 @@ impl ChatWidget {
             current_status_header: String::from("Working"),
             retry_status_header: None,
             conversation_id: None,
+            conversation_title: None,
             queued_user_messages: VecDeque::new(),
             show_welcome_banner: false,
             suppress_session_configured_redraw: true,
@@ -1420,11 +1700,34 @@ impl ChatWidget {
             is_review_mode: false,
             pre_review_token_info: None,
             needs_final_message_separator: false,
+            last_applied_patch: None,
             last_rendered_width: std::cell::Cell::new(None),
             feedback,
             current_rollout_path: None,
         };
 
+        widget.bottom_pane.set_theme(widget.theme);
+        widget
+            .bottom_pane
+            .set_expand_file_mentions(widget.config.tui_expand_file_mentions);
+        widget
+            .bottom_pane
+            .set_composer_prompt_glyph(widget.config.tui_composer_prompt.as_deref());
+        widget.refresh_composer_prompt_context();
+        widget.bottom_pane.set_tab_behavior(
+            widget.config.tui_composer_tab_width,
+            widget.config.tui_composer_literal_tab,
+        );
+        widget
+            .bottom_pane
+            .set_show_whitespace(widget.config.tui_composer_show_whitespace);
+        widget
+            .bottom_pane
+            .set_paste_placeholder_threshold(widget.config.tui_paste_placeholder_threshold);
+        widget
+            .bottom_pane
+            .set_composer_line_warning(widget.config.tui_composer_line_warning);
+        widget.begin_skills_load();
         widget.prefetch_rate_limits();
 
         widget
@@ -1460,9 +1763,10 @@ impl ChatWidget {
                     }
                     Err(err) => {
                         tracing::warn!("failed to paste image: {err}");
-                        self.add_to_history(history_cell::new_error_event(format!(
-                            "Failed to paste image: {err}",
-                        )));
+                        self.add_to_history(history_cell::new_error_event(
+                            format!("Failed to paste image: {err}",),
+                            &self.theme,
+                        ));
                     }
                 }
                 return;
@@ -1490,10 +1794,22 @@ impl ChatWidget {
             _ => {
                 match self.bottom_pane.handle_key_event(key_event) {
                     InputResult::Submitted(text) => {
+                        let image_paths = self.bottom_pane.take_recent_submission_images();
+                        if short_message_check(
+                            &text,
+                            !image_paths.is_empty(),
+                            self.config.tui_confirm_short_messages,
+                        ) == ShortMessageCheck::Confirm
+                        {
+                            self.open_short_message_confirmation(text);
+                            return;
+                        }
                         // If a task is running, queue the user input to be sent after the turn completes.
+                        let file_mentions = self.bottom_pane.take_recent_submission_file_mentions();
                         let user_message = UserMessage {
                             text,
-                            image_paths: self.bottom_pane.take_recent_submission_images(),
+                            image_paths,
+                            file_mentions,
                         };
                         self.queue_user_message(user_message);
                     }
@@ -1527,7 +1843,7 @@ impl ChatWidget {
                 "'/{}' is disabled while a task is in progress.",
                 cmd.command()
             );
-            self.add_to_history(history_cell::new_error_event(message));
+            self.add_to_history(history_cell::new_error_event(message, &self.theme));
             self.request_redraw();
             return;
         }
@@ -1540,11 +1856,33 @@ impl ChatWidget {
                 self.request_redraw();
             }
             SlashCommand::New => {
-                self.app_event_tx.send(AppEvent::NewSession);
+                if self.bottom_pane.composer_is_empty() && !self.bottom_pane.is_task_running() {
+                    self.app_event_tx.send(AppEvent::NewSession { draft: None });
+                } else {
+                    self.open_new_session_confirmation();
+                }
             }
             SlashCommand::Resume => {
                 self.app_event_tx.send(AppEvent::OpenResumePicker);
             }
+            SlashCommand::CopyResume => {
+                self.app_event_tx.send(AppEvent::CopyResumeCommand);
+            }
+            SlashCommand::CopyId => {
+                self.app_event_tx.send(AppEvent::CopyConversationId);
+            }
+            SlashCommand::Title => {
+                self.show_title_prompt();
+            }
+            SlashCommand::Errors => {
+                self.app_event_tx.send(AppEvent::OpenErrorList);
+            }
+            SlashCommand::ApprovalHistory => {
+                self.app_event_tx.send(AppEvent::OpenApprovalHistory);
+            }
+            SlashCommand::History => {
+                self.app_event_tx.send(AppEvent::OpenPromptHistory);
+            }
             SlashCommand::Init => {
                 let init_target = self.config.cwd.join(DEFAULT_PROJECT_DOC_FILENAME);
                 if init_target.exists() {
@@ -1561,6 +1899,9 @@ impl ChatWidget {
                 self.clear_token_usage();
                 self.app_event_tx.send(AppEvent::CodexOp(Op::Compact));
             }
+            SlashCommand::Retry => {
+                self.app_event_tx.send(AppEvent::RetryWithHigherEffort);
+            }
             SlashCommand::Review => {
                 self.open_review_popup();
             }
@@ -1570,6 +1911,9 @@ impl ChatWidget {
             SlashCommand::Approvals => {
                 self.open_approvals_popup();
             }
+            SlashCommand::Sandbox => {
+                self.cycle_sandbox_policy();
+            }
             SlashCommand::Quit | SlashCommand::Exit => {
                 self.request_exit();
             }
@@ -1585,22 +1929,11 @@ impl ChatWidget {
             // SlashCommand::Undo => {
             //     self.app_event_tx.send(AppEvent::CodexOp(Op::Undo));
             // }
+            SlashCommand::RevertPatch => {
+                self.open_revert_patch_confirmation();
+            }
             SlashCommand::Diff => {
-                self.add_diff_in_progress();
-                let tx = self.app_event_tx.clone();
-                tokio::spawn(async move {
-                    let text = match get_git_diff().await {
-                        Ok((is_git_repo, diff_text)) => {
-                            if is_git_repo {
-                                diff_text
-                            } else {
-                                "`/diff` — _not inside a git repository_".to_string()
-                            }
-                        }
-                        Err(e) => format!("Failed to compute diff: {e}"),
-                    };
-                    tx.send(AppEvent::DiffResult(text));
-                });
+                self.request_git_diff();
             }
             SlashCommand::Mention => {
                 self.insert_str("@");
@@ -1608,9 +1941,19 @@ impl ChatWidget {
             SlashCommand::Skills => {
                 self.insert_str("$");
             }
+            SlashCommand::SkillsPreview => {
+                self.preview_skill_injections();
+            }
             SlashCommand::Status => {
                 self.add_status_output();
             }
+            SlashCommand::Settings => {
+                self.open_session_settings_popup();
+            }
+            SlashCommand::Scroll => {
+                self.app_event_tx
+                    .send(AppEvent::OpenScrollTuning { selected: None });
+            }
             SlashCommand::Mcp => {
                 self.add_mcp_output();
             }
@@ -1672,17 +2015,20 @@ impl ChatWidget {
     // Returns true if caller should skip rendering this frame (a future frame is scheduled).
     pub(crate) fn handle_paste_burst_tick(&mut self, frame_requester: FrameRequester) -> bool {
         if self.bottom_pane.flush_paste_burst_if_due() {
-            // A paste just flushed; request an immediate redraw and skip this frame.
+            // A paste just flushed; clear the "pasting…" cue, request an immediate redraw
+            // and skip this frame.
+            self.bottom_pane.update_paste_burst_indicator();
             self.request_redraw();
             true
         } else if self.bottom_pane.is_in_paste_burst() {
-            // While capturing a burst, schedule a follow-up tick and skip this frame
-            // to avoid redundant renders between ticks.
+            // While capturing a burst, schedule a follow-up tick. Skip this frame to avoid
+            // redundant renders between ticks, unless the "pasting…" cue just appeared.
             frame_requester.schedule_frame_in(
                 crate::bottom_pane::ChatComposer::recommended_paste_flush_delay(),
             );
-            true
+            !self.bottom_pane.update_paste_burst_indicator()
         } else {
+            self.bottom_pane.update_paste_burst_indicator();
             false
         }
     }
@@ -1717,7 +2063,11 @@ impl ChatWidget {
     }
 
     fn submit_user_message(&mut self, user_message: UserMessage) {
-        let UserMessage { text, image_paths } = user_message;
+        let UserMessage {
+            text,
+            image_paths,
+            file_mentions,
+        } = user_message;
         if text.is_empty() && image_paths.is_empty() {
             return;
         }
@@ -1742,16 +2092,31 @@ impl ChatWidget {
             return;
         }
 
-        if !text.is_empty() {
+        let templated = apply_prompt_template(&self.config.prompt_template, &text);
+        if let Some(templated) = templated.as_ref() {
+            items.push(UserInput::Text {
+                text: templated.clone(),
+            });
+        } else if !text.is_empty() {
             items.push(UserInput::Text { text: text.clone() });
         }
 
+        // File attachments go right after the prompt text; they are read off the UI thread.
+        let attachments_at = items.len();
+        let file_mentions = if self.config.tui_expand_file_mentions {
+            file_mentions
+        } else {
+            Vec::new()
+        };
+
         for path in image_paths {
             items.push(UserInput::LocalImage { path });
         }
 
+        let mut ambiguous_skill_mentions = Vec::new();
         if let Some(skills) = self.bottom_pane.skills() {
-            let skill_mentions = find_skill_mentions(&text, skills);
+            let (skill_mentions, ambiguous) = find_skill_mentions(&text, skills);
+            ambiguous_skill_mentions = ambiguous;
             for skill in skill_mentions {
                 items.push(UserInput::Skill {
                     name: skill.name.clone(),
@@ -1760,11 +2125,7 @@ impl ChatWidget {
             }
         }
 
-        self.codex_op_tx
-            .send(Op::UserInput { items })
-            .unwrap_or_else(|e| {
-                tracing::error!("failed to send message: {e}");
-            });
+        self.send_user_input(items, attachments_at, file_mentions);
 
         // Persist the text to cross-session message history.
         if !text.is_empty() {
@@ -1776,12 +2137,103 @@ impl ChatWidget {
         }
 
         // Only show the text portion in conversation history.
-        if !text.is_empty() {
-            self.add_to_history(history_cell::new_user_prompt(text));
+        let show_template = self.config.prompt_template.show_in_transcript;
+        match templated {
+            Some(templated) if show_template => {
+                self.add_to_history(history_cell::new_user_prompt(templated, &self.theme));
+            }
+            Some(_) => {
+                self.add_to_history(history_cell::new_user_prompt(text, &self.theme));
+                if !self.prompt_template_noted {
+                    self.prompt_template_noted = true;
+                    self.add_info_message(
+                        "Messages are sent with your prompt.prefix / prompt.suffix template."
+                            .to_string(),
+                        Some("Set prompt.show_in_transcript to show it here.".to_string()),
+                    );
+                }
+            }
+            None if !text.is_empty() => {
+                self.add_to_history(history_cell::new_user_prompt(text, &self.theme));
+            }
+            None => {}
+        }
+        for warning in ambiguous_skill_mentions {
+            self.add_to_history(history_cell::new_warning_event(warning, &self.theme));
         }
         self.needs_final_message_separator = false;
     }
 
+    /// Send `items` to the agent, first inserting the contents of `file_mentions` at
+    /// `attachments_at`.
+    ///
+    /// Mentioned files are read on a blocking worker so a large or slow file never stalls the
+    /// UI. While a read is in flight, later submissions are chained behind it so the agent
+    /// still receives them in order. Mentions that don't resolve to a file are sent as
+    /// literal text and reported as warnings once the read finishes.
+    fn send_user_input(
+        &mut self,
+        mut items: Vec<UserInput>,
+        attachments_at: usize,
+        file_mentions: Vec<String>,
+    ) {
+        let previous = self
+            .pending_user_input
+            .take()
+            .filter(|handle| !handle.is_finished());
+        if file_mentions.is_empty() && previous.is_none() {
+            self.codex_op_tx
+                .send(Op::UserInput { items })
+                .unwrap_or_else(|e| {
+                    tracing::error!("failed to send message: {e}");
+                });
+            return;
+        }
+
+        let cwd = self.config.cwd.clone();
+        let theme = self.theme;
+        let codex_op_tx = self.codex_op_tx.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        self.pending_user_input = Some(tokio::spawn(async move {
+            if let Some(previous) = previous {
+                let _ = previous.await;
+            }
+            if !file_mentions.is_empty() {
+                let expansion =
+                    read_file_mentions(file_mentions, cwd, FILE_MENTION_MAX_BYTES).await;
+                items.splice(
+                    attachments_at..attachments_at,
+                    expansion
+                        .attachments
+                        .into_iter()
+                        .map(|text| UserInput::Text { text }),
+                );
+                for path in expansion.missing {
+                    app_event_tx.send(AppEvent::InsertHistoryCell(Box::new(
+                        history_cell::new_warning_event(
+                            format!(
+                                "Could not attach @{path}: not a readable file. Sent as plain text."
+                            ),
+                            &theme,
+                        ),
+                    )));
+                }
+                for mention in expansion.malformed {
+                    app_event_tx.send(AppEvent::InsertHistoryCell(Box::new(
+                        history_cell::new_warning_event(format!(
+                            "Could not attach @{mention}: invalid line range (expected @path:START-END). Sent as plain text."
+                        ), &theme),
+                    )));
+                }
+            }
+            codex_op_tx
+                .send(Op::UserInput { items })
+                .unwrap_or_else(|e| {
+                    tracing::error!("failed to send message: {e}");
+                });
+        }));
+    }
+
     /// Replay a subset of initial events into the UI to seed the transcript when
     /// resuming an existing session. This approximates the live event flow and
     /// is intentionally conservative: only safe-to-replay items are rendered to
@@ -1953,12 +2405,13 @@ impl ChatWidget {
                     tracing::error!("Reviewer failed to output a response.");
                     self.add_to_history(history_cell::new_error_event(
                         "Reviewer failed to output a response.".to_owned(),
+                        &self.theme,
                     ));
                 } else {
                     // Show explanation when there are no structured findings.
                     let mut rendered: Vec<ratatui::text::Line<'static>> = vec!["".into()];
                     append_markdown(&explanation, None, &mut rendered);
-                    let body_cell = AgentMessageCell::new(rendered, false);
+                    let body_cell = AgentMessageCell::new(rendered, false, &self.theme);
                     self.app_event_tx
                         .send(AppEvent::InsertHistoryCell(Box::new(body_cell)));
                 }
@@ -1978,7 +2431,10 @@ impl ChatWidget {
     fn on_user_message_event(&mut self, event: UserMessageEvent) {
         let message = event.message.trim();
         if !message.is_empty() {
-            self.add_to_history(history_cell::new_user_prompt(message.to_string()));
+            self.add_to_history(history_cell::new_user_prompt(
+                message.to_string(),
+                &self.theme,
+            ));
         }
     }
 
@@ -1990,20 +2446,44 @@ impl ChatWidget {
         self.frame_requester.schedule_frame();
     }
 
+    /// Queue a desktop notification. Nothing is queued while the terminal has focus; the user is
+    /// already looking at it.
     fn notify(&mut self, notification: Notification) {
-        if !notification.allowed_for(&self.config.tui_notifications) {
+        if self.terminal_focused || !notification.allowed_for(&self.config.tui_notifications) {
             return;
         }
         self.pending_notification = Some(notification);
         self.request_redraw();
     }
 
+    /// Drop a queued notification that has not been posted yet, e.g. because focus returned first.
+    pub(crate) fn clear_pending_notification(&mut self) {
+        self.pending_notification = None;
+    }
+
     pub(crate) fn maybe_post_pending_notification(&mut self, tui: &mut crate::tui::Tui) {
-        if let Some(notif) = self.pending_notification.take() {
+        if let Some(notif) = self.pending_notification.take()
+            && !self.in_notification_quiet_hours(chrono::Local::now().time())
+        {
             tui.notify(notif.display());
         }
     }
 
+    /// Whether `time` falls within `tui.notification_quiet_hours`. History cells still appear;
+    /// only the desktop notification is dropped.
+    fn in_notification_quiet_hours(&self, time: chrono::NaiveTime) -> bool {
+        let Some(spec) = self.config.tui_notification_quiet_hours.as_deref() else {
+            return false;
+        };
+        match QuietHours::parse(spec) {
+            Ok(quiet_hours) => quiet_hours.contains(time),
+            Err(err) => {
+                tracing::warn!("ignoring tui.notification_quiet_hours: {err}");
+                false
+            }
+        }
+    }
+
     /// Mark the active cell as failed (✗) and flush it into history.
     fn finalize_active_cell_as_failed(&mut self) {
         if let Some(mut cell) = self.active_cell.take() {
@@ -2039,6 +2519,27 @@ impl ChatWidget {
         self.bottom_pane.set_queued_user_messages(messages);
     }
 
+    /// Compute the working-tree diff in the background and deliver it as
+    /// [`AppEvent::DiffResult`], which opens the diff overlay. Used by `/diff` and `Alt+D`; no
+    /// pending patch or approval is needed.
+    pub(crate) fn request_git_diff(&mut self) {
+        self.add_diff_in_progress();
+        let tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let text = match get_git_diff().await {
+                Ok((is_git_repo, diff_text)) => {
+                    if is_git_repo {
+                        diff_text
+                    } else {
+                        "`/diff` — _not inside a git repository_".to_string()
+                    }
+                }
+                Err(e) => format!("Failed to compute diff: {e}"),
+            };
+            tx.send(AppEvent::DiffResult(text));
+        });
+    }
+
     pub(crate) fn add_diff_in_progress(&mut self) {
         self.request_redraw();
     }
@@ -2303,52 +2804,282 @@ impl ChatWidget {
         }
     }
 
-    pub(crate) fn open_all_models_popup(&mut self, presets: Vec<ModelPreset>) {
-        if presets.is_empty() {
+    /// Show the session's answered approval requests; selecting one jumps the transcript to it.
+    pub(crate) fn open_approval_history(&mut self, entries: Vec<ApprovalHistoryEntry>) {
+        if entries.is_empty() {
             self.add_info_message(
-                "No additional models are available right now.".to_string(),
+                "No approval requests have been answered in this session.".to_string(),
                 None,
             );
             return;
         }
 
-        let mut items: Vec<SelectionItem> = Vec::new();
-        for preset in presets.into_iter() {
-            let description =
-                (!preset.description.is_empty()).then_some(preset.description.to_string());
-            let is_current = preset.model == self.model;
-            let single_supported_effort = preset.supported_reasoning_efforts.len() == 1;
-            let preset_for_action = preset.clone();
-            let actions: Vec<SelectionAction> = vec![Box::new(move |tx| {
-                let preset_for_event = preset_for_action.clone();
-                tx.send(AppEvent::OpenReasoningPopup {
-                    model: preset_for_event,
-                });
-            })];
-            items.push(SelectionItem {
-                name: preset.display_name.clone(),
-                description,
-                is_current,
-                is_default: preset.is_default,
-                actions,
-                dismiss_on_select: single_supported_effort,
-                ..Default::default()
-            });
+        let initial_selected_idx = entries.len() - 1;
+        let items: Vec<SelectionItem> = entries
+            .into_iter()
+            .map(|entry| {
+                let ApprovalHistoryEntry { cell_index, record } = entry;
+                let marker = if record.outcome.is_approved() {
+                    "✔"
+                } else {
+                    "✗"
+                };
+                let name = format!("{marker} {}: {}", record.kind.label(), record.subject);
+                let actions: Vec<SelectionAction> = vec![Box::new(move |tx| {
+                    tx.send(AppEvent::ScrollTranscriptToCell(cell_index));
+                })];
+                SelectionItem {
+                    search_value: Some(name.clone()),
+                    name,
+                    description: Some(record.outcome.label().to_string()),
+                    actions,
+                    dismiss_on_select: true,
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some("Approval history".to_string()),
+            subtitle: Some("Select a request to jump to it in the transcript.".to_string()),
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            is_searchable: true,
+            search_placeholder: Some("Type to search requests".to_string()),
+            initial_selected_idx: Some(initial_selected_idx),
+            ..Default::default()
+        });
+    }
+
+    /// Show the errors in the transcript; selecting one jumps the transcript to it.
+    /// Ask which language to tag a composer code fence with; the first entry fences without one.
+    pub(crate) fn open_code_fence_popup(&mut self) {
+        let items: Vec<SelectionItem> = std::iter::once("")
+            .chain(CODE_FENCE_LANGUAGES.iter().copied())
+            .map(|language| {
+                let fence_language = language.to_string();
+                let actions: Vec<SelectionAction> = vec![Box::new(move |tx| {
+                    tx.send(AppEvent::FenceComposerLine {
+                        language: fence_language.clone(),
+                    });
+                })];
+                let name = if language.is_empty() {
+                    "No language".to_string()
+                } else {
+                    language.to_string()
+                };
+                SelectionItem {
+                    search_value: Some(name.clone()),
+                    name,
+                    actions,
+                    dismiss_on_select: true,
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some("Fence as code".to_string()),
+            subtitle: Some("Wrap the current line in a ``` code fence.".to_string()),
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            is_searchable: true,
+            search_placeholder: Some("Type to search languages".to_string()),
+            ..Default::default()
+        });
+    }
+
+    pub(crate) fn fence_composer_line(&mut self, language: &str) {
+        self.bottom_pane.fence_composer_line(language);
+    }
+
+    pub(crate) fn open_error_list(&mut self, entries: Vec<ErrorEntry>) {
+        if entries.is_empty() {
+            self.add_info_message("No errors in this session.".to_string(), None);
+            return;
         }
 
+        let error_count = entries.len();
+        let items: Vec<SelectionItem> = entries
+            .into_iter()
+            .map(|entry| {
+                let ErrorEntry {
+                    cell_index,
+                    message,
+                    count,
+                } = entry;
+                let actions: Vec<SelectionAction> = vec![Box::new(move |tx| {
+                    tx.send(AppEvent::ScrollTranscriptToCell(cell_index));
+                })];
+                SelectionItem {
+                    search_value: Some(message.clone()),
+                    name: message,
+                    description: (count > 1).then(|| format!("×{count}")),
+                    actions,
+                    dismiss_on_select: true,
+                    ..Default::default()
+                }
+            })
+            .collect();
+
         self.bottom_pane.show_selection_view(SelectionViewParams {
-            title: Some("Select Model and Effort".to_string()),
+            title: Some(format!("Errors ({error_count})")),
             subtitle: Some(
-                "Access legacy models by running codex -m <model_name> or in your config.toml"
-                    .to_string(),
+                "Select an error to jump to it. Alt+N / Alt+P step through them.".to_string(),
             ),
-            footer_hint: Some("Press enter to select reasoning effort, or esc to dismiss.".into()),
+            footer_hint: Some(standard_popup_hint_line()),
             items,
+            is_searchable: true,
+            search_placeholder: Some("Type to search errors".to_string()),
+            initial_selected_idx: Some(error_count - 1),
             ..Default::default()
         });
     }
 
-    fn model_selection_actions(
+    /// Show the prompts sent in this session, newest first, with incremental filtering. Picking
+    /// one asks whether to edit it in the composer or send it again.
+    pub(crate) fn open_prompt_history(&mut self, prompts: Vec<String>) {
+        if prompts.is_empty() {
+            self.add_info_message(
+                "No prompts have been sent in this session yet.".to_string(),
+                None,
+            );
+            return;
+        }
+
+        let items: Vec<SelectionItem> = prompts
+            .into_iter()
+            .map(|prompt| {
+                let line_count = prompt.lines().count();
+                let description = (line_count > 1).then(|| format!("{line_count} lines"));
+                let name = crate::prompt_history::prompt_label(&prompt);
+                let selected = prompt.clone();
+                let actions: Vec<SelectionAction> = vec![Box::new(move |tx| {
+                    tx.send(AppEvent::PromptHistorySelected(selected.clone()));
+                })];
+                SelectionItem {
+                    name,
+                    description,
+                    search_value: Some(prompt),
+                    actions,
+                    dismiss_on_select: true,
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some("Prompt history".to_string()),
+            subtitle: Some("Select a prompt to edit it or send it again.".to_string()),
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            is_searchable: true,
+            search_placeholder: Some("Type to filter prompts".to_string()),
+            search_matcher: Some(crate::prompt_history::prompt_matches_filter),
+            ..Default::default()
+        });
+    }
+
+    /// Ask what to do with a prompt picked from `/history`.
+    pub(crate) fn open_prompt_history_actions(&mut self, prompt: String) {
+        let mut header_children: Vec<Box<dyn Renderable>> = Vec::new();
+        header_children.push(Box::new(Line::from("Reuse this prompt?").bold()));
+        header_children.push(Box::new(
+            Line::from(crate::prompt_history::prompt_label(&prompt)).dim(),
+        ));
+        let header = ColumnRenderable::with(header_children);
+
+        let edit_text = prompt.clone();
+        let items = vec![
+            SelectionItem {
+                name: "Edit in composer".to_string(),
+                description: Some("Replace the composer text with this prompt".to_string()),
+                actions: vec![Box::new(move |tx| {
+                    tx.send(AppEvent::ReusePrompt {
+                        text: edit_text.clone(),
+                        send: false,
+                    });
+                })],
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+            SelectionItem {
+                name: "Send again".to_string(),
+                description: Some("Queued if a task is running".to_string()),
+                actions: vec![Box::new(move |tx| {
+                    tx.send(AppEvent::ReusePrompt {
+                        text: prompt.clone(),
+                        send: true,
+                    });
+                })],
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+        ];
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            header: Box::new(header),
+            ..Default::default()
+        });
+    }
+
+    /// Put a prompt from `/history` back into the composer, or send it again.
+    pub(crate) fn reuse_prompt(&mut self, text: String, send: bool) {
+        if send {
+            self.queue_user_message(text.into());
+        } else {
+            self.bottom_pane.set_composer_text(text);
+        }
+    }
+
+    pub(crate) fn open_all_models_popup(&mut self, presets: Vec<ModelPreset>) {
+        if presets.is_empty() {
+            self.add_info_message(
+                "No additional models are available right now.".to_string(),
+                None,
+            );
+            return;
+        }
+
+        let mut items: Vec<SelectionItem> = Vec::new();
+        for preset in presets.into_iter() {
+            let description =
+                (!preset.description.is_empty()).then_some(preset.description.to_string());
+            let is_current = preset.model == self.model;
+            let single_supported_effort = preset.supported_reasoning_efforts.len() == 1;
+            let preset_for_action = preset.clone();
+            let actions: Vec<SelectionAction> = vec![Box::new(move |tx| {
+                let preset_for_event = preset_for_action.clone();
+                tx.send(AppEvent::OpenReasoningPopup {
+                    model: preset_for_event,
+                });
+            })];
+            items.push(SelectionItem {
+                name: preset.display_name.clone(),
+                description,
+                is_current,
+                is_default: preset.is_default,
+                actions,
+                dismiss_on_select: single_supported_effort,
+                ..Default::default()
+            });
+        }
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some("Select Model and Effort".to_string()),
+            subtitle: Some(
+                "Access legacy models by running codex -m <model_name> or in your config.toml"
+                    .to_string(),
+            ),
+            footer_hint: Some("Press enter to select reasoning effort, or esc to dismiss.".into()),
+            items,
+            ..Default::default()
+        });
+    }
+
+    fn model_selection_actions(
         model_for_action: String,
         effort_for_action: Option<ReasoningEffortConfig>,
     ) -> Vec<SelectionAction> {
@@ -2525,6 +3256,96 @@ impl ChatWidget {
         }
     }
 
+    /// Advance the reasoning effort to the next level the current model supports (Alt+E).
+    ///
+    /// Applies the new effort to the session and returns it, or `None` when the model offers no
+    /// other effort to switch to. Unlike the popup, the quick cycle is not persisted to config.
+    pub(crate) fn cycle_reasoning_effort(&mut self) -> Option<ReasoningEffortConfig> {
+        let (current, supported) = self.reasoning_effort_options();
+        let next = next_reasoning_effort(current, &supported)?;
+        self.app_event_tx
+            .send(AppEvent::CodexOp(Op::OverrideTurnContext {
+                cwd: None,
+                approval_policy: None,
+                sandbox_policy: None,
+                model: None,
+                effort: Some(Some(next)),
+                summary: None,
+            }));
+        self.app_event_tx
+            .send(AppEvent::UpdateReasoningEffort(Some(next)));
+        Some(next)
+    }
+
+    /// Resend `prompt` with the reasoning effort raised one supported level (`/retry`).
+    ///
+    /// Refuses while a task is running and when the model is already at its highest effort.
+    pub(crate) fn retry_with_higher_effort(&mut self, prompt: String) {
+        if self.bottom_pane.is_task_running() {
+            self.add_error_message(
+                "Wait for the current task to finish before retrying.".to_string(),
+            );
+            return;
+        }
+        let (current, supported) = self.reasoning_effort_options();
+        let Some(next) = higher_reasoning_effort(current, &supported) else {
+            self.add_info_message(
+                format!("{} is already at its highest reasoning effort.", self.model),
+                None,
+            );
+            return;
+        };
+
+        // Submit the override directly so it reaches the agent ahead of the resent message.
+        self.submit_op(Op::OverrideTurnContext {
+            cwd: None,
+            approval_policy: None,
+            sandbox_policy: None,
+            model: None,
+            effort: Some(Some(next)),
+            summary: None,
+        });
+        self.config.model_reasoning_effort = Some(next);
+        self.app_event_tx
+            .send(AppEvent::UpdateReasoningEffort(Some(next)));
+        self.add_info_message(
+            format!(
+                "Retrying with {} reasoning effort.",
+                Self::reasoning_effort_label(next)
+            ),
+            None,
+        );
+        self.submit_user_message(prompt.into());
+    }
+
+    /// The session's current reasoning effort (falling back to the model default) and the
+    /// efforts the current model supports; an empty list means the model does not say.
+    fn reasoning_effort_options(
+        &self,
+    ) -> (Option<ReasoningEffortConfig>, Vec<ReasoningEffortConfig>) {
+        let preset = self
+            .models_manager
+            .try_list_models(&self.config)
+            .ok()
+            .and_then(|models| models.into_iter().find(|preset| preset.model == self.model));
+        let supported: Vec<ReasoningEffortConfig> = preset
+            .as_ref()
+            .map(|preset| {
+                preset
+                    .supported_reasoning_efforts
+                    .iter()
+                    .map(|option| option.effort)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let current = self.config.model_reasoning_effort.or_else(|| {
+            preset
+                .as_ref()
+                .map(|preset| preset.default_reasoning_effort)
+        });
+        (current, supported)
+    }
+
     fn apply_model_and_effort(&self, model: String, effort: Option<ReasoningEffortConfig>) {
         self.app_event_tx
             .send(AppEvent::CodexOp(Op::OverrideTurnContext {
@@ -2563,52 +3384,7 @@ impl ChatWidget {
             let name = preset.label.to_string();
             let description_text = preset.description;
             let description = Some(description_text.to_string());
-            let requires_confirmation = preset.id == "full-access"
-                && !self
-                    .config
-                    .notices
-                    .hide_full_access_warning
-                    .unwrap_or(false);
-            let actions: Vec<SelectionAction> = if requires_confirmation {
-                let preset_clone = preset.clone();
-                vec![Box::new(move |tx| {
-                    tx.send(AppEvent::OpenFullAccessConfirmation {
-                        preset: preset_clone.clone(),
-                    });
-                })]
-            } else if preset.id == "auto" {
-                #[cfg(target_os = "windows")]
-                {
-                    if codex_core::get_platform_sandbox().is_none() {
-                        let preset_clone = preset.clone();
-                        vec![Box::new(move |tx| {
-                            tx.send(AppEvent::OpenWindowsSandboxEnablePrompt {
-                                preset: preset_clone.clone(),
-                            });
-                        })]
-                    } else if let Some((sample_paths, extra_count, failed_scan)) =
-                        self.world_writable_warning_details()
-                    {
-                        let preset_clone = preset.clone();
-                        vec![Box::new(move |tx| {
-                            tx.send(AppEvent::OpenWorldWritableWarningConfirmation {
-                                preset: Some(preset_clone.clone()),
-                                sample_paths: sample_paths.clone(),
-                                extra_count,
-                                failed_scan,
-                            });
-                        })]
-                    } else {
-                        Self::approval_preset_actions(preset.approval, preset.sandbox.clone())
-                    }
-                }
-                #[cfg(not(target_os = "windows"))]
-                {
-                    Self::approval_preset_actions(preset.approval, preset.sandbox.clone())
-                }
-            } else {
-                Self::approval_preset_actions(preset.approval, preset.sandbox.clone())
-            };
+            let actions = self.approval_preset_selection_actions(&preset);
             items.push(SelectionItem {
                 name,
                 description,
@@ -2628,6 +3404,144 @@ impl ChatWidget {
         });
     }
 
+    /// Show the effective turn context (`/settings`): model, reasoning effort, approval policy, and
+    /// sandbox, each opening the picker that changes it. The working directory is shown for
+    /// reference only since it cannot change mid-session.
+    pub(crate) fn open_session_settings_popup(&mut self) {
+        let open_model: SelectionAction = Box::new(|tx| tx.send(AppEvent::OpenModelPopup));
+        let open_approvals = || -> Vec<SelectionAction> {
+            vec![Box::new(|tx| tx.send(AppEvent::OpenApprovalsPopup))]
+        };
+
+        let (effort, _) = self.reasoning_effort_options();
+        let preset = self
+            .models_manager
+            .try_list_models(&self.config)
+            .ok()
+            .and_then(|models| models.into_iter().find(|preset| preset.model == self.model));
+        let reasoning_action: SelectionAction = match preset {
+            Some(preset) => Box::new(move |tx| {
+                tx.send(AppEvent::OpenReasoningPopup {
+                    model: preset.clone(),
+                });
+            }),
+            None => Box::new(|tx| tx.send(AppEvent::OpenModelPopup)),
+        };
+
+        let items = vec![
+            SelectionItem {
+                name: "Model".to_string(),
+                description: Some(self.model.clone()),
+                actions: vec![open_model],
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+            SelectionItem {
+                name: "Reasoning effort".to_string(),
+                description: Some(
+                    effort
+                        .map(Self::reasoning_effort_label)
+                        .unwrap_or("Default")
+                        .to_string(),
+                ),
+                actions: vec![reasoning_action],
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+            SelectionItem {
+                name: "Approval policy".to_string(),
+                description: Some(self.config.approval_policy.value().to_string()),
+                actions: open_approvals(),
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+            SelectionItem {
+                name: "Sandbox".to_string(),
+                description: Some(summarize_sandbox_policy(self.config.sandbox_policy.get())),
+                actions: open_approvals(),
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+        ];
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some("Session Settings".to_string()),
+            subtitle: Some(format!("Working directory: {}", self.config.cwd.display())),
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            ..Default::default()
+        });
+    }
+
+    /// Actions that apply `preset`, routing through the full-access confirmation and the Windows
+    /// sandbox checks first when those apply. Shared by `/approvals` and the Alt+S quick toggle.
+    fn approval_preset_selection_actions(&self, preset: &ApprovalPreset) -> Vec<SelectionAction> {
+        let requires_confirmation = preset.id == "full-access"
+            && !self
+                .config
+                .notices
+                .hide_full_access_warning
+                .unwrap_or(false);
+        if requires_confirmation {
+            let preset_clone = preset.clone();
+            vec![Box::new(move |tx| {
+                tx.send(AppEvent::OpenFullAccessConfirmation {
+                    preset: preset_clone.clone(),
+                });
+            })]
+        } else if preset.id == "auto" {
+            #[cfg(target_os = "windows")]
+            {
+                if codex_core::get_platform_sandbox().is_none() {
+                    let preset_clone = preset.clone();
+                    vec![Box::new(move |tx| {
+                        tx.send(AppEvent::OpenWindowsSandboxEnablePrompt {
+                            preset: preset_clone.clone(),
+                        });
+                    })]
+                } else if let Some((sample_paths, extra_count, failed_scan)) =
+                    self.world_writable_warning_details()
+                {
+                    let preset_clone = preset.clone();
+                    vec![Box::new(move |tx| {
+                        tx.send(AppEvent::OpenWorldWritableWarningConfirmation {
+                            preset: Some(preset_clone.clone()),
+                            sample_paths: sample_paths.clone(),
+                            extra_count,
+                            failed_scan,
+                        });
+                    })]
+                } else {
+                    Self::approval_preset_actions(preset.approval, preset.sandbox.clone())
+                }
+            }
+            #[cfg(not(target_os = "windows"))]
+            {
+                Self::approval_preset_actions(preset.approval, preset.sandbox.clone())
+            }
+        } else {
+            Self::approval_preset_actions(preset.approval, preset.sandbox.clone())
+        }
+    }
+
+    /// Switch to the next approval preset by sandbox strictness (Alt+S, `/sandbox`):
+    /// read-only → workspace-write → full access → read-only.
+    ///
+    /// Goes through the same confirmations as picking the preset in `/approvals`.
+    pub(crate) fn cycle_sandbox_policy(&mut self) {
+        let presets = builtin_approval_presets();
+        let Some(next) = next_sandbox_preset(self.config.sandbox_policy.get(), &presets) else {
+            return;
+        };
+        self.add_info_message(
+            format!("Switching approval mode to {}.", next.label),
+            Some(next.description.to_string()),
+        );
+        for action in self.approval_preset_selection_actions(&next) {
+            action(&self.app_event_tx);
+        }
+    }
+
     fn approval_preset_actions(
         approval: AskForApproval,
         sandbox: SandboxPolicy,
@@ -2699,6 +3613,274 @@ impl ChatWidget {
         None
     }
 
+    /// Ask before sending a suspiciously short message (`tui.confirm_short_messages`).
+    ///
+    /// The text goes back into the composer so dismissing the popup leaves it ready to edit.
+    fn open_short_message_confirmation(&mut self, text: String) {
+        self.bottom_pane.set_composer_text(text.clone());
+
+        let preview = if text.trim().is_empty() {
+            "This message is only whitespace.".to_string()
+        } else {
+            format!("Send \"{text}\"?")
+        };
+        let mut header_children: Vec<Box<dyn Renderable>> = Vec::new();
+        header_children.push(Box::new(Line::from("That message is very short.").bold()));
+        header_children.push(Box::new(Line::from(preview).dim()));
+        let header = ColumnRenderable::with(header_children);
+
+        let items = vec![
+            SelectionItem {
+                name: "Send anyway".to_string(),
+                actions: vec![Box::new(move |tx| {
+                    tx.send(AppEvent::SubmitConfirmedMessage(text.clone()));
+                })],
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+            SelectionItem {
+                name: "Keep editing".to_string(),
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+        ];
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            header: Box::new(header),
+            ..Default::default()
+        });
+    }
+
+    /// Send a message the user confirmed from the short-message prompt.
+    pub(crate) fn submit_confirmed_message(&mut self, text: String) {
+        self.bottom_pane.set_composer_text(String::new());
+        self.queue_user_message(text.into());
+    }
+
+    /// Confirm `/new` when it would discard an unsent draft or a running task.
+    ///
+    /// When the composer has text the user can carry it into the new session or drop it.
+    pub(crate) fn open_new_session_confirmation(&mut self) {
+        let draft = self.bottom_pane.composer_text();
+        let has_draft = !draft.trim().is_empty();
+        let task_running = self.bottom_pane.is_task_running();
+
+        let mut header_children: Vec<Box<dyn Renderable>> = Vec::new();
+        header_children.push(Box::new(Line::from("Start a new chat?").bold()));
+        let detail = match (has_draft, task_running) {
+            (true, true) => "The current task will be stopped and you have an unsent message.",
+            (true, false) => "You have an unsent message in the composer.",
+            (false, _) => "The current task will be stopped.",
+        };
+        header_children.push(Box::new(Line::from(detail).dim()));
+        let header = ColumnRenderable::with(header_children);
+
+        let mut items = Vec::new();
+        if has_draft {
+            let keep_draft = draft.clone();
+            items.push(SelectionItem {
+                name: "Start new chat and keep draft".to_string(),
+                description: Some("Carry the unsent message into the new chat".to_string()),
+                actions: vec![Box::new(move |tx| {
+                    tx.send(AppEvent::NewSession {
+                        draft: Some(keep_draft.clone()),
+                    });
+                })],
+                dismiss_on_select: true,
+                ..Default::default()
+            });
+        }
+        items.push(SelectionItem {
+            name: if has_draft {
+                "Start new chat and discard draft".to_string()
+            } else {
+                "Start new chat".to_string()
+            },
+            description: None,
+            actions: vec![Box::new(|tx| {
+                tx.send(AppEvent::NewSession { draft: None });
+            })],
+            dismiss_on_select: true,
+            ..Default::default()
+        });
+        items.push(SelectionItem {
+            name: "Cancel".to_string(),
+            description: Some("Stay in the current chat".to_string()),
+            dismiss_on_select: true,
+            ..Default::default()
+        });
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            header: Box::new(header),
+            ..Default::default()
+        });
+    }
+
+    /// Show the `/scroll` popup for nudging the live scroll settings described by `summary`.
+    ///
+    /// Each adjustment reopens the popup with the updated values and `selected` highlighted, so
+    /// the user can keep nudging until scrolling feels right.
+    pub(crate) fn open_scroll_tuning_popup(&mut self, summary: String, selected: Option<usize>) {
+        let tune =
+            |name: &str, description: &str, tuning: ScrollTuning, index: usize| SelectionItem {
+                name: name.to_string(),
+                description: Some(description.to_string()),
+                actions: vec![Box::new(move |tx| {
+                    tx.send(AppEvent::TuneScroll {
+                        tuning,
+                        selected: index,
+                    });
+                })],
+                dismiss_on_select: true,
+                ..Default::default()
+            };
+        let mut items = vec![
+            tune(
+                "Events per tick +1",
+                "Use when one wheel notch scrolls too far",
+                ScrollTuning::EventsPerTick(1),
+                0,
+            ),
+            tune(
+                "Events per tick -1",
+                "Use when one wheel notch barely scrolls",
+                ScrollTuning::EventsPerTick(-1),
+                1,
+            ),
+            tune(
+                "Wheel lines per tick +1",
+                "Scroll more lines per wheel notch",
+                ScrollTuning::WheelLinesPerTick(1),
+                2,
+            ),
+            tune(
+                "Wheel lines per tick -1",
+                "Scroll fewer lines per wheel notch",
+                ScrollTuning::WheelLinesPerTick(-1),
+                3,
+            ),
+            tune(
+                "Invert direction",
+                "Flip which way the wheel scrolls",
+                ScrollTuning::ToggleInvert,
+                4,
+            ),
+        ];
+        items.push(SelectionItem {
+            name: "Save to config.toml".to_string(),
+            description: Some("Keep these settings for future sessions".to_string()),
+            actions: vec![Box::new(|tx| {
+                tx.send(AppEvent::PersistScrollTuning);
+            })],
+            dismiss_on_select: true,
+            ..Default::default()
+        });
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some("Scroll settings".to_string()),
+            subtitle: Some(summary),
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            initial_selected_idx: selected,
+            ..Default::default()
+        });
+    }
+
+    /// Confirm `/revert-patch`, warning about files that changed since the patch was applied.
+    fn open_revert_patch_confirmation(&mut self) {
+        let Some(changes) = self.last_applied_patch.as_ref() else {
+            self.add_info_message("No applied patch to revert.".to_string(), None);
+            return;
+        };
+        let plan = plan_revert(changes, &self.config.cwd);
+        let display = |paths: &[PathBuf]| {
+            paths
+                .iter()
+                .map(|path| display_path_for(path, &self.config.cwd))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let mut header_children: Vec<Box<dyn Renderable>> = Vec::new();
+        header_children.push(Box::new(
+            Line::from("Revert the last applied patch?").bold(),
+        ));
+        let files = changes.len();
+        let noun = if files == 1 { "file" } else { "files" };
+        header_children.push(Box::new(
+            Line::from(format!("Undoes the changes to {files} {noun}.")).dim(),
+        ));
+        if !plan.modified.is_empty() {
+            header_children.push(Box::new(
+                Line::from(format!(
+                    "Changed since the patch was applied, edits will be lost: {}",
+                    display(&plan.modified)
+                ))
+                .red(),
+            ));
+        }
+        if !plan.unrevertable.is_empty() {
+            header_children.push(Box::new(
+                Line::from(format!(
+                    "Cannot be reverted and will be left as is: {}",
+                    display(&plan.unrevertable)
+                ))
+                .red(),
+            ));
+        }
+        let header = ColumnRenderable::with(header_children);
+
+        let items = vec![
+            SelectionItem {
+                name: "Revert patch".to_string(),
+                actions: vec![Box::new(|tx| {
+                    tx.send(AppEvent::RevertLastPatch);
+                })],
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+            SelectionItem {
+                name: "Cancel".to_string(),
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+        ];
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            header: Box::new(header),
+            ..Default::default()
+        });
+    }
+
+    /// Undo the most recent applied patch after the user confirmed `/revert-patch`.
+    pub(crate) fn revert_last_patch(&mut self) {
+        let Some(changes) = self.last_applied_patch.take() else {
+            self.add_info_message("No applied patch to revert.".to_string(), None);
+            return;
+        };
+        let plan = plan_revert(&changes, &self.config.cwd);
+        match plan.apply() {
+            Ok(()) => {
+                let reverted = changes.len() - plan.unrevertable.len();
+                let noun = if reverted == 1 { "file" } else { "files" };
+                self.add_info_message(
+                    format!("Reverted the last patch ({reverted} {noun})."),
+                    None,
+                );
+            }
+            Err(err) => {
+                self.add_error_message(format!("Failed to revert the last patch: {err}"));
+                self.last_applied_patch = Some(changes);
+            }
+        }
+    }
+
     pub(crate) fn open_full_access_confirmation(&mut self, preset: ApprovalPreset) {
         let approval = preset.approval;
         let sandbox = preset.sandbox;
@@ -2998,12 +4180,34 @@ impl ChatWidget {
     /// Set the reasoning effort in the widget's config copy.
     pub(crate) fn set_reasoning_effort(&mut self, effort: Option<ReasoningEffortConfig>) {
         self.config.model_reasoning_effort = effort;
+        self.refresh_composer_prompt_context();
     }
 
     /// Set the model in the widget's config copy.
     pub(crate) fn set_model(&mut self, model: &str) {
         self.session_header.set_model(model);
         self.model = model.to_string();
+        self.refresh_composer_prompt_context();
+    }
+
+    /// The model/effort label shown above the composer text (`tui.composer_prompt_model`).
+    fn composer_prompt_context(&self) -> Option<String> {
+        if !self.config.tui_composer_prompt_model {
+            return None;
+        }
+        Some(match self.config.model_reasoning_effort {
+            Some(effort) => format!(
+                "{} · {}",
+                self.model,
+                Self::reasoning_effort_label(effort).to_lowercase()
+            ),
+            None => self.model.clone(),
+        })
+    }
+
+    fn refresh_composer_prompt_context(&mut self) {
+        let context = self.composer_prompt_context();
+        self.bottom_pane.set_composer_prompt_context(context);
     }
 
     pub(crate) fn add_info_message(&mut self, message: String, hint: Option<String>) {
@@ -3017,7 +4221,7 @@ impl ChatWidget {
     }
 
     pub(crate) fn add_error_message(&mut self, message: String) {
-        self.add_to_history(history_cell::new_error_event(message));
+        self.add_to_history(history_cell::new_error_event(message, &self.theme));
         self.request_redraw();
     }
 
@@ -3060,10 +4264,31 @@ impl ChatWidget {
         self.bottom_pane.is_normal_backtrack_mode()
     }
 
+    /// Clear the composer draft, keeping it in local history so Up brings it back.
+    pub(crate) fn clear_composer(&mut self) {
+        self.bottom_pane.clear_composer_for_ctrl_c();
+    }
+
     pub(crate) fn insert_str(&mut self, text: &str) {
         self.bottom_pane.insert_str(text);
     }
 
+    /// Pointer movement at screen position `(x, y)`, used for hover affordances.
+    pub(crate) fn handle_mouse_moved(&mut self, x: u16, y: u16) {
+        self.bottom_pane.handle_mouse_moved(x, y);
+    }
+
+    /// Current composer text (for tests and programmatic checks).
+    pub(crate) fn composer_text(&self) -> String {
+        self.bottom_pane.composer_text()
+    }
+
+    /// Byte offset of the composer cursor.
+    #[cfg(test)]
+    pub(crate) fn composer_cursor(&self) -> usize {
+        self.bottom_pane.composer_cursor()
+    }
+
     /// Replace the composer content with the provided text and reset cursor.
     pub(crate) fn set_composer_text(&mut self, text: String) {
         self.bottom_pane.set_composer_text(text);
@@ -3085,6 +4310,19 @@ impl ChatWidget {
         self.bottom_pane.is_task_running()
     }
 
+    /// Interrupt the running task from the transcript's stop control (click or Alt+X).
+    ///
+    /// The status header switches to "Stopping" right away; the usual interrupted-turn notice
+    /// follows once the turn aborts. Returns `false` when no task is running.
+    pub(crate) fn stop_generation(&mut self) -> bool {
+        if !self.is_task_running() {
+            return false;
+        }
+        self.set_status_header("Stopping".to_string());
+        self.submit_op(Op::Interrupt);
+        true
+    }
+
     /// Inform the bottom pane about the current transcript scroll state.
     ///
     /// This is used by the footer to surface when the inline transcript is
@@ -3133,6 +4371,7 @@ impl ChatWidget {
 
     fn on_list_skills(&mut self, ev: ListSkillsResponseEvent) {
         self.set_skills_from_response(&ev);
+        self.bottom_pane.set_skills_loading(false);
     }
 
     pub(crate) fn open_review_popup(&mut self) {
@@ -3294,6 +4533,32 @@ impl ChatWidget {
         self.bottom_pane.show_view(Box::new(view));
     }
 
+    pub(crate) fn show_title_prompt(&mut self) {
+        if self.conversation_id.is_none() {
+            self.add_info_message(
+                "No conversation to title yet.".to_string(),
+                Some("Send a message first, then try again.".to_string()),
+            );
+            return;
+        }
+        let tx = self.app_event_tx.clone();
+        let view = CustomPromptView::new(
+            "Chat title".to_string(),
+            "Type a title and press Enter".to_string(),
+            self.conversation_title
+                .as_ref()
+                .map(|title| format!("Current title: {title}")),
+            Box::new(move |title: String| {
+                tx.send(AppEvent::SetConversationTitle(title));
+            }),
+        );
+        self.bottom_pane.show_view(Box::new(view));
+    }
+
+    pub(crate) fn set_conversation_title(&mut self, title: Option<String>) {
+        self.conversation_title = title;
+    }
+
     pub(crate) fn token_usage(&self) -> TokenUsage {
         self.token_info
             .as_ref()
@@ -3305,6 +4570,20 @@ impl ChatWidget {
         self.conversation_id
     }
 
+    pub(crate) fn set_terminal_focused(&mut self, focused: bool) {
+        self.terminal_focused = focused;
+    }
+
+    /// Whether the composer is shrunk to a single line (`tui.compact_composer`): the terminal is
+    /// unfocused and there is nothing to show besides an empty prompt.
+    fn composer_collapsed(&self) -> bool {
+        self.config.tui_compact_composer
+            && !self.terminal_focused
+            && self.active_cell.is_none()
+            && self.bottom_pane.composer_is_empty()
+            && self.bottom_pane.is_normal_backtrack_mode()
+    }
+
     pub(crate) fn rollout_path(&self) -> Option<PathBuf> {
         self.current_rollout_path.clone()
     }
@@ -3325,6 +4604,14 @@ impl ChatWidget {
             None => RenderableItem::Owned(Box::new(())),
         };
         let mut flex = FlexRenderable::new();
+        // The line a `char`/`word` stream reveal is partway through continues the transcript.
+        if let Some(partial) = self
+            .stream_controller
+            .as_ref()
+            .and_then(StreamController::partial_cell)
+        {
+            flex.push(0, RenderableItem::Owned(Box::new(partial)));
+        }
         flex.push(1, active_cell_renderable);
         flex.push(
             0,
@@ -3342,15 +4629,25 @@ impl Drop for ChatWidget {
 
 impl Renderable for ChatWidget {
     fn render(&self, area: Rect, buf: &mut Buffer) {
-        self.as_renderable().render(area, buf);
+        if self.composer_collapsed() {
+            self.bottom_pane.render_collapsed_composer(area, buf);
+        } else {
+            self.as_renderable().render(area, buf);
+        }
         self.last_rendered_width.set(Some(area.width as usize));
     }
 
     fn desired_height(&self, width: u16) -> u16 {
+        if self.composer_collapsed() {
+            return 1;
+        }
         self.as_renderable().desired_height(width)
     }
 
     fn cursor_pos(&self, area: Rect) -> Option<(u16, u16)> {
+        if self.composer_collapsed() {
+            return None;
+        }
         self.as_renderable().cursor_pos(area)
     }
 }
@@ -3519,7 +4816,34 @@ pub(crate) fn show_review_commit_picker_with_entries(
     });
 }
 
-fn find_skill_mentions(text: &str, skills: &[SkillMetadata]) -> Vec<SkillMetadata> {
+/// Skills mentioned as `$name` in `text`, in `skills` order, plus a warning for each `$token`
+/// that matches several skills.
+///
+/// Besides the exact `$name`, a `$token` that differs only in case or separators (`$My_Skill` for
+/// `my-skill`) counts when [`SkillNameIndex::lookup`] maps it to a single skill.
+fn find_skill_mentions(text: &str, skills: &[SkillMetadata]) -> (Vec<SkillMetadata>, Vec<String>) {
+    let index = SkillNameIndex::new(skills);
+    let mut resolved: Vec<SkillMetadata> = Vec::new();
+    let mut warnings: Vec<String> = Vec::new();
+    for token in text
+        .split_whitespace()
+        .filter_map(|word| word.strip_prefix('$'))
+        .map(|token| token.trim_end_matches(['.', ',', ';', ':', '!', '?', ')']))
+    {
+        match index.lookup(token) {
+            SkillLookup::Found(skill) => resolved.push(skill),
+            SkillLookup::Ambiguous(candidates) => {
+                let warning = format!(
+                    "${token} matches several skills ({}); use the exact name to attach one.",
+                    candidates.join(", ")
+                );
+                if !warnings.contains(&warning) {
+                    warnings.push(warning);
+                }
+            }
+            SkillLookup::NotFound => {}
+        }
+    }
     let mut seen: HashSet<String> = HashSet::new();
     let mut matches: Vec<SkillMetadata> = Vec::new();
     for skill in skills {
@@ -3527,12 +4851,16 @@ fn find_skill_mentions(text: &str, skills: &[SkillMetadata]) -> Vec<SkillMetadat
             continue;
         }
         let needle = format!("${}", skill.name);
-        if text.contains(&needle) {
+        if text.contains(&needle)
+            || resolved
+                .iter()
+                .any(|found| found.name == skill.name && found.path == skill.path)
+        {
             seen.insert(skill.name.clone());
             matches.push(skill.clone());
         }
     }
-    matches
+    (matches, warnings)
 }
 
 fn skills_for_cwd(cwd: &Path, skills_entries: &[SkillsListEntry]) -> Vec<SkillMetadata> {
@@ -3547,6 +4875,11 @@ fn skills_for_cwd(cwd: &Path, skills_entries: &[SkillsListEntry]) -> Vec<SkillMe
                     name: skill.name.clone(),
                     description: skill.description.clone(),
                     short_description: skill.short_description.clone(),
+                    allowed_tools: Vec::new(),
+                    priority: 0,
+                    version: skill.version.clone(),
+                    license: skill.license.clone(),
+                    applies_to: Vec::new(),
                     path: skill.path.clone(),
                     scope: skill.scope,
                 })
@@ -3555,5 +4888,107 @@ fn skills_for_cwd(cwd: &Path, skills_entries: &[SkillsListEntry]) -> Vec<SkillMe
         .unwrap_or_default()
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ShortMessageCheck {
+    Send,
+    Confirm,
+}
+
+/// Whether a submitted message should go out directly or ask first (`tui.confirm_short_messages`).
+///
+/// Messages carrying attachments always send: an image with no text is a normal request.
+fn short_message_check(
+    text: &str,
+    has_attachments: bool,
+    min_chars: Option<usize>,
+) -> ShortMessageCheck {
+    let Some(min_chars) = min_chars else {
+        return ShortMessageCheck::Send;
+    };
+    let trimmed = text.trim();
+    if has_attachments || (!trimmed.is_empty() && trimmed.chars().count() >= min_chars) {
+        ShortMessageCheck::Send
+    } else {
+        ShortMessageCheck::Confirm
+    }
+}
+
+/// Wraps `text` in the configured `[prompt]` prefix/suffix, separated by blank lines.
+///
+/// Returns `None` when there is no text or neither part is set, so the message goes out as typed.
+fn apply_prompt_template(template: &PromptTemplate, text: &str) -> Option<String> {
+    let non_empty = |part: &Option<String>| part.as_deref().filter(|part| !part.trim().is_empty());
+    let prefix = non_empty(&template.prefix);
+    let suffix = non_empty(&template.suffix);
+    if text.is_empty() || (prefix.is_none() && suffix.is_none()) {
+        return None;
+    }
+    let parts: Vec<&str> = prefix.into_iter().chain([text]).chain(suffix).collect();
+    Some(parts.join("\n\n"))
+}
+
+/// The preset after the one matching `current`'s sandbox kind, wrapping around.
+///
+/// A sandbox no preset uses (e.g. an external sandbox) starts the cycle from the first preset.
+fn next_sandbox_preset(
+    current: &SandboxPolicy,
+    presets: &[ApprovalPreset],
+) -> Option<ApprovalPreset> {
+    let next_index = presets
+        .iter()
+        .position(|preset| {
+            std::mem::discriminant(&preset.sandbox) == std::mem::discriminant(current)
+        })
+        .map_or(0, |index| (index + 1) % presets.len());
+    presets.get(next_index).cloned()
+}
+
+/// Order in which the quick-cycle binding steps through reasoning efforts.
+const REASONING_EFFORT_CYCLE: [ReasoningEffortConfig; 5] = [
+    ReasoningEffortConfig::Minimal,
+    ReasoningEffortConfig::Low,
+    ReasoningEffortConfig::Medium,
+    ReasoningEffortConfig::High,
+    ReasoningEffortConfig::XHigh,
+];
+
+/// The effort after `current` in [`REASONING_EFFORT_CYCLE`], wrapping around and skipping
+/// efforts missing from `supported` (an empty list means every effort is allowed).
+///
+/// An unset or `none` effort starts the cycle from the top. Returns `None` when no other
+/// supported effort exists.
+fn next_reasoning_effort(
+    current: Option<ReasoningEffortConfig>,
+    supported: &[ReasoningEffortConfig],
+) -> Option<ReasoningEffortConfig> {
+    let is_supported =
+        |effort: &ReasoningEffortConfig| supported.is_empty() || supported.contains(effort);
+    let start = current
+        .and_then(|current| REASONING_EFFORT_CYCLE.iter().position(|e| *e == current))
+        .map_or(0, |index| index + 1);
+    (0..REASONING_EFFORT_CYCLE.len())
+        .map(|offset| REASONING_EFFORT_CYCLE[(start + offset) % REASONING_EFFORT_CYCLE.len()])
+        .find(|effort| is_supported(effort) && Some(*effort) != current)
+}
+
+/// The first effort above `current` in [`REASONING_EFFORT_CYCLE`] that `supported` allows
+/// (an empty list means every effort is allowed), without wrapping around.
+///
+/// An unset or `none` effort bumps to the lowest supported effort. Returns `None` when
+/// `current` is already the highest supported effort.
+fn higher_reasoning_effort(
+    current: Option<ReasoningEffortConfig>,
+    supported: &[ReasoningEffortConfig],
+) -> Option<ReasoningEffortConfig> {
+    let start = current
+        .and_then(|current| REASONING_EFFORT_CYCLE.iter().position(|e| *e == current))
+        .map_or(0, |index| index + 1);
+    REASONING_EFFORT_CYCLE
+        .iter()
+        .skip(start)
+        .copied()
+        .find(|effort| supported.is_empty() || supported.contains(effort))
+}
+
 #[cfg(test)]
 pub(crate) mod tests;
@@ -27,6 +27,7 @@ use codex_core::protocol::ExecCommandSource;
 use codex_core::protocol::ExecPolicyAmendment;
 use codex_core::protocol::ExitedReviewModeEvent;
 use codex_core::protocol::FileChange;
+use codex_core::protocol::ListSkillsResponseEvent;
 use codex_core::protocol::McpStartupStatus;
 use codex_core::protocol::McpStartupUpdateEvent;
 use codex_core::protocol::Op;
@@ -35,6 +36,7 @@ use codex_core::protocol::PatchApplyEndEvent;
 use codex_core::protocol::RateLimitWindow;
 use codex_core::protocol::ReviewRequest;
 use codex_core::protocol::ReviewTarget;
+use codex_core::protocol::SkillsListEntry;
 use codex_core::protocol::StreamErrorEvent;
 use codex_core::protocol::TaskCompleteEvent;
 use codex_core::protocol::TaskStartedEvent;
@@ -150,7 +152,56 @@ async fn resumed_initial_messages_render_history() {
     );
 }
 
-/// Entering review mode uses the hint provided by the review request.
+#[tokio::test]
+async fn skills_loading_indicator_clears_when_skills_arrive() {
+    let (mut chat, _rx, _ops) = make_chatwidget_manual(None).await;
+    chat.config.features.enable(Feature::Skills);
+    let cwd = chat.config.cwd.clone();
+    let rollout_file = NamedTempFile::new().unwrap();
+
+    chat.begin_skills_load();
+    assert!(
+        render_bottom_popup(&chat, 100).contains("loading skills…"),
+        "indicator shown before the session is configured"
+    );
+
+    chat.handle_codex_event(Event {
+        id: "initial".into(),
+        msg: EventMsg::SessionConfigured(codex_core::protocol::SessionConfiguredEvent {
+            session_id: ConversationId::new(),
+            model: "test-model".to_string(),
+            model_provider_id: "test-provider".to_string(),
+            approval_policy: AskForApproval::Never,
+            sandbox_policy: SandboxPolicy::ReadOnly,
+            cwd: cwd.clone(),
+            reasoning_effort: Some(ReasoningEffortConfig::default()),
+            history_log_id: 0,
+            history_entry_count: 0,
+            initial_messages: None,
+            rollout_path: rollout_file.path().to_path_buf(),
+        }),
+    });
+    assert!(
+        render_bottom_popup(&chat, 100).contains("loading skills…"),
+        "indicator kept while skills load"
+    );
+
+    chat.handle_codex_event(Event {
+        id: "skills".into(),
+        msg: EventMsg::ListSkillsResponse(ListSkillsResponseEvent {
+            skills: vec![SkillsListEntry {
+                cwd,
+                skills: Vec::new(),
+                errors: Vec::new(),
+            }],
+        }),
+    });
+    assert!(
+        !render_bottom_popup(&chat, 100).contains("loading skills…"),
+        "indicator cleared once skills arrive"
+    );
+}
+
 #[tokio::test]
 async fn entered_review_mode_uses_request_hint() {
     let (mut chat, mut rx, _ops) = make_chatwidget_manual(None).await;
@@ -368,6 +419,7 @@ async fn make_chatwidget_manual(
         codex_op_tx: op_tx,
         bottom_pane: bottom,
         active_cell: None,
+        theme: Theme::default(),
         config: cfg,
         model: resolved_model.clone(),
         auth_manager: auth_manager.clone(),
@@ -379,7 +431,11 @@ async fn make_chatwidget_manual(
         plan_type: None,
         rate_limit_warnings: RateLimitWarningState::default(),
         rate_limit_switch_prompt: RateLimitSwitchPromptState::default(),
+        compact_prompt: CompactPromptState::default(),
+        prompt_template_noted: false,
+        terminal_focused: true,
         rate_limit_poller: None,
+        pending_user_input: None,
         stream_controller: None,
         running_commands: HashMap::new(),
         suppressed_exec_calls: HashSet::new(),
@@ -392,6 +448,7 @@ async fn make_chatwidget_manual(
         current_status_header: String::from("Working"),
         retry_status_header: None,
         conversation_id: None,
+        conversation_title: None,
         frame_requester: FrameRequester::test_dummy(),
         show_welcome_banner: true,
         queued_user_messages: VecDeque::new(),
@@ -689,6 +746,71 @@ async fn rate_limit_switch_prompt_defers_until_task_complete() {
     ));
 }
 
+#[test]
+fn compact_prompt_triggers_at_configured_usage_threshold() {
+    // 12k baseline tokens are excluded, so a 100k window has 88k usable tokens.
+    let half_full = make_token_info(12_000 + 44_000, 100_000);
+    let ninety_percent_full = make_token_info(12_000 + 79_200, 100_000);
+
+    assert!(!context_usage_reaches(&half_full, 85));
+    assert!(context_usage_reaches(&half_full, 50));
+    assert!(context_usage_reaches(&ninety_percent_full, 85));
+    assert!(!context_usage_reaches(&ninety_percent_full, 95));
+    assert!(!context_usage_reaches(&ninety_percent_full, 0));
+
+    let unknown_window = TokenUsageInfo {
+        model_context_window: None,
+        ..ninety_percent_full
+    };
+    assert!(!context_usage_reaches(&unknown_window, 85));
+}
+
+#[tokio::test]
+async fn compact_prompt_shows_after_task_and_rearms_when_usage_drops() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    chat.set_token_info(Some(make_token_info(12_000 + 79_200, 100_000)));
+    assert_eq!(chat.compact_prompt, CompactPromptState::Pending);
+
+    chat.maybe_show_pending_compact_prompt();
+    assert_eq!(chat.compact_prompt, CompactPromptState::Shown);
+    let popup = render_bottom_popup(&chat, 80);
+    assert!(popup.contains("Context window nearly full"), "{popup}");
+
+    chat.set_token_info(Some(make_token_info(12_000 + 79_200, 100_000)));
+    assert_eq!(chat.compact_prompt, CompactPromptState::Shown);
+
+    chat.set_token_info(Some(make_token_info(20_000, 100_000)));
+    assert_eq!(chat.compact_prompt, CompactPromptState::Idle);
+}
+
+#[tokio::test]
+async fn snoozed_compact_prompt_is_not_offered_again_this_session() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    chat.set_token_info(Some(make_token_info(12_000 + 79_200, 100_000)));
+    chat.maybe_show_pending_compact_prompt();
+    chat.snooze_compact_prompt();
+
+    chat.set_token_info(Some(make_token_info(20_000, 100_000)));
+    chat.set_token_info(Some(make_token_info(12_000 + 85_000, 100_000)));
+    chat.maybe_show_pending_compact_prompt();
+    assert_eq!(chat.compact_prompt, CompactPromptState::Snoozed);
+}
+
+#[tokio::test]
+async fn compact_prompt_respects_hidden_notice_and_threshold() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(None).await;
+    chat.config.notices.hide_compact_prompt = Some(true);
+    chat.set_token_info(Some(make_token_info(12_000 + 85_000, 100_000)));
+    assert_eq!(chat.compact_prompt, CompactPromptState::Idle);
+
+    chat.config.notices.hide_compact_prompt = None;
+    chat.config.tui_compact_prompt_threshold = Some(0);
+    chat.set_token_info(Some(make_token_info(12_000 + 85_000, 100_000)));
+    assert_eq!(chat.compact_prompt, CompactPromptState::Idle);
+}
+
 #[tokio::test]
 async fn rate_limit_switch_prompt_popup_snapshot() {
     let (mut chat, _rx, _op_rx) = make_chatwidget_manual(Some("gpt-5")).await;
@@ -1034,6 +1156,125 @@ async fn streaming_final_answer_keeps_task_running_state() {
     assert!(chat.bottom_pane.ctrl_c_quit_hint_visible());
 }
 
+#[tokio::test]
+async fn prompt_template_wraps_dispatched_text_but_not_transcript() {
+    let (mut chat, mut rx, mut op_rx) = make_chatwidget_manual(None).await;
+    chat.config.prompt_template = PromptTemplate {
+        prefix: Some("Always respond in English.".to_string()),
+        suffix: Some("Follow the team style guide.".to_string()),
+        show_in_transcript: false,
+    };
+
+    chat.bottom_pane
+        .set_composer_text("fix the build".to_string());
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+    match op_rx.try_recv() {
+        Ok(Op::UserInput { items }) => assert_eq!(
+            items,
+            vec![UserInput::Text {
+                text: "Always respond in English.\n\nfix the build\n\nFollow the team style guide."
+                    .to_string(),
+            }]
+        ),
+        other => panic!("expected Op::UserInput, got {other:?}"),
+    }
+    match op_rx.try_recv() {
+        Ok(Op::AddToHistory { text }) => assert_eq!(text, "fix the build"),
+        other => panic!("expected Op::AddToHistory, got {other:?}"),
+    }
+
+    let cells = drain_insert_history(&mut rx);
+    let user_cell = lines_to_single_string(&cells[0]);
+    assert!(user_cell.contains("fix the build"), "{user_cell}");
+    assert!(!user_cell.contains("Always respond"), "{user_cell}");
+    let note = lines_to_single_string(&cells[1]);
+    assert!(note.contains("prompt.prefix"), "{note}");
+}
+
+#[tokio::test]
+async fn file_mentions_are_attached_off_the_ui_thread() {
+    let (mut chat, mut rx, mut op_rx) = make_chatwidget_manual(None).await;
+    let dir = tempdir().expect("tempdir");
+    std::fs::write(dir.path().join("notes.md"), "remember\n").expect("write");
+    chat.config.cwd = dir.path().to_path_buf();
+    chat.config.tui_expand_file_mentions = true;
+
+    chat.submit_user_message(UserMessage {
+        text: "read @notes.md and @gone.md, then ping @alice".to_string(),
+        image_paths: Vec::new(),
+        file_mentions: vec!["@notes.md".to_string(), "@gone.md".to_string()],
+    });
+
+    // Only the history entry is sent synchronously; the input follows once the read finishes.
+    assert_matches!(op_rx.try_recv(), Ok(Op::AddToHistory { .. }));
+    assert_matches!(op_rx.try_recv(), Err(TryRecvError::Empty));
+    match op_rx.recv().await {
+        Some(Op::UserInput { items }) => assert_eq!(
+            items,
+            vec![
+                UserInput::Text {
+                    text: "read @notes.md and @gone.md, then ping @alice".to_string(),
+                },
+                UserInput::Text {
+                    text: "<file path=\"notes.md\">\nremember\n</file>".to_string(),
+                },
+            ]
+        ),
+        other => panic!("expected Op::UserInput, got {other:?}"),
+    }
+
+    let cells = drain_insert_history(&mut rx);
+    let warnings: Vec<String> = cells
+        .iter()
+        .map(|cell| lines_to_single_string(cell))
+        .filter(|cell| cell.contains("Could not attach"))
+        .collect();
+    assert_eq!(warnings.len(), 1, "{warnings:?}");
+    assert!(warnings[0].contains("@gone.md"), "{warnings:?}");
+}
+
+#[tokio::test]
+async fn prompt_template_can_be_shown_in_transcript() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+    chat.config.prompt_template = PromptTemplate {
+        prefix: Some("Be brief.".to_string()),
+        suffix: None,
+        show_in_transcript: true,
+    };
+
+    chat.bottom_pane.set_composer_text("hello".to_string());
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+    let cells = drain_insert_history(&mut rx);
+    assert_eq!(cells.len(), 1);
+    let user_cell = lines_to_single_string(&cells[0]);
+    assert!(user_cell.contains("Be brief."), "{user_cell}");
+    assert!(user_cell.contains("hello"), "{user_cell}");
+}
+
+#[test]
+fn empty_prompt_template_leaves_text_unchanged() {
+    assert_eq!(
+        apply_prompt_template(&PromptTemplate::default(), "hello"),
+        None
+    );
+    let blank = PromptTemplate {
+        prefix: Some("  ".to_string()),
+        ..PromptTemplate::default()
+    };
+    assert_eq!(apply_prompt_template(&blank, "hello"), None);
+    let suffix_only = PromptTemplate {
+        suffix: Some("Thanks.".to_string()),
+        ..PromptTemplate::default()
+    };
+    assert_eq!(
+        apply_prompt_template(&suffix_only, "hello"),
+        Some("hello\n\nThanks.".to_string())
+    );
+    assert_eq!(apply_prompt_template(&suffix_only, ""), None);
+}
+
 #[tokio::test]
 async fn ctrl_c_shutdown_ignores_caps_lock() {
     let (mut chat, _rx, mut op_rx) = make_chatwidget_manual(None).await;
@@ -1280,6 +1521,43 @@ async fn slash_exit_requests_exit() {
     assert_matches!(rx.try_recv(), Ok(AppEvent::ExitRequest));
 }
 
+#[tokio::test]
+async fn request_git_diff_delivers_diff_result_without_pending_patch() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    chat.request_git_diff();
+
+    let event = tokio::time::timeout(std::time::Duration::from_secs(30), rx.recv())
+        .await
+        .expect("diff arrives")
+        .expect("channel open");
+    assert!(
+        matches!(event, AppEvent::DiffResult(_)),
+        "expected DiffResult, got {event:?}"
+    );
+}
+
+#[tokio::test]
+async fn slash_skills_preview_requests_preview_from_app() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    chat.dispatch_command(SlashCommand::SkillsPreview);
+
+    assert_matches!(rx.try_recv(), Ok(AppEvent::PreviewSkillInjections));
+}
+
+#[tokio::test]
+async fn slash_skills_preview_reports_disabled_skills() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+    chat.config.features.disable(Feature::Skills);
+
+    chat.dispatch_command(SlashCommand::SkillsPreview);
+
+    let cells = drain_insert_history(&mut rx);
+    assert_eq!(cells.len(), 1);
+    assert!(lines_to_single_string(&cells[0]).contains("Skills are disabled"));
+}
+
 #[tokio::test]
 async fn slash_resume_opens_picker() {
     let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
@@ -1289,6 +1567,85 @@ async fn slash_resume_opens_picker() {
     assert_matches!(rx.try_recv(), Ok(AppEvent::OpenResumePicker));
 }
 
+#[tokio::test]
+async fn slash_new_with_empty_composer_starts_session_immediately() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    chat.dispatch_command(SlashCommand::New);
+
+    assert_matches!(rx.try_recv(), Ok(AppEvent::NewSession { draft: None }));
+}
+
+#[tokio::test]
+async fn slash_new_with_draft_asks_for_confirmation() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+    chat.set_composer_text("half-written question".to_string());
+
+    chat.dispatch_command(SlashCommand::New);
+
+    assert_matches!(rx.try_recv(), Err(TryRecvError::Empty));
+    let popup = render_bottom_popup(&chat, 80);
+    assert!(popup.contains("Start a new chat?"), "popup: {popup}");
+    assert!(popup.contains("keep draft"), "popup: {popup}");
+}
+
+#[tokio::test]
+async fn slash_new_confirmation_keeps_draft() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+    chat.set_composer_text("half-written question".to_string());
+    chat.dispatch_command(SlashCommand::New);
+
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+    match rx.try_recv() {
+        Ok(AppEvent::NewSession { draft }) => {
+            assert_eq!(draft.as_deref(), Some("half-written question"));
+        }
+        other => panic!("expected NewSession, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn slash_new_confirmation_can_discard_draft() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+    chat.set_composer_text("half-written question".to_string());
+    chat.dispatch_command(SlashCommand::New);
+
+    chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+    assert_matches!(rx.try_recv(), Ok(AppEvent::NewSession { draft: None }));
+}
+
+#[tokio::test]
+async fn slash_new_while_task_running_asks_for_confirmation() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+    chat.bottom_pane.set_task_running(true);
+
+    chat.dispatch_command(SlashCommand::New);
+
+    assert_matches!(rx.try_recv(), Err(TryRecvError::Empty));
+    let popup = render_bottom_popup(&chat, 80);
+    assert!(popup.contains("task will be stopped"), "popup: {popup}");
+}
+
+#[tokio::test]
+async fn notification_quiet_hours_wrap_past_midnight() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(None).await;
+    let at = |hour| chrono::NaiveTime::from_hms_opt(hour, 0, 0).expect("valid time");
+    assert!(!chat.in_notification_quiet_hours(at(23)));
+
+    chat.config.tui_notification_quiet_hours = Some("22:00-08:00".to_string());
+    assert!(chat.in_notification_quiet_hours(at(23)));
+    assert!(chat.in_notification_quiet_hours(at(3)));
+    assert!(!chat.in_notification_quiet_hours(at(8)));
+    assert!(!chat.in_notification_quiet_hours(at(15)));
+
+    // A malformed window never suppresses notifications.
+    chat.config.tui_notification_quiet_hours = Some("late".to_string());
+    assert!(!chat.in_notification_quiet_hours(at(23)));
+}
+
 #[tokio::test]
 async fn slash_rollout_displays_current_path() {
     let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
@@ -1747,6 +2104,159 @@ async fn approvals_selection_popup_snapshot() {
     assert_snapshot!("approvals_selection_popup", popup);
 }
 
+#[test]
+fn short_message_check_flags_whitespace_and_short_text() {
+    assert_eq!(
+        short_message_check("   ", false, Some(3)),
+        ShortMessageCheck::Confirm
+    );
+    assert_eq!(
+        short_message_check("ok", false, Some(3)),
+        ShortMessageCheck::Confirm
+    );
+    assert_eq!(
+        short_message_check(" yes ", false, Some(3)),
+        ShortMessageCheck::Send
+    );
+    assert_eq!(
+        short_message_check("fix the build", false, Some(3)),
+        ShortMessageCheck::Send
+    );
+    // Image-only messages are intentional.
+    assert_eq!(
+        short_message_check("", true, Some(3)),
+        ShortMessageCheck::Send
+    );
+    // Whitespace-only still asks with a zero threshold.
+    assert_eq!(
+        short_message_check(" ", false, Some(0)),
+        ShortMessageCheck::Confirm
+    );
+}
+
+#[test]
+fn short_message_check_is_bypassed_when_disabled() {
+    assert_eq!(
+        short_message_check("", false, None),
+        ShortMessageCheck::Send
+    );
+    assert_eq!(
+        short_message_check("k", false, None),
+        ShortMessageCheck::Send
+    );
+}
+
+#[tokio::test]
+async fn short_message_waits_for_confirmation() {
+    let (mut chat, mut rx, mut op_rx) = make_chatwidget_manual(None).await;
+    chat.config.tui_confirm_short_messages = Some(3);
+
+    chat.bottom_pane.set_composer_text("k".to_string());
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    assert_matches!(op_rx.try_recv(), Err(TryRecvError::Empty));
+    assert_eq!(chat.bottom_pane.composer_text(), "k");
+
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    assert_matches!(
+        rx.try_recv(),
+        Ok(AppEvent::SubmitConfirmedMessage(text)) if text == "k"
+    );
+}
+
+#[tokio::test]
+async fn compact_composer_collapses_only_when_empty_and_unfocused() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(None).await;
+    let width = 60;
+    let expanded = chat.desired_height(width);
+    assert!(expanded > 1);
+
+    chat.config.tui_compact_composer = true;
+    assert_eq!(
+        chat.desired_height(width),
+        expanded,
+        "focused stays expanded"
+    );
+
+    chat.set_terminal_focused(false);
+    assert_eq!(chat.desired_height(width), 1);
+
+    let area = Rect::new(0, 0, width, 1);
+    let mut buf = Buffer::empty(area);
+    chat.render(area, &mut buf);
+    let row: String = (0..width)
+        .map(|x| buf[(x, 0)].symbol().to_string())
+        .collect();
+    assert!(row.starts_with('›'), "{row:?}");
+}
+
+#[tokio::test]
+async fn compact_composer_keeps_wrapped_height_for_drafts() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(None).await;
+    let width = 60;
+    let empty = chat.desired_height(width);
+
+    chat.config.tui_compact_composer = true;
+    chat.set_terminal_focused(false);
+    chat.bottom_pane
+        .set_composer_text("first line\nsecond line\nthird line".to_string());
+    assert_eq!(chat.desired_height(width), empty + 2);
+}
+
+#[tokio::test]
+async fn notifications_are_queued_only_while_unfocused() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(None).await;
+    chat.config.tui_notifications = codex_core::config::types::Notifications::Enabled(true);
+    let done = || Notification::AgentTurnComplete {
+        response: "done".to_string(),
+    };
+
+    chat.notify(done());
+    assert!(chat.pending_notification.is_none(), "focused terminal");
+
+    chat.set_terminal_focused(false);
+    chat.notify(done());
+    assert!(chat.pending_notification.is_some());
+
+    chat.set_terminal_focused(true);
+    chat.clear_pending_notification();
+    assert!(chat.pending_notification.is_none());
+}
+
+#[tokio::test]
+async fn session_settings_popup_reflects_config() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(Some("gpt-5-codex")).await;
+    chat.config.approval_policy = Constrained::allow_any(AskForApproval::Never);
+    chat.set_sandbox_policy(SandboxPolicy::ReadOnly)
+        .expect("set sandbox policy");
+
+    chat.open_session_settings_popup();
+
+    let popup = render_bottom_popup(&chat, 120);
+    assert!(popup.contains("Session Settings"), "{popup}");
+    assert!(popup.contains("gpt-5-codex"), "{popup}");
+    assert!(popup.contains("never"), "{popup}");
+    assert!(popup.contains("read-only"), "{popup}");
+    assert!(
+        popup.contains(&format!("Working directory: {}", chat.config.cwd.display())),
+        "{popup}"
+    );
+}
+
+#[tokio::test]
+async fn session_settings_popup_dispatches_pickers() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    chat.open_session_settings_popup();
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    assert_matches!(rx.try_recv(), Ok(AppEvent::OpenModelPopup));
+
+    chat.open_session_settings_popup();
+    chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+    chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    assert_matches!(rx.try_recv(), Ok(AppEvent::OpenApprovalsPopup));
+}
+
 #[tokio::test]
 async fn preset_matching_ignores_extra_writable_roots() {
     let preset = builtin_approval_presets()
@@ -1770,6 +2280,69 @@ async fn preset_matching_ignores_extra_writable_roots() {
     );
 }
 
+#[test]
+fn sandbox_cycle_steps_through_presets_in_order() {
+    let presets = builtin_approval_presets();
+    let next_id = |current: &SandboxPolicy| {
+        next_sandbox_preset(current, &presets)
+            .map(|preset| preset.id)
+            .expect("next preset")
+    };
+    assert_eq!(next_id(&SandboxPolicy::ReadOnly), "auto");
+    assert_eq!(
+        next_id(&SandboxPolicy::new_workspace_write_policy()),
+        "full-access"
+    );
+    assert_eq!(next_id(&SandboxPolicy::DangerFullAccess), "read-only");
+}
+
+#[cfg(not(target_os = "windows"))]
+#[tokio::test]
+async fn cycle_sandbox_policy_applies_workspace_write_from_read_only() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+    chat.set_sandbox_policy(SandboxPolicy::ReadOnly)
+        .expect("set sandbox policy");
+
+    chat.cycle_sandbox_policy();
+
+    let mut saw_override = false;
+    let mut applied = None;
+    while let Ok(event) = rx.try_recv() {
+        match event {
+            AppEvent::CodexOp(Op::OverrideTurnContext { sandbox_policy, .. }) => {
+                saw_override = true;
+                assert_matches!(sandbox_policy, Some(SandboxPolicy::WorkspaceWrite { .. }));
+            }
+            AppEvent::UpdateSandboxPolicy(policy) => applied = Some(policy),
+            _ => {}
+        }
+    }
+    assert!(saw_override);
+    assert_matches!(applied, Some(SandboxPolicy::WorkspaceWrite { .. }));
+}
+
+#[tokio::test]
+async fn cycle_sandbox_policy_confirms_before_full_access() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+    chat.config.notices.hide_full_access_warning = None;
+    chat.set_sandbox_policy(SandboxPolicy::new_workspace_write_policy())
+        .expect("set sandbox policy");
+
+    chat.cycle_sandbox_policy();
+
+    let mut confirmation = None;
+    while let Ok(event) = rx.try_recv() {
+        match event {
+            AppEvent::OpenFullAccessConfirmation { preset } => confirmation = Some(preset.id),
+            AppEvent::UpdateSandboxPolicy(policy) => {
+                panic!("full access applied without confirmation: {policy:?}")
+            }
+            _ => {}
+        }
+    }
+    assert_eq!(confirmation, Some("full-access"));
+}
+
 #[tokio::test]
 async fn full_access_confirmation_popup_snapshot() {
     let (mut chat, _rx, _op_rx) = make_chatwidget_manual(None).await;
@@ -1936,6 +2509,143 @@ async fn feedback_upload_consent_popup_snapshot() {
     assert_snapshot!("feedback_upload_consent_popup", popup);
 }
 
+#[test]
+fn reasoning_effort_cycle_advances_and_wraps() {
+    type Effort = ReasoningEffortConfig;
+    let order: Vec<_> = std::iter::successors(Some(Effort::Minimal), |effort| {
+        next_reasoning_effort(Some(*effort), &[])
+    })
+    .take(6)
+    .collect();
+    assert_eq!(
+        order,
+        vec![
+            Effort::Minimal,
+            Effort::Low,
+            Effort::Medium,
+            Effort::High,
+            Effort::XHigh,
+            Effort::Minimal
+        ]
+    );
+    assert_eq!(next_reasoning_effort(None, &[]), Some(Effort::Minimal));
+}
+
+#[test]
+fn reasoning_effort_cycle_skips_unsupported_efforts() {
+    type Effort = ReasoningEffortConfig;
+    let supported = [Effort::Medium, Effort::High];
+    assert_eq!(
+        next_reasoning_effort(Some(Effort::Medium), &supported),
+        Some(Effort::High)
+    );
+    assert_eq!(
+        next_reasoning_effort(Some(Effort::High), &supported),
+        Some(Effort::Medium)
+    );
+    assert_eq!(
+        next_reasoning_effort(Some(Effort::Minimal), &supported),
+        Some(Effort::Medium)
+    );
+    assert_eq!(
+        next_reasoning_effort(Some(Effort::Medium), &[Effort::Medium]),
+        None
+    );
+}
+
+#[test]
+fn higher_reasoning_effort_bumps_one_supported_level() {
+    type Effort = ReasoningEffortConfig;
+    assert_eq!(
+        higher_reasoning_effort(Some(Effort::Low), &[]),
+        Some(Effort::Medium)
+    );
+    assert_eq!(higher_reasoning_effort(None, &[]), Some(Effort::Minimal));
+    assert_eq!(
+        higher_reasoning_effort(Some(Effort::None), &[]),
+        Some(Effort::Minimal)
+    );
+    let supported = [Effort::Low, Effort::High];
+    assert_eq!(
+        higher_reasoning_effort(Some(Effort::Low), &supported),
+        Some(Effort::High)
+    );
+    assert_eq!(
+        higher_reasoning_effort(Some(Effort::High), &supported),
+        None
+    );
+    assert_eq!(higher_reasoning_effort(Some(Effort::XHigh), &[]), None);
+}
+
+#[tokio::test]
+async fn retry_with_higher_effort_is_refused_while_task_running() {
+    let (mut chat, mut rx, mut op_rx) = make_chatwidget_manual(None).await;
+    chat.config.model_reasoning_effort = Some(ReasoningEffortConfig::Low);
+    chat.bottom_pane.set_task_running(true);
+
+    chat.retry_with_higher_effort("fix the build".to_string());
+
+    assert_matches!(op_rx.try_recv(), Err(TryRecvError::Empty));
+    assert_eq!(
+        chat.config.model_reasoning_effort,
+        Some(ReasoningEffortConfig::Low)
+    );
+    let cells = drain_insert_history(&mut rx);
+    let message = lines_to_single_string(&cells[0]);
+    assert!(message.contains("Wait for the current task"), "{message}");
+}
+
+#[tokio::test]
+async fn retry_with_higher_effort_overrides_effort_before_resending() {
+    let (mut chat, _rx, mut op_rx) = make_chatwidget_manual(None).await;
+    chat.config.model_reasoning_effort = Some(ReasoningEffortConfig::Minimal);
+
+    chat.retry_with_higher_effort("fix the build".to_string());
+
+    let effort = match op_rx.try_recv() {
+        Ok(Op::OverrideTurnContext {
+            effort: Some(Some(effort)),
+            ..
+        }) => effort,
+        other => panic!("expected Op::OverrideTurnContext, got {other:?}"),
+    };
+    assert_ne!(effort, ReasoningEffortConfig::Minimal);
+    assert_eq!(chat.config.model_reasoning_effort, Some(effort));
+    match op_rx.try_recv() {
+        Ok(Op::UserInput { items }) => assert_eq!(
+            items,
+            vec![UserInput::Text {
+                text: "fix the build".to_string(),
+            }]
+        ),
+        other => panic!("expected Op::UserInput, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn cycle_reasoning_effort_dispatches_update() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(Some("gpt-5.1-codex-max")).await;
+    chat.set_reasoning_effort(Some(ReasoningEffortConfig::XHigh));
+
+    // gpt-5.1-codex-max does not offer Minimal, so XHigh wraps to Low.
+    let next = chat.cycle_reasoning_effort();
+    assert_eq!(next, Some(ReasoningEffortConfig::Low));
+
+    assert_matches!(
+        rx.try_recv(),
+        Ok(AppEvent::CodexOp(Op::OverrideTurnContext {
+            effort: Some(Some(ReasoningEffortConfig::Low)),
+            ..
+        }))
+    );
+    assert_matches!(
+        rx.try_recv(),
+        Ok(AppEvent::UpdateReasoningEffort(Some(
+            ReasoningEffortConfig::Low
+        )))
+    );
+}
+
 #[tokio::test]
 async fn reasoning_popup_escape_returns_to_model_popup() {
     let (mut chat, _rx, _op_rx) = make_chatwidget_manual(Some("gpt-5.1-codex-max")).await;
@@ -3303,3 +4013,189 @@ async fn chatwidget_tall() {
     .unwrap();
     assert_snapshot!(term.backend().vt100().screen().contents());
 }
+
+#[tokio::test]
+async fn prompt_history_filters_and_reuses_prompts() {
+    let (mut chat, mut rx, mut op_rx) = make_chatwidget_manual(None).await;
+
+    chat.open_prompt_history(vec![
+        "run the tests".to_string(),
+        "explain the parser\nin detail".to_string(),
+    ]);
+    let popup = render_bottom_popup(&chat, 80);
+    assert!(popup.contains("Prompt history"), "{popup}");
+    assert!(popup.contains("explain the parser …"), "{popup}");
+
+    // Terms can match across lines and in any order.
+    for ch in "detail parser".chars() {
+        chat.handle_key_event(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+    }
+    assert!(!render_bottom_popup(&chat, 80).contains("run the tests"));
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    assert_matches!(
+        rx.try_recv(),
+        Ok(AppEvent::PromptHistorySelected(prompt)) if prompt == "explain the parser\nin detail"
+    );
+
+    chat.reuse_prompt("run the tests".to_string(), false);
+    assert_eq!(chat.bottom_pane.composer_text(), "run the tests");
+    assert_matches!(op_rx.try_recv(), Err(TryRecvError::Empty));
+}
+
+#[tokio::test]
+async fn instant_stream_reveal_inserts_lines_without_commit_animation() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+    chat.config.tui_stream_reveal = StreamReveal::Instant;
+
+    chat.handle_codex_event(Event {
+        id: "s1".into(),
+        msg: EventMsg::AgentMessageDelta(AgentMessageDeltaEvent {
+            delta: "first line\nsecond line\n".into(),
+        }),
+    });
+
+    let mut inserted = Vec::new();
+    while let Ok(event) = rx.try_recv() {
+        match event {
+            AppEvent::InsertHistoryCell(cell) => inserted.push(cell.display_lines(80)),
+            AppEvent::StartCommitAnimation => panic!("instant reveal must not animate"),
+            _ => {}
+        }
+    }
+    assert_eq!(inserted.len(), 1, "both lines land in one cell");
+    assert_eq!(
+        lines_to_single_string(&inserted[0]),
+        "• first line\n  second line\n"
+    );
+}
+
+#[tokio::test]
+async fn long_agent_message_gets_stats_footer_when_enabled() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+    chat.config.tui_message_stats = true;
+
+    chat.handle_codex_event(Event {
+        id: "s1".into(),
+        msg: EventMsg::AgentMessage(AgentMessageEvent {
+            message: "word ".repeat(200).trim_end().to_string(),
+        }),
+    });
+
+    let inserted = drain_insert_history(&mut rx);
+    let footer = inserted.last().expect("footer cell");
+    assert_eq!(
+        lines_to_single_string(footer),
+        "  999 chars · 200 words · ~1 min read\n"
+    );
+}
+
+#[tokio::test]
+async fn revert_last_patch_undoes_the_most_recent_applied_patch() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+    let dir = tempfile::tempdir().expect("tempdir");
+    chat.config.cwd = dir.path().to_path_buf();
+    let added = dir.path().join("added.txt");
+    std::fs::write(&added, "hello\n").expect("write");
+
+    chat.handle_codex_event(Event {
+        id: "sub-1".into(),
+        msg: EventMsg::PatchApplyEnd(PatchApplyEndEvent {
+            call_id: "call-1".into(),
+            turn_id: "turn-1".into(),
+            stdout: String::from("ok"),
+            stderr: String::new(),
+            success: true,
+            changes: HashMap::from([(
+                added.clone(),
+                FileChange::Add {
+                    content: "hello\n".into(),
+                },
+            )]),
+        }),
+    });
+    let _ = drain_insert_history(&mut rx);
+
+    chat.revert_last_patch();
+    assert!(!added.exists());
+    let inserted = drain_insert_history(&mut rx);
+    assert!(
+        lines_to_single_string(inserted.last().expect("info message"))
+            .contains("Reverted the last patch (1 file)."),
+    );
+
+    // The patch is forgotten once reverted.
+    chat.dispatch_command(SlashCommand::RevertPatch);
+    let inserted = drain_insert_history(&mut rx);
+    assert!(
+        lines_to_single_string(inserted.last().expect("info message"))
+            .contains("No applied patch to revert."),
+    );
+}
+
+#[tokio::test]
+async fn composer_prompt_context_tracks_model_and_effort() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(None).await;
+    assert_eq!(chat.composer_prompt_context(), None, "off by default");
+
+    chat.config.tui_composer_prompt_model = true;
+    chat.set_model("gpt-5.1-codex-max");
+    chat.set_reasoning_effort(None);
+    assert_eq!(
+        chat.composer_prompt_context().as_deref(),
+        Some("gpt-5.1-codex-max")
+    );
+
+    chat.set_reasoning_effort(Some(ReasoningEffortConfig::High));
+    assert_eq!(
+        chat.composer_prompt_context().as_deref(),
+        Some("gpt-5.1-codex-max · high")
+    );
+    assert_eq!(
+        chat.bottom_pane.composer_prompt_context(),
+        Some("gpt-5.1-codex-max · high")
+    );
+}
+
+#[test]
+fn skill_mentions_resolve_case_and_separator_variants() {
+    let skill = |name: &str| SkillMetadata {
+        name: name.to_string(),
+        description: format!("{name} description"),
+        short_description: None,
+        allowed_tools: Vec::new(),
+        priority: 0,
+        version: None,
+        license: None,
+        applies_to: Vec::new(),
+        path: PathBuf::from(format!("/skills/{name}/SKILL.md")),
+        scope: codex_protocol::protocol::SkillScope::User,
+    };
+    let skills = vec![
+        skill("my-skill"),
+        skill("dup-name"),
+        skill("dup_name"),
+        skill("other"),
+    ];
+    let names = |text: &str| -> Vec<String> {
+        find_skill_mentions(text, &skills)
+            .0
+            .into_iter()
+            .map(|skill| skill.name)
+            .collect()
+    };
+
+    assert_eq!(names("use $My_Skill, please"), vec!["my-skill"]);
+    assert_eq!(names("$other and $my-skill"), vec!["my-skill", "other"]);
+    // `$Dup-Name` matches two skills once normalized, so neither is picked and the user is told.
+    assert_eq!(
+        find_skill_mentions("try $Dup-Name", &skills),
+        (
+            Vec::new(),
+            vec![
+                "$Dup-Name matches several skills (dup-name, dup_name); use the exact name to \
+                 attach one."
+                    .to_string()
+            ]
+        )
+    );
+}
@@ -295,6 +295,85 @@ pub fn normalize_pasted_path(pasted: &str) -> Option<PathBuf> {
     None
 }
 
+/// Normalize line endings in pasted text to `\n`.
+///
+/// Many terminals convert newlines to `\r` when pasting (e.g. [iTerm2]) and Windows clipboards use
+/// `\r\n`, but the composer (like [tui-textarea]) expects `\n`. Both `\r\n` and lone `\r` become
+/// a single `\n`; a naive `\r` → `\n` replace would turn `\r\n` into a blank line. With
+/// `strip_trailing_newline`, one trailing newline is dropped so pasting a single copied line
+/// doesn't add an empty line.
+///
+/// [tui-textarea]: https://github.com/rhysd/tui-textarea/blob/4d18622eeac13b309e0ff6a55a46ac6706da68cf/src/textarea.rs#L782-L783
+/// [iTerm2]: https://github.com/gnachman/iTerm2/blob/5d0c0d9f68523cbd0494dad5422998964a2ecd8d/sources/iTermPasteHelper.m#L206-L216
+pub(crate) fn normalize_pasted_text(pasted: &str, strip_trailing_newline: bool) -> String {
+    let mut normalized = pasted.replace("\r\n", "\n").replace('\r', "\n");
+    if strip_trailing_newline && normalized.ends_with('\n') {
+        normalized.pop();
+    }
+    normalized
+}
+
+/// Remove ANSI escape sequences and other control characters from pasted text
+/// (`tui.paste_strip_ansi`).
+///
+/// Terminal output copied with its colors arrives as CSI (`ESC [ … m`), OSC (`ESC ] … BEL`) and
+/// similar sequences that would otherwise show up as garbage in the composer. Printable text,
+/// tabs and line breaks (`\n`, `\r`) are kept so [`normalize_pasted_text`] can still fix line
+/// endings afterwards.
+pub(crate) fn strip_ansi_sequences(pasted: &str) -> String {
+    let mut out = String::with_capacity(pasted.len());
+    let mut chars = pasted.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\u{1b}' => match chars.next() {
+                // CSI: parameters and intermediates up to a final byte in `@`..=`~`.
+                Some('[') => {
+                    for c in chars.by_ref() {
+                        if ('@'..='~').contains(&c) {
+                            break;
+                        }
+                    }
+                }
+                // OSC/DCS/SOS/PM/APC strings end with BEL or ST (`ESC \`).
+                Some(']' | 'P' | 'X' | '^' | '_') => {
+                    while let Some(c) = chars.next() {
+                        if c == '\u{7}' {
+                            break;
+                        }
+                        if c == '\u{1b}' && chars.peek() == Some(&'\\') {
+                            chars.next();
+                            break;
+                        }
+                    }
+                }
+                // Escapes with intermediate bytes (`ESC ( B`, `ESC # 8`): skip the intermediates
+                // in ` `..=`/` and the final byte after them.
+                Some(' '..='/') => {
+                    for c in chars.by_ref() {
+                        if !(' '..='/').contains(&c) {
+                            break;
+                        }
+                    }
+                }
+                // Two-byte escapes (`ESC 7`, `ESC =`, ...).
+                Some(_) | None => {}
+            },
+            // C1 CSI.
+            '\u{9b}' => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            '\n' | '\r' | '\t' => out.push(c),
+            c if c.is_control() => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
 #[cfg(target_os = "linux")]
 pub(crate) fn is_probably_wsl() -> bool {
     // Primary: Check /proc/version for "microsoft" or "WSL" (most reliable for standard WSL).
@@ -502,3 +581,70 @@ mod pasted_paths_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod pasted_text_tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn crlf_becomes_a_single_newline() {
+        assert_eq!(normalize_pasted_text("one\r\ntwo\r\n", false), "one\ntwo\n");
+    }
+
+    #[test]
+    fn lone_cr_becomes_newline() {
+        assert_eq!(
+            normalize_pasted_text("one\rtwo\rthree", false),
+            "one\ntwo\nthree"
+        );
+    }
+
+    #[test]
+    fn mixed_line_endings_are_normalized() {
+        assert_eq!(
+            normalize_pasted_text("a\r\nb\rc\nd\r\r\ne", false),
+            "a\nb\nc\nd\n\ne"
+        );
+    }
+
+    #[test]
+    fn strips_one_trailing_newline_when_enabled() {
+        assert_eq!(normalize_pasted_text("line\r\n", true), "line");
+        assert_eq!(normalize_pasted_text("line\r", true), "line");
+        assert_eq!(normalize_pasted_text("a\n\n", true), "a\n");
+        assert_eq!(normalize_pasted_text("line", true), "line");
+        assert_eq!(normalize_pasted_text("line\r\n", false), "line\n");
+    }
+
+    #[test]
+    fn strip_ansi_keeps_only_visible_text() {
+        assert_eq!(
+            strip_ansi_sequences("\u{1b}[1;31merror\u{1b}[0m: \u{1b}[38;5;208mboom\u{1b}[m"),
+            "error: boom"
+        );
+        assert_eq!(
+            strip_ansi_sequences(
+                "\u{1b}]8;;https://example.com\u{7}link\u{1b}]8;;\u{1b}\\ done\u{7}"
+            ),
+            "link done"
+        );
+    }
+
+    #[test]
+    fn strip_ansi_consumes_escape_intermediates_and_final_byte() {
+        assert_eq!(
+            strip_ansi_sequences("\u{1b}(Bplain\u{1b})0 \u{1b}#8text\u{1b}7!"),
+            "plain text!"
+        );
+    }
+
+    #[test]
+    fn strip_ansi_preserves_newlines_and_tabs() {
+        let pasted = "\u{1b}[32mok\u{1b}[0m\r\n\tindented\u{1b}[K\nlast";
+        assert_eq!(
+            normalize_pasted_text(&strip_ansi_sequences(pasted), false),
+            "ok\n\tindented\nlast"
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! Human-friendly titles for saved conversations (`/title`).
+//!
+//! Conversations are otherwise identified only by their id and first user message. Titles are
+//! stored in `$CODEX_HOME/conversation_titles.json` as a flat `{ "<conversation id>": "<title>" }`
+//! map, so the resume picker can look them up without opening every rollout file.
+//!
+//! Titles are sanitized the same way skill names are: collapsed onto a single line, stripped of
+//! control characters, and capped at [`MAX_TITLE_LEN`] characters. The store is rewritten through
+//! a temp file renamed into place, and a missing or unreadable file is treated as "no titles" so a
+//! corrupt store never blocks resuming a session.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_protocol::ConversationId;
+use tempfile::NamedTempFile;
+
+/// File name of the title store, relative to `codex_home`.
+pub(crate) const CONVERSATION_TITLES_FILENAME: &str = "conversation_titles.json";
+
+/// Maximum title length in characters (matches the skill `name` limit).
+pub(crate) const MAX_TITLE_LEN: usize = 64;
+
+/// Conversation id (as a string) to title.
+pub(crate) type ConversationTitles = BTreeMap<String, String>;
+
+fn titles_path(codex_home: &Path) -> PathBuf {
+    codex_home.join(CONVERSATION_TITLES_FILENAME)
+}
+
+/// Collapse `raw` onto one line, drop control characters (e.g. stray escape sequences that
+/// would restyle the resume picker), and cap it at [`MAX_TITLE_LEN`] characters.
+///
+/// Returns `None` when nothing but whitespace remains.
+pub(crate) fn sanitize_title(raw: &str) -> Option<String> {
+    let printable: String = raw
+        .chars()
+        .filter(|c| c.is_whitespace() || !c.is_control())
+        .collect();
+    let single_line = printable.split_whitespace().collect::<Vec<_>>().join(" ");
+    if single_line.is_empty() {
+        return None;
+    }
+    Some(single_line.chars().take(MAX_TITLE_LEN).collect())
+}
+
+/// Load every stored title. Missing or malformed files yield an empty map.
+pub(crate) fn load_titles(codex_home: &Path) -> ConversationTitles {
+    let path = titles_path(codex_home);
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return ConversationTitles::new(),
+        Err(err) => {
+            tracing::warn!("failed to read {}: {err}", path.display());
+            return ConversationTitles::new();
+        }
+    };
+    serde_json::from_str(&contents).unwrap_or_else(|err| {
+        tracing::warn!("failed to parse {}: {err}", path.display());
+        ConversationTitles::new()
+    })
+}
+
+/// Title stored for `conversation_id`, if any.
+pub(crate) fn load_title(codex_home: &Path, conversation_id: ConversationId) -> Option<String> {
+    load_titles(codex_home).remove(&conversation_id.to_string())
+}
+
+/// Store `title` for `conversation_id`, returning the sanitized title that was saved.
+///
+/// A title that sanitizes to nothing removes any stored title instead.
+pub(crate) fn save_title(
+    codex_home: &Path,
+    conversation_id: ConversationId,
+    title: &str,
+) -> io::Result<Option<String>> {
+    let mut titles = load_titles(codex_home);
+    let key = conversation_id.to_string();
+    let title = sanitize_title(title);
+    match &title {
+        Some(title) => {
+            titles.insert(key, title.clone());
+        }
+        None => {
+            titles.remove(&key);
+        }
+    }
+
+    let json = serde_json::to_string_pretty(&titles).map_err(io::Error::other)?;
+    std::fs::create_dir_all(codex_home)?;
+    let tmp = NamedTempFile::new_in(codex_home)?;
+    std::fs::write(tmp.path(), format!("{json}\n"))?;
+    tmp.persist(titles_path(codex_home))
+        .map_err(|err| err.error)?;
+    Ok(title)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+
+    #[test]
+    fn sanitize_title_collapses_whitespace_and_caps_length() {
+        assert_eq!(
+            sanitize_title("  fix\nthe   parser\t"),
+            Some("fix the parser".to_string())
+        );
+        assert_eq!(sanitize_title(" \n\t "), None);
+        assert_eq!(
+            sanitize_title("\u{1b}[31mred\u{1b}[0m\u{7} title"),
+            Some("[31mred[0m title".to_string())
+        );
+        assert_eq!(sanitize_title("\u{1b}\u{0}"), None);
+
+        let long = "\u{1F4A1}".repeat(MAX_TITLE_LEN + 5);
+        let capped = sanitize_title(&long).expect("title");
+        assert_eq!(capped.chars().count(), MAX_TITLE_LEN);
+    }
+
+    #[test]
+    fn titles_round_trip_through_codex_home() {
+        let dir = tempdir().expect("tempdir");
+        let first = ConversationId::new();
+        let second = ConversationId::new();
+
+        assert_eq!(load_title(dir.path(), first), None);
+
+        let saved = save_title(dir.path(), first, "Parser\nrewrite").expect("save");
+        assert_eq!(saved, Some("Parser rewrite".to_string()));
+        save_title(dir.path(), second, "Docs").expect("save");
+
+        assert_eq!(
+            load_title(dir.path(), first),
+            Some("Parser rewrite".to_string())
+        );
+        assert_eq!(load_titles(dir.path()).len(), 2);
+
+        // Clearing one title leaves the others intact.
+        assert_eq!(save_title(dir.path(), first, "   ").expect("clear"), None);
+        assert_eq!(load_title(dir.path(), first), None);
+        assert_eq!(load_title(dir.path(), second), Some("Docs".to_string()));
+    }
+
+    #[test]
+    fn malformed_store_loads_as_empty() {
+        let dir = tempdir().expect("tempdir");
+        std::fs::write(dir.path().join(CONVERSATION_TITLES_FILENAME), "not json").expect("write");
+
+        assert_eq!(load_titles(dir.path()), ConversationTitles::new());
+    }
+}
@@ -76,6 +76,123 @@ impl From<DiffSummary> for Box<dyn Renderable> {
     }
 }
 
+/// Aggregate size of a patch: how many files it touches and how many lines it adds/removes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct DiffStat {
+    pub(crate) files: usize,
+    pub(crate) insertions: usize,
+    pub(crate) deletions: usize,
+}
+
+impl DiffStat {
+    pub(crate) fn from_changes(changes: &HashMap<PathBuf, FileChange>) -> Self {
+        Self::from_rows(&collect_rows(changes))
+    }
+
+    fn from_rows(rows: &[Row]) -> Self {
+        Self {
+            files: rows.len(),
+            insertions: rows.iter().map(|r| r.added).sum(),
+            deletions: rows.iter().map(|r| r.removed).sum(),
+        }
+    }
+}
+
+/// Compact apply-patch preview used by the approval overlay.
+///
+/// Shows a stat line and one row per file; a file's hunks are only rendered once the user
+/// expands it. The full diff stays available through [`DiffSummary`].
+#[derive(Clone)]
+pub(crate) struct PatchPreview {
+    rows: Vec<Row>,
+    cwd: PathBuf,
+    expanded: Vec<bool>,
+    focused: usize,
+}
+
+impl PatchPreview {
+    pub(crate) fn new(changes: &HashMap<PathBuf, FileChange>, cwd: PathBuf) -> Self {
+        let rows = collect_rows(changes);
+        let expanded = vec![false; rows.len()];
+        Self {
+            rows,
+            cwd,
+            expanded,
+            focused: 0,
+        }
+    }
+
+    pub(crate) fn stat(&self) -> DiffStat {
+        DiffStat::from_rows(&self.rows)
+    }
+
+    pub(crate) fn is_expanded(&self, idx: usize) -> bool {
+        self.expanded.get(idx).copied().unwrap_or(false)
+    }
+
+    /// Move the focus to the next file (wrapping). Returns whether the focus moved.
+    pub(crate) fn focus_next(&mut self) -> bool {
+        if self.rows.len() < 2 {
+            return false;
+        }
+        self.focused = (self.focused + 1) % self.rows.len();
+        true
+    }
+
+    /// Move the focus to the previous file (wrapping). Returns whether the focus moved.
+    pub(crate) fn focus_prev(&mut self) -> bool {
+        if self.rows.len() < 2 {
+            return false;
+        }
+        self.focused = (self.focused + self.rows.len() - 1) % self.rows.len();
+        true
+    }
+
+    /// Expand or collapse the focused file's hunks.
+    pub(crate) fn toggle_focused(&mut self) {
+        if let Some(expanded) = self.expanded.get_mut(self.focused) {
+            *expanded = !*expanded;
+        }
+    }
+
+    pub(crate) fn renderable(&self) -> Box<dyn Renderable> {
+        let stat = self.stat();
+        let noun = if stat.files == 1 { "file" } else { "files" };
+        let mut header = RtLine::from(format!("{} {noun} changed ", stat.files));
+        header.extend(render_line_count_summary(stat.insertions, stat.deletions));
+        header.push_span(" · space to expand".dim());
+        if stat.files > 1 {
+            header.push_span(" · tab to switch file".dim());
+        }
+
+        let mut rows: Vec<Box<dyn Renderable>> = vec![Box::new(header)];
+        for (idx, row) in self.rows.iter().enumerate() {
+            let expanded = self.is_expanded(idx);
+            let marker = if expanded { "▾ " } else { "▸ " };
+            let mut path = display_path_for(&row.path, &self.cwd);
+            if let Some(move_path) = &row.move_path {
+                path.push_str(&format!(" → {}", display_path_for(move_path, &self.cwd)));
+            }
+            let path = if idx == self.focused && self.rows.len() > 1 {
+                path.cyan()
+            } else {
+                path.into()
+            };
+            let mut line = RtLine::from(vec![marker.dim(), path, " ".into()]);
+            line.extend(render_line_count_summary(row.added, row.removed));
+            rows.push(Box::new(line));
+            if expanded {
+                rows.push(Box::new(InsetRenderable::new(
+                    Box::new(row.change.clone()) as Box<dyn Renderable>,
+                    Insets::tlbr(0, 4, 0, 0),
+                )));
+            }
+        }
+
+        Box::new(ColumnRenderable::with(rows))
+    }
+}
+
 pub(crate) fn create_diff_summary(
     changes: &HashMap<PathBuf, FileChange>,
     cwd: &Path,
@@ -88,7 +205,6 @@ pub(crate) fn create_diff_summary(
 // Shared row for per-file presentation
 #[derive(Clone)]
 struct Row {
-    #[allow(dead_code)]
     path: PathBuf,
     move_path: Option<PathBuf>,
     added: usize,
@@ -470,6 +586,92 @@ mod tests {
         assert_snapshot!(name, text);
     }
 
+    fn render_text(renderable: &dyn Renderable, width: u16) -> String {
+        let height = renderable.desired_height(width);
+        let area = Rect::new(0, 0, width, height);
+        let mut buf = Buffer::empty(area);
+        renderable.render(area, &mut buf);
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn stat_fixture() -> HashMap<PathBuf, FileChange> {
+        let original = "one\ntwo\nthree\n";
+        let modified = "one\nTWO\nthree\n";
+        let patch = diffy::create_patch(original, modified).to_string();
+        HashMap::from([
+            (
+                PathBuf::from("/repo/a_added.txt"),
+                FileChange::Add {
+                    content: "x\ny\nz\n".to_string(),
+                },
+            ),
+            (
+                PathBuf::from("/repo/b_deleted.txt"),
+                FileChange::Delete {
+                    content: "gone\nalso gone\n".to_string(),
+                },
+            ),
+            (
+                PathBuf::from("/repo/c_updated.txt"),
+                FileChange::Update {
+                    unified_diff: patch,
+                    move_path: None,
+                },
+            ),
+        ])
+    }
+
+    #[test]
+    fn diff_stat_counts_files_insertions_and_deletions() {
+        assert_eq!(
+            DiffStat::from_changes(&stat_fixture()),
+            DiffStat {
+                files: 3,
+                insertions: 4,
+                deletions: 3,
+            }
+        );
+        assert_eq!(DiffStat::from_changes(&HashMap::new()), DiffStat::default());
+    }
+
+    #[test]
+    fn patch_preview_is_collapsed_until_a_file_is_expanded() {
+        let mut preview = PatchPreview::new(&stat_fixture(), PathBuf::from("/repo"));
+        assert_eq!(preview.stat(), DiffStat::from_changes(&stat_fixture()));
+
+        let collapsed = render_text(preview.renderable().as_ref(), 80);
+        assert_eq!(
+            collapsed,
+            "3 files changed (+4 -3) · space to expand · tab to switch file\n\
+             ▸ a_added.txt (+3 -0)\n\
+             ▸ b_deleted.txt (+0 -2)\n\
+             ▸ c_updated.txt (+1 -1)"
+        );
+
+        // Expanding the second file reveals only its hunks.
+        assert!(preview.focus_next());
+        preview.toggle_focused();
+        assert!(preview.is_expanded(1));
+        let expanded = render_text(preview.renderable().as_ref(), 80);
+        assert!(expanded.contains("▾ b_deleted.txt (+0 -2)"), "{expanded}");
+        assert!(expanded.contains("-also gone"), "{expanded}");
+        assert!(!expanded.contains("+x"), "{expanded}");
+        assert!(!expanded.contains("+TWO"), "{expanded}");
+
+        // Collapsing it again restores the compact view.
+        preview.toggle_focused();
+        assert_eq!(render_text(preview.renderable().as_ref(), 80), collapsed);
+    }
+
     #[test]
     fn ui_snapshot_wrap_behavior_insert() {
         // Narrow width to force wrapping within our diff line rendering
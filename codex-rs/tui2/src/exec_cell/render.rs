@@ -206,6 +206,17 @@ impl HistoryCell for ExecCell {
         self.transcript_lines(width).len() as u16
     }
 
+    fn source_path(&self) -> Option<String> {
+        // Only a lone call that reads exactly one file has unambiguous provenance.
+        let [call] = self.calls.as_slice() else {
+            return None;
+        };
+        let [ParsedCommand::Read { path, .. }] = call.parsed.as_slice() else {
+            return None;
+        };
+        Some(path.display().to_string())
+    }
+
     fn transcript_lines(&self, width: u16) -> Vec<Line<'static>> {
         let mut lines: Vec<Line<'static>> = vec![];
         for (i, call) in self.iter_calls().enumerate() {
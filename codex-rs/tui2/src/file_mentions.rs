@@ -0,0 +1,484 @@
+//! Expansion of `@path` file mentions into attached file contents.
+//!
+//! When `tui.expand_file_mentions` is enabled, a composer submission like
+//! `explain @src/main.rs` is sent with the literal text *plus* an extra text
+//! item containing the contents of `src/main.rs`, so the model does not need a
+//! tool call to read a file the user already pointed at.
+//!
+//! Only mentions inserted by the file search popup are expanded: the composer
+//! inserts them as atomic elements and hands their text over on submit, so an
+//! `@username` typed by hand stays plain text. Paths containing whitespace are
+//! quoted (`@"docs/some file.md"`). Relative paths are resolved against the
+//! session cwd.
+//!
+//! A mention can end in a line range (`@src/main.rs:10-20`, or `:12` for a
+//! single line). Only those lines are attached, numbered and padded with
+//! [`FILE_MENTION_RANGE_CONTEXT_LINES`] lines of context on each side; ranges
+//! past the end of the file are clamped to its last line. A trailing `:suffix`
+//! made only of digits and `-` that is not a valid range (`:0-3`, `:5-2`,
+//! `:1-`) is rejected rather than read as part of the path.
+//!
+//! Attachments are capped at [`FILE_MENTION_MAX_BYTES`]; larger files are
+//! truncated (at a char boundary) with a trailing note. Files are read on a
+//! blocking worker ([`read_file_mentions`]), never on the UI thread, and only as
+//! much as is needed: `max_bytes + 1` for a whole file, and at most
+//! [`FILE_MENTION_RANGE_READ_LIMIT`] when looking for a line range (ranges
+//! beyond that are clamped like ranges past the end of the file). Only regular
+//! files are opened, so a FIFO or device never stalls the read. Mentions that
+//! do not resolve to a regular file, or carry a malformed range, are left as
+//! literal text and reported back so the caller can surface a warning.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Maximum number of bytes of a mentioned file that are attached to a message.
+pub(crate) const FILE_MENTION_MAX_BYTES: usize = 32 * 1024;
+
+/// Most bytes read from a file while looking for a mentioned line range.
+pub(crate) const FILE_MENTION_RANGE_READ_LIMIT: u64 = 4 * 1024 * 1024;
+
+/// Lines of context attached before and after a requested line range.
+pub(crate) const FILE_MENTION_RANGE_CONTEXT_LINES: usize = 3;
+
+/// An inclusive, 1-based line range from an `@path:START-END` mention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LineRange {
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+/// The result of expanding the file mentions in a submitted message.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct FileMentionExpansion {
+    /// Rendered attachment blocks, one per distinct mentioned file, in mention order.
+    pub(crate) attachments: Vec<String>,
+    /// Mentioned paths (as written) that did not resolve to a readable file.
+    pub(crate) missing: Vec<String>,
+    /// Mentions (as written) whose line range could not be parsed.
+    pub(crate) malformed: Vec<String>,
+}
+
+/// Return the path (with any `:range` suffix) of a file-mention element such as `@a.rs`,
+/// `@a.rs:3-9` or `@"my notes.md":3-9`, or `None` if `element` is not a mention.
+pub(crate) fn parse_file_mention(element: &str) -> Option<String> {
+    let after_sigil = element.strip_prefix('@')?;
+    let path = match after_sigil.strip_prefix('"') {
+        Some(quoted) => match quoted.split_once('"') {
+            // Keep a line range written after the closing quote: `@"a b.rs":3-9`.
+            Some((path, suffix)) if suffix.is_empty() || suffix.starts_with(':') => {
+                format!("{path}{suffix}")
+            }
+            Some(_) => return None,
+            None => quoted.to_string(),
+        },
+        None => after_sigil.to_string(),
+    };
+    (!path.is_empty()).then_some(path)
+}
+
+/// Split a trailing `:suffix` that looks like a line range off `mention`.
+///
+/// The suffix is returned unvalidated; anything after the last `:` that is not made only of
+/// digits and `-` stays part of the path.
+pub(crate) fn split_range_suffix(mention: &str) -> (&str, Option<&str>) {
+    match mention.rsplit_once(':') {
+        Some((path, suffix))
+            if !path.is_empty()
+                && !suffix.is_empty()
+                && suffix.chars().all(|c| c.is_ascii_digit() || c == '-') =>
+        {
+            (path, Some(suffix))
+        }
+        _ => (mention, None),
+    }
+}
+
+/// Split `mention` into its path and optional line range.
+///
+/// Returns `None` when the mention ends in a range-like suffix that is not `START` or
+/// `START-END` with `1 <= START <= END`.
+pub(crate) fn parse_mention_range(mention: &str) -> Option<(&str, Option<LineRange>)> {
+    let (path, suffix) = split_range_suffix(mention);
+    let Some(suffix) = suffix else {
+        return Some((path, None));
+    };
+    let (start, end) = suffix.split_once('-').unwrap_or((suffix, suffix));
+    let start: usize = start.parse().ok()?;
+    let end: usize = end.parse().ok()?;
+    if start == 0 || end < start {
+        return None;
+    }
+    Some((path, Some(LineRange { start, end })))
+}
+
+/// Render the attachment block for a mentioned file.
+///
+/// `contents` (the start of a file that is `total_bytes` long) longer than
+/// `max_bytes` are cut at the last char boundary that fits and followed by a
+/// note stating how much was included.
+pub(crate) fn render_file_attachment(
+    display_path: &str,
+    contents: &[u8],
+    total_bytes: usize,
+    max_bytes: usize,
+) -> String {
+    let text = String::from_utf8_lossy(contents);
+    render_block(
+        &format!("<file path=\"{display_path}\">"),
+        &text,
+        total_bytes,
+        max_bytes,
+    )
+}
+
+/// Render the attachment block for `range` of a mentioned file.
+///
+/// The range is clamped to the file's last line and widened by
+/// [`FILE_MENTION_RANGE_CONTEXT_LINES`] on each side; every line is prefixed with its number.
+pub(crate) fn render_line_range_attachment(
+    display_path: &str,
+    contents: &[u8],
+    range: LineRange,
+    max_bytes: usize,
+) -> String {
+    let text = String::from_utf8_lossy(contents);
+    let lines: Vec<&str> = text.lines().collect();
+    if lines.is_empty() {
+        return render_file_attachment(display_path, contents, contents.len(), max_bytes);
+    }
+
+    let total = lines.len();
+    let end = range.end.min(total);
+    let start = range.start.min(end);
+    let context_start = start
+        .saturating_sub(FILE_MENTION_RANGE_CONTEXT_LINES)
+        .max(1);
+    let context_end = (end + FILE_MENTION_RANGE_CONTEXT_LINES).min(total);
+
+    let mut body = String::new();
+    for (number, line) in (context_start..=context_end).zip(&lines[context_start - 1..context_end])
+    {
+        body.push_str(&format!("{number}: {line}\n"));
+    }
+    render_block(
+        &format!(
+            "<file path=\"{display_path}\" lines=\"{start}-{end}\" context=\"{context_start}-{context_end}\">"
+        ),
+        &body,
+        body.len(),
+        max_bytes,
+    )
+}
+
+fn render_block(open_tag: &str, text: &str, total_bytes: usize, max_bytes: usize) -> String {
+    let (body, truncated) = if text.len() > max_bytes {
+        let mut end = max_bytes;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        (&text[..end], true)
+    } else {
+        (text, false)
+    };
+
+    let mut out = format!("{open_tag}\n{body}");
+    if !body.is_empty() && !body.ends_with('\n') {
+        out.push('\n');
+    }
+    if truncated {
+        let shown = body.len();
+        out.push_str(&format!(
+            "[truncated: showing the first {shown} of {total_bytes} bytes]\n"
+        ));
+    }
+    out.push_str("</file>");
+    out
+}
+
+/// Read at most `limit` bytes from the start of the regular file at `path`,
+/// along with the file's full size.
+///
+/// Returns `None` for anything that is not a regular file (checked before
+/// opening, since opening a FIFO for reading blocks until a writer appears) or
+/// that cannot be read.
+fn read_file_prefix(path: &Path, limit: u64) -> Option<(Vec<u8>, usize)> {
+    use std::io::Read;
+
+    if !std::fs::metadata(path).ok()?.is_file() {
+        return None;
+    }
+    let file = std::fs::File::open(path).ok()?;
+    let metadata = file.metadata().ok()?;
+    if !metadata.is_file() {
+        return None;
+    }
+    let mut contents = Vec::new();
+    file.take(limit).read_to_end(&mut contents).ok()?;
+    let total_bytes = usize::try_from(metadata.len())
+        .unwrap_or(usize::MAX)
+        .max(contents.len());
+    Some((contents, total_bytes))
+}
+
+/// Resolve the file-mention `elements` (as inserted in the composer) against `cwd` and read
+/// the files, once per distinct mention.
+///
+/// This does blocking file I/O; the UI goes through [`read_file_mentions`].
+pub(crate) fn expand_file_mentions(
+    elements: &[String],
+    cwd: &Path,
+    max_bytes: usize,
+) -> FileMentionExpansion {
+    let mut mentions: Vec<String> = Vec::new();
+    for mention in elements
+        .iter()
+        .filter_map(|element| parse_file_mention(element))
+    {
+        if !mentions.contains(&mention) {
+            mentions.push(mention);
+        }
+    }
+
+    let mut expansion = FileMentionExpansion::default();
+    for mention in mentions {
+        let Some((display_path, range)) = parse_mention_range(&mention) else {
+            expansion.malformed.push(mention);
+            continue;
+        };
+        let path = cwd.join(display_path);
+        let limit = match range {
+            Some(_) => FILE_MENTION_RANGE_READ_LIMIT,
+            None => max_bytes as u64 + 1,
+        };
+        let attachment = match (read_file_prefix(&path, limit), range) {
+            (Some((contents, _)), Some(range)) => {
+                render_line_range_attachment(display_path, &contents, range, max_bytes)
+            }
+            (Some((contents, total_bytes)), None) => {
+                render_file_attachment(display_path, &contents, total_bytes, max_bytes)
+            }
+            (None, _) => {
+                expansion.missing.push(mention);
+                continue;
+            }
+        };
+        expansion.attachments.push(attachment);
+    }
+    expansion
+}
+
+/// [`expand_file_mentions`] on a blocking worker thread.
+pub(crate) async fn read_file_mentions(
+    elements: Vec<String>,
+    cwd: PathBuf,
+    max_bytes: usize,
+) -> FileMentionExpansion {
+    tokio::task::spawn_blocking(move || expand_file_mentions(&elements, &cwd, max_bytes))
+        .await
+        .unwrap_or_else(|err| {
+            tracing::warn!("reading file mentions failed: {err}");
+            FileMentionExpansion::default()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+
+    fn mentions(elements: &[&str]) -> Vec<String> {
+        elements.iter().map(|element| element.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_mention_elements() {
+        assert_eq!(parse_file_mention("@a.rs"), Some("a.rs".to_string()));
+        assert_eq!(
+            parse_file_mention(r#"@"my notes.md""#),
+            Some("my notes.md".to_string())
+        );
+        assert_eq!(parse_file_mention("@"), None);
+        assert_eq!(parse_file_mention("[Pasted Content 1200 chars]"), None);
+    }
+
+    #[test]
+    fn non_mention_elements_and_duplicates_are_ignored() {
+        let dir = tempdir().expect("tempdir");
+        std::fs::write(dir.path().join("a.rs"), "a\n").expect("write");
+
+        let expansion = expand_file_mentions(
+            &mentions(&["@a.rs", "[image 10x10 PNG]", "@a.rs"]),
+            dir.path(),
+            1024,
+        );
+        assert_eq!(
+            expansion.attachments,
+            vec!["<file path=\"a.rs\">\na\n</file>".to_string()]
+        );
+    }
+
+    #[test]
+    fn small_file_is_attached_verbatim() {
+        let dir = tempdir().expect("tempdir");
+        std::fs::write(dir.path().join("small.txt"), "hello\nworld\n").expect("write");
+
+        let expansion = expand_file_mentions(&mentions(&["@small.txt"]), dir.path(), 1024);
+        assert_eq!(
+            expansion,
+            FileMentionExpansion {
+                attachments: vec!["<file path=\"small.txt\">\nhello\nworld\n</file>".to_string()],
+                missing: Vec::new(),
+                malformed: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn large_file_is_truncated_with_note() {
+        let dir = tempdir().expect("tempdir");
+        std::fs::write(dir.path().join("big.txt"), "é".repeat(10)).expect("write");
+
+        // 5 bytes would split the third `é`, so only two chars (4 bytes) fit.
+        let expansion = expand_file_mentions(&mentions(&["@big.txt"]), dir.path(), 5);
+        assert_eq!(
+            expansion,
+            FileMentionExpansion {
+                attachments: vec![
+                    "<file path=\"big.txt\">\néé\n[truncated: showing the first 4 of 20 bytes]\n</file>"
+                        .to_string()
+                ],
+                missing: Vec::new(),
+                malformed: Vec::new(),
+            }
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn fifo_is_reported_missing_without_blocking() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let dir = tempdir().expect("tempdir");
+        let fifo = dir.path().join("pipe");
+        let c_path = std::ffi::CString::new(fifo.as_os_str().as_bytes()).expect("c path");
+        // SAFETY: `c_path` is a valid NUL-terminated path for the duration of the call.
+        assert_eq!(unsafe { libc::mkfifo(c_path.as_ptr(), 0o600) }, 0);
+
+        let expansion = expand_file_mentions(&mentions(&["@pipe"]), dir.path(), 1024);
+        assert_eq!(expansion.missing, vec!["pipe".to_string()]);
+        assert!(expansion.attachments.is_empty());
+    }
+
+    #[test]
+    fn missing_paths_are_reported() {
+        let dir = tempdir().expect("tempdir");
+        std::fs::create_dir(dir.path().join("src")).expect("mkdir");
+
+        let expansion = expand_file_mentions(&mentions(&["@nope.rs", "@src"]), dir.path(), 1024);
+        assert_eq!(
+            expansion,
+            FileMentionExpansion {
+                attachments: Vec::new(),
+                missing: vec!["nope.rs".to_string(), "src".to_string()],
+                malformed: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_line_ranges_from_mentions() {
+        assert_eq!(
+            parse_mention_range("src/main.rs:10-20"),
+            Some(("src/main.rs", Some(LineRange { start: 10, end: 20 })))
+        );
+        assert_eq!(
+            parse_mention_range("src/main.rs:7"),
+            Some(("src/main.rs", Some(LineRange { start: 7, end: 7 })))
+        );
+        assert_eq!(
+            parse_mention_range("src/main.rs"),
+            Some(("src/main.rs", None))
+        );
+        // A non-numeric suffix is part of the path.
+        assert_eq!(
+            parse_mention_range("notes:draft.md"),
+            Some(("notes:draft.md", None))
+        );
+        assert_eq!(
+            parse_file_mention("@a.rs:1-2"),
+            Some("a.rs:1-2".to_string())
+        );
+        assert_eq!(
+            parse_file_mention(r#"@"my notes.md":3-4"#),
+            Some("my notes.md:3-4".to_string())
+        );
+    }
+
+    #[test]
+    fn malformed_ranges_are_rejected() {
+        for mention in [
+            "a.rs:0-3",
+            "a.rs:5-2",
+            "a.rs:1-",
+            "a.rs:-4",
+            "a.rs:1-2-3",
+            "a.rs:0",
+        ] {
+            assert_eq!(parse_mention_range(mention), None, "{mention}");
+        }
+
+        let dir = tempdir().expect("tempdir");
+        std::fs::write(dir.path().join("a.rs"), "one\n").expect("write");
+        let expansion = expand_file_mentions(&mentions(&["@a.rs:5-2"]), dir.path(), 1024);
+        assert_eq!(
+            expansion,
+            FileMentionExpansion {
+                attachments: Vec::new(),
+                missing: Vec::new(),
+                malformed: vec!["a.rs:5-2".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn line_range_is_attached_with_context() {
+        let dir = tempdir().expect("tempdir");
+        let contents: String = (1..=12).map(|n| format!("line {n}\n")).collect();
+        std::fs::write(dir.path().join("a.rs"), contents).expect("write");
+
+        let expansion = expand_file_mentions(&mentions(&["@a.rs:6-7"]), dir.path(), 1024);
+        assert_eq!(
+            expansion.attachments,
+            vec![
+                "<file path=\"a.rs\" lines=\"6-7\" context=\"3-10\">\n3: line 3\n4: line 4\n5: line 5\n6: line 6\n7: line 7\n8: line 8\n9: line 9\n10: line 10\n</file>"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn line_range_beyond_eof_is_clamped() {
+        let dir = tempdir().expect("tempdir");
+        std::fs::write(dir.path().join("a.rs"), "one\ntwo\nthree\nfour\nfive\n").expect("write");
+
+        let expansion =
+            expand_file_mentions(&mentions(&["@a.rs:4-50", "@a.rs:90-99"]), dir.path(), 1024);
+        assert_eq!(
+            expansion,
+            FileMentionExpansion {
+                attachments: vec![
+                    "<file path=\"a.rs\" lines=\"4-5\" context=\"1-5\">\n1: one\n2: two\n3: three\n4: four\n5: five\n</file>"
+                        .to_string(),
+                    "<file path=\"a.rs\" lines=\"5-5\" context=\"2-5\">\n2: two\n3: three\n4: four\n5: five\n</file>"
+                        .to_string(),
+                ],
+                missing: Vec::new(),
+                malformed: Vec::new(),
+            }
+        );
+
+        let missing = expand_file_mentions(&mentions(&["@gone.rs:1-3"]), dir.path(), 1024);
+        assert_eq!(missing.missing, vec!["gone.rs:1-3".to_string()]);
+    }
+}
@@ -34,8 +34,9 @@ use crate::app_event_sender::AppEventSender;
 const MAX_FILE_SEARCH_RESULTS: NonZeroUsize = NonZeroUsize::new(20).unwrap();
 const NUM_FILE_SEARCH_THREADS: NonZeroUsize = NonZeroUsize::new(2).unwrap();
 
-/// How long to wait after a keystroke before firing the first search when none
-/// is currently running. Keeps early queries more meaningful.
+/// Default time to wait after a keystroke before firing the first search when
+/// none is currently running (`tui.file_search_debounce_ms`). Keeps early
+/// queries more meaningful.
 const FILE_SEARCH_DEBOUNCE: Duration = Duration::from_millis(100);
 
 const ACTIVE_SEARCH_COMPLETE_POLL_INTERVAL: Duration = Duration::from_millis(20);
@@ -47,6 +48,7 @@ pub(crate) struct FileSearchManager {
 
     search_dir: PathBuf,
     app_tx: AppEventSender,
+    debounce: Duration,
 }
 
 struct SearchState {
@@ -75,9 +77,16 @@ impl FileSearchManager {
             })),
             search_dir,
             app_tx: tx,
+            debounce: FILE_SEARCH_DEBOUNCE,
         }
     }
 
+    /// Override the idle delay before a search starts; `None` keeps the default.
+    pub fn with_debounce_ms(mut self, debounce_ms: Option<u64>) -> Self {
+        self.debounce = debounce_ms.map_or(FILE_SEARCH_DEBOUNCE, Duration::from_millis);
+        self
+    }
+
     /// Call whenever the user edits the `@` token.
     pub fn on_user_query(&self, query: String) {
         {
@@ -117,10 +126,11 @@ impl FileSearchManager {
         let state = self.state.clone();
         let search_dir = self.search_dir.clone();
         let tx_clone = self.app_tx.clone();
+        let debounce = self.debounce;
         thread::spawn(move || {
             // Always do a minimum debounce, but then poll until the
             // `active_search` is cleared.
-            thread::sleep(FILE_SEARCH_DEBOUNCE);
+            thread::sleep(debounce);
             loop {
                 #[expect(clippy::unwrap_used)]
                 if state.lock().unwrap().active_search.is_none() {
@@ -197,3 +207,45 @@ impl FileSearchManager {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+    use tokio::sync::mpsc::error::TryRecvError;
+    use tokio::sync::mpsc::unbounded_channel;
+
+    #[test]
+    fn rapid_queries_dispatch_a_single_search_for_the_latest_query() {
+        let dir = tempdir().expect("tempdir");
+        std::fs::write(dir.path().join("alpha.rs"), "").expect("write file");
+        let (tx, mut rx) = unbounded_channel();
+        let manager = FileSearchManager::new(dir.path().to_path_buf(), AppEventSender::new(tx))
+            .with_debounce_ms(Some(50));
+
+        for query in ["a", "al", "alp"] {
+            manager.on_user_query(query.to_string());
+        }
+
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        let mut queries = Vec::new();
+        while std::time::Instant::now() < deadline {
+            match rx.try_recv() {
+                Ok(AppEvent::FileSearchResult { query, .. }) => queries.push(query),
+                Ok(_) => {}
+                Err(TryRecvError::Empty) if !queries.is_empty() => break,
+                Err(_) => thread::sleep(Duration::from_millis(10)),
+            }
+        }
+        // Give any (unexpected) follow-up search time to report.
+        thread::sleep(Duration::from_millis(200));
+        while let Ok(event) = rx.try_recv() {
+            if let AppEvent::FileSearchResult { query, .. } = event {
+                queries.push(query);
+            }
+        }
+
+        assert_eq!(queries, vec!["alp".to_string()]);
+    }
+}
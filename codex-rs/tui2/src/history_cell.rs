@@ -1,3 +1,6 @@
+use crate::approval_history::ApprovalKind;
+use crate::approval_history::ApprovalOutcome;
+use crate::approval_history::ApprovalRecord;
 use crate::diff_render::create_diff_summary;
 use crate::diff_render::display_path_for;
 use crate::exec_cell::CommandOutput;
@@ -11,9 +14,13 @@ use crate::markdown::append_markdown;
 use crate::render::line_utils::line_to_static;
 use crate::render::line_utils::prefix_lines;
 use crate::render::renderable::Renderable;
+use crate::style::agent_text_style;
+use crate::style::error_style;
 use crate::style::user_message_style;
+use crate::style::warning_style;
 use crate::text_formatting::format_and_truncate_tool_result;
 use crate::text_formatting::truncate_text;
+use crate::theme::Theme;
 use crate::tooltips;
 use crate::ui_consts::LIVE_PREFIX_COLS;
 use crate::update_action::UpdateAction;
@@ -28,6 +35,7 @@ use codex_core::protocol::FileChange;
 use codex_core::protocol::McpAuthStatus;
 use codex_core::protocol::McpInvocation;
 use codex_core::protocol::SessionConfiguredEvent;
+use codex_protocol::num_format::format_with_separators;
 use codex_protocol::openai_models::ReasoningEffort as ReasoningEffortConfig;
 use codex_protocol::plan_tool::PlanItemArg;
 use codex_protocol::plan_tool::StepStatus;
@@ -149,6 +157,13 @@ pub(crate) trait HistoryCell: std::fmt::Debug + Send + Sync + Any {
     fn is_stream_continuation(&self) -> bool {
         false
     }
+
+    /// Display path of the single file this cell's content comes from, if any.
+    ///
+    /// Selection copy uses this to prepend a provenance comment (`tui.copy_path_header`).
+    fn source_path(&self) -> Option<String> {
+        None
+    }
 }
 
 impl Renderable for Box<dyn HistoryCell> {
@@ -182,6 +197,7 @@ impl dyn HistoryCell {
 #[derive(Debug)]
 pub(crate) struct UserHistoryCell {
     pub message: String,
+    pub theme: Theme,
 }
 
 impl HistoryCell for UserHistoryCell {
@@ -196,13 +212,12 @@ impl HistoryCell for UserHistoryCell {
             )
             .max(1);
 
-        let style = user_message_style();
+        let style = user_message_style(&self.theme);
 
         let (wrapped, joiner_before) = crate::wrapping::word_wrap_lines_with_joiners(
             self.message.lines().map(|l| Line::from(l).style(style)),
-            // Wrap algorithm matches textarea.rs.
-            RtOptions::new(usize::from(wrap_width))
-                .wrap_algorithm(textwrap::WrapAlgorithm::FirstFit),
+            // `RtOptions::new` uses the configured wrap algorithm, matching textarea.rs.
+            RtOptions::new(usize::from(wrap_width)),
         );
 
         let mut lines: Vec<Line<'static>> = Vec::new();
@@ -315,13 +330,15 @@ impl HistoryCell for ReasoningSummaryCell {
 pub(crate) struct AgentMessageCell {
     lines: Vec<Line<'static>>,
     is_first_line: bool,
+    theme: Theme,
 }
 
 impl AgentMessageCell {
-    pub(crate) fn new(lines: Vec<Line<'static>>, is_first_line: bool) -> Self {
+    pub(crate) fn new(lines: Vec<Line<'static>>, is_first_line: bool, theme: &Theme) -> Self {
         Self {
             lines,
             is_first_line,
+            theme: *theme,
         }
     }
 }
@@ -356,6 +373,9 @@ impl HistoryCell for AgentMessageCell {
                 continue;
             }
 
+            let line = &line
+                .clone()
+                .style(agent_text_style(&self.theme).patch(line.style));
             let opts = RtOptions::new(width as usize)
                 .initial_indent(initial_indent)
                 .subsequent_indent(subsequent_indent.clone());
@@ -379,6 +399,75 @@ impl HistoryCell for AgentMessageCell {
     }
 }
 
+/// Agent messages with fewer words than this get no stats footer.
+pub(crate) const MESSAGE_STATS_MIN_WORDS: usize = 150;
+
+/// Reading speed used for the reading-time estimate.
+const READING_WORDS_PER_MINUTE: usize = 200;
+
+/// Character and word counts of a finished agent message (`tui.message_stats`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct MessageStats {
+    pub(crate) chars: usize,
+    pub(crate) words: usize,
+}
+
+impl MessageStats {
+    pub(crate) fn from_text(text: &str) -> Self {
+        Self {
+            chars: text.chars().count(),
+            words: text.split_whitespace().count(),
+        }
+    }
+
+    /// Estimated reading time, rounded up to whole minutes (at least one).
+    pub(crate) fn reading_minutes(&self) -> usize {
+        self.words.div_ceil(READING_WORDS_PER_MINUTE).max(1)
+    }
+
+    pub(crate) fn summary(&self) -> String {
+        format!(
+            "{} chars · {} words · ~{} min read",
+            format_with_separators(self.chars as i64),
+            format_with_separators(self.words as i64),
+            self.reading_minutes()
+        )
+    }
+}
+
+/// Dim footer line under a finished agent message.
+///
+/// The line is built once from the message text, and the cell continues the message's stream so
+/// it adds exactly one transcript row with no spacer before it.
+#[derive(Debug)]
+pub(crate) struct AgentMessageStatsCell {
+    line: Line<'static>,
+}
+
+impl AgentMessageStatsCell {
+    pub(crate) fn new(stats: MessageStats) -> Self {
+        Self {
+            line: Line::from(format!("  {}", stats.summary())).dim(),
+        }
+    }
+}
+
+impl HistoryCell for AgentMessageStatsCell {
+    fn display_lines(&self, _width: u16) -> Vec<Line<'static>> {
+        vec![self.line.clone()]
+    }
+
+    fn is_stream_continuation(&self) -> bool {
+        true
+    }
+}
+
+/// Footer for `message` when it is long enough to warrant one.
+pub(crate) fn new_agent_message_stats(message: &str) -> Option<AgentMessageStatsCell> {
+    let stats = MessageStats::from_text(message);
+    (stats.words >= MESSAGE_STATS_MIN_WORDS).then(|| AgentMessageStatsCell::new(stats))
+}
+
 #[derive(Debug)]
 pub(crate) struct PlainHistoryCell {
     lines: Vec<Line<'static>>,
@@ -513,83 +602,205 @@ fn exec_snippet(command: &[String]) -> String {
     truncate_exec_snippet(&full_cmd)
 }
 
+/// Transcript record of the user's answer to an approval request.
+///
+/// Renders like a [`PrefixedWrappedHistoryCell`] but also keeps the structured [`ApprovalRecord`]
+/// so the approval history can be rebuilt from the transcript
+/// (see [`crate::approval_history`]).
+#[derive(Debug)]
+pub(crate) struct ApprovalDecisionCell {
+    record: ApprovalRecord,
+    inner: PrefixedWrappedHistoryCell,
+}
+
+impl ApprovalDecisionCell {
+    fn new(record: ApprovalRecord, symbol: Span<'static>, summary: Vec<Span<'static>>) -> Self {
+        Self {
+            record,
+            inner: PrefixedWrappedHistoryCell::new(Line::from(summary), symbol, "  "),
+        }
+    }
+
+    pub(crate) fn record(&self) -> &ApprovalRecord {
+        &self.record
+    }
+}
+
+impl HistoryCell for ApprovalDecisionCell {
+    fn display_lines(&self, width: u16) -> Vec<Line<'static>> {
+        self.inner.display_lines(width)
+    }
+
+    fn desired_height(&self, width: u16) -> u16 {
+        self.inner.desired_height(width)
+    }
+
+    fn transcript_lines_with_joiners(&self, width: u16) -> TranscriptLinesWithJoiners {
+        self.inner.transcript_lines_with_joiners(width)
+    }
+}
+
+fn approval_outcome(decision: &codex_core::protocol::ReviewDecision) -> ApprovalOutcome {
+    use codex_core::protocol::ReviewDecision::*;
+
+    match decision {
+        Approved => ApprovalOutcome::Approved,
+        ApprovedExecpolicyAmendment { .. } => ApprovalOutcome::ApprovedWithPolicyAmendment,
+        ApprovedForSession => ApprovalOutcome::ApprovedForSession,
+        Denied => ApprovalOutcome::Denied,
+        Abort => ApprovalOutcome::Canceled,
+    }
+}
+
 pub fn new_approval_decision_cell(
     command: Vec<String>,
     decision: codex_core::protocol::ReviewDecision,
 ) -> Box<dyn HistoryCell> {
-    use codex_core::protocol::ReviewDecision::*;
+    let subject = exec_snippet(&command);
+    let outcome = approval_outcome(&decision);
+    let snippet = Span::from(subject.clone()).dim();
+    let (symbol, summary): (Span<'static>, Vec<Span<'static>>) = match outcome {
+        ApprovalOutcome::Approved => (
+            "✔ ".green(),
+            vec![
+                "You ".into(),
+                "approved".bold(),
+                " codex to run ".into(),
+                snippet,
+                " this time".bold(),
+            ],
+        ),
+        ApprovalOutcome::ApprovedWithPolicyAmendment => (
+            "✔ ".green(),
+            vec![
+                "You ".into(),
+                "approved".bold(),
+                " codex to run ".into(),
+                snippet,
+                " and applied the execpolicy amendment".bold(),
+            ],
+        ),
+        ApprovalOutcome::ApprovedForSession => (
+            "✔ ".green(),
+            vec![
+                "You ".into(),
+                "approved".bold(),
+                " codex to run ".into(),
+                snippet,
+                " every time this session".bold(),
+            ],
+        ),
+        ApprovalOutcome::Denied => (
+            "✗ ".red(),
+            vec![
+                "You ".into(),
+                "did not approve".bold(),
+                " codex to run ".into(),
+                snippet,
+            ],
+        ),
+        ApprovalOutcome::Canceled => (
+            "✗ ".red(),
+            vec![
+                "You ".into(),
+                "canceled".bold(),
+                " the request to run ".into(),
+                snippet,
+            ],
+        ),
+    };
 
-    let (symbol, summary): (Span<'static>, Vec<Span<'static>>) = match decision {
-        Approved => {
-            let snippet = Span::from(exec_snippet(&command)).dim();
-            (
-                "✔ ".green(),
-                vec![
-                    "You ".into(),
-                    "approved".bold(),
-                    " codex to run ".into(),
-                    snippet,
-                    " this time".bold(),
-                ],
-            )
-        }
-        ApprovedExecpolicyAmendment { .. } => {
-            let snippet = Span::from(exec_snippet(&command)).dim();
-            (
-                "✔ ".green(),
-                vec![
-                    "You ".into(),
-                    "approved".bold(),
-                    " codex to run ".into(),
-                    snippet,
-                    " and applied the execpolicy amendment".bold(),
-                ],
-            )
-        }
-        ApprovedForSession => {
-            let snippet = Span::from(exec_snippet(&command)).dim();
-            (
-                "✔ ".green(),
-                vec![
-                    "You ".into(),
-                    "approved".bold(),
-                    " codex to run ".into(),
-                    snippet,
-                    " every time this session".bold(),
-                ],
-            )
-        }
-        Denied => {
-            let snippet = Span::from(exec_snippet(&command)).dim();
-            (
-                "✗ ".red(),
-                vec![
-                    "You ".into(),
-                    "did not approve".bold(),
-                    " codex to run ".into(),
-                    snippet,
-                ],
-            )
-        }
-        Abort => {
-            let snippet = Span::from(exec_snippet(&command)).dim();
-            (
-                "✗ ".red(),
-                vec![
-                    "You ".into(),
-                    "canceled".bold(),
-                    " the request to run ".into(),
-                    snippet,
-                ],
-            )
-        }
+    let record = ApprovalRecord {
+        kind: ApprovalKind::Exec,
+        subject,
+        outcome,
     };
+    Box::new(ApprovalDecisionCell::new(record, symbol, summary))
+}
 
-    Box::new(PrefixedWrappedHistoryCell::new(
-        Line::from(summary),
-        symbol,
-        "  ",
-    ))
+/// History line recording the user's answer to a patch approval request.
+///
+/// `files` is a short description of the edited files (a single path or a count).
+pub(crate) fn new_patch_decision_cell(
+    files: String,
+    decision: codex_core::protocol::ReviewDecision,
+) -> Box<dyn HistoryCell> {
+    let outcome = approval_outcome(&decision);
+    let target = Span::from(files.clone()).dim();
+    let (symbol, summary): (Span<'static>, Vec<Span<'static>>) = match outcome {
+        ApprovalOutcome::Approved
+        | ApprovalOutcome::ApprovedForSession
+        | ApprovalOutcome::ApprovedWithPolicyAmendment => (
+            "✔ ".green(),
+            vec![
+                "You ".into(),
+                "approved".bold(),
+                " codex to edit ".into(),
+                target,
+            ],
+        ),
+        ApprovalOutcome::Denied => (
+            "✗ ".red(),
+            vec![
+                "You ".into(),
+                "did not approve".bold(),
+                " codex to edit ".into(),
+                target,
+            ],
+        ),
+        ApprovalOutcome::Canceled => (
+            "✗ ".red(),
+            vec![
+                "You ".into(),
+                "canceled".bold(),
+                " the request to edit ".into(),
+                target,
+            ],
+        ),
+    };
+
+    let record = ApprovalRecord {
+        kind: ApprovalKind::Patch,
+        subject: files,
+        outcome,
+    };
+    Box::new(ApprovalDecisionCell::new(record, symbol, summary))
+}
+
+/// History line recording the user's answer to an MCP elicitation request.
+pub(crate) fn new_elicitation_decision_cell(
+    server_name: String,
+    action: codex_core::protocol::ElicitationAction,
+) -> Box<dyn HistoryCell> {
+    use codex_core::protocol::ElicitationAction;
+
+    let server = Span::from(server_name.clone()).dim();
+    let (symbol, verb, outcome) = match action {
+        ElicitationAction::Accept => ("✔ ".green(), "provided", ApprovalOutcome::Approved),
+        ElicitationAction::Decline => ("✗ ".red(), "declined", ApprovalOutcome::Denied),
+        ElicitationAction::Cancel => ("✗ ".red(), "canceled", ApprovalOutcome::Canceled),
+    };
+    let summary = match action {
+        ElicitationAction::Accept => vec![
+            "You ".into(),
+            verb.bold(),
+            " the info requested by ".into(),
+            server,
+        ],
+        ElicitationAction::Decline | ElicitationAction::Cancel => vec![
+            "You ".into(),
+            verb.bold(),
+            " the request from ".into(),
+            server,
+        ],
+    };
+
+    let record = ApprovalRecord {
+        kind: ApprovalKind::McpElicitation,
+        subject: server_name,
+        outcome,
+    };
+    Box::new(ApprovalDecisionCell::new(record, symbol, summary))
 }
 
 /// Cyan history cell line showing the current review status.
@@ -609,6 +820,13 @@ impl HistoryCell for PatchHistoryCell {
     fn display_lines(&self, width: u16) -> Vec<Line<'static>> {
         create_diff_summary(&self.changes, &self.cwd, width as usize)
     }
+
+    fn source_path(&self) -> Option<String> {
+        match self.changes.keys().collect::<Vec<_>>().as_slice() {
+            [path] => Some(display_path_for(path, &self.cwd)),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -748,6 +966,7 @@ pub(crate) fn new_session_info(
     requested_model: &str,
     event: SessionConfiguredEvent,
     is_first_event: bool,
+    title: Option<String>,
 ) -> SessionInfoCell {
     let SessionConfiguredEvent {
         model,
@@ -760,7 +979,8 @@ pub(crate) fn new_session_info(
         reasoning_effort,
         config.cwd.clone(),
         CODEX_CLI_VERSION,
-    );
+    )
+    .with_title(title);
     let mut parts: Vec<Box<dyn HistoryCell>> = vec![Box::new(header)];
 
     if is_first_event {
@@ -817,8 +1037,11 @@ pub(crate) fn new_session_info(
     SessionInfoCell(CompositeHistoryCell { parts })
 }
 
-pub(crate) fn new_user_prompt(message: String) -> UserHistoryCell {
-    UserHistoryCell { message }
+pub(crate) fn new_user_prompt(message: String, theme: &Theme) -> UserHistoryCell {
+    UserHistoryCell {
+        message,
+        theme: *theme,
+    }
 }
 
 #[derive(Debug)]
@@ -827,6 +1050,8 @@ struct SessionHeaderHistoryCell {
     model: String,
     reasoning_effort: Option<ReasoningEffortConfig>,
     directory: PathBuf,
+    /// Custom conversation title set with `/title`, shown above the model row.
+    title: Option<String>,
 }
 
 impl SessionHeaderHistoryCell {
@@ -841,9 +1066,15 @@ impl SessionHeaderHistoryCell {
             model,
             reasoning_effort,
             directory,
+            title: None,
         }
     }
 
+    fn with_title(mut self, title: Option<String>) -> Self {
+        self.title = title;
+        self
+    }
+
     fn format_directory(&self, max_width: Option<usize>) -> String {
         Self::format_directory_inner(&self.directory, max_width)
     }
@@ -928,12 +1159,22 @@ impl HistoryCell for SessionHeaderHistoryCell {
         let dir = self.format_directory(Some(dir_max_width));
         let dir_spans = vec![Span::from(dir_prefix).dim(), Span::from(dir)];
 
-        let lines = vec![
-            make_row(title_spans),
-            make_row(Vec::new()),
-            make_row(model_spans),
-            make_row(dir_spans),
-        ];
+        let mut lines = vec![make_row(title_spans), make_row(Vec::new())];
+        if let Some(title) = &self.title {
+            let title_prefix = format!("{:<label_width$} ", "title:");
+            let title_max_width =
+                inner_width.saturating_sub(UnicodeWidthStr::width(title_prefix.as_str()));
+            lines.push(make_row(vec![
+                Span::from(title_prefix).dim(),
+                Span::from(crate::text_formatting::truncate_text(
+                    title,
+                    title_max_width,
+                ))
+                .bold(),
+            ]));
+        }
+        lines.push(make_row(model_spans));
+        lines.push(make_row(dir_spans));
 
         with_border(lines)
     }
@@ -1190,9 +1431,13 @@ fn try_new_completed_mcp_tool_call_with_image_output(
     }
 }
 
-#[allow(clippy::disallowed_methods)]
-pub(crate) fn new_warning_event(message: String) -> PrefixedWrappedHistoryCell {
-    PrefixedWrappedHistoryCell::new(message.yellow(), "⚠ ".yellow(), "  ")
+pub(crate) fn new_warning_event(message: String, theme: &Theme) -> PrefixedWrappedHistoryCell {
+    let style = warning_style(theme);
+    PrefixedWrappedHistoryCell::new(
+        Span::styled(message, style),
+        Span::styled("⚠ ", style),
+        "  ",
+    )
 }
 
 #[derive(Debug)]
@@ -1419,12 +1664,70 @@ pub(crate) fn new_info_event(message: String, hint: Option<String>) -> PlainHist
     PlainHistoryCell { lines }
 }
 
-pub(crate) fn new_error_event(message: String) -> PlainHistoryCell {
-    // Use a hair space (U+200A) to create a subtle, near-invisible separation
-    // before the text. VS16 is intentionally omitted to keep spacing tighter
-    // in terminals like Ghostty.
-    let lines: Vec<Line<'static>> = vec![vec![format!("■ {message}").red()].into()];
-    PlainHistoryCell { lines }
+pub(crate) fn new_error_event(message: String, theme: &Theme) -> ErrorHistoryCell {
+    ErrorHistoryCell {
+        message,
+        count: 1,
+        theme: *theme,
+    }
+}
+
+/// An error message, plus how many identical errors arrived back to back.
+///
+/// The app merges a repeat into the previous cell (see [`ErrorHistoryCell::merge_repeat`]) so a
+/// command failing the same way over and over shows one `(×N)` line instead of N copies.
+#[derive(Debug)]
+pub(crate) struct ErrorHistoryCell {
+    message: String,
+    count: usize,
+    theme: Theme,
+}
+
+impl ErrorHistoryCell {
+    pub(crate) fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// How many identical errors were merged into this cell.
+    pub(crate) fn count(&self) -> usize {
+        self.count
+    }
+
+    /// If `next` is an error with the same message, returns this cell with the repeats added.
+    pub(crate) fn merge_repeat(&self, next: &dyn HistoryCell) -> Option<Self> {
+        let next = next.as_any().downcast_ref::<Self>()?;
+        (next.message == self.message).then(|| Self {
+            message: self.message.clone(),
+            count: self.count + next.count,
+            theme: self.theme,
+        })
+    }
+}
+
+impl HistoryCell for ErrorHistoryCell {
+    fn display_lines(&self, _width: u16) -> Vec<Line<'static>> {
+        // Use a hair space (U+200A) to create a subtle, near-invisible separation
+        // before the text. VS16 is intentionally omitted to keep spacing tighter
+        // in terminals like Ghostty.
+        let mut spans = vec![Span::styled(
+            format!("■ {}", self.message),
+            error_style(&self.theme),
+        )];
+        if self.count > 1 {
+            spans.push(format!(" (×{})", self.count).dim());
+        }
+        vec![spans.into()]
+    }
+}
+
+/// Merges `cell` into `last` when both are the same error, for collapsing consecutive repeats.
+pub(crate) fn merge_repeated_error(
+    last: &dyn HistoryCell,
+    cell: &dyn HistoryCell,
+) -> Option<ErrorHistoryCell> {
+    last.as_any()
+        .downcast_ref::<ErrorHistoryCell>()?
+        .merge_repeat(cell)
 }
 
 /// Render a user‑friendly plan update styled like a checkbox todo list.
@@ -1761,11 +2064,44 @@ mod tests {
 
     #[test]
     fn empty_agent_message_cell_transcript() {
-        let cell = AgentMessageCell::new(vec![Line::default()], false);
+        let cell = AgentMessageCell::new(vec![Line::default()], false, &Theme::default());
         assert_eq!(cell.transcript_lines(80), vec![Line::from("  ")]);
         assert_eq!(cell.desired_transcript_height(80), 1);
     }
 
+    #[test]
+    fn message_stats_count_words_and_round_reading_time_up() {
+        let stats = MessageStats::from_text("héllo  wörld\nthree words here");
+        assert_eq!(
+            stats,
+            MessageStats {
+                chars: 29,
+                words: 5
+            }
+        );
+        assert_eq!(stats.reading_minutes(), 1);
+
+        let long = MessageStats {
+            chars: 12_345,
+            words: 401,
+        };
+        assert_eq!(long.reading_minutes(), 3);
+        assert_eq!(long.summary(), "12,345 chars · 401 words · ~3 min read");
+    }
+
+    #[test]
+    fn message_stats_footer_only_for_long_messages() {
+        assert!(new_agent_message_stats("short answer").is_none());
+
+        let message = "word ".repeat(MESSAGE_STATS_MIN_WORDS);
+        let cell = new_agent_message_stats(&message).expect("footer for long message");
+        assert!(cell.is_stream_continuation());
+        assert_eq!(
+            render_lines(&cell.display_lines(20)),
+            vec!["  750 chars · 150 words · ~1 min read".to_string()]
+        );
+    }
+
     #[test]
     fn prefixed_wrapped_history_cell_indents_wrapped_lines() {
         let summary = Line::from(vec![
@@ -2038,6 +2374,25 @@ mod tests {
         assert!(model_line.contains("/model to change"));
     }
 
+    #[test]
+    fn session_header_shows_custom_title() {
+        let cell =
+            SessionHeaderHistoryCell::new("gpt-4o".to_string(), None, std::env::temp_dir(), "test")
+                .with_title(Some("Parser rewrite".to_string()));
+
+        let lines = render_lines(&cell.display_lines(80));
+        let title_line = lines
+            .iter()
+            .find(|line| line.contains("title:"))
+            .expect("title line");
+        assert!(title_line.contains("Parser rewrite"));
+
+        let untitled =
+            SessionHeaderHistoryCell::new("gpt-4o".to_string(), None, std::env::temp_dir(), "test");
+        let lines = render_lines(&untitled.display_lines(80));
+        assert!(!lines.iter().any(|line| line.contains("title:")));
+    }
+
     #[test]
     fn session_header_directory_center_truncates() {
         let mut dir = home_dir().expect("home directory");
@@ -2418,6 +2773,7 @@ mod tests {
         let msg = "one two three four five six seven";
         let cell = UserHistoryCell {
             message: msg.to_string(),
+            theme: Theme::default(),
         };
 
         // Small width to force wrapping more clearly. Effective wrap width is width-2 due to the ▌ prefix and trailing space.
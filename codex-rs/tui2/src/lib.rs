@@ -36,6 +36,7 @@ mod app;
 mod app_backtrack;
 mod app_event;
 mod app_event_sender;
+mod approval_history;
 mod ascii_animation;
 mod bottom_pane;
 mod chatwidget;
@@ -43,10 +44,12 @@ mod cli;
 mod clipboard_copy;
 mod clipboard_paste;
 mod color;
+mod conversation_titles;
 pub mod custom_terminal;
 mod diff_render;
 mod exec_cell;
 mod exec_command;
+mod file_mentions;
 mod file_search;
 mod frames;
 mod get_git_diff;
@@ -62,6 +65,10 @@ mod notifications;
 pub mod onboarding;
 mod oss_selection;
 mod pager_overlay;
+mod patch_revert;
+mod prompt_history;
+#[cfg(feature = "debug-logs")]
+mod protocol_event_log;
 pub mod public_widgets;
 mod render;
 mod resume_picker;
@@ -75,9 +82,14 @@ mod streaming;
 mod style;
 mod terminal_palette;
 mod text_formatting;
+mod theme;
 mod tooltips;
+mod transcript_collapse;
 mod transcript_copy;
 mod transcript_copy_ui;
+mod transcript_errors;
+mod transcript_minimap;
+mod transcript_mirror;
 mod transcript_multi_click;
 mod transcript_render;
 mod transcript_selection;
@@ -515,8 +527,11 @@ async fn run_ratatui_app(
     // Run the main chat + transcript UI on the terminal's alternate screen so
     // the entire viewport can be used without polluting normal scrollback. This
     // mirrors the behavior of the legacy TUI but keeps inline mode available
-    // for smaller prompts like onboarding and model migration.
-    let _ = tui.enter_alt_screen();
+    // for smaller prompts like onboarding and model migration. Users who prefer
+    // the normal screen opt out with `tui.alternate_screen = false` (or Alt+M).
+    if config.tui_alternate_screen {
+        let _ = tui.enter_alt_screen();
+    }
 
     let app_result = App::run(
         &mut tui,
@@ -531,6 +546,11 @@ async fn run_ratatui_app(
     )
     .await;
 
+    // Inline mode leaves the last frame on the normal screen; clear it so the
+    // session summary below starts from a clean screen.
+    if !tui.is_alt_screen_active() {
+        let _ = tui.terminal.clear();
+    }
     let _ = tui.leave_alt_screen();
     restore();
     if let Ok(exit_info) = &app_result {
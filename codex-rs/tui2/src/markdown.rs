@@ -8,6 +8,12 @@ pub(crate) fn append_markdown(
     crate::render::line_utils::push_owned_lines(&rendered.lines, lines);
 }
 
+/// Append `source` verbatim, one unstyled line per source line, for when Markdown
+/// rendering is turned off (`tui.render_markdown = false`).
+pub(crate) fn append_plain(source: &str, lines: &mut Vec<Line<'static>>) {
+    lines.extend(source.lines().map(|line| Line::from(line.to_string())));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -8,6 +8,7 @@ pub(crate) struct MarkdownStreamCollector {
     buffer: String,
     committed_line_count: usize,
     width: Option<usize>,
+    render_markdown: bool,
 }
 
 impl MarkdownStreamCollector {
@@ -16,6 +17,20 @@ impl MarkdownStreamCollector {
             buffer: String::new(),
             committed_line_count: 0,
             width,
+            render_markdown: true,
+        }
+    }
+
+    /// Show the source verbatim instead of rendering it as Markdown.
+    pub fn set_render_markdown(&mut self, render_markdown: bool) {
+        self.render_markdown = render_markdown;
+    }
+
+    fn render(&self, source: &str, rendered: &mut Vec<Line<'static>>) {
+        if self.render_markdown {
+            markdown::append_markdown(source, self.width, rendered);
+        } else {
+            markdown::append_plain(source, rendered);
         }
     }
 
@@ -41,7 +56,7 @@ impl MarkdownStreamCollector {
             return Vec::new();
         };
         let mut rendered: Vec<Line<'static>> = Vec::new();
-        markdown::append_markdown(&source, self.width, &mut rendered);
+        self.render(&source, &mut rendered);
         let mut complete_line_count = rendered.len();
         if complete_line_count > 0
             && crate::render::line_utils::is_blank_line_spaces_only(
@@ -82,7 +97,7 @@ impl MarkdownStreamCollector {
         tracing::trace!("markdown finalize (raw source):\n---\n{source}\n---");
 
         let mut rendered: Vec<Line<'static>> = Vec::new();
-        markdown::append_markdown(&source, self.width, &mut rendered);
+        self.render(&source, &mut rendered);
 
         let out = if self.committed_line_count >= rendered.len() {
             Vec::new()
@@ -119,6 +134,7 @@ pub(crate) fn simulate_stream_markdown_for_tests(
 mod tests {
     use super::*;
     use ratatui::style::Color;
+    use ratatui::style::Modifier;
 
     #[tokio::test]
     async fn no_commit_until_newline() {
@@ -139,6 +155,38 @@ mod tests {
         assert_eq!(out.len(), 1);
     }
 
+    #[test]
+    fn bold_renders_as_bold_span() {
+        let out = super::simulate_stream_markdown_for_tests(&["Make it **bold** now\n"], true);
+        assert_eq!(out.len(), 1);
+        let bold = out[0]
+            .spans
+            .iter()
+            .find(|span| span.content == "bold")
+            .expect("bold span");
+        assert!(bold.style.add_modifier.contains(Modifier::BOLD));
+        assert!(!lines_to_plain_strings(&out)[0].contains("**"));
+    }
+
+    #[test]
+    fn raw_mode_keeps_markdown_source_verbatim() {
+        let mut c = super::MarkdownStreamCollector::new(None);
+        c.set_render_markdown(false);
+        c.push_delta("Make it **bold**\n\n```rust\nlet  x = 1;\n");
+        let mut out = c.commit_complete_lines();
+        c.push_delta("```\n");
+        out.extend(c.finalize_and_drain());
+        assert_eq!(
+            lines_to_plain_strings(&out),
+            vec!["Make it **bold**", "", "```rust", "let  x = 1;", "```"]
+        );
+        assert!(
+            out.iter()
+                .flat_map(|line| &line.spans)
+                .all(|span| span.style == ratatui::style::Style::default())
+        );
+    }
+
     #[tokio::test]
     async fn e2e_stream_blockquote_simple_is_green() {
         let out = super::simulate_stream_markdown_for_tests(&["> Hello\n"], true);
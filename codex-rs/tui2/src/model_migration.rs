@@ -116,6 +116,7 @@ pub(crate) async fn run_model_migration_prompt(
                 TuiEvent::Key(key_event) => screen.handle_key(key_event),
                 TuiEvent::Mouse(_) => {}
                 TuiEvent::Paste(_) => {}
+                TuiEvent::FocusChanged(_) => {}
                 TuiEvent::Draw => {
                     let _ = alt.tui.draw(u16::MAX, |frame| {
                         frame.render_widget_ref(&screen, frame.area());
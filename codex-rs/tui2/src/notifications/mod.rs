@@ -1,4 +1,5 @@
 mod osc9;
+mod quiet_hours;
 mod windows_toast;
 
 use std::env;
@@ -6,6 +7,7 @@ use std::io;
 
 use codex_core::env::is_wsl;
 use osc9::Osc9Backend;
+pub(crate) use quiet_hours::QuietHours;
 use windows_toast::WindowsToastBackend;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
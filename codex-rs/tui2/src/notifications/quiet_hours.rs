@@ -0,0 +1,85 @@
+//! `tui.notification_quiet_hours`: a daily local-time window with no desktop notifications.
+
+use chrono::NaiveTime;
+
+/// A daily `start-end` window, end-exclusive. When `end` is earlier than `start` the window
+/// wraps past midnight (e.g. `22:00-08:00`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct QuietHours {
+    start: NaiveTime,
+    end: NaiveTime,
+}
+
+impl QuietHours {
+    /// Parse an `HH:MM-HH:MM` range.
+    pub(crate) fn parse(spec: &str) -> Result<Self, String> {
+        let (start, end) = spec
+            .split_once('-')
+            .ok_or_else(|| format!("expected HH:MM-HH:MM, got `{spec}`"))?;
+        let parse_time = |value: &str| {
+            NaiveTime::parse_from_str(value.trim(), "%H:%M")
+                .map_err(|err| format!("invalid time `{}` in `{spec}`: {err}", value.trim()))
+        };
+        Ok(Self {
+            start: parse_time(start)?,
+            end: parse_time(end)?,
+        })
+    }
+
+    /// Whether `time` falls inside the window. An empty window (`start == end`) is never quiet.
+    pub(crate) fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn at(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).expect("valid time")
+    }
+
+    #[test]
+    fn same_day_window() {
+        let quiet = QuietHours::parse("12:30-14:00").expect("parse");
+        assert!(!quiet.contains(at(12, 29)));
+        assert!(quiet.contains(at(12, 30)));
+        assert!(quiet.contains(at(13, 59)));
+        assert!(!quiet.contains(at(14, 0)));
+    }
+
+    #[test]
+    fn window_wrapping_past_midnight() {
+        let quiet = QuietHours::parse("22:00-08:00").expect("parse");
+        assert!(quiet.contains(at(22, 0)));
+        assert!(quiet.contains(at(23, 59)));
+        assert!(quiet.contains(at(0, 0)));
+        assert!(quiet.contains(at(7, 59)));
+        assert!(!quiet.contains(at(8, 0)));
+        assert!(!quiet.contains(at(12, 0)));
+        assert!(!quiet.contains(at(21, 59)));
+    }
+
+    #[test]
+    fn empty_window_is_never_quiet() {
+        let quiet = QuietHours::parse("09:00-09:00").expect("parse");
+        assert!(!quiet.contains(at(9, 0)));
+        assert!(!quiet.contains(at(3, 0)));
+    }
+
+    #[test]
+    fn rejects_malformed_ranges() {
+        assert!(QuietHours::parse("22:00").is_err());
+        assert!(QuietHours::parse("25:00-08:00").is_err());
+        assert_eq!(
+            QuietHours::parse(" 22:00 - 08:00 "),
+            QuietHours::parse("22:00-08:00")
+        );
+    }
+}
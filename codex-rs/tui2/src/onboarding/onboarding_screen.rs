@@ -394,11 +394,13 @@ pub(crate) async fn run_onboarding_app(
         if let Some(event) = tui_events.next().await {
             match event {
                 TuiEvent::Mouse(_) => {}
+                TuiEvent::FocusChanged(_) => {}
                 TuiEvent::Key(key_event) => {
                     onboarding_screen.handle_key_event(key_event);
                 }
                 TuiEvent::Paste(text) => {
-                    onboarding_screen.handle_paste(text);
+                    onboarding_screen
+                        .handle_paste(crate::clipboard_paste::normalize_pasted_text(&text, false));
                 }
                 TuiEvent::Draw => {
                     if !did_full_clear_after_success
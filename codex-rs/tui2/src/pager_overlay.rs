@@ -1,4 +1,6 @@
 use std::io::Result;
+use std::path::Path;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use crate::history_cell::HistoryCell;
@@ -8,9 +10,12 @@ use crate::key_hint::KeyBinding;
 use crate::render::Insets;
 use crate::render::renderable::InsetRenderable;
 use crate::render::renderable::Renderable;
+use crate::style::selection_style;
 use crate::style::user_message_style;
+use crate::theme::Theme;
 use crate::tui;
 use crate::tui::TuiEvent;
+use codex_ansi_escape::ansi_escape_line;
 use crossterm::event::KeyCode;
 use crossterm::event::KeyEvent;
 use crossterm::event::MouseEvent;
@@ -35,14 +40,35 @@ pub(crate) enum Overlay {
 }
 
 impl Overlay {
-    pub(crate) fn new_transcript(cells: Vec<Arc<dyn HistoryCell>>) -> Self {
-        Self::Transcript(TranscriptOverlay::new(cells))
+    pub(crate) fn new_transcript(cells: Vec<Arc<dyn HistoryCell>>, theme: Theme) -> Self {
+        Self::Transcript(TranscriptOverlay::new(cells, theme))
     }
 
     pub(crate) fn new_static_with_lines(lines: Vec<Line<'static>>, title: String) -> Self {
         Self::Static(StaticOverlay::with_title(lines, title))
     }
 
+    /// Like [`Self::new_static_with_lines`], but opened scrolled to the last line.
+    #[cfg(feature = "debug-logs")]
+    pub(crate) fn new_static_tail(lines: Vec<Line<'static>>, title: String) -> Self {
+        let mut overlay = StaticOverlay::with_title(lines, title);
+        overlay.view.scroll_offset = usize::MAX;
+        Self::Static(overlay)
+    }
+
+    /// Static overlay for `/diff` output that can also save the patch with `s`.
+    ///
+    /// `raw_diff` is the unrendered `git diff` output (it may contain color escapes, which are
+    /// stripped when saving); the file is written under `save_dir`.
+    pub(crate) fn new_diff(lines: Vec<Line<'static>>, raw_diff: String, save_dir: PathBuf) -> Self {
+        let mut overlay = StaticOverlay::with_title(lines, "D I F F".to_string());
+        overlay.diff_save = Some(DiffSave {
+            raw: raw_diff,
+            dir: save_dir,
+        });
+        Self::Static(overlay)
+    }
+
     pub(crate) fn new_static_with_renderables(
         renderables: Vec<Box<dyn Renderable>>,
         title: String,
@@ -80,6 +106,7 @@ const KEY_CTRL_D: KeyBinding = key_hint::ctrl(KeyCode::Char('d'));
 const KEY_CTRL_B: KeyBinding = key_hint::ctrl(KeyCode::Char('b'));
 const KEY_CTRL_U: KeyBinding = key_hint::ctrl(KeyCode::Char('u'));
 const KEY_Q: KeyBinding = key_hint::plain(KeyCode::Char('q'));
+const KEY_S: KeyBinding = key_hint::plain(KeyCode::Char('s'));
 const KEY_ESC: KeyBinding = key_hint::plain(KeyCode::Esc);
 const KEY_ENTER: KeyBinding = key_hint::plain(KeyCode::Enter);
 const KEY_CTRL_T: KeyBinding = key_hint::ctrl(KeyCode::Char('t'));
@@ -234,6 +261,9 @@ impl PagerView {
         };
         let pct_text = format!(" {percent}% ");
         let pct_w = pct_text.chars().count() as u16;
+        if sep_rect.width <= pct_w {
+            return;
+        }
         let pct_x = sep_rect.x + sep_rect.width - pct_w - 1;
         Span::from(pct_text)
             .dim()
@@ -424,25 +454,28 @@ pub(crate) struct TranscriptOverlay {
     cells: Vec<Arc<dyn HistoryCell>>,
     highlight_cell: Option<usize>,
     is_done: bool,
+    theme: Theme,
 }
 
 impl TranscriptOverlay {
-    pub(crate) fn new(transcript_cells: Vec<Arc<dyn HistoryCell>>) -> Self {
+    pub(crate) fn new(transcript_cells: Vec<Arc<dyn HistoryCell>>, theme: Theme) -> Self {
         Self {
             view: PagerView::new(
-                Self::render_cells(&transcript_cells, None),
+                Self::render_cells(&transcript_cells, None, &theme),
                 "T R A N S C R I P T".to_string(),
                 usize::MAX,
             ),
             cells: transcript_cells,
             highlight_cell: None,
             is_done: false,
+            theme,
         }
     }
 
     fn render_cells(
         cells: &[Arc<dyn HistoryCell>],
         highlight_cell: Option<usize>,
+        theme: &Theme,
     ) -> Vec<Box<dyn Renderable>> {
         cells
             .iter()
@@ -453,9 +486,9 @@ impl TranscriptOverlay {
                     Box::new(CachedRenderable::new(CellRenderable {
                         cell: c.clone(),
                         style: if highlight_cell == Some(i) {
-                            user_message_style().reversed()
+                            selection_style(theme, user_message_style(theme))
                         } else {
-                            user_message_style()
+                            user_message_style(theme)
                         },
                     })) as Box<dyn Renderable>
                 } else {
@@ -479,15 +512,24 @@ impl TranscriptOverlay {
     pub(crate) fn insert_cell(&mut self, cell: Arc<dyn HistoryCell>) {
         let follow_bottom = self.view.is_scrolled_to_bottom();
         self.cells.push(cell);
-        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell);
+        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell, &self.theme);
         if follow_bottom {
             self.view.scroll_offset = usize::MAX;
         }
     }
 
+    /// Swap the newest cell for an updated version of it (e.g. a repeated error's new count).
+    pub(crate) fn replace_last_cell(&mut self, cell: Arc<dyn HistoryCell>) {
+        let Some(last) = self.cells.last_mut() else {
+            return self.insert_cell(cell);
+        };
+        *last = cell;
+        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell, &self.theme);
+    }
+
     pub(crate) fn set_highlight_cell(&mut self, cell: Option<usize>) {
         self.highlight_cell = cell;
-        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell);
+        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell, &self.theme);
         if let Some(idx) = self.highlight_cell {
             self.view.scroll_chunk_into_view(idx);
         }
@@ -540,9 +582,18 @@ impl TranscriptOverlay {
     }
 }
 
+/// Raw diff text kept alongside the rendered lines so the diff overlay can save it.
+struct DiffSave {
+    raw: String,
+    dir: PathBuf,
+}
+
 pub(crate) struct StaticOverlay {
     view: PagerView,
     is_done: bool,
+    diff_save: Option<DiffSave>,
+    /// Result of the last save, shown under the key hints.
+    save_status: Option<Line<'static>>,
 }
 
 impl StaticOverlay {
@@ -555,15 +606,42 @@ impl StaticOverlay {
         Self {
             view: PagerView::new(renderables, title, 0),
             is_done: false,
+            diff_save: None,
+            save_status: None,
         }
     }
 
     fn render_hints(&self, area: Rect, buf: &mut Buffer) {
         let line1 = Rect::new(area.x, area.y, area.width, 1);
         let line2 = Rect::new(area.x, area.y.saturating_add(1), area.width, 1);
+        let line3 = Rect::new(area.x, area.y.saturating_add(2), area.width, 1);
         render_key_hints(line1, buf, PAGER_KEY_HINTS);
-        let pairs: Vec<(&[KeyBinding], &str)> = vec![(&[KEY_Q], "to quit")];
+        let mut pairs: Vec<(&[KeyBinding], &str)> = vec![(&[KEY_Q], "to quit")];
+        if self.diff_save.is_some() {
+            pairs.push((&[KEY_S], "to save as .patch"));
+        }
         render_key_hints(line2, buf, &pairs);
+        if let Some(status) = &self.save_status {
+            Paragraph::new(status.clone()).render_ref(line3, buf);
+        }
+    }
+
+    /// Write the raw diff to a fresh `.patch` file and record the outcome for display.
+    fn save_diff(&mut self) {
+        let Some(diff_save) = &self.diff_save else {
+            return;
+        };
+        let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S").to_string();
+        let path = diff_patch_path(&diff_save.dir, &stamp);
+        self.save_status = Some(
+            match std::fs::write(&path, plain_diff_text(&diff_save.raw)) {
+                Ok(()) => Line::from(format!("Saved diff to {}", path.display())).green(),
+                Err(err) => {
+                    tracing::error!(error = %err, "failed to save diff");
+                    Line::from(format!("Failed to save diff to {}: {err}", path.display())).red()
+                }
+            },
+        );
     }
 
     pub(crate) fn render(&mut self, area: Rect, buf: &mut Buffer) {
@@ -583,6 +661,11 @@ impl StaticOverlay {
                     self.is_done = true;
                     Ok(())
                 }
+                e if KEY_S.is_press(e) && self.diff_save.is_some() => {
+                    self.save_diff();
+                    tui.frame_requester().schedule_frame();
+                    Ok(())
+                }
                 other => self.view.handle_key_event(tui, other),
             },
             TuiEvent::Mouse(mouse_event) => self.view.handle_mouse_scroll(tui, mouse_event),
@@ -600,6 +683,30 @@ impl StaticOverlay {
     }
 }
 
+/// First free `codex-diff-<stamp>.patch` path in `dir`, adding `-1`, `-2`, ... on collisions.
+fn diff_patch_path(dir: &Path, stamp: &str) -> PathBuf {
+    let candidate = dir.join(format!("codex-diff-{stamp}.patch"));
+    if !candidate.exists() {
+        return candidate;
+    }
+    (1..)
+        .map(|n| dir.join(format!("codex-diff-{stamp}-{n}.patch")))
+        .find(|path| !path.exists())
+        .unwrap_or(candidate)
+}
+
+/// `git diff --color` output with the color escapes removed, so it applies with `git apply`.
+fn plain_diff_text(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    for line in raw.lines() {
+        for span in ansi_escape_line(line).spans {
+            out.push_str(&span.content);
+        }
+        out.push('\n');
+    }
+    out
+}
+
 fn render_offset_content(
     area: Rect,
     buf: &mut Buffer,
@@ -648,6 +755,57 @@ mod tests {
     use ratatui::backend::TestBackend;
     use ratatui::text::Text;
 
+    #[test]
+    fn diff_patch_path_avoids_existing_files() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let first = diff_patch_path(dir.path(), "20260101-120000");
+        assert_eq!(first, dir.path().join("codex-diff-20260101-120000.patch"));
+
+        std::fs::write(&first, "").expect("write");
+        let second = diff_patch_path(dir.path(), "20260101-120000");
+        assert_eq!(
+            second,
+            dir.path().join("codex-diff-20260101-120000-1.patch")
+        );
+    }
+
+    #[test]
+    fn diff_overlay_keeps_raw_text_and_saves_it_without_colors() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let raw = "\u{1b}[1mdiff --git a/a.txt b/a.txt\u{1b}[m\n\u{1b}[31m-old\u{1b}[m\n\u{1b}[32m+new\u{1b}[m\n";
+        let Overlay::Static(mut overlay) = Overlay::new_diff(
+            raw.lines().map(ansi_escape_line).collect(),
+            raw.to_string(),
+            dir.path().to_path_buf(),
+        ) else {
+            panic!("expected static overlay");
+        };
+        assert_eq!(
+            overlay.diff_save.as_ref().map(|save| save.raw.as_str()),
+            Some(raw)
+        );
+
+        overlay.save_diff();
+
+        let saved: Vec<_> = std::fs::read_dir(dir.path())
+            .expect("read dir")
+            .flatten()
+            .map(|entry| entry.path())
+            .collect();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(
+            std::fs::read_to_string(&saved[0]).expect("read patch"),
+            "diff --git a/a.txt b/a.txt\n-old\n+new\n"
+        );
+        let status = overlay.save_status.expect("status line");
+        let status: String = status
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        assert!(status.starts_with("Saved diff to "), "status: {status}");
+    }
+
     #[derive(Debug)]
     struct TestCell {
         lines: Vec<Line<'static>>,
@@ -674,9 +832,12 @@ mod tests {
 
     #[test]
     fn edit_prev_hint_is_visible() {
-        let mut overlay = TranscriptOverlay::new(vec![Arc::new(TestCell {
-            lines: vec![Line::from("hello")],
-        })]);
+        let mut overlay = TranscriptOverlay::new(
+            vec![Arc::new(TestCell {
+                lines: vec![Line::from("hello")],
+            })],
+            Theme::default(),
+        );
 
         // Render into a small buffer and assert the backtrack hint is present
         let area = Rect::new(0, 0, 40, 10);
@@ -700,17 +861,20 @@ mod tests {
     #[test]
     fn transcript_overlay_snapshot_basic() {
         // Prepare a transcript overlay with a few lines
-        let mut overlay = TranscriptOverlay::new(vec![
-            Arc::new(TestCell {
-                lines: vec![Line::from("alpha")],
-            }),
-            Arc::new(TestCell {
-                lines: vec![Line::from("beta")],
-            }),
-            Arc::new(TestCell {
-                lines: vec![Line::from("gamma")],
-            }),
-        ]);
+        let mut overlay = TranscriptOverlay::new(
+            vec![
+                Arc::new(TestCell {
+                    lines: vec![Line::from("alpha")],
+                }),
+                Arc::new(TestCell {
+                    lines: vec![Line::from("beta")],
+                }),
+                Arc::new(TestCell {
+                    lines: vec![Line::from("gamma")],
+                }),
+            ],
+            Theme::default(),
+        );
         let mut term = Terminal::new(TestBackend::new(40, 10)).expect("term");
         term.draw(|f| overlay.render(f.area(), f.buffer_mut()))
             .expect("draw");
@@ -787,7 +951,7 @@ mod tests {
         let exec_cell: Arc<dyn HistoryCell> = Arc::new(exec_cell);
         cells.push(exec_cell);
 
-        let mut overlay = TranscriptOverlay::new(cells);
+        let mut overlay = TranscriptOverlay::new(cells, Theme::default());
         let area = Rect::new(0, 0, 80, 12);
         let mut buf = Buffer::empty(area);
 
@@ -809,6 +973,7 @@ mod tests {
                     }) as Arc<dyn HistoryCell>
                 })
                 .collect(),
+            Theme::default(),
         );
         let mut term = Terminal::new(TestBackend::new(40, 12)).expect("term");
         term.draw(|f| overlay.render(f.area(), f.buffer_mut()))
@@ -836,6 +1001,7 @@ mod tests {
                     }) as Arc<dyn HistoryCell>
                 })
                 .collect(),
+            Theme::default(),
         );
         let mut term = Terminal::new(TestBackend::new(40, 12)).expect("term");
         term.draw(|f| overlay.render(f.area(), f.buffer_mut()))
@@ -899,6 +1065,7 @@ mod tests {
                     }) as Arc<dyn HistoryCell>
                 })
                 .collect(),
+            Theme::default(),
         );
         let area = Rect::new(0, 0, 40, 15);
 
@@ -0,0 +1,323 @@
+//! Undoing the most recent patch Codex applied (`/revert-patch`).
+//!
+//! The chat widget keeps the change set of the last successful patch. Reverting builds the
+//! inverse change set (adds become deletes, deletes become adds, update diffs are reversed and
+//! moves are undone) and writes it straight to disk.
+//!
+//! Before anything is written, every file is checked against what the patch left behind. Files
+//! that were edited since are reported as `modified` so the confirmation prompt can warn that
+//! those edits will be lost; updates whose reversed diff no longer applies are reported as
+//! `unrevertable` and left untouched.
+
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_core::protocol::FileChange;
+
+/// Return the change set that undoes `changes`.
+///
+/// Moved files are keyed by their destination, with `move_path` pointing back at the original
+/// location.
+pub(crate) fn inverse_changes(
+    changes: &HashMap<PathBuf, FileChange>,
+) -> HashMap<PathBuf, FileChange> {
+    changes
+        .iter()
+        .map(|(path, change)| match change {
+            FileChange::Add { content } => (
+                path.clone(),
+                FileChange::Delete {
+                    content: content.clone(),
+                },
+            ),
+            FileChange::Delete { content } => (
+                path.clone(),
+                FileChange::Add {
+                    content: content.clone(),
+                },
+            ),
+            FileChange::Update {
+                unified_diff,
+                move_path,
+            } => (
+                move_path.clone().unwrap_or_else(|| path.clone()),
+                FileChange::Update {
+                    unified_diff: reverse_unified_diff(unified_diff),
+                    move_path: move_path.as_ref().map(|_| path.clone()),
+                },
+            ),
+        })
+        .collect()
+}
+
+/// Reverse a single-file unified diff: swap the `---`/`+++` headers, the old and new ranges of
+/// each hunk, and the added and removed lines.
+pub(crate) fn reverse_unified_diff(diff: &str) -> String {
+    let mut out = String::with_capacity(diff.len());
+    let mut in_hunks = false;
+    let mut pending_old_header: Option<&str> = None;
+    for line in diff.split_inclusive('\n') {
+        if let Some(header) = reverse_hunk_header(line) {
+            in_hunks = true;
+            out.push_str(&header);
+            continue;
+        }
+        if !in_hunks {
+            // File headers come in `---` / `+++` pairs before the first hunk.
+            if let Some(old) = line.strip_prefix("--- ") {
+                pending_old_header = Some(old);
+                continue;
+            }
+            if let Some(new) = line.strip_prefix("+++ ") {
+                out.push_str("--- ");
+                out.push_str(new);
+                if let Some(old) = pending_old_header.take() {
+                    out.push_str("+++ ");
+                    out.push_str(old);
+                }
+                continue;
+            }
+            out.push_str(line);
+            continue;
+        }
+        if let Some(rest) = line.strip_prefix('+') {
+            out.push('-');
+            out.push_str(rest);
+        } else if let Some(rest) = line.strip_prefix('-') {
+            out.push('+');
+            out.push_str(rest);
+        } else {
+            out.push_str(line);
+        }
+    }
+    out
+}
+
+fn reverse_hunk_header(line: &str) -> Option<String> {
+    let rest = line.strip_prefix("@@ -")?;
+    let (old, rest) = rest.split_once(" +")?;
+    let (new, tail) = rest.split_once(" @@")?;
+    Some(format!("@@ -{new} +{old} @@{tail}"))
+}
+
+/// One file operation performed by a revert.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum RevertStep {
+    Write { path: PathBuf, contents: String },
+    Remove { path: PathBuf },
+}
+
+/// What reverting a patch would do, computed against the files as they are now.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct RevertPlan {
+    pub(crate) steps: Vec<RevertStep>,
+    /// Files edited since the patch was applied; reverting discards those edits.
+    pub(crate) modified: Vec<PathBuf>,
+    /// Files whose reversed change no longer applies; they are left as they are.
+    pub(crate) unrevertable: Vec<PathBuf>,
+}
+
+/// Plan the revert of `changes`, resolving relative paths against `cwd`.
+pub(crate) fn plan_revert(changes: &HashMap<PathBuf, FileChange>, cwd: &Path) -> RevertPlan {
+    let mut inverse: Vec<(PathBuf, FileChange)> = inverse_changes(changes).into_iter().collect();
+    inverse.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut plan = RevertPlan::default();
+    for (path, change) in inverse {
+        let abs = cwd.join(&path);
+        match change {
+            // The patch added this file.
+            FileChange::Delete { content } => match std::fs::read_to_string(&abs) {
+                Ok(current) => {
+                    if current != content {
+                        plan.modified.push(path);
+                    }
+                    plan.steps.push(RevertStep::Remove { path: abs });
+                }
+                // Already gone; nothing to undo.
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(_) => plan.unrevertable.push(path),
+            },
+            // The patch deleted this file.
+            FileChange::Add { content } => {
+                if abs.exists() {
+                    plan.modified.push(path);
+                }
+                plan.steps.push(RevertStep::Write {
+                    path: abs,
+                    contents: content,
+                });
+            }
+            FileChange::Update {
+                unified_diff,
+                move_path,
+            } => {
+                let reverted = std::fs::read_to_string(&abs).ok().and_then(|current| {
+                    let patch = diffy::Patch::from_str(&unified_diff).ok()?;
+                    diffy::apply(&current, &patch).ok()
+                });
+                let Some(contents) = reverted else {
+                    plan.unrevertable.push(path);
+                    continue;
+                };
+                let target = move_path.map_or_else(|| abs.clone(), |dest| cwd.join(dest));
+                if target != abs {
+                    if target.exists() {
+                        plan.modified.push(path);
+                    }
+                    plan.steps.push(RevertStep::Remove { path: abs });
+                }
+                plan.steps.push(RevertStep::Write {
+                    path: target,
+                    contents,
+                });
+            }
+        }
+    }
+    plan
+}
+
+impl RevertPlan {
+    /// Perform the planned steps, stopping at the first I/O error.
+    pub(crate) fn apply(&self) -> io::Result<()> {
+        for step in &self.steps {
+            match step {
+                RevertStep::Write { path, contents } => {
+                    if let Some(parent) = path.parent() {
+                        std::fs::create_dir_all(parent)?;
+                    }
+                    std::fs::write(path, contents)?;
+                }
+                RevertStep::Remove { path } => match std::fs::remove_file(path) {
+                    Ok(()) => {}
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                    Err(err) => return Err(err),
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+
+    fn simple_patch() -> HashMap<PathBuf, FileChange> {
+        HashMap::from([
+            (
+                PathBuf::from("added.txt"),
+                FileChange::Add {
+                    content: "new\n".to_string(),
+                },
+            ),
+            (
+                PathBuf::from("deleted.txt"),
+                FileChange::Delete {
+                    content: "old\n".to_string(),
+                },
+            ),
+            (
+                PathBuf::from("modified.txt"),
+                FileChange::Update {
+                    unified_diff: "@@ -1,2 +1,2 @@\n one\n-two\n+TWO\n".to_string(),
+                    move_path: None,
+                },
+            ),
+        ])
+    }
+
+    #[test]
+    fn inverse_of_add_modify_delete_patch() {
+        assert_eq!(
+            inverse_changes(&simple_patch()),
+            HashMap::from([
+                (
+                    PathBuf::from("added.txt"),
+                    FileChange::Delete {
+                        content: "new\n".to_string(),
+                    },
+                ),
+                (
+                    PathBuf::from("deleted.txt"),
+                    FileChange::Add {
+                        content: "old\n".to_string(),
+                    },
+                ),
+                (
+                    PathBuf::from("modified.txt"),
+                    FileChange::Update {
+                        unified_diff: "@@ -1,2 +1,2 @@\n one\n+two\n-TWO\n".to_string(),
+                        move_path: None,
+                    },
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn inverse_of_move_points_back_at_the_original_path() {
+        let changes = HashMap::from([(
+            PathBuf::from("old.rs"),
+            FileChange::Update {
+                unified_diff: "--- a/old.rs\n+++ b/new.rs\n@@ -1 +1,2 @@\n a\n+b\n".to_string(),
+                move_path: Some(PathBuf::from("new.rs")),
+            },
+        )]);
+        assert_eq!(
+            inverse_changes(&changes),
+            HashMap::from([(
+                PathBuf::from("new.rs"),
+                FileChange::Update {
+                    unified_diff: "--- b/new.rs\n+++ a/old.rs\n@@ -1,2 +1 @@\n a\n-b\n".to_string(),
+                    move_path: Some(PathBuf::from("old.rs")),
+                },
+            )])
+        );
+    }
+
+    #[test]
+    fn revert_restores_files_the_patch_touched() {
+        let dir = tempdir().expect("tempdir");
+        std::fs::write(dir.path().join("added.txt"), "new\n").expect("write");
+        std::fs::write(dir.path().join("modified.txt"), "one\nTWO\n").expect("write");
+
+        let plan = plan_revert(&simple_patch(), dir.path());
+        assert_eq!(plan.modified, Vec::<PathBuf>::new());
+        assert_eq!(plan.unrevertable, Vec::<PathBuf>::new());
+        plan.apply().expect("apply");
+
+        assert!(!dir.path().join("added.txt").exists());
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("deleted.txt")).expect("read"),
+            "old\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("modified.txt")).expect("read"),
+            "one\ntwo\n"
+        );
+    }
+
+    #[test]
+    fn files_changed_since_the_patch_are_reported() {
+        let dir = tempdir().expect("tempdir");
+        std::fs::write(dir.path().join("added.txt"), "new\nedited later\n").expect("write");
+        std::fs::write(dir.path().join("deleted.txt"), "recreated\n").expect("write");
+        std::fs::write(dir.path().join("modified.txt"), "rewritten\n").expect("write");
+
+        let plan = plan_revert(&simple_patch(), dir.path());
+        assert_eq!(
+            plan.modified,
+            vec![PathBuf::from("added.txt"), PathBuf::from("deleted.txt")]
+        );
+        assert_eq!(plan.unrevertable, vec![PathBuf::from("modified.txt")]);
+        assert!(plan.steps.iter().all(|step| !matches!(
+            step,
+            RevertStep::Write { path, .. } if path.ends_with("modified.txt")
+        )));
+    }
+}
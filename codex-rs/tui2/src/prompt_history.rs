@@ -0,0 +1,112 @@
+//! Session prompt history (`/history`).
+//!
+//! Every prompt the user sends leaves a [`UserHistoryCell`] in the transcript. Like
+//! [`crate::approval_history`], the list is rebuilt from those cells on demand, so it needs no
+//! separate bookkeeping and follows backtracking, which trims transcript cells.
+//!
+//! Prompts are listed newest first and repeated prompts are listed once, at their most recent
+//! position, the way shells deduplicate their history.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::history_cell::HistoryCell;
+use crate::history_cell::UserHistoryCell;
+
+/// Collect the prompts sent in `cells`, newest first, without duplicates or blank prompts.
+pub(crate) fn prompt_history(cells: &[Arc<dyn HistoryCell>]) -> Vec<String> {
+    let mut seen: HashSet<&str> = HashSet::new();
+    cells
+        .iter()
+        .rev()
+        .filter_map(|cell| cell.as_any().downcast_ref::<UserHistoryCell>())
+        .map(|cell| cell.message.as_str())
+        .filter(|message| !message.trim().is_empty() && seen.insert(message))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Incremental filter for the history popup: every whitespace-separated term of `query` must
+/// appear in `prompt`, ignoring case. Terms may match across line breaks in any order, so
+/// `"test fix"` finds `"fix the flaky\ntest"`.
+pub(crate) fn prompt_matches_filter(prompt: &str, query: &str) -> bool {
+    let prompt = prompt.to_lowercase();
+    query
+        .split_whitespace()
+        .all(|term| prompt.contains(&term.to_lowercase()))
+}
+
+/// One-line label for a prompt: its first non-blank line, with `…` when more lines follow.
+pub(crate) fn prompt_label(prompt: &str) -> String {
+    let mut lines = prompt.lines().filter(|line| !line.trim().is_empty());
+    let first = lines.next().unwrap_or_default().trim();
+    if lines.next().is_some() {
+        format!("{first} …")
+    } else {
+        first.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history_cell::AgentMessageCell;
+    use crate::history_cell::new_user_prompt;
+    use crate::theme::Theme;
+    use pretty_assertions::assert_eq;
+    use ratatui::text::Line;
+
+    fn user(message: &str) -> Arc<dyn HistoryCell> {
+        Arc::new(new_user_prompt(message.to_string(), &Theme::default()))
+    }
+
+    fn agent(message: &'static str) -> Arc<dyn HistoryCell> {
+        Arc::new(AgentMessageCell::new(
+            vec![Line::from(message)],
+            true,
+            &Theme::default(),
+        ))
+    }
+
+    #[test]
+    fn builds_newest_first_history_from_transcript() {
+        let cells = vec![
+            user("explain the parser"),
+            agent("sure"),
+            user("run the tests"),
+            agent("done"),
+            user("   "),
+            user("explain the parser"),
+            agent("again"),
+            user("fix the flaky\ntest"),
+        ];
+
+        assert_eq!(
+            prompt_history(&cells),
+            vec![
+                "fix the flaky\ntest".to_string(),
+                "explain the parser".to_string(),
+                "run the tests".to_string(),
+            ]
+        );
+        assert_eq!(prompt_history(&[agent("hi")]), Vec::<String>::new());
+    }
+
+    #[test]
+    fn filter_requires_every_term_ignoring_case() {
+        let prompt = "Fix the flaky\ntest in CI";
+
+        assert!(prompt_matches_filter(prompt, ""));
+        assert!(prompt_matches_filter(prompt, "flaky"));
+        assert!(prompt_matches_filter(prompt, "ci FIX"));
+        assert!(prompt_matches_filter(prompt, "test fix"));
+        assert!(!prompt_matches_filter(prompt, "fix parser"));
+        assert!(!prompt_matches_filter(prompt, "flaky test in cd"));
+    }
+
+    #[test]
+    fn label_uses_first_non_blank_line() {
+        assert_eq!(prompt_label("run the tests"), "run the tests");
+        assert_eq!(prompt_label("\n  fix the flaky\ntest"), "fix the flaky …");
+    }
+}
@@ -0,0 +1,143 @@
+//! Raw protocol event log for the F11 debug overlay (`debug-logs` only).
+//!
+//! Every [`EventMsg`] that reaches the app is serialized to its wire JSON and kept with the time
+//! it arrived, so integration work can watch the protocol traffic without external logging. The
+//! buffer is bounded; once full, the oldest events are dropped.
+
+use std::collections::VecDeque;
+
+use chrono::DateTime;
+use chrono::Local;
+use codex_core::protocol::EventMsg;
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+
+/// How many events the log keeps before evicting the oldest.
+pub(crate) const PROTOCOL_EVENT_LOG_CAPACITY: usize = 500;
+
+/// One received event: when it arrived and its serialized form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ProtocolEventEntry {
+    pub(crate) received_at: DateTime<Local>,
+    pub(crate) json: String,
+}
+
+#[derive(Debug)]
+pub(crate) struct ProtocolEventLog {
+    entries: VecDeque<ProtocolEventEntry>,
+    capacity: usize,
+    /// Events evicted so far; shown so a trimmed log is not mistaken for the full stream.
+    dropped: usize,
+}
+
+impl ProtocolEventLog {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            capacity: capacity.max(1),
+            dropped: 0,
+        }
+    }
+
+    /// Record `msg` as received at `received_at`, evicting the oldest entry when full.
+    pub(crate) fn push(&mut self, received_at: DateTime<Local>, msg: &EventMsg) {
+        let json = serde_json::to_string(msg).unwrap_or_else(|_| format!("{msg:?}"));
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+            self.dropped += 1;
+        }
+        self.entries
+            .push_back(ProtocolEventEntry { received_at, json });
+    }
+
+    #[cfg(test)]
+    pub(crate) fn entries(&self) -> impl Iterator<Item = &ProtocolEventEntry> {
+        self.entries.iter()
+    }
+
+    /// Render the log oldest first, one line per event, for a static overlay.
+    pub(crate) fn lines(&self) -> Vec<Line<'static>> {
+        let mut lines = Vec::with_capacity(self.entries.len() + 1);
+        if self.dropped > 0 {
+            lines.push(
+                format!(
+                    "… {} older events dropped (keeping {})",
+                    self.dropped, self.capacity
+                )
+                .dim()
+                .into(),
+            );
+        } else if self.entries.is_empty() {
+            lines.push("No protocol events received yet.".dim().into());
+        }
+        lines.extend(self.entries.iter().map(|entry| {
+            Line::from(vec![
+                entry.received_at.format("%H:%M:%S%.3f").to_string().dim(),
+                "  ".into(),
+                entry.json.clone().into(),
+            ])
+        }));
+        lines
+    }
+}
+
+impl Default for ProtocolEventLog {
+    fn default() -> Self {
+        Self::new(PROTOCOL_EVENT_LOG_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_core::protocol::WarningEvent;
+    use pretty_assertions::assert_eq;
+
+    fn warning(message: &str) -> EventMsg {
+        EventMsg::Warning(WarningEvent {
+            message: message.to_string(),
+        })
+    }
+
+    fn text(line: &Line<'_>) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    fn messages(log: &ProtocolEventLog) -> Vec<String> {
+        log.entries()
+            .map(|entry| {
+                let value: serde_json::Value =
+                    serde_json::from_str(&entry.json).expect("entry is json");
+                value["message"].as_str().unwrap_or_default().to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn records_events_in_order_and_evicts_oldest_beyond_capacity() {
+        let mut log = ProtocolEventLog::new(3);
+        let now = Local::now();
+
+        for message in ["one", "two"] {
+            log.push(now, &warning(message));
+        }
+        assert_eq!(messages(&log), vec!["one", "two"]);
+
+        for message in ["three", "four", "five"] {
+            log.push(now, &warning(message));
+        }
+        assert_eq!(messages(&log), vec!["three", "four", "five"]);
+
+        let lines = log.lines();
+        assert_eq!(lines.len(), 4);
+        assert!(
+            text(&lines[0]).contains("2 older events dropped"),
+            "{:?}",
+            lines[0]
+        );
+        assert!(text(&lines[1]).contains("\"type\":\"warning\""));
+    }
+}
@@ -396,9 +396,11 @@ impl<'a> Renderable for InsetRenderable<'a> {
         self.child.render(area.inset(self.insets), buf);
     }
     fn desired_height(&self, width: u16) -> u16 {
-        self.child
-            .desired_height(width - self.insets.left - self.insets.right)
-            + self.insets.top
+        self.child.desired_height(
+            width
+                .saturating_sub(self.insets.left)
+                .saturating_sub(self.insets.right),
+        ) + self.insets.top
             + self.insets.bottom
     }
     fn cursor_pos(&self, area: Rect) -> Option<(u16, u16)> {
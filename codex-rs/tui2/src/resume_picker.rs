@@ -11,6 +11,7 @@ use codex_core::Cursor;
 use codex_core::INTERACTIVE_SESSION_SOURCES;
 use codex_core::RolloutRecorder;
 use codex_core::path_utils;
+use codex_protocol::ConversationId;
 use codex_protocol::items::TurnItem;
 use color_eyre::eyre::Result;
 use crossterm::event::KeyCode;
@@ -27,6 +28,7 @@ use tokio_stream::StreamExt;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use unicode_width::UnicodeWidthStr;
 
+use crate::conversation_titles::ConversationTitles;
 use crate::diff_render::display_path_for;
 use crate::key_hint;
 use crate::text_formatting::truncate_text;
@@ -66,8 +68,9 @@ enum BackgroundEvent {
 }
 
 /// Interactive session picker that lists recorded rollout files with simple
-/// search and pagination. Shows the first user input as the preview, relative
-/// time (e.g., "5 seconds ago"), and the absolute path.
+/// search and pagination. Shows the conversation's custom title (see `/title`), or else the first
+/// user input, as the preview, along with relative time (e.g., "5 seconds ago") and the absolute
+/// path.
 pub async fn run_resume_picker(
     tui: &mut Tui,
     codex_home: &Path,
@@ -190,6 +193,8 @@ struct PickerState {
     default_provider: String,
     show_all: bool,
     filter_cwd: Option<PathBuf>,
+    /// Custom titles loaded from `codex_home` when the picker opens.
+    titles: ConversationTitles,
 }
 
 struct PaginationState {
@@ -260,6 +265,7 @@ impl PickerState {
         show_all: bool,
         filter_cwd: Option<PathBuf>,
     ) -> Self {
+        let titles = crate::conversation_titles::load_titles(&codex_home);
         Self {
             codex_home,
             requester,
@@ -283,6 +289,7 @@ impl PickerState {
             default_provider,
             show_all,
             filter_cwd,
+            titles,
         }
     }
 
@@ -429,7 +436,7 @@ impl PickerState {
             self.pagination.reached_scan_cap = true;
         }
 
-        let rows = rows_from_items(page.items);
+        let rows = rows_from_items(page.items, &self.titles);
         for row in rows {
             if self.seen_paths.insert(row.path.clone()) {
                 self.all_rows.push(row);
@@ -627,11 +634,14 @@ impl PickerState {
     }
 }
 
-fn rows_from_items(items: Vec<ConversationItem>) -> Vec<Row> {
-    items.into_iter().map(|item| head_to_row(&item)).collect()
+fn rows_from_items(items: Vec<ConversationItem>, titles: &ConversationTitles) -> Vec<Row> {
+    items
+        .into_iter()
+        .map(|item| head_to_row(&item, titles))
+        .collect()
 }
 
-fn head_to_row(item: &ConversationItem) -> Row {
+fn head_to_row(item: &ConversationItem, titles: &ConversationTitles) -> Row {
     let created_at = item
         .created_at
         .as_deref()
@@ -644,7 +654,12 @@ fn head_to_row(item: &ConversationItem) -> Row {
         .or(created_at);
 
     let (cwd, git_branch) = extract_session_meta_from_head(&item.head);
-    let preview = preview_from_head(&item.head)
+    // A custom title wins over the first-message preview.
+    let title = conversation_id_from_head(&item.head)
+        .and_then(|id| titles.get(&id.to_string()))
+        .cloned();
+    let preview = title
+        .or_else(|| preview_from_head(&item.head))
         .map(|s| s.trim().to_string())
         .filter(|s| !s.is_empty())
         .unwrap_or_else(|| String::from("(no message yet)"));
@@ -670,6 +685,12 @@ fn extract_session_meta_from_head(head: &[serde_json::Value]) -> (Option<PathBuf
     (None, None)
 }
 
+fn conversation_id_from_head(head: &[serde_json::Value]) -> Option<ConversationId> {
+    head.iter()
+        .find_map(|value| serde_json::from_value::<SessionMetaLine>(value.clone()).ok())
+        .map(|meta_line| meta_line.meta.id)
+}
+
 fn paths_match(a: &Path, b: &Path) -> bool {
     if let (Ok(ca), Ok(cb)) = (
         path_utils::normalize_for_path_comparison(a),
@@ -1156,13 +1177,51 @@ mod tests {
             created_at: Some("2025-01-02T00:00:00Z".into()),
             updated_at: Some("2025-01-02T00:00:00Z".into()),
         };
-        let rows = rows_from_items(vec![a, b]);
+        let rows = rows_from_items(vec![a, b], &ConversationTitles::new());
         assert_eq!(rows.len(), 2);
         // Preserve the given order even if timestamps differ; backend already provides newest-first.
         assert!(rows[0].preview.contains('A'));
         assert!(rows[1].preview.contains('B'));
     }
 
+    #[test]
+    fn custom_title_replaces_first_message_preview() {
+        use codex_protocol::protocol::SessionMeta;
+
+        let titled_id = ConversationId::new();
+        let untitled_id = ConversationId::new();
+        let item_for = |id: ConversationId, text: &str| {
+            let meta = SessionMetaLine {
+                meta: SessionMeta {
+                    id,
+                    ..Default::default()
+                },
+                git: None,
+            };
+            let mut head = head_with_ts_and_user_text("2025-01-01T00:00:00Z", &[text]);
+            head.insert(0, serde_json::to_value(meta).expect("session meta"));
+            ConversationItem {
+                path: PathBuf::from(format!("/tmp/{id}.jsonl")),
+                head,
+                created_at: Some("2025-01-01T00:00:00Z".into()),
+                updated_at: Some("2025-01-01T00:00:00Z".into()),
+            }
+        };
+
+        let mut titles = ConversationTitles::new();
+        titles.insert(titled_id.to_string(), "Parser rewrite".to_string());
+        let rows = rows_from_items(
+            vec![
+                item_for(titled_id, "please rewrite the parser"),
+                item_for(untitled_id, "explain the codebase"),
+            ],
+            &titles,
+        );
+
+        let previews: Vec<_> = rows.iter().map(|row| row.preview.as_str()).collect();
+        assert_eq!(previews, vec!["Parser rewrite", "explain the codebase"]);
+    }
+
     #[test]
     fn row_uses_tail_timestamp_for_updated_at() {
         let head = head_with_ts_and_user_text("2025-01-01T00:00:00Z", &["Hello"]);
@@ -1173,7 +1232,7 @@ mod tests {
             updated_at: Some("2025-01-01T01:00:00Z".into()),
         };
 
-        let row = head_to_row(&item);
+        let row = head_to_row(&item, &ConversationTitles::new());
         let expected_created = chrono::DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
             .unwrap()
             .with_timezone(&Utc);
@@ -1362,7 +1421,7 @@ mod tests {
         .await
         .expect("list conversations");
 
-        let rows = rows_from_items(page.items);
+        let rows = rows_from_items(page.items, &state.titles);
         state.all_rows = rows.clone();
         state.filtered_rows = rows;
         state.view_rows = Some(4);
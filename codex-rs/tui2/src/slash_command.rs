@@ -14,22 +14,34 @@ pub enum SlashCommand {
     // more frequently used commands should be listed first.
     Model,
     Approvals,
+    Sandbox,
+    ApprovalHistory,
+    Errors,
     Skills,
     Review,
     New,
     Resume,
     Init,
     Compact,
+    Retry,
+    History,
     // Undo,
     Diff,
+    RevertPatch,
     Mention,
     Status,
+    Settings,
+    Scroll,
+    CopyResume,
+    CopyId,
+    Title,
     Mcp,
     Logout,
     Quit,
     Exit,
     Feedback,
     Rollout,
+    SkillsPreview,
     TestApproval,
 }
 
@@ -42,18 +54,34 @@ impl SlashCommand {
             SlashCommand::Init => "create an AGENTS.md file with instructions for Codex",
             SlashCommand::Compact => "summarize conversation to prevent hitting the context limit",
             SlashCommand::Review => "review my current changes and find issues",
+            SlashCommand::Retry => "resend your last message with higher reasoning effort",
+            SlashCommand::History => "browse the prompts sent in this session and reuse one",
             SlashCommand::Resume => "resume a saved chat",
             // SlashCommand::Undo => "ask Codex to undo a turn",
             SlashCommand::Quit | SlashCommand::Exit => "exit Codex",
             SlashCommand::Diff => "show git diff (including untracked files)",
+            SlashCommand::RevertPatch => "undo the most recent patch Codex applied",
             SlashCommand::Mention => "mention a file",
             SlashCommand::Skills => "use skills to improve how Codex performs specific tasks",
             SlashCommand::Status => "show current session configuration and token usage",
+            SlashCommand::Settings => {
+                "view and change the model, reasoning, and permissions for this session"
+            }
+            SlashCommand::Scroll => "tune mouse wheel scrolling live",
+            SlashCommand::CopyResume => "copy the command to resume this chat to the clipboard",
+            SlashCommand::CopyId => "copy this chat's conversation id to the clipboard",
+            SlashCommand::Title => "set a title for this chat",
             SlashCommand::Model => "choose what model and reasoning effort to use",
             SlashCommand::Approvals => "choose what Codex can do without approval",
+            SlashCommand::Sandbox => {
+                "switch to the next sandbox level (read-only, agent, full access)"
+            }
+            SlashCommand::ApprovalHistory => "list the approval requests answered in this session",
+            SlashCommand::Errors => "list the errors in this session and jump to one",
             SlashCommand::Mcp => "list configured MCP tools",
             SlashCommand::Logout => "log out of Codex",
             SlashCommand::Rollout => "print the rollout file path",
+            SlashCommand::SkillsPreview => "show the text injected when each skill is mentioned",
             SlashCommand::TestApproval => "test approval request",
         }
     }
@@ -67,31 +95,44 @@ impl SlashCommand {
     /// Whether this command can be run while a task is in progress.
     pub fn available_during_task(self) -> bool {
         match self {
-            SlashCommand::New
-            | SlashCommand::Resume
+            SlashCommand::Resume
             | SlashCommand::Init
             | SlashCommand::Compact
+            | SlashCommand::Retry
             // | SlashCommand::Undo
             | SlashCommand::Model
             | SlashCommand::Approvals
+            | SlashCommand::Sandbox
+            | SlashCommand::Settings
             | SlashCommand::Review
+            | SlashCommand::RevertPatch
             | SlashCommand::Logout => false,
-            SlashCommand::Diff
+            // `/new` asks for confirmation before discarding a running task.
+            SlashCommand::New
+            | SlashCommand::Diff
             | SlashCommand::Mention
             | SlashCommand::Skills
             | SlashCommand::Status
+            | SlashCommand::ApprovalHistory
+            | SlashCommand::Errors
+            | SlashCommand::History
+            | SlashCommand::CopyResume
+            | SlashCommand::CopyId
+            | SlashCommand::Title
             | SlashCommand::Mcp
             | SlashCommand::Feedback
             | SlashCommand::Quit
             | SlashCommand::Exit => true,
             SlashCommand::Rollout => true,
+            SlashCommand::Scroll => true,
+            SlashCommand::SkillsPreview => true,
             SlashCommand::TestApproval => true,
         }
     }
 
     fn is_visible(self) -> bool {
         match self {
-            SlashCommand::Rollout | SlashCommand::TestApproval => cfg!(debug_assertions),
+            SlashCommand::TestApproval => cfg!(debug_assertions),
             _ => true,
         }
     }
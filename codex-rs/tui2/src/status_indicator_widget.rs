@@ -23,6 +23,7 @@ use crate::key_hint;
 use crate::render::renderable::Renderable;
 use crate::shimmer::shimmer_spans;
 use crate::text_formatting::capitalize_first;
+use crate::text_formatting::truncate_to_width;
 use crate::tui::FrameRequester;
 use crate::wrapping::RtOptions;
 use crate::wrapping::word_wrap_lines;
@@ -181,7 +182,7 @@ impl StatusIndicatorWidget {
             if let Some(last) = out.last_mut()
                 && let Some(span) = last.spans.last_mut()
             {
-                let trimmed: String = span.content.as_ref().chars().take(max_base_len).collect();
+                let (trimmed, _) = truncate_to_width(span.content.as_ref(), max_base_len);
                 *span = format!("{trimmed}…").dim();
             }
         }
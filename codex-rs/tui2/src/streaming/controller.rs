@@ -1,6 +1,9 @@
 use crate::history_cell::HistoryCell;
 use crate::history_cell::{self};
+use crate::theme::Theme;
+use codex_core::config::types::StreamReveal;
 use ratatui::text::Line;
+use ratatui::text::Span;
 
 use super::StreamState;
 
@@ -10,6 +13,10 @@ pub(crate) struct StreamController {
     state: StreamState,
     finishing_after_drain: bool,
     header_emitted: bool,
+    reveal: StreamReveal,
+    /// Characters of the front queued line already shown (`char`/`word` reveal).
+    revealed_chars: usize,
+    theme: Theme,
 }
 
 impl StreamController {
@@ -18,9 +25,27 @@ impl StreamController {
             state: StreamState::new(width),
             finishing_after_drain: false,
             header_emitted: false,
+            reveal: StreamReveal::default(),
+            revealed_chars: 0,
+            theme: Theme::default(),
         }
     }
 
+    /// Colors for the agent message cells this controller emits (`[tui.theme]`).
+    pub(crate) fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    /// Choose how queued lines are revealed on each commit tick (`tui.stream_reveal`).
+    pub(crate) fn set_reveal(&mut self, reveal: StreamReveal) {
+        self.reveal = reveal;
+    }
+
+    /// Show agent output verbatim instead of rendering it as Markdown.
+    pub(crate) fn set_render_markdown(&mut self, render_markdown: bool) {
+        self.state.collector.set_render_markdown(render_markdown);
+    }
+
     /// Push a delta; if it contains a newline, commit completed lines and start animation.
     pub(crate) fn push(&mut self, delta: &str) -> bool {
         let state = &mut self.state;
@@ -59,25 +84,110 @@ impl StreamController {
         // Cleanup
         self.state.clear();
         self.finishing_after_drain = false;
+        self.revealed_chars = 0;
         self.emit(out_lines)
     }
 
+    /// Commit every queued line at once, for `instant` reveal where no animation runs.
+    pub(crate) fn drain_queued(&mut self) -> Option<Box<dyn HistoryCell>> {
+        let lines = self.state.drain_all();
+        self.revealed_chars = 0;
+        self.emit(lines)
+    }
+
     /// Step animation: commit at most one queued line and handle end-of-drain cleanup.
+    ///
+    /// With `char`/`word` reveal a tick usually only advances [`Self::partial_cell`]; the line is
+    /// committed once it is fully revealed.
     pub(crate) fn on_commit_tick(&mut self) -> (Option<Box<dyn HistoryCell>>, bool) {
-        let step = self.state.step();
+        let step = match self.reveal {
+            StreamReveal::Char | StreamReveal::Word => self.reveal_step(),
+            StreamReveal::Line | StreamReveal::Instant => self.state.step(),
+        };
         (self.emit(step), self.state.is_idle())
     }
 
+    /// The front queued line as revealed so far, shown live below the transcript while a
+    /// `char`/`word` reveal is in progress.
+    pub(crate) fn partial_cell(&self) -> Option<Box<dyn HistoryCell>> {
+        if self.revealed_chars == 0 {
+            return None;
+        }
+        let line = truncate_line(self.state.front()?, self.revealed_chars);
+        Some(Box::new(history_cell::AgentMessageCell::new(
+            vec![line],
+            !self.header_emitted,
+            &self.theme,
+        )))
+    }
+
+    fn reveal_step(&mut self) -> Vec<Line<'static>> {
+        // Lines queued behind the one being revealed are committed whole, so the reveal never
+        // falls more than a line behind the stream.
+        if self.state.queued_len() > 1 {
+            self.revealed_chars = 0;
+            return self.state.step();
+        }
+        let Some(front) = self.state.front() else {
+            return Vec::new();
+        };
+        let text: String = front
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        let next = match self.reveal {
+            StreamReveal::Word => next_word_end(&text, self.revealed_chars),
+            _ => self.revealed_chars + 1,
+        };
+        if next >= text.chars().count() {
+            self.revealed_chars = 0;
+            self.state.step()
+        } else {
+            self.revealed_chars = next;
+            Vec::new()
+        }
+    }
+
     fn emit(&mut self, lines: Vec<Line<'static>>) -> Option<Box<dyn HistoryCell>> {
         if lines.is_empty() {
             return None;
         }
-        Some(Box::new(history_cell::AgentMessageCell::new(lines, {
-            let header_emitted = self.header_emitted;
-            self.header_emitted = true;
-            !header_emitted
-        })))
+        Some(Box::new(history_cell::AgentMessageCell::new(
+            lines,
+            {
+                let header_emitted = self.header_emitted;
+                self.header_emitted = true;
+                !header_emitted
+            },
+            &self.theme,
+        )))
+    }
+}
+
+/// Character index just past the word that follows `from` (skipping leading whitespace).
+fn next_word_end(text: &str, from: usize) -> usize {
+    let mut chars = text.chars().enumerate().skip(from).peekable();
+    while chars.next_if(|(_, ch)| ch.is_whitespace()).is_some() {}
+    while chars.next_if(|(_, ch)| !ch.is_whitespace()).is_some() {}
+    chars.peek().map_or(text.chars().count(), |(idx, _)| *idx)
+}
+
+/// The first `chars` characters of `line`, keeping each span's style.
+fn truncate_line(line: &Line<'static>, chars: usize) -> Line<'static> {
+    let mut remaining = chars;
+    let mut spans = Vec::new();
+    for span in &line.spans {
+        if remaining == 0 {
+            break;
+        }
+        let content: String = span.content.chars().take(remaining).collect();
+        remaining -= content.chars().count();
+        spans.push(Span::styled(content, span.style));
     }
+    let mut truncated = line.clone();
+    truncated.spans = spans;
+    truncated
 }
 
 #[cfg(test)]
@@ -97,6 +207,89 @@ mod tests {
             .collect()
     }
 
+    fn cell_text(cell: &dyn HistoryCell) -> Vec<String> {
+        lines_to_plain_strings(&cell.transcript_lines(u16::MAX))
+            .into_iter()
+            .map(|s| s.chars().skip(2).collect())
+            .collect()
+    }
+
+    #[test]
+    fn instant_reveal_drains_all_completed_lines_at_once() {
+        let mut ctrl = StreamController::new(None);
+        ctrl.set_reveal(StreamReveal::Instant);
+
+        assert!(ctrl.push("first line\nsecond line\nthird"));
+        let cell = ctrl.drain_queued().expect("completed lines");
+        assert_eq!(cell_text(cell.as_ref()), vec!["first line", "second line"]);
+        assert!(ctrl.on_commit_tick().0.is_none(), "nothing left to animate");
+        assert!(ctrl.partial_cell().is_none());
+
+        let cell = ctrl.finalize().expect("trailing text");
+        assert_eq!(cell_text(cell.as_ref()), vec!["third"]);
+    }
+
+    #[test]
+    fn line_reveal_commits_one_line_per_tick() {
+        let mut ctrl = StreamController::new(None);
+        ctrl.set_reveal(StreamReveal::Line);
+
+        assert!(ctrl.push("alpha beta\ngamma\n"));
+        let (cell, idle) = ctrl.on_commit_tick();
+        assert_eq!(
+            cell_text(cell.expect("first line").as_ref()),
+            vec!["alpha beta"]
+        );
+        assert!(!idle);
+        let (cell, idle) = ctrl.on_commit_tick();
+        assert_eq!(
+            cell_text(cell.expect("second line").as_ref()),
+            vec!["gamma"]
+        );
+        assert!(idle);
+    }
+
+    #[test]
+    fn word_reveal_grows_the_partial_line_before_committing_it() {
+        let mut ctrl = StreamController::new(None);
+        ctrl.set_reveal(StreamReveal::Word);
+        assert!(ctrl.push("one two three\n"));
+
+        let mut partials = Vec::new();
+        let committed = loop {
+            let (cell, _) = ctrl.on_commit_tick();
+            if let Some(cell) = cell {
+                break cell;
+            }
+            let partial = ctrl.partial_cell().expect("partial line");
+            partials.extend(cell_text(partial.as_ref()));
+        };
+
+        assert_eq!(partials, vec!["one", "one two"]);
+        assert_eq!(cell_text(committed.as_ref()), vec!["one two three"]);
+        assert!(ctrl.partial_cell().is_none());
+    }
+
+    #[test]
+    fn char_reveal_commits_lines_queued_behind_the_revealed_one() {
+        let mut ctrl = StreamController::new(None);
+        ctrl.set_reveal(StreamReveal::Char);
+        assert!(ctrl.push("ab\ncd\n"));
+
+        let (cell, _) = ctrl.on_commit_tick();
+        assert_eq!(
+            cell_text(cell.expect("caught-up line").as_ref()),
+            vec!["ab"]
+        );
+        let (cell, _) = ctrl.on_commit_tick();
+        assert!(cell.is_none());
+        let partial = ctrl.partial_cell().expect("partial line");
+        assert_eq!(cell_text(partial.as_ref()), vec!["c"]);
+        let (cell, idle) = ctrl.on_commit_tick();
+        assert_eq!(cell_text(cell.expect("last line").as_ref()), vec!["cd"]);
+        assert!(idle);
+    }
+
     #[tokio::test]
     async fn controller_loose_vs_tight_with_commit_ticks_matches_full() {
         let mut ctrl = StreamController::new(None);
@@ -30,6 +30,12 @@ impl StreamState {
     pub(crate) fn drain_all(&mut self) -> Vec<Line<'static>> {
         self.queued_lines.drain(..).collect()
     }
+    pub(crate) fn front(&self) -> Option<&Line<'static>> {
+        self.queued_lines.front()
+    }
+    pub(crate) fn queued_len(&self) -> usize {
+        self.queued_lines.len()
+    }
     pub(crate) fn is_idle(&self) -> bool {
         self.queued_lines.is_empty()
     }
@@ -2,11 +2,23 @@ use crate::color::blend;
 use crate::color::is_light;
 use crate::terminal_palette::best_color;
 use crate::terminal_palette::default_bg;
+use crate::theme::Theme;
+use codex_core::config::types::SelectionHighlight;
 use ratatui::style::Color;
+use ratatui::style::Modifier;
 use ratatui::style::Style;
 
-pub fn user_message_style() -> Style {
-    user_message_style_for(default_bg())
+pub fn user_message_style(theme: &Theme) -> Style {
+    user_message_style_with(theme, default_bg())
+}
+
+/// Returns the style for a user-authored message, preferring the theme's background over the
+/// terminal-adaptive tint.
+pub fn user_message_style_with(theme: &Theme, terminal_bg: Option<(u8, u8, u8)>) -> Style {
+    match theme.user_message_bg {
+        Some(bg) => Style::default().bg(bg),
+        None => user_message_style_for(terminal_bg),
+    }
 }
 
 /// Returns the style for a user-authored message using the provided terminal background.
@@ -26,3 +38,38 @@ pub fn user_message_bg(terminal_bg: (u8, u8, u8)) -> Color {
     };
     best_color(blend(top, terminal_bg, 0.1))
 }
+
+/// Base style for agent message text; empty unless the theme sets `agent_text`.
+pub fn agent_text_style(theme: &Theme) -> Style {
+    match theme.agent_text {
+        Some(fg) => Style::default().fg(fg),
+        None => Style::default(),
+    }
+}
+
+pub fn error_style(theme: &Theme) -> Style {
+    Style::default().fg(theme.error)
+}
+
+pub fn warning_style(theme: &Theme) -> Style {
+    Style::default().fg(theme.warning)
+}
+
+/// Applies the theme's selection highlight on top of `style`.
+///
+/// In the default `reverse` mode this uses reverse video unless the theme sets `selection_bg`.
+/// `high-contrast` paints explicit colors (black on cyan unless the theme overrides them) so
+/// the selection stays legible where reverse video is low-contrast.
+pub fn selection_style(theme: &Theme, style: Style) -> Style {
+    match theme.selection_highlight {
+        SelectionHighlight::Reverse => match theme.selection_bg {
+            Some(bg) => style.bg(bg),
+            None => style.add_modifier(Modifier::REVERSED),
+        },
+        SelectionHighlight::HighContrast => style
+            .fg(theme.selection_fg.unwrap_or(Color::Black))
+            .bg(theme.selection_bg.unwrap_or(Color::Cyan))
+            .remove_modifier(Modifier::REVERSED),
+        SelectionHighlight::Underline => style.add_modifier(Modifier::UNDERLINED),
+    }
+}
@@ -114,6 +114,23 @@ pub(crate) fn truncate_text(text: &str, max_graphemes: usize) -> String {
     }
 }
 
+/// Truncate `text` to at most `max_width` terminal columns without splitting a grapheme cluster.
+///
+/// Returns the kept prefix and whether anything was cut, so callers can append an ellipsis
+/// (reserving a column for it). A wide grapheme that would straddle the limit is dropped, so the
+/// result can be one column narrower than `max_width`.
+pub(crate) fn truncate_to_width(text: &str, max_width: usize) -> (String, bool) {
+    let mut used = 0usize;
+    for (byte_index, grapheme) in text.grapheme_indices(true) {
+        let width = UnicodeWidthStr::width(grapheme);
+        if used + width > max_width {
+            return (text[..byte_index].to_string(), true);
+        }
+        used += width;
+    }
+    (text.to_string(), false)
+}
+
 /// Truncate a path-like string to the given display width, keeping leading and trailing segments
 /// where possible and inserting a single Unicode ellipsis between them. If an individual segment
 /// cannot fit, it is front-truncated with an ellipsis.
@@ -430,6 +447,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn truncate_to_width_keeps_short_text() {
+        assert_eq!(truncate_to_width("hello", 5), ("hello".to_string(), false));
+        assert_eq!(truncate_to_width("", 0), (String::new(), false));
+    }
+
+    #[test]
+    fn truncate_to_width_drops_wide_grapheme_at_odd_width() {
+        // Each CJK character is two columns wide.
+        assert_eq!(truncate_to_width("日本語", 5), ("日本".to_string(), true));
+        assert_eq!(
+            truncate_to_width("日本語", 6),
+            ("日本語".to_string(), false)
+        );
+        assert_eq!(truncate_to_width("a日本", 2), ("a".to_string(), true));
+    }
+
+    #[test]
+    fn truncate_to_width_never_splits_emoji_clusters() {
+        // Family emoji (ZWJ sequence) and a flag are each a single grapheme.
+        let text = "👨‍👩‍👧x🇯🇵";
+        assert_eq!(truncate_to_width(text, 1), (String::new(), true));
+        assert_eq!(truncate_to_width(text, 3), ("👨‍👩‍👧x".to_string(), true));
+        let (kept, truncated) = truncate_to_width("e\u{301}e\u{301}", 1);
+        assert_eq!(kept, "e\u{301}");
+        assert!(truncated);
+    }
+
     #[test]
     fn test_center_truncate_doesnt_truncate_short_path() {
         let sep = std::path::MAIN_SEPARATOR;
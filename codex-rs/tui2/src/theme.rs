@@ -0,0 +1,272 @@
+//! Color theme resolved from the `[tui.theme]` config table.
+//!
+//! The config stores colors as strings so that a typo doesn't prevent Codex from starting.
+//! [`Theme::from_config`] resolves the table into a [`Theme`] (a built-in base plus per-role
+//! overrides) and returns a warning for every value it couldn't understand; those roles keep the
+//! base theme's color. The app and chat widget each resolve it from their `Config` and hand it to
+//! the widgets and history cells they create; the style helpers in `crate::style` take it as an
+//! argument.
+
+use std::str::FromStr;
+
+use codex_core::config::Config;
+use codex_core::config::types::SelectionHighlight;
+use codex_core::config::types::TuiTheme;
+use ratatui::style::Color;
+
+use crate::style::user_message_bg;
+
+/// Colors used by the TUI for the roles that can be themed.
+///
+/// `None` for an optional role means "keep the terminal-adaptive default" (for example, user
+/// messages blend a tint into the terminal background and selections use reverse video).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Theme {
+    pub user_message_bg: Option<Color>,
+    pub agent_text: Option<Color>,
+    pub error: Color,
+    pub warning: Color,
+    pub selection_bg: Option<Color>,
+    pub selection_fg: Option<Color>,
+    pub selection_highlight: SelectionHighlight,
+    pub copy_pill_bg: Color,
+    pub copy_pill_fg: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            user_message_bg: None,
+            agent_text: None,
+            error: Color::Red,
+            warning: Color::Yellow,
+            selection_bg: None,
+            selection_fg: None,
+            selection_highlight: SelectionHighlight::Reverse,
+            copy_pill_bg: Color::DarkGray,
+            copy_pill_fg: Color::White,
+        }
+    }
+}
+
+impl Theme {
+    /// Looks up a built-in theme by name (`dark`, `light`, or `high-contrast`).
+    pub(crate) fn built_in(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().replace('_', "-").as_str() {
+            "dark" => Some(Self {
+                user_message_bg: Some(user_message_bg((0, 0, 0))),
+                ..Self::default()
+            }),
+            "light" => Some(Self {
+                user_message_bg: Some(user_message_bg((255, 255, 255))),
+                warning: Color::Magenta,
+                copy_pill_bg: Color::Gray,
+                copy_pill_fg: Color::Black,
+                ..Self::default()
+            }),
+            // Black is only used over the explicit cyan backgrounds, where it keeps its contrast.
+            "high-contrast" => Some(Self {
+                user_message_bg: Some(Color::DarkGray),
+                error: Color::LightRed,
+                warning: Color::LightMagenta,
+                selection_bg: Some(Color::Cyan),
+                selection_fg: Some(Color::Black),
+                copy_pill_bg: Color::Cyan,
+                copy_pill_fg: Color::Black,
+                ..Self::default()
+            }),
+            _ => None,
+        }
+    }
+
+    /// Resolves the `[tui.theme]` table and `tui.selection_highlight` of `config`, returning a
+    /// warning for each value that was ignored.
+    pub(crate) fn from_config(config: &Config) -> (Self, Vec<String>) {
+        Self::resolve(&config.tui_theme, config.tui_selection_highlight)
+    }
+
+    /// Resolves the config table into a theme, returning a warning for each value that was
+    /// ignored.
+    pub(crate) fn resolve(
+        config: &TuiTheme,
+        selection_highlight: SelectionHighlight,
+    ) -> (Self, Vec<String>) {
+        let mut warnings = Vec::new();
+        let mut theme = match config.name.as_deref() {
+            Some(name) => Self::built_in(name).unwrap_or_else(|| {
+                warnings.push(format!(
+                    "Unknown tui.theme.name `{name}`; expected `dark`, `light`, or `high-contrast`."
+                ));
+                Self::default()
+            }),
+            None => Self::default(),
+        };
+        theme.selection_highlight = selection_highlight;
+
+        let mut color = |key: &str, value: &Option<String>| -> Option<Color> {
+            let value = value.as_deref()?;
+            match parse_color(value) {
+                Some(color) => Some(color),
+                None => {
+                    warnings.push(format!(
+                        "Ignoring tui.theme.{key} = `{value}`: not a recognized color."
+                    ));
+                    None
+                }
+            }
+        };
+
+        if let Some(c) = color("user_message_bg", &config.user_message_bg) {
+            theme.user_message_bg = Some(c);
+        }
+        if let Some(c) = color("agent_text", &config.agent_text) {
+            theme.agent_text = Some(c);
+        }
+        if let Some(c) = color("error", &config.error) {
+            theme.error = c;
+        }
+        if let Some(c) = color("warning", &config.warning) {
+            theme.warning = c;
+        }
+        if let Some(c) = color("selection_bg", &config.selection_bg) {
+            theme.selection_bg = Some(c);
+        }
+        if let Some(c) = color("selection_fg", &config.selection_fg) {
+            theme.selection_fg = Some(c);
+        }
+        if let Some(c) = color("copy_pill_bg", &config.copy_pill_bg) {
+            theme.copy_pill_bg = c;
+        }
+        if let Some(c) = color("copy_pill_fg", &config.copy_pill_fg) {
+            theme.copy_pill_fg = c;
+        }
+
+        (theme, warnings)
+    }
+}
+
+/// Parses an ANSI color name (`red`, `light-blue`, ...), `#rrggbb`, or a 256-color index.
+fn parse_color(value: &str) -> Option<Color> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    Color::from_str(value).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::selection_style;
+    use crate::style::user_message_style_with;
+    use pretty_assertions::assert_eq;
+    use ratatui::style::Modifier;
+    use ratatui::style::Style;
+
+    #[test]
+    fn custom_theme_overrides_user_message_style() {
+        let config = TuiTheme {
+            user_message_bg: Some("blue".to_string()),
+            ..TuiTheme::default()
+        };
+        let (theme, warnings) = Theme::resolve(&config, SelectionHighlight::Reverse);
+
+        assert_eq!(warnings, Vec::<String>::new());
+        assert_eq!(
+            user_message_style_with(&theme, Some((0, 0, 0))),
+            Style::default().bg(Color::Blue)
+        );
+    }
+
+    #[test]
+    fn invalid_color_falls_back_to_default() {
+        let config = TuiTheme {
+            error: Some("not-a-color".to_string()),
+            warning: Some("#00ff00".to_string()),
+            ..TuiTheme::default()
+        };
+        let (theme, warnings) = Theme::resolve(&config, SelectionHighlight::Reverse);
+
+        assert_eq!(theme.error, Theme::default().error);
+        #[allow(clippy::disallowed_methods)]
+        let green = Color::Rgb(0, 255, 0);
+        assert_eq!(theme.warning, green);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("tui.theme.error"));
+    }
+
+    #[test]
+    fn unknown_built_in_name_uses_default_theme() {
+        let config = TuiTheme {
+            name: Some("solarized".to_string()),
+            ..TuiTheme::default()
+        };
+        let (theme, warnings) = Theme::resolve(&config, SelectionHighlight::Reverse);
+
+        assert_eq!(theme, Theme::default());
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn high_contrast_built_in_sets_selection_background() {
+        let config = TuiTheme {
+            name: Some("High_Contrast".to_string()),
+            ..TuiTheme::default()
+        };
+        let (theme, warnings) = Theme::resolve(&config, SelectionHighlight::Reverse);
+
+        assert_eq!(theme.selection_bg, Some(Color::Cyan));
+        assert_eq!(theme.copy_pill_bg, Color::Cyan);
+        assert_eq!(warnings, Vec::<String>::new());
+    }
+
+    #[test]
+    fn high_contrast_selection_uses_theme_colors_instead_of_reverse() {
+        let config = TuiTheme {
+            selection_fg: Some("white".to_string()),
+            selection_bg: Some("blue".to_string()),
+            ..TuiTheme::default()
+        };
+        let (theme, warnings) = Theme::resolve(&config, SelectionHighlight::HighContrast);
+        assert_eq!(warnings, Vec::<String>::new());
+
+        let style = selection_style(&theme, Style::default().fg(Color::Green));
+
+        assert_eq!(style.fg, Some(Color::White));
+        assert_eq!(style.bg, Some(Color::Blue));
+        assert!(!style.add_modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn underline_selection_keeps_cell_colors() {
+        let (theme, _) = Theme::resolve(&TuiTheme::default(), SelectionHighlight::Underline);
+
+        let style = selection_style(&theme, Style::default().fg(Color::Green));
+
+        assert_eq!(style.fg, Some(Color::Green));
+        assert!(style.add_modifier.contains(Modifier::UNDERLINED));
+        assert!(!style.add_modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn default_selection_is_reversed() {
+        let style = selection_style(&Theme::default(), Style::default());
+
+        assert!(style.add_modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn configured_selection_background_replaces_reverse() {
+        let config = TuiTheme {
+            selection_bg: Some("magenta".to_string()),
+            ..TuiTheme::default()
+        };
+        let (theme, warnings) = Theme::resolve(&config, SelectionHighlight::Reverse);
+        assert_eq!(warnings, Vec::<String>::new());
+
+        let style = selection_style(&theme, Style::default());
+
+        assert_eq!(style.bg, Some(Color::Magenta));
+        assert!(!style.add_modifier.contains(Modifier::REVERSED));
+    }
+}
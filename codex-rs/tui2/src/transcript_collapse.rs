@@ -0,0 +1,274 @@
+//! Optional cap on the number of history cells rendered in the inline transcript
+//! (`tui.max_transcript_cells`).
+//!
+//! Very long sessions keep every cell in `App::transcript_cells`, and the inline viewport wraps and
+//! scrolls over all of them. With a cap of `N`, the viewport instead renders a single summary cell
+//! followed by the newest `N` cells; the summary stands in for every older cell as one unit for
+//! scroll math, selection, and copy.
+//!
+//! The full cell list is left untouched, so the transcript overlay (`Ctrl+T`), backtracking, and
+//! the exit transcript still see the whole conversation.
+//!
+//! Past the cap, every new cell folds one more old cell into the summary. The summary cell keeps
+//! its identity across that growth, so the wrapped transcript cache only drops the lines of the
+//! newly folded cells instead of re-wrapping the whole view. Any other change to the kept cells
+//! (backtracking, a new session) gets a fresh summary cell, which makes the wrapped cache rebuild.
+//! Either way, cell-index based scroll anchors must be remapped with
+//! [`CollapsedTranscript::remap_cell_index`].
+//!
+//! [`CollapsedTranscript::view`] runs every frame, so it only compares the list length and two
+//! cell identities to decide whether anything changed.
+
+use std::sync::Arc;
+
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+
+use crate::history_cell::HistoryCell;
+use crate::history_cell::PlainHistoryCell;
+
+/// Viewport view of the transcript with old cells folded into a summary cell.
+pub(crate) struct CollapsedTranscript {
+    /// Maximum number of real cells to keep; `None` keeps everything.
+    max_cells: Option<usize>,
+    /// Number of leading cells currently folded into the summary.
+    collapsed: usize,
+    /// Stands in for the collapsed prefix; replaced only when the kept cells change other than by
+    /// new cells being appended.
+    summary: Arc<dyn HistoryCell>,
+    /// Summary cell followed by the kept suffix (empty when nothing is collapsed).
+    cells: Vec<Arc<dyn HistoryCell>>,
+    /// Length, first kept cell, and last cell of the list `cells` was built from.
+    synced: Option<(usize, usize, usize)>,
+}
+
+impl CollapsedTranscript {
+    pub(crate) fn new(max_cells: Option<usize>) -> Self {
+        Self {
+            max_cells: max_cells.map(|max| max.max(1)),
+            collapsed: 0,
+            summary: Arc::new(summary_cell()),
+            cells: Vec::new(),
+            synced: None,
+        }
+    }
+
+    /// Number of leading cells of the full list folded into the summary.
+    pub(crate) fn collapsed(&self) -> usize {
+        self.collapsed
+    }
+
+    /// Bring the view up to date with `all`, returning the previous collapsed count.
+    ///
+    /// Callers compare the result against [`Self::collapsed`] to detect that the prefix changed
+    /// and cell indices need remapping.
+    pub(crate) fn sync(&mut self, all: &[Arc<dyn HistoryCell>]) -> usize {
+        let previous = self.collapsed;
+        let collapsed = match self.max_cells {
+            Some(max_cells) if all.len() > max_cells => all.len() - max_cells,
+            _ => 0,
+        };
+
+        if collapsed == 0 {
+            self.collapsed = 0;
+            self.cells.clear();
+            self.synced = None;
+            return previous;
+        }
+
+        let synced = Some((
+            all.len(),
+            cell_addr(&all[collapsed]),
+            all.last().map_or(0, cell_addr),
+        ));
+        if synced == self.synced && collapsed == self.collapsed {
+            return previous;
+        }
+
+        if !self.only_appended(all, collapsed) {
+            // A fresh summary cell changes the first-cell identity, which tells the wrapped
+            // transcript cache to rebuild rather than treat the new list as a shift or append.
+            self.summary = Arc::new(summary_cell());
+        }
+        self.collapsed = collapsed;
+        self.synced = synced;
+        self.cells.clear();
+        self.cells.push(self.summary.clone());
+        self.cells.extend(all[collapsed..].iter().cloned());
+        previous
+    }
+
+    /// Whether `all` (collapsed at `collapsed`) only appended cells to the list the current view
+    /// was built from, folding older ones into the summary.
+    ///
+    /// The last kept cell may have been replaced in place (e.g. a merged repeated error); the
+    /// wrapped cache re-wraps its tail on its own.
+    fn only_appended(&self, all: &[Arc<dyn HistoryCell>], collapsed: usize) -> bool {
+        if self.cells.is_empty() || collapsed < self.collapsed {
+            return false;
+        }
+        let kept = &self.cells[1..];
+        let folded = collapsed - self.collapsed;
+        let Some(still_kept) = kept.get(folded..kept.len().saturating_sub(1)) else {
+            return false;
+        };
+        all.len() >= collapsed + still_kept.len()
+            && still_kept
+                .iter()
+                .zip(&all[collapsed..])
+                .all(|(cached, cell)| Arc::ptr_eq(cached, cell))
+    }
+
+    /// The cells the viewport should render for `all`.
+    pub(crate) fn view<'a>(
+        &'a mut self,
+        all: &'a [Arc<dyn HistoryCell>],
+    ) -> &'a [Arc<dyn HistoryCell>] {
+        self.sync(all);
+        if self.collapsed == 0 {
+            all
+        } else {
+            &self.cells
+        }
+    }
+
+    /// Map a viewport cell index from a view with `previous` collapsed cells to the current view.
+    ///
+    /// Returns `None` when the cell is now part of the summary.
+    pub(crate) fn remap_cell_index(&self, previous: usize, cell_index: usize) -> Option<usize> {
+        let full_index = if previous == 0 {
+            cell_index
+        } else if cell_index == 0 {
+            return None;
+        } else {
+            previous + cell_index - 1
+        };
+
+        if self.collapsed == 0 {
+            Some(full_index)
+        } else if full_index < self.collapsed {
+            None
+        } else {
+            Some(full_index - self.collapsed + 1)
+        }
+    }
+}
+
+fn cell_addr(cell: &Arc<dyn HistoryCell>) -> usize {
+    Arc::as_ptr(cell).cast::<()>() as usize
+}
+
+fn summary_cell() -> PlainHistoryCell {
+    PlainHistoryCell::new(vec![
+        Line::from("… earlier conversation (collapsed) …").dim(),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn cells(count: usize) -> Vec<Arc<dyn HistoryCell>> {
+        (0..count)
+            .map(|idx| {
+                Arc::new(PlainHistoryCell::new(vec![Line::from(format!(
+                    "cell {idx}"
+                ))])) as Arc<dyn HistoryCell>
+            })
+            .collect()
+    }
+
+    fn texts(view: &[Arc<dyn HistoryCell>]) -> Vec<String> {
+        view.iter()
+            .map(|cell| {
+                cell.display_lines(80)
+                    .iter()
+                    .map(|line| {
+                        line.spans
+                            .iter()
+                            .map(|span| span.content.as_ref())
+                            .collect::<String>()
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn unlimited_or_under_cap_keeps_every_cell() {
+        let all = cells(3);
+        let mut unlimited = CollapsedTranscript::new(None);
+        assert_eq!(unlimited.view(&all).len(), 3);
+
+        let mut capped = CollapsedTranscript::new(Some(3));
+        assert_eq!(capped.view(&all).len(), 3);
+        assert_eq!(capped.collapsed(), 0);
+    }
+
+    #[test]
+    fn exceeding_cap_collapses_oldest_prefix() {
+        let all = cells(5);
+        let mut collapsed = CollapsedTranscript::new(Some(2));
+
+        assert_eq!(
+            texts(collapsed.view(&all)),
+            vec![
+                "… earlier conversation (collapsed) …".to_string(),
+                "cell 3".to_string(),
+                "cell 4".to_string(),
+            ]
+        );
+        assert_eq!(collapsed.collapsed(), 3);
+    }
+
+    #[test]
+    fn remaps_indices_when_prefix_grows() {
+        let mut all = cells(3);
+        let mut collapsed = CollapsedTranscript::new(Some(2));
+        let previous = collapsed.sync(&all);
+        assert_eq!((previous, collapsed.collapsed()), (0, 1));
+
+        all.extend(cells(1));
+        let previous = collapsed.sync(&all);
+        assert_eq!((previous, collapsed.collapsed()), (1, 2));
+
+        // View before: [summary, cell 1, cell 2]; after: [summary, cell 2, cell 3].
+        assert_eq!(collapsed.remap_cell_index(previous, 0), None);
+        assert_eq!(collapsed.remap_cell_index(previous, 1), None);
+        assert_eq!(collapsed.remap_cell_index(previous, 2), Some(1));
+    }
+
+    #[test]
+    fn summary_cell_is_reused_while_the_transcript_only_grows() {
+        let mut all = cells(3);
+        let mut collapsed = CollapsedTranscript::new(Some(2));
+        let summary = collapsed.view(&all)[0].clone();
+
+        all.extend(cells(2));
+        let view = collapsed.view(&all);
+        assert!(Arc::ptr_eq(&view[0], &summary));
+        assert!(Arc::ptr_eq(&view[1], &all[3]));
+
+        // Backtracking drops kept cells, so the summary is replaced and the wrapped cache
+        // rebuilds.
+        all.truncate(4);
+        all.extend(cells(1));
+        assert!(!Arc::ptr_eq(&collapsed.view(&all)[0], &summary));
+    }
+
+    #[test]
+    fn replacing_the_last_cell_keeps_the_summary() {
+        let all = cells(5);
+        let mut collapsed = CollapsedTranscript::new(Some(2));
+        let summary = collapsed.view(&all)[0].clone();
+
+        // A merged repeated error replaces the last cell in place.
+        let mut merged = all.clone();
+        merged[4] = cells(1).remove(0);
+        let view = collapsed.view(&merged);
+        assert!(Arc::ptr_eq(&view[0], &summary));
+        assert!(Arc::ptr_eq(&view[2], &merged[4]));
+    }
+}
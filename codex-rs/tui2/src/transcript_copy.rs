@@ -41,13 +41,27 @@
 //! (by passing `visible_start..visible_end`) or spans the entire transcript
 //! (by passing `0..lines.len()`).
 //!
+//! [`selection_to_ansi_text_for_cells`] is the opt-in styled variant
+//! (`tui.copy_ansi`): it slices the same per-line ranges but keeps span styles
+//! as ANSI escape codes instead of emitting Markdown markers.
+//!
+//! [`quote_selection_for_prompt`] reuses the same clipboard text to seed the
+//! composer with a quoted block ("quote selection").
+//!
+//! [`last_code_block_text`] uses the same code-block detection to find the most
+//! recent code block in agent output ("copy last code block").
+//!
 //! UI affordances (keybinding detection and the on-screen "copy" pill) live in
 //! `transcript_copy_ui`.
 
 use ratatui::text::Line;
 use ratatui::text::Span;
 
+use crate::exec_cell::ExecCell;
+use crate::history_cell::AgentMessageCell;
 use crate::history_cell::HistoryCell;
+use crate::history_cell::UserHistoryCell;
+use crate::theme::Theme;
 use crate::transcript_selection::TRANSCRIPT_GUTTER_COLS;
 use crate::transcript_selection::TranscriptSelection;
 use crate::transcript_selection::TranscriptSelectionPoint;
@@ -58,15 +72,18 @@ use std::sync::Arc;
 /// This is the `App`-level helper: it rebuilds wrapped transcript lines using
 /// the same rules as the on-screen viewport and then applies
 /// [`selection_to_copy_text`] across the full transcript range (including
-/// off-screen lines).
+/// off-screen lines). `cell_spacing` must match the viewport's so selection
+/// line indices refer to the same rows.
 pub(crate) fn selection_to_copy_text_for_cells(
     cells: &[Arc<dyn HistoryCell>],
     selection: TranscriptSelection,
     width: u16,
+    cell_spacing: u16,
 ) -> Option<String> {
     let (anchor, head) = selection.anchor.zip(selection.head)?;
 
-    let transcript = crate::transcript_render::build_wrapped_transcript_lines(cells, width);
+    let transcript =
+        crate::transcript_render::build_wrapped_transcript_lines(cells, width, cell_spacing);
     let total_lines = transcript.lines.len();
     if total_lines == 0 {
         return None;
@@ -83,6 +100,202 @@ pub(crate) fn selection_to_copy_text_for_cells(
     )
 }
 
+/// Render the current transcript selection as ANSI-styled text.
+///
+/// This is the styled counterpart of [`selection_to_copy_text_for_cells`]: it uses the same wrapped
+/// lines and per-line column range (including off-screen lines), but instead of re-encoding
+/// Markdown it slices each selected line and renders it with
+/// [`crate::transcript_render::render_lines_to_ansi`]. Soft-wrapped prose is rejoined with the
+/// recorded joiners so the result matches plain copy line-for-line.
+pub(crate) fn selection_to_ansi_text_for_cells(
+    cells: &[Arc<dyn HistoryCell>],
+    selection: TranscriptSelection,
+    width: u16,
+    cell_spacing: u16,
+) -> Option<String> {
+    let (anchor, head) = selection.anchor.zip(selection.head)?;
+    if width <= TRANSCRIPT_GUTTER_COLS {
+        return None;
+    }
+    let (start, end) = order_points(anchor, head);
+    if start == end {
+        return None;
+    }
+
+    let transcript =
+        crate::transcript_render::build_wrapped_transcript_lines(cells, width, cell_spacing);
+    let base_x = TRANSCRIPT_GUTTER_COLS;
+    let max_x = width.saturating_sub(1);
+
+    let last_line = end.line_index.min(transcript.lines.len().checked_sub(1)?);
+    let mut selected: Vec<Line<'static>> = Vec::new();
+    for line_index in start.line_index..=last_line {
+        let line = &transcript.lines[line_index];
+        let line_start_col = if line_index == start.line_index {
+            start.column
+        } else {
+            0
+        };
+        let line_end_col = if line_index == end.line_index {
+            end.column
+        } else {
+            max_x.saturating_sub(base_x)
+        };
+        let from_col = base_x.saturating_add(line_start_col).min(max_x);
+        let to_col = base_x.saturating_add(line_end_col).min(max_x);
+
+        let text_end = last_non_space_col(line_to_flat(line).as_str()).map(|c| c.min(max_x));
+        selected.push(match text_end {
+            Some(text_end) if from_col <= to_col.min(text_end) => {
+                slice_line_by_cols(line, from_col, to_col.min(text_end))
+            }
+            _ => Line::default(),
+        });
+    }
+
+    let rendered = crate::transcript_render::render_lines_to_ansi(
+        &selected,
+        &[],
+        &[],
+        width,
+        &Theme::default(),
+    );
+    let mut out = String::new();
+    for (offset, text) in rendered.iter().enumerate() {
+        let line_index = start.line_index + offset;
+        if offset > 0 {
+            match transcript.joiner_before.get(line_index) {
+                Some(Some(joiner)) => out.push_str(joiner),
+                _ => out.push('\n'),
+            }
+        }
+        out.push_str(text);
+    }
+    Some(out)
+}
+
+/// Format copied selection text as a Markdown blockquote for seeding the composer.
+///
+/// Every line is prefixed with `> ` (blank lines become a bare `>`), and the block is followed by
+/// an empty line so the user's question starts on its own paragraph.
+pub(crate) fn quote_selection_for_prompt(text: &str) -> String {
+    let mut out = String::new();
+    for line in text.trim_end_matches('\n').lines() {
+        if line.trim().is_empty() {
+            out.push_str(">\n");
+        } else {
+            out.push_str("> ");
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out.push('\n');
+    out
+}
+
+/// Display path of the file behind the current selection, for `tui.copy_path_header`.
+///
+/// Returns `Some` only when both selection endpoints land in the same cell and that cell reports
+/// a [`HistoryCell::source_path`]. Selections that span cells (or start/end on spacer rows) have
+/// no single provenance and copy unchanged.
+pub(crate) fn selection_source_path(
+    cells: &[Arc<dyn HistoryCell>],
+    selection: TranscriptSelection,
+    width: u16,
+    cell_spacing: u16,
+) -> Option<String> {
+    let (anchor, head) = selection.anchor.zip(selection.head)?;
+    let (start, end) = order_points(anchor, head);
+
+    let transcript =
+        crate::transcript_render::build_wrapped_transcript_lines(cells, width, cell_spacing);
+    let (start_cell, _) = transcript.meta.get(start.line_index)?.cell_line()?;
+    let (end_cell, _) = transcript.meta.get(end.line_index)?.cell_line()?;
+    if start_cell != end_cell {
+        return None;
+    }
+    cells.get(start_cell)?.source_path()
+}
+
+/// Contents of the most recent code block in agent output, without fences or the transcript indent.
+///
+/// Agent messages stream in as several cells, so a block that spans cell boundaries is stitched
+/// back together. Returns `None` when no agent message contains a code block.
+pub(crate) fn last_code_block_text(cells: &[Arc<dyn HistoryCell>]) -> Option<String> {
+    let mut block: Vec<String> = Vec::new();
+    'cells: for cell in cells.iter().rev() {
+        let Some(agent) = cell.as_any().downcast_ref::<AgentMessageCell>() else {
+            if block.is_empty() {
+                continue;
+            }
+            break;
+        };
+        // Code lines are never wrapped, so the width only affects prose we skip anyway.
+        let lines = agent.transcript_lines_with_joiners(u16::MAX).lines;
+        for line in lines.iter().rev() {
+            if is_code_block_line(line) {
+                let text: String = line_to_flat(line)
+                    .chars()
+                    .skip(usize::from(TRANSCRIPT_GUTTER_COLS))
+                    .collect();
+                block.push(text.trim_end().to_string());
+            } else if !block.is_empty() {
+                break 'cells;
+            }
+        }
+    }
+    if block.is_empty() {
+        return None;
+    }
+    block.reverse();
+    Some(block.join("\n"))
+}
+
+/// Combined stdout/stderr of the commands run since the most recent user message.
+///
+/// Only the captured output is returned: the command echo, exit status and transcript chrome are
+/// left out. Outputs of separate commands are joined with a blank line. Returns `None` when the
+/// turn ran no commands or none of them printed anything.
+pub(crate) fn last_turn_command_output(cells: &[Arc<dyn HistoryCell>]) -> Option<String> {
+    let turn_start = cells
+        .iter()
+        .rposition(|cell| cell.as_any().is::<UserHistoryCell>())
+        .map_or(0, |idx| idx + 1);
+    let outputs: Vec<&str> = cells[turn_start..]
+        .iter()
+        .filter_map(|cell| cell.as_any().downcast_ref::<ExecCell>())
+        .flat_map(|exec| exec.calls.iter())
+        .filter_map(|call| call.output.as_ref())
+        .map(|output| output.aggregated_output.trim_end_matches('\n'))
+        .filter(|output| !output.trim().is_empty())
+        .collect();
+    (!outputs.is_empty()).then(|| outputs.join("\n\n"))
+}
+
+/// Prepend a comment naming `path` to copied text, using the comment syntax of the file type.
+pub(crate) fn with_path_header(text: &str, path: &str) -> String {
+    let path_ref = std::path::Path::new(path);
+    // Extensionless files are keyed by name so `Makefile`/`Dockerfile` get `#` comments.
+    let kind = path_ref
+        .extension()
+        .or_else(|| path_ref.file_name())
+        .and_then(|kind| kind.to_str())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+    let header = match kind.as_str() {
+        "py" | "sh" | "bash" | "zsh" | "rb" | "pl" | "toml" | "yaml" | "yml" | "r" | "nix"
+        | "ex" | "exs" | "cfg" | "ini" | "conf" | "mk" | "makefile" | "dockerfile" => {
+            format!("# {path}")
+        }
+        "sql" | "lua" | "hs" | "elm" => format!("-- {path}"),
+        "html" | "htm" | "xml" | "md" | "markdown" | "svg" | "vue" => format!("<!-- {path} -->"),
+        "css" => format!("/* {path} */"),
+        "lisp" | "clj" | "el" | "scm" => format!(";; {path}"),
+        _ => format!("// {path}"),
+    };
+    format!("{header}\n{text}")
+}
+
 /// Render the selected region into clipboard text.
 ///
 /// `lines` must be the wrapped transcript lines as rendered by the TUI,
@@ -113,8 +326,6 @@ pub(crate) fn selection_to_copy_text(
     visible_end: usize,
     width: u16,
 ) -> Option<String> {
-    use ratatui::style::Color;
-
     if width <= TRANSCRIPT_GUTTER_COLS {
         return None;
     }
@@ -157,7 +368,7 @@ pub(crate) fn selection_to_copy_text(
         // "verbatim lines" (no inline Markdown re-encoding). This also enables special handling for
         // narrow terminals: selecting "to the right edge" should copy the full logical line, not a
         // viewport-truncated slice.
-        let is_code_block_line = line.style.fg == Some(Color::Cyan);
+        let is_code_block_line = is_code_block_line(line);
 
         // Flatten the line to compute the rightmost non-space column. We use that to:
         // - avoid copying trailing right-margin padding
@@ -428,6 +639,15 @@ fn slice_line_by_cols(line: &Line<'static>, start_col: u16, end_col: u16) -> Lin
     Line::from(spans).style(line.style)
 }
 
+/// Whether a visual line belongs to a code block (or other preformatted content).
+///
+/// TUI2 renders code block lines with a line-level cyan foreground.
+fn is_code_block_line(line: &Line<'_>) -> bool {
+    use ratatui::style::Color;
+
+    line.style.fg == Some(Color::Cyan)
+}
+
 /// Whether a span should be treated as "inline code" when reconstructing Markdown.
 ///
 /// TUI2 renders inline code using a cyan foreground. Links also use cyan, but are underlined, so we
@@ -479,6 +699,14 @@ mod tests {
     use ratatui::style::Style;
     use ratatui::style::Stylize;
 
+    #[test]
+    fn quote_selection_prefixes_lines_and_ends_with_blank_line() {
+        assert_eq!(
+            quote_selection_for_prompt("first line\n\n  indented\n"),
+            "> first line\n>\n>   indented\n\n"
+        );
+    }
+
     #[test]
     fn selection_to_copy_text_returns_none_for_zero_content_width() {
         let lines = vec![Line::from("• Hello")];
@@ -624,11 +852,105 @@ mod tests {
             head: Some(TranscriptSelectionPoint::new(0, viewport_edge_col)),
         };
 
-        let out =
-            selection_to_copy_text_for_cells(&cells, selection, width).expect("expected text");
+        let out = selection_to_copy_text_for_cells(
+            &cells,
+            selection,
+            width,
+            crate::transcript_render::DEFAULT_CELL_SPACING,
+        )
+        .expect("expected text");
         assert_eq!(out, "```\n    0123456789ABCDEFGHIJ\n```");
     }
 
+    #[test]
+    fn ansi_copy_keeps_styles_and_plain_copy_strips_them() {
+        let cells: Vec<Arc<dyn HistoryCell>> =
+            vec![Arc::new(crate::history_cell::PlainHistoryCell::new(vec![
+                Line::from(vec!["• ".into(), "red".red(), " plain".into()]),
+                Line::from(vec!["  ".into(), "bold".bold()]),
+            ]))];
+        let selection = TranscriptSelection {
+            anchor: Some(TranscriptSelectionPoint::new(0, 0)),
+            head: Some(TranscriptSelectionPoint::new(1, 20)),
+        };
+        let width = 40;
+        let spacing = crate::transcript_render::DEFAULT_CELL_SPACING;
+
+        let plain = selection_to_copy_text_for_cells(&cells, selection, width, spacing)
+            .expect("plain text");
+        assert_eq!(plain, "red plain\nbold");
+        assert!(!plain.contains('\u{1b}'));
+
+        let ansi =
+            selection_to_ansi_text_for_cells(&cells, selection, width, spacing).expect("ansi text");
+        assert!(ansi.contains('\u{1b}'));
+        assert!(ansi.contains("red"));
+        assert!(ansi.contains("bold"));
+        assert!(!ansi.contains('•'));
+        assert_eq!(ansi.lines().count(), 2);
+    }
+
+    #[derive(Debug)]
+    struct FileCell {
+        path: Option<String>,
+    }
+
+    impl HistoryCell for FileCell {
+        fn display_lines(&self, _width: u16) -> Vec<Line<'static>> {
+            vec![Line::from("• fn main() {}"), Line::from("  let x = 1;")]
+        }
+
+        fn source_path(&self) -> Option<String> {
+            self.path.clone()
+        }
+    }
+
+    fn copy_with_optional_header(cells: &[Arc<dyn HistoryCell>]) -> String {
+        let selection = TranscriptSelection {
+            anchor: Some(TranscriptSelectionPoint::new(0, 0)),
+            head: Some(TranscriptSelectionPoint::new(1, 20)),
+        };
+        let width = 40;
+        let spacing = crate::transcript_render::DEFAULT_CELL_SPACING;
+        let text =
+            selection_to_copy_text_for_cells(cells, selection, width, spacing).expect("text");
+        match selection_source_path(cells, selection, width, spacing) {
+            Some(path) => with_path_header(&text, &path),
+            None => text,
+        }
+    }
+
+    #[test]
+    fn copy_prefixes_path_header_for_cell_with_source_path() {
+        let cells: Vec<Arc<dyn HistoryCell>> = vec![Arc::new(FileCell {
+            path: Some("src/main.rs".to_string()),
+        })];
+
+        assert_eq!(
+            copy_with_optional_header(&cells),
+            "// src/main.rs\nfn main() {}\nlet x = 1;"
+        );
+    }
+
+    #[test]
+    fn copy_without_source_path_is_unchanged() {
+        let cells: Vec<Arc<dyn HistoryCell>> = vec![Arc::new(FileCell { path: None })];
+
+        assert_eq!(
+            copy_with_optional_header(&cells),
+            "fn main() {}\nlet x = 1;"
+        );
+    }
+
+    #[test]
+    fn path_header_uses_language_comment_syntax() {
+        assert_eq!(with_path_header("x", "tool.py"), "# tool.py\nx");
+        assert_eq!(with_path_header("x", "q.sql"), "-- q.sql\nx");
+        assert_eq!(with_path_header("x", "README.md"), "<!-- README.md -->\nx");
+        assert_eq!(with_path_header("x", "Makefile"), "# Makefile\nx");
+        assert_eq!(with_path_header("x", "lib.rs"), "// lib.rs\nx");
+    }
+
     #[test]
     fn order_points_orders_by_line_then_column() {
         let a = TranscriptSelectionPoint::new(2, 5);
@@ -640,6 +962,109 @@ mod tests {
         assert_eq!(order_points(a, b), (a, b));
     }
 
+    fn agent_cell(lines: Vec<Line<'static>>, is_first_line: bool) -> Arc<dyn HistoryCell> {
+        Arc::new(AgentMessageCell::new(
+            lines,
+            is_first_line,
+            &Theme::default(),
+        ))
+    }
+
+    #[test]
+    fn last_code_block_is_found_across_agent_cells() {
+        let code = |text: &'static str| Line::from(text).style(Style::default().fg(Color::Cyan));
+        let cells = vec![
+            agent_cell(
+                vec![Line::from("first"), code("old()"), Line::from("done")],
+                true,
+            ),
+            Arc::new(crate::history_cell::PlainHistoryCell::new(vec![code(
+                "not agent output",
+            )])),
+            agent_cell(vec![Line::from("second"), code("fn main() {")], true),
+            // Streamed continuation of the same block, followed by trailing prose.
+            agent_cell(
+                vec![code("    run();"), code("}"), Line::from("that's it")],
+                false,
+            ),
+            agent_cell(vec![Line::from("no code here")], true),
+        ];
+
+        assert_eq!(
+            last_code_block_text(&cells),
+            Some("fn main() {\n    run();\n}".to_string())
+        );
+        assert_eq!(last_code_block_text(&cells[..2]), Some("old()".to_string()));
+    }
+
+    #[test]
+    fn last_code_block_is_none_without_code() {
+        let cells = vec![agent_cell(vec![Line::from("just prose")], true)];
+        assert_eq!(last_code_block_text(&cells), None);
+        assert_eq!(last_code_block_text(&[]), None);
+    }
+
+    fn exec_cell(command: &str, output: Option<&str>) -> Arc<dyn HistoryCell> {
+        Arc::new(ExecCell::new(
+            crate::exec_cell::ExecCall {
+                call_id: command.to_string(),
+                command: vec!["bash".into(), "-lc".into(), command.into()],
+                parsed: Vec::new(),
+                output: output.map(|output| crate::exec_cell::CommandOutput {
+                    exit_code: 0,
+                    aggregated_output: output.to_string(),
+                    formatted_output: output.to_string(),
+                }),
+                source: codex_core::protocol::ExecCommandSource::Agent,
+                start_time: None,
+                duration: None,
+                interaction_input: None,
+            },
+            false,
+        ))
+    }
+
+    fn user_cell(message: &str) -> Arc<dyn HistoryCell> {
+        Arc::new(UserHistoryCell {
+            message: message.to_string(),
+            theme: Theme::default(),
+        })
+    }
+
+    #[test]
+    fn last_turn_command_output_returns_only_output_text() {
+        let cells = vec![
+            user_cell("build it"),
+            exec_cell("cargo build", Some("old turn output\n")),
+            user_cell("now test"),
+            agent_cell(vec![Line::from("running tests")], true),
+            exec_cell("cargo test", Some("running 2 tests\ntest ok\n")),
+            exec_cell("true", Some("")),
+            exec_cell("git status", Some("clean\n")),
+            agent_cell(vec![Line::from("all good")], true),
+        ];
+
+        assert_eq!(
+            last_turn_command_output(&cells),
+            Some("running 2 tests\ntest ok\n\nclean".to_string())
+        );
+    }
+
+    #[test]
+    fn last_turn_command_output_is_none_without_commands() {
+        let cells = vec![
+            exec_cell("ls", Some("a.txt\n")),
+            user_cell("hi"),
+            agent_cell(vec![Line::from("hello")], true),
+            exec_cell("sleep 1", None),
+        ];
+        assert_eq!(last_turn_command_output(&cells), None);
+        assert_eq!(
+            last_turn_command_output(&cells[..1]),
+            Some("a.txt".to_string())
+        );
+    }
+
     #[test]
     fn line_to_flat_concatenates_spans() {
         let line = Line::from(vec!["a".into(), "b".into(), "c".into()]);
@@ -25,6 +25,7 @@
 //! Clipboard text reconstruction (preserving indentation, joining soft-wrapped
 //! prose, and emitting Markdown source markers) lives in `transcript_copy`.
 
+use codex_core::config::types::CopyPillPosition;
 use codex_core::terminal::TerminalName;
 use codex_core::terminal::terminal_info;
 use crossterm::event::KeyCode;
@@ -42,6 +43,7 @@ use unicode_width::UnicodeWidthStr;
 
 use crate::key_hint;
 use crate::key_hint::KeyBinding;
+use crate::theme::Theme;
 use crate::transcript_selection::TRANSCRIPT_GUTTER_COLS;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -100,6 +102,19 @@ pub(crate) fn is_copy_selection_key(
     }
 }
 
+/// Whether the given `(ch, modifiers)` should trigger "quote selection into the composer".
+///
+/// This is `Alt+Q` in every terminal; unlike copy, it does not compete with terminal-level
+/// clipboard shortcuts.
+pub(crate) fn is_quote_selection_key(ch: char, modifiers: KeyModifiers) -> bool {
+    matches!(ch, 'q' | 'Q')
+        && modifiers.contains(KeyModifiers::ALT)
+        && !modifiers.contains(KeyModifiers::CONTROL)
+}
+
+/// Default text of the copy pill (`tui.copy_pill_label`).
+const DEFAULT_PILL_LABEL: &str = "copy";
+
 /// UI state for the on-screen copy affordance shown near an active selection.
 ///
 /// This tracks a `Rect` for hit-testing so we can treat the pill as a clickable button.
@@ -108,6 +123,9 @@ pub(crate) struct TranscriptCopyUi {
     shortcut: CopySelectionShortcut,
     dragging: bool,
     affordance_rect: Option<Rect>,
+    pill_label: String,
+    pill_position: CopyPillPosition,
+    theme: Theme,
 }
 
 impl TranscriptCopyUi {
@@ -117,9 +135,27 @@ impl TranscriptCopyUi {
             shortcut,
             dragging: false,
             affordance_rect: None,
+            pill_label: DEFAULT_PILL_LABEL.to_string(),
+            pill_position: CopyPillPosition::default(),
+            theme: Theme::default(),
         }
     }
 
+    /// Apply `tui.copy_pill_label` and `tui.copy_pill_position`. A blank label keeps the default.
+    pub(crate) fn set_pill_appearance(&mut self, label: Option<&str>, position: CopyPillPosition) {
+        self.pill_label = label
+            .map(str::trim)
+            .filter(|label| !label.is_empty())
+            .unwrap_or(DEFAULT_PILL_LABEL)
+            .to_string();
+        self.pill_position = position;
+    }
+
+    /// Apply the resolved `tui.theme` to the copy pill.
+    pub(crate) fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
     pub(crate) fn key_binding(&self) -> KeyBinding {
         key_binding_for(self.shortcut)
     }
@@ -147,6 +183,10 @@ impl TranscriptCopyUi {
 
     /// Render the copy "pill" just below the visible end of the selection.
     ///
+    /// `area` is the transcript *content* area (see
+    /// [`crate::transcript_selection::TranscriptGutter::content_area`]): it excludes any gutter
+    /// margin, so the first content column is `area.x + TRANSCRIPT_GUTTER_COLS`.
+    ///
     /// Inputs are expressed in logical transcript coordinates:
     /// - `anchor`/`head`: `(line_index, column)` in the wrapped transcript (not screen rows).
     /// - `view_top`: first logical line index currently visible in `area`.
@@ -257,16 +297,19 @@ impl TranscriptCopyUi {
         let key_label: Span<'static> = self.key_binding().into();
         let key_label = key_label.content.as_ref().to_string();
 
-        let pill_text = format!(" ⧉ copy {key_label} ");
+        let pill_text = format!(" ⧉ {} {key_label} ", self.pill_label);
         let pill_width = UnicodeWidthStr::width(pill_text.as_str());
         if pill_width == 0 || area.width == 0 {
             return;
         }
 
         let pill_width = (pill_width as u16).min(area.width);
-        // Prefer a small gap between the selected content and the pill so we don't visually merge
-        // into the highlighted selection block.
-        let desired_x = to_x.saturating_add(2);
+        let desired_x = match self.pill_position {
+            // Prefer a small gap between the selected content and the pill so we don't visually
+            // merge into the highlighted selection block.
+            CopyPillPosition::Right => to_x.saturating_add(2),
+            CopyPillPosition::Left => base_x,
+        };
         let max_start_x = area.right().saturating_sub(pill_width);
         let x = if max_start_x < area.x {
             area.x
@@ -275,7 +318,9 @@ impl TranscriptCopyUi {
         };
 
         let pill_area = Rect::new(x, y, pill_width, 1);
-        let base_style = Style::new().bg(Color::DarkGray).fg(Color::White);
+        let base_style = Style::new()
+            .bg(self.theme.copy_pill_bg)
+            .fg(self.theme.copy_pill_fg);
         let icon_style = base_style.add_modifier(Modifier::BOLD).fg(Color::LightCyan);
         let bold_style = base_style.add_modifier(Modifier::BOLD);
 
@@ -283,7 +328,7 @@ impl TranscriptCopyUi {
             Span::styled(" ", base_style),
             Span::styled("⧉", icon_style),
             Span::styled(" ", base_style),
-            Span::styled("copy", bold_style),
+            Span::styled(self.pill_label.clone(), bold_style),
             Span::styled(" ", base_style),
             Span::styled(key_label, base_style),
         ];
@@ -329,4 +374,28 @@ mod tests {
         assert!(!rendered.contains("ctrl + shift + c"));
         assert!(ui.affordance_rect.is_some());
     }
+
+    #[test]
+    fn custom_pill_label_and_left_position_render() {
+        let area = Rect::new(0, 0, 60, 3);
+        let mut buf = Buffer::empty(area);
+        for y in 0..area.height {
+            for x in 2..20 {
+                buf[(x, y)].set_symbol("X");
+            }
+        }
+
+        let mut ui = TranscriptCopyUi::new_with_shortcut(CopySelectionShortcut::CtrlY);
+        ui.set_pill_appearance(Some("yank"), CopyPillPosition::Left);
+        ui.render_copy_pill(area, &mut buf, (1, 2), (1, 10), 0, 3);
+
+        let rendered = buf_to_string(&buf, area);
+        let pill_row = rendered.lines().nth(2).expect("pill row");
+        assert!(pill_row.starts_with("   ⧉ yank ctrl + y "), "{pill_row}");
+        assert!(!rendered.contains("copy"));
+        assert_eq!(
+            ui.affordance_rect.map(|rect| rect.x),
+            Some(TRANSCRIPT_GUTTER_COLS)
+        );
+    }
 }
@@ -0,0 +1,137 @@
+//! Error navigation in the transcript (`/errors`, `Alt+N`/`Alt+P`).
+//!
+//! Like [`crate::approval_history`], the list of errors is rebuilt from the transcript on demand:
+//! every [`ErrorHistoryCell`] is an entry. It is built from the *viewport* view of the transcript
+//! (after collapsing), so each entry's `cell_index` can be used directly as a scroll anchor.
+
+use std::sync::Arc;
+
+use crate::history_cell::ErrorHistoryCell;
+use crate::history_cell::HistoryCell;
+use crate::tui::scrolling::ScrollDirection;
+
+/// An error cell and where it sits in the transcript viewport.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ErrorEntry {
+    /// Index of the error cell in the viewport cell list (usable as a scroll anchor).
+    pub(crate) cell_index: usize,
+    pub(crate) message: String,
+    /// How many identical errors were merged into the cell.
+    pub(crate) count: usize,
+}
+
+/// Collect every error in `cells`, oldest first.
+pub(crate) fn error_entries(cells: &[Arc<dyn HistoryCell>]) -> Vec<ErrorEntry> {
+    cells
+        .iter()
+        .enumerate()
+        .filter_map(|(cell_index, cell)| {
+            let error = cell.as_any().downcast_ref::<ErrorHistoryCell>()?;
+            Some(ErrorEntry {
+                cell_index,
+                message: error.message().to_string(),
+                count: error.count(),
+            })
+        })
+        .collect()
+}
+
+/// Position in `entries` of the error to jump to from the cell at the top of the viewport.
+///
+/// `current` is `None` when the transcript follows the bottom, so `Up` lands on the newest error.
+/// Navigation wraps around at either end.
+pub(crate) fn adjacent_error(
+    entries: &[ErrorEntry],
+    current: Option<usize>,
+    direction: ScrollDirection,
+) -> Option<usize> {
+    if entries.is_empty() {
+        return None;
+    }
+    let position = match (direction, current) {
+        (ScrollDirection::Down, Some(current)) => entries
+            .iter()
+            .position(|entry| entry.cell_index > current)
+            .unwrap_or(0),
+        (ScrollDirection::Down, None) => 0,
+        (ScrollDirection::Up, Some(current)) => entries
+            .iter()
+            .rposition(|entry| entry.cell_index < current)
+            .unwrap_or(entries.len() - 1),
+        (ScrollDirection::Up, None) => entries.len() - 1,
+    };
+    Some(position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history_cell::PlainHistoryCell;
+    use crate::history_cell::new_error_event;
+    use crate::history_cell::new_warning_event;
+    use crate::theme::Theme;
+    use pretty_assertions::assert_eq;
+    use ratatui::text::Line;
+
+    fn mixed_transcript() -> Vec<Arc<dyn HistoryCell>> {
+        vec![
+            Arc::new(PlainHistoryCell::new(vec![Line::from("• hello")])),
+            Arc::new(new_error_event(
+                "stream disconnected".to_string(),
+                &Theme::default(),
+            )),
+            Arc::new(new_warning_event(
+                "approaching rate limit".to_string(),
+                &Theme::default(),
+            )),
+            Arc::new(PlainHistoryCell::new(vec![Line::from("• working")])),
+            Arc::new(new_error_event(
+                "command timed out".to_string(),
+                &Theme::default(),
+            )),
+        ]
+    }
+
+    #[test]
+    fn finds_error_cells_in_mixed_transcript() {
+        let entries = error_entries(&mixed_transcript());
+        let summary: Vec<(usize, &str)> = entries
+            .iter()
+            .map(|entry| (entry.cell_index, entry.message.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![(1, "stream disconnected"), (4, "command timed out")]
+        );
+    }
+
+    #[test]
+    fn navigation_steps_between_errors_and_wraps() {
+        let entries = error_entries(&mixed_transcript());
+
+        assert_eq!(adjacent_error(&entries, None, ScrollDirection::Up), Some(1));
+        assert_eq!(
+            adjacent_error(&entries, Some(4), ScrollDirection::Up),
+            Some(0)
+        );
+        assert_eq!(
+            adjacent_error(&entries, Some(1), ScrollDirection::Up),
+            Some(1),
+            "wraps to the newest error"
+        );
+        assert_eq!(
+            adjacent_error(&entries, Some(0), ScrollDirection::Down),
+            Some(0)
+        );
+        assert_eq!(
+            adjacent_error(&entries, Some(1), ScrollDirection::Down),
+            Some(1)
+        );
+        assert_eq!(
+            adjacent_error(&entries, Some(4), ScrollDirection::Down),
+            Some(0),
+            "wraps to the oldest error"
+        );
+        assert_eq!(adjacent_error(&[], None, ScrollDirection::Down), None);
+    }
+}
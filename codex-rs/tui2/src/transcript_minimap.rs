@@ -0,0 +1,152 @@
+//! Editor-style overview strip drawn along the right edge of the transcript (`tui.transcript_minimap`).
+//!
+//! Each minimap row stands for an equal slice of the wrapped transcript. Slices that contain any
+//! user-authored row are shaded differently from agent output so prompts stand out, and the rows
+//! covering the current viewport are drawn as a highlighted box. Clicking a row jumps there.
+//!
+//! Everything here is expressed in flattened, wrapped transcript line indices (the same space as
+//! `App::transcript_view_top` / `App::transcript_total_lines`).
+
+use std::ops::Range;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::style::Stylize;
+
+/// Columns reserved to the right of the transcript when the minimap is enabled.
+pub(crate) const MINIMAP_COLS: u16 = 1;
+
+/// The transcript must be at least this many times taller than the viewport for the minimap to
+/// appear; shorter conversations are easy enough to scroll through.
+const MIN_HEIGHT_RATIO: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum MinimapShade {
+    User,
+    Agent,
+}
+
+/// Whether a transcript of `total_lines` is tall enough, relative to `visible_lines`, to show the
+/// minimap.
+pub(crate) fn should_show(total_lines: usize, visible_lines: usize) -> bool {
+    visible_lines > 0 && total_lines >= visible_lines.saturating_mul(MIN_HEIGHT_RATIO)
+}
+
+/// Transcript lines summarized by minimap `row` out of `rows`.
+fn lines_for_row(row: u16, rows: u16, total_lines: usize) -> Range<usize> {
+    let rows = usize::from(rows.max(1));
+    let row = usize::from(row).min(rows - 1);
+    let start = row * total_lines / rows;
+    let end = ((row + 1) * total_lines / rows).max(start + 1);
+    start..end.min(total_lines.max(start + 1))
+}
+
+/// Shade for each of the `rows` minimap rows: `User` when any line in the row's slice belongs to a
+/// user cell, otherwise `Agent`.
+pub(crate) fn row_shades(
+    total_lines: usize,
+    rows: u16,
+    is_user_line: impl Fn(usize) -> bool,
+) -> Vec<MinimapShade> {
+    (0..rows)
+        .map(|row| {
+            if lines_for_row(row, rows, total_lines).any(&is_user_line) {
+                MinimapShade::User
+            } else {
+                MinimapShade::Agent
+            }
+        })
+        .collect()
+}
+
+/// The transcript line a click on minimap `row` should bring into view.
+pub(crate) fn line_for_row(row: u16, rows: u16, total_lines: usize) -> usize {
+    lines_for_row(row, rows, total_lines)
+        .start
+        .min(total_lines.saturating_sub(1))
+}
+
+/// Minimap rows covered by a viewport showing `visible_lines` lines from `view_top`.
+pub(crate) fn viewport_rows(
+    view_top: usize,
+    visible_lines: usize,
+    total_lines: usize,
+    rows: u16,
+) -> Range<u16> {
+    if total_lines == 0 || rows == 0 {
+        return 0..0;
+    }
+    let to_row = |line: usize| {
+        u16::try_from(line.saturating_mul(usize::from(rows)) / total_lines).unwrap_or(u16::MAX)
+    };
+    let start = to_row(view_top).min(rows - 1);
+    let end = to_row(view_top.saturating_add(visible_lines)).clamp(start + 1, rows);
+    start..end
+}
+
+/// Draw the minimap into `area` (one column wide) using precomputed `shades` and `viewport` rows.
+pub(crate) fn render(area: Rect, buf: &mut Buffer, shades: &[MinimapShade], viewport: Range<u16>) {
+    for (row, shade) in (0..area.height).zip(shades) {
+        let (symbol, style) = match shade {
+            MinimapShade::User => ("▐", Style::new().cyan()),
+            MinimapShade::Agent => ("│", Style::new().dim()),
+        };
+        let style = if viewport.contains(&row) {
+            style.reversed()
+        } else {
+            style
+        };
+        let y = area.y + row;
+        for x in area.x..area.right() {
+            buf[(x, y)].set_symbol(symbol).set_style(style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn shows_only_for_much_taller_transcripts() {
+        assert!(!should_show(50, 20));
+        assert!(should_show(60, 20));
+        assert!(!should_show(60, 0));
+    }
+
+    #[test]
+    fn click_maps_row_to_proportional_line() {
+        assert_eq!(line_for_row(0, 10, 200), 0);
+        assert_eq!(line_for_row(5, 10, 200), 100);
+        assert_eq!(line_for_row(9, 10, 200), 180);
+        // Rows past the end clamp to the last row.
+        assert_eq!(line_for_row(12, 10, 200), 180);
+        // More rows than lines never points past the transcript.
+        assert_eq!(line_for_row(9, 10, 4), 3);
+    }
+
+    #[test]
+    fn rows_containing_user_lines_are_shaded_as_user() {
+        let user_lines = [0, 1, 45];
+        let shades = row_shades(100, 5, |line| user_lines.contains(&line));
+        assert_eq!(
+            shades,
+            vec![
+                MinimapShade::User,
+                MinimapShade::Agent,
+                MinimapShade::User,
+                MinimapShade::Agent,
+                MinimapShade::Agent,
+            ]
+        );
+    }
+
+    #[test]
+    fn viewport_box_tracks_view_top() {
+        assert_eq!(viewport_rows(0, 20, 200, 10), 0..1);
+        assert_eq!(viewport_rows(100, 40, 200, 10), 5..7);
+        assert_eq!(viewport_rows(180, 20, 200, 10), 9..10);
+    }
+}
@@ -0,0 +1,342 @@
+//! Live plain-text mirror of the transcript (`tui.mirror_transcript`).
+//!
+//! When enabled, every history cell inserted into the transcript is also appended to
+//! `$CODEX_HOME/transcript-<conversation id>.log` as plain text, so the conversation can be
+//! followed with `tail -f` from another terminal. Each session gets its own log, so concurrent
+//! Codex instances never clobber each other's mirror. Cells inserted before the session id is
+//! known (the session header) are held back and written once the log opens.
+//!
+//! The mirror follows the same spacing rules as the viewport: non-continuation cells are preceded
+//! by `tui.cell_spacing` blank lines, while streaming continuations are appended directly after
+//! the previous chunk. Each cell is written (and flushed) once when it is inserted; nothing already
+//! written is rewritten.
+//!
+//! Only the newest [`MAX_MIRROR_LOGS`] logs are kept: opening a session's log deletes the oldest
+//! other `transcript-*.log` files beyond that count.
+//!
+//! IO errors are logged once and disable the mirror for the rest of the process, so a full disk or
+//! removed file never interrupts the UI.
+
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use codex_protocol::ConversationId;
+use ratatui::text::Line;
+
+use crate::history_cell::HistoryCell;
+
+/// Number of mirror logs kept in `codex_home`, including the one being written.
+pub(crate) const MAX_MIRROR_LOGS: usize = 20;
+
+/// File name of the mirror log for `conversation_id`, relative to `codex_home`.
+pub(crate) fn transcript_mirror_filename(conversation_id: ConversationId) -> String {
+    format!("transcript-{conversation_id}.log")
+}
+
+fn is_mirror_log(file_name: &str) -> bool {
+    file_name.starts_with("transcript-") && file_name.ends_with(".log")
+}
+
+/// Appends inserted history cells to a plain-text log, one file per session.
+pub(crate) struct TranscriptMirror {
+    /// Directory the logs are created in; `None` when the mirror is disabled or a write failed.
+    codex_home: Option<PathBuf>,
+    /// Blank lines written between non-continuation cells (`tui.cell_spacing`).
+    cell_spacing: u16,
+    /// The session whose log is open, and that log.
+    log: Option<(ConversationId, File)>,
+    /// Text not yet written because the session id is still unknown.
+    pending: String,
+    /// Whether the current session has written a cell yet (used to decide on separating lines).
+    has_emitted_lines: bool,
+}
+
+impl TranscriptMirror {
+    /// A mirror that writes nothing.
+    pub(crate) fn disabled() -> Self {
+        Self {
+            codex_home: None,
+            cell_spacing: 0,
+            log: None,
+            pending: String::new(),
+            has_emitted_lines: false,
+        }
+    }
+
+    /// A mirror writing per-session logs under `codex_home`, separating cells like the viewport.
+    ///
+    /// Logs are opened lazily, once the first cell of a session with a known id is appended.
+    pub(crate) fn new(codex_home: &Path, cell_spacing: u16) -> Self {
+        Self {
+            codex_home: Some(codex_home.to_path_buf()),
+            cell_spacing,
+            ..Self::disabled()
+        }
+    }
+
+    /// Append `cell` as rendered at `width` to the log of `conversation_id`.
+    ///
+    /// A different id than the open log's starts a new log; `None` (a session that is not
+    /// configured yet) holds the text back until the id is known.
+    pub(crate) fn append_cell(
+        &mut self,
+        conversation_id: Option<ConversationId>,
+        cell: &dyn HistoryCell,
+        width: u16,
+    ) {
+        let Some(codex_home) = self.codex_home.as_deref() else {
+            return;
+        };
+        let open_id = self.log.as_ref().map(|(id, _)| *id);
+        if open_id.is_some() && conversation_id != open_id {
+            // The previous session ended; the next cells start a new log.
+            self.log = None;
+            self.has_emitted_lines = false;
+        }
+        if self.log.is_none()
+            && let Some(conversation_id) = conversation_id
+        {
+            let path = codex_home.join(transcript_mirror_filename(conversation_id));
+            match open_log(&path) {
+                Ok(file) => {
+                    self.log = Some((conversation_id, file));
+                    if let Err(err) = prune_logs(codex_home, &path, MAX_MIRROR_LOGS) {
+                        tracing::warn!("failed to prune old transcript mirrors: {err}");
+                    }
+                }
+                Err(err) => {
+                    tracing::warn!("failed to open transcript mirror {}: {err}", path.display());
+                    self.disable();
+                    return;
+                }
+            }
+        }
+
+        self.push_cell(cell, width);
+        let Some((_, file)) = self.log.as_mut() else {
+            return;
+        };
+        let result = file
+            .write_all(self.pending.as_bytes())
+            .and_then(|()| file.flush());
+        self.pending.clear();
+        if let Err(err) = result {
+            tracing::warn!("transcript mirror write failed; disabling mirror: {err}");
+            self.disable();
+        }
+    }
+
+    /// Render `cell` into [`Self::pending`], preceded by the spacing the viewport would use.
+    fn push_cell(&mut self, cell: &dyn HistoryCell, width: u16) {
+        let lines = cell.display_lines(width);
+        if lines.is_empty() {
+            return;
+        }
+        if !cell.is_stream_continuation() {
+            if self.has_emitted_lines {
+                for _ in 0..self.cell_spacing {
+                    self.pending.push('\n');
+                }
+            }
+            self.has_emitted_lines = true;
+        }
+        self.pending.push_str(&lines_to_plain_text(&lines));
+    }
+
+    fn disable(&mut self) {
+        *self = Self::disabled();
+    }
+}
+
+/// Open the log at `path` for appending, readable only by the user.
+///
+/// Appending keeps a resumed session's earlier mirror intact.
+fn open_log(path: &Path) -> io::Result<File> {
+    let mut opts = OpenOptions::new();
+    opts.create(true).append(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        opts.mode(0o600);
+    }
+
+    opts.open(path)
+}
+
+/// Delete the least recently modified mirror logs in `codex_home` so that at most `max_logs`
+/// remain, counting `keep` (the log just opened), which is never deleted.
+fn prune_logs(codex_home: &Path, keep: &Path, max_logs: usize) -> io::Result<()> {
+    let mut logs: Vec<(SystemTime, PathBuf)> = Vec::new();
+    for entry in std::fs::read_dir(codex_home)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path == keep || !entry.file_name().to_str().is_some_and(is_mirror_log) {
+            continue;
+        }
+        let metadata = entry.metadata()?;
+        if metadata.is_file() {
+            logs.push((metadata.modified()?, path));
+        }
+    }
+
+    // Newest first; everything past the retained count goes.
+    logs.sort_by(|a, b| b.0.cmp(&a.0));
+    for (_, path) in logs.into_iter().skip(max_logs.saturating_sub(1)) {
+        std::fs::remove_file(&path)?;
+    }
+    Ok(())
+}
+
+/// Render lines as plain text, one `\n`-terminated line per [`Line`], with trailing whitespace
+/// trimmed.
+pub(crate) fn lines_to_plain_text(lines: &[Line<'_>]) -> String {
+    let mut out = String::new();
+    for line in lines {
+        let text: String = line
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        out.push_str(text.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history_cell::PlainHistoryCell;
+    use pretty_assertions::assert_eq;
+    use ratatui::style::Stylize;
+
+    #[derive(Debug)]
+    struct ContinuationCell(Vec<Line<'static>>);
+
+    impl HistoryCell for ContinuationCell {
+        fn display_lines(&self, _width: u16) -> Vec<Line<'static>> {
+            self.0.clone()
+        }
+
+        fn is_stream_continuation(&self) -> bool {
+            true
+        }
+    }
+
+    fn plain(text: &'static str) -> PlainHistoryCell {
+        PlainHistoryCell::new(vec![Line::from(text)])
+    }
+
+    fn written(codex_home: &Path, conversation_id: ConversationId) -> String {
+        std::fs::read_to_string(codex_home.join(transcript_mirror_filename(conversation_id)))
+            .expect("mirror log")
+    }
+
+    #[test]
+    fn lines_serialize_as_plain_text() {
+        let lines = vec![
+            Line::from(vec!["• ".dim(), "hello ".bold(), "world  ".into()]),
+            Line::from(""),
+            Line::from("  indented"),
+        ];
+        assert_eq!(lines_to_plain_text(&lines), "• hello world\n\n  indented\n");
+    }
+
+    #[test]
+    fn stream_continuations_append_without_rewriting() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let id = ConversationId::new();
+        let mut mirror = TranscriptMirror::new(codex_home.path(), 1);
+
+        mirror.append_cell(Some(id), &plain("› hi"), 80);
+        mirror.append_cell(Some(id), &plain("• first"), 80);
+        assert_eq!(written(codex_home.path(), id), "› hi\n\n• first\n");
+
+        mirror.append_cell(
+            Some(id),
+            &ContinuationCell(vec![Line::from("  second")]),
+            80,
+        );
+        mirror.append_cell(Some(id), &ContinuationCell(vec![Line::from("  third")]), 80);
+        assert_eq!(
+            written(codex_home.path(), id),
+            "› hi\n\n• first\n  second\n  third\n"
+        );
+    }
+
+    #[test]
+    fn cells_before_the_session_id_are_written_once_it_is_known() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let id = ConversationId::new();
+        let mut mirror = TranscriptMirror::new(codex_home.path(), 2);
+
+        mirror.append_cell(None, &plain("header"), 80);
+        assert_eq!(
+            std::fs::read_dir(codex_home.path()).expect("dir").count(),
+            0
+        );
+
+        mirror.append_cell(Some(id), &plain("› hi"), 80);
+        assert_eq!(written(codex_home.path(), id), "header\n\n\n› hi\n");
+    }
+
+    #[test]
+    fn each_session_gets_its_own_log() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let first = ConversationId::new();
+        let second = ConversationId::new();
+        let mut mirror = TranscriptMirror::new(codex_home.path(), 1);
+
+        mirror.append_cell(Some(first), &plain("› one"), 80);
+        mirror.append_cell(None, &plain("header"), 80);
+        mirror.append_cell(Some(second), &plain("› two"), 80);
+
+        assert_eq!(written(codex_home.path(), first), "› one\n");
+        assert_eq!(written(codex_home.path(), second), "header\n\n› two\n");
+    }
+
+    #[test]
+    fn opening_a_log_prunes_the_oldest_logs() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let now = SystemTime::now();
+        let old_logs: Vec<PathBuf> = (0..3)
+            .map(|age| {
+                let path = codex_home
+                    .path()
+                    .join(transcript_mirror_filename(ConversationId::new()));
+                let file = File::create(&path).expect("create log");
+                file.set_modified(now - std::time::Duration::from_secs(60 * (age + 1)))
+                    .expect("set mtime");
+                path
+            })
+            .collect();
+        let unrelated = codex_home.path().join("conversation_titles.json");
+        std::fs::write(&unrelated, "{}").expect("write");
+
+        let keep = codex_home.path().join("transcript-current.log");
+        File::create(&keep).expect("create log");
+        prune_logs(codex_home.path(), &keep, 3).expect("prune");
+
+        assert!(keep.exists());
+        assert!(unrelated.exists());
+        assert!(old_logs[0].exists());
+        assert!(old_logs[1].exists());
+        assert!(!old_logs[2].exists());
+    }
+
+    #[test]
+    fn open_errors_disable_the_mirror() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let missing = codex_home.path().join("missing");
+        let mut mirror = TranscriptMirror::new(&missing, 1);
+
+        mirror.append_cell(Some(ConversationId::new()), &plain("hello"), 80);
+        assert!(mirror.codex_home.is_none());
+    }
+}
@@ -60,7 +60,7 @@ use unicode_width::UnicodeWidthChar;
 /// This holds the click history required to infer multi-click sequences across
 /// mouse events. The actual selection expansion is computed from the current
 /// transcript content so it stays aligned with on-screen wrapping.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub(crate) struct TranscriptMultiClick {
     /// Tracks recent clicks so we can infer a multi-click sequence.
     ///
@@ -68,9 +68,26 @@ pub(crate) struct TranscriptMultiClick {
     /// endpoints are owned by `TranscriptSelection`, while multi-click behavior
     /// is a transient input gesture state.
     tracker: ClickTracker,
+    /// Blank rows between cells in the rendered transcript (`tui.cell_spacing`),
+    /// so expansion rebuilds the same line stream as the viewport.
+    cell_spacing: u16,
+}
+
+impl Default for TranscriptMultiClick {
+    fn default() -> Self {
+        Self {
+            tracker: ClickTracker::default(),
+            cell_spacing: crate::transcript_render::DEFAULT_CELL_SPACING,
+        }
+    }
 }
 
 impl TranscriptMultiClick {
+    /// Match the viewport's spacing between history cells.
+    pub(crate) fn set_cell_spacing(&mut self, cell_spacing: u16) {
+        self.cell_spacing = cell_spacing;
+    }
+
     /// Handle a left-button mouse down within the transcript viewport.
     ///
     /// This is intended to be called from `App`'s mouse handler.
@@ -166,7 +183,7 @@ impl TranscriptMultiClick {
             return *selection != before;
         }
 
-        *selection = selection_for_click(cells, width, point, click_count);
+        *selection = selection_for_click(cells, width, self.cell_spacing, point, click_count);
         *selection != before
     }
 }
@@ -277,6 +294,7 @@ fn max_column_distance(prev_click_count: u8) -> u16 {
 fn selection_for_click(
     cells: &[Arc<dyn HistoryCell>],
     width: u16,
+    cell_spacing: u16,
     point: TranscriptSelectionPoint,
     click_count: u8,
 ) -> TranscriptSelection {
@@ -297,7 +315,8 @@ fn selection_for_click(
     // Rebuild the same logical line stream the transcript renders from. This
     // keeps expansion boundaries aligned with current streaming output and the
     // current wrap width.
-    let (lines, line_cell_index) = build_transcript_lines_with_cell_index(cells, width);
+    let (lines, line_cell_index) =
+        build_transcript_lines_with_cell_index(cells, width, cell_spacing);
     if lines.is_empty() {
         return TranscriptSelection {
             anchor: Some(point),
@@ -418,6 +437,7 @@ fn build_transcript_lines(cells: &[Arc<dyn HistoryCell>], width: u16) -> Vec<Lin
 fn build_transcript_lines_with_cell_index(
     cells: &[Arc<dyn HistoryCell>],
     width: u16,
+    cell_spacing: u16,
 ) -> (Vec<Line<'static>>, Vec<Option<usize>>) {
     let mut lines: Vec<Line<'static>> = Vec::new();
     let mut line_cell_index: Vec<Option<usize>> = Vec::new();
@@ -431,8 +451,10 @@ fn build_transcript_lines_with_cell_index(
 
         if !cell.is_stream_continuation() {
             if has_emitted_lines {
-                lines.push(Line::from(""));
-                line_cell_index.push(None);
+                for _ in 0..cell_spacing {
+                    lines.push(Line::from(""));
+                    line_cell_index.push(None);
+                }
             } else {
                 has_emitted_lines = true;
             }
@@ -13,12 +13,16 @@
 //!   logical line instead of inserting hard newlines.
 
 use crate::history_cell::HistoryCell;
+use crate::theme::Theme;
 use crate::tui::scrolling::TranscriptLineMeta;
 use ratatui::text::Line;
 use std::sync::Arc;
 
+/// Default number of blank spacer rows between non-continuation cells (`tui.cell_spacing`).
+pub(crate) const DEFAULT_CELL_SPACING: u16 = 1;
+
 /// Flattened transcript lines plus the metadata required to interpret them.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub(crate) struct TranscriptLines {
     /// Flattened visual transcript lines, in the same order they are rendered.
     pub(crate) lines: Vec<Line<'static>>,
@@ -47,11 +51,18 @@ pub(crate) struct TranscriptLines {
 pub(crate) fn build_transcript_lines(
     cells: &[Arc<dyn HistoryCell>],
     width: u16,
+    cell_spacing: u16,
 ) -> TranscriptLines {
     // This function is the "lossless" transcript flattener:
     // - it asks each cell for its transcript lines (including any per-cell prefixes/indents)
     // - it inserts spacer rows between non-continuation cells to match the viewport layout
     // - it emits parallel metadata so scroll anchoring can map visual lines back to cells.
+    //
+    // A zero-width terminal has nowhere to put any row, so there is nothing to flatten.
+    if width == 0 {
+        return TranscriptLines::default();
+    }
+
     let mut lines: Vec<Line<'static>> = Vec::new();
     let mut meta: Vec<TranscriptLineMeta> = Vec::new();
     let mut joiner_before: Vec<Option<String>> = Vec::new();
@@ -65,14 +76,16 @@ pub(crate) fn build_transcript_lines(
             continue;
         }
 
-        // Cells that are not stream continuations are separated by an explicit spacer row.
-        // This keeps the flattened transcript aligned with what the user sees in the viewport
-        // and preserves intentional blank lines in copy.
+        // Cells that are not stream continuations are separated by `cell_spacing` explicit spacer
+        // rows. This keeps the flattened transcript aligned with what the user sees in the
+        // viewport and preserves intentional blank lines in copy.
         if !cell.is_stream_continuation() {
             if has_emitted_lines {
-                lines.push(Line::from(""));
-                meta.push(TranscriptLineMeta::Spacer);
-                joiner_before.push(None);
+                for _ in 0..cell_spacing {
+                    lines.push(Line::from(""));
+                    meta.push(TranscriptLineMeta::Spacer);
+                    joiner_before.push(None);
+                }
             } else {
                 has_emitted_lines = true;
             }
@@ -112,20 +125,13 @@ pub(crate) fn build_transcript_lines(
 pub(crate) fn build_wrapped_transcript_lines(
     cells: &[Arc<dyn HistoryCell>],
     width: u16,
+    cell_spacing: u16,
 ) -> TranscriptLines {
     if width == 0 {
-        return TranscriptLines {
-            lines: Vec::new(),
-            meta: Vec::new(),
-            joiner_before: Vec::new(),
-        };
+        return TranscriptLines::default();
     }
 
-    let mut transcript = TranscriptLines {
-        lines: Vec::new(),
-        meta: Vec::new(),
-        joiner_before: Vec::new(),
-    };
+    let mut transcript = TranscriptLines::default();
     let mut has_emitted_lines = false;
     let base_opts: crate::wrapping::RtOptions<'_> =
         crate::wrapping::RtOptions::new(width.max(1) as usize);
@@ -137,6 +143,7 @@ pub(crate) fn build_wrapped_transcript_lines(
             cell_index,
             cell,
             width,
+            cell_spacing,
             &base_opts,
         );
     }
@@ -151,13 +158,14 @@ pub(crate) fn build_wrapped_transcript_lines(
 /// at a time.
 ///
 /// `has_emitted_lines` tracks whether the output already contains any non-spacer lines and is used
-/// to decide when to insert an inter-cell spacer row.
+/// to decide when to insert the `cell_spacing` inter-cell spacer rows.
 pub(crate) fn append_wrapped_transcript_cell(
     out: &mut TranscriptLines,
     has_emitted_lines: &mut bool,
     cell_index: usize,
     cell: &Arc<dyn HistoryCell>,
     width: u16,
+    cell_spacing: u16,
     base_opts: &crate::wrapping::RtOptions<'_>,
 ) {
     use crate::render::line_utils::line_to_static;
@@ -176,9 +184,11 @@ pub(crate) fn append_wrapped_transcript_cell(
 
     if !cell.is_stream_continuation() {
         if *has_emitted_lines {
-            out.lines.push(Line::from(""));
-            out.meta.push(TranscriptLineMeta::Spacer);
-            out.joiner_before.push(None);
+            for _ in 0..cell_spacing {
+                out.lines.push(Line::from(""));
+                out.meta.push(TranscriptLineMeta::Spacer);
+                out.joiner_before.push(None);
+            }
         } else {
             *has_emitted_lines = true;
         }
@@ -258,12 +268,55 @@ pub(crate) fn append_wrapped_transcript_cell(
     }
 }
 
+/// Prefix each flattened line with its [`TranscriptLineMeta`] for the line-map debug overlay.
+///
+/// Cell rows are labelled `cell:line`, with a trailing `u` when `is_user_row` marks the row as
+/// user-authored; spacer rows are labelled `spacer`. Reading the labels next to the rendered text
+/// makes selection and scroll mapping bugs easy to reproduce.
+#[cfg(feature = "debug-logs")]
+pub(crate) fn annotate_transcript_lines(
+    lines: &[Line<'static>],
+    line_meta: &[TranscriptLineMeta],
+    is_user_row: &[bool],
+) -> Vec<Line<'static>> {
+    use ratatui::style::Stylize;
+
+    lines
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| {
+            let label = match line_meta.get(idx) {
+                Some(TranscriptLineMeta::CellLine {
+                    cell_index,
+                    line_in_cell,
+                }) => {
+                    let user = if is_user_row.get(idx).copied().unwrap_or(false) {
+                        "u"
+                    } else {
+                        " "
+                    };
+                    format!("{:>9} {user} │ ", format!("{cell_index}:{line_in_cell}"))
+                }
+                Some(TranscriptLineMeta::Spacer) => format!("{:>9}   │ ", "spacer"),
+                None => format!("{:>9}   │ ", "?"),
+            };
+            let mut spans = vec![label.dim()];
+            spans.extend(
+                line.spans
+                    .iter()
+                    .map(|span| span.clone().patch_style(line.style)),
+            );
+            Line::from(spans)
+        })
+        .collect()
+}
+
 /// Render flattened transcript lines into ANSI strings suitable for printing after the TUI exits.
 ///
 /// This helper mirrors the transcript viewport behavior:
 /// - Merges line-level style into each span so ANSI output matches on-screen styling.
-/// - For user-authored rows, pads the background style out to the full terminal width so prompts
-///   appear as solid blocks in scrollback.
+/// - For user-authored rows, pads `theme`'s user-message background out to the full terminal
+///   width so prompts appear as solid blocks in scrollback.
 /// - Streams spans through the shared vt100 writer so downstream tests and tools see consistent
 ///   escape sequences.
 pub(crate) fn render_lines_to_ansi(
@@ -271,6 +324,7 @@ pub(crate) fn render_lines_to_ansi(
     line_meta: &[TranscriptLineMeta],
     is_user_cell: &[bool],
     width: u16,
+    theme: &Theme,
 ) -> Vec<String> {
     use unicode_width::UnicodeWidthStr;
 
@@ -311,7 +365,7 @@ pub(crate) fn render_lines_to_ansi(
                 if text_width < total_width {
                     let pad_len = total_width.saturating_sub(text_width);
                     if pad_len > 0 {
-                        let pad_style = crate::style::user_message_style();
+                        let pad_style = crate::style::user_message_style(theme);
                         merged_spans.push(ratatui::text::Span {
                             style: pad_style,
                             content: " ".repeat(pad_len).into(),
@@ -381,7 +435,7 @@ mod tests {
         ];
 
         // Force wrapping so we get soft-wrap joiners for the second segment of each cell's line.
-        let transcript = build_wrapped_transcript_lines(&cells, 8);
+        let transcript = build_wrapped_transcript_lines(&cells, 8, DEFAULT_CELL_SPACING);
 
         assert_eq!(transcript.lines.len(), transcript.meta.len());
         assert_eq!(transcript.lines.len(), transcript.joiner_before.len());
@@ -424,6 +478,78 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cell_spacing_controls_spacer_rows_between_cells() {
+        let cells: Vec<Arc<dyn HistoryCell>> = vec![
+            Arc::new(FakeCell {
+                lines: vec![Line::from("• one")],
+                joiner_before: vec![None],
+                is_stream_continuation: false,
+            }),
+            Arc::new(FakeCell {
+                lines: vec![Line::from("• two")],
+                joiner_before: vec![None],
+                is_stream_continuation: false,
+            }),
+            // Continuations never get spacing, whatever the setting.
+            Arc::new(FakeCell {
+                lines: vec![Line::from("more")],
+                joiner_before: vec![None],
+                is_stream_continuation: true,
+            }),
+        ];
+
+        let dense = build_wrapped_transcript_lines(&cells, 20, 0);
+        let rendered: Vec<String> = dense.lines.iter().map(concat_line).collect();
+        assert_eq!(rendered, vec!["• one", "• two", "more"]);
+
+        let spaced = build_wrapped_transcript_lines(&cells, 20, 1);
+        let rendered: Vec<String> = spaced.lines.iter().map(concat_line).collect();
+        assert_eq!(rendered, vec!["• one", "", "• two", "more"]);
+
+        let unwrapped = build_transcript_lines(&cells, 20, 0);
+        assert_eq!(unwrapped.lines, dense.lines);
+    }
+
+    #[test]
+    fn degenerate_widths_with_wide_glyphs_do_not_panic() {
+        let cells: Vec<Arc<dyn HistoryCell>> = vec![
+            Arc::new(FakeCell {
+                lines: vec![Line::from("• 中文字 ok")],
+                joiner_before: vec![None],
+                is_stream_continuation: false,
+            }),
+            Arc::new(FakeCell {
+                lines: vec![Line::from("界")],
+                joiner_before: vec![None],
+                is_stream_continuation: false,
+            }),
+        ];
+
+        for width in [0, 1, 2] {
+            let wrapped = build_wrapped_transcript_lines(&cells, width, DEFAULT_CELL_SPACING);
+            let unwrapped = build_transcript_lines(&cells, width, DEFAULT_CELL_SPACING);
+            for transcript in [&wrapped, &unwrapped] {
+                assert_eq!(transcript.lines.len(), transcript.meta.len());
+                assert_eq!(transcript.lines.len(), transcript.joiner_before.len());
+                if width == 0 {
+                    assert!(transcript.lines.is_empty());
+                }
+            }
+
+            // Narrow wrapping may split a line anywhere, but never drops or duplicates glyphs.
+            let text: String = wrapped
+                .lines
+                .iter()
+                .map(concat_line)
+                .collect::<String>()
+                .split_whitespace()
+                .collect();
+            let expected = if width == 0 { "" } else { "•中文字ok界" };
+            assert_eq!(text, expected, "width {width}");
+        }
+    }
+
     #[test]
     fn append_wrapped_transcript_cell_matches_full_build() {
         use ratatui::style::Color;
@@ -450,7 +576,7 @@ mod tests {
         ];
 
         let width = 7;
-        let full = build_wrapped_transcript_lines(&cells, width);
+        let full = build_wrapped_transcript_lines(&cells, width, DEFAULT_CELL_SPACING);
 
         let mut out = TranscriptLines {
             lines: Vec::new(),
@@ -467,6 +593,7 @@ mod tests {
                 cell_index,
                 cell,
                 width,
+                DEFAULT_CELL_SPACING,
                 &base_opts,
             );
         }
@@ -475,4 +602,60 @@ mod tests {
         assert_eq!(out.meta, full.meta);
         assert_eq!(out.joiner_before, full.joiner_before);
     }
+
+    #[cfg(feature = "debug-logs")]
+    #[test]
+    fn annotations_match_wrapped_line_meta() {
+        let cells: Vec<Arc<dyn HistoryCell>> = vec![
+            Arc::new(FakeCell {
+                lines: vec![Line::from("› hi there")],
+                joiner_before: vec![None],
+                is_stream_continuation: false,
+            }),
+            Arc::new(FakeCell {
+                lines: vec![Line::from("• ok")],
+                joiner_before: vec![None],
+                is_stream_continuation: false,
+            }),
+        ];
+        let is_user_cell = [true, false];
+
+        let transcript = build_wrapped_transcript_lines(&cells, 8, DEFAULT_CELL_SPACING);
+        let is_user_row: Vec<bool> = transcript
+            .meta
+            .iter()
+            .map(|meta| meta.cell_index().is_some_and(|idx| is_user_cell[idx]))
+            .collect();
+        let annotated =
+            annotate_transcript_lines(&transcript.lines, &transcript.meta, &is_user_row);
+
+        let rendered: Vec<String> = annotated.iter().map(concat_line).collect();
+        assert_eq!(
+            rendered,
+            vec![
+                "      0:0 u │ › hi",
+                "      0:1 u │ there",
+                "   spacer   │ ",
+                "      1:0   │ • ok",
+            ]
+        );
+        assert_eq!(
+            transcript.meta,
+            vec![
+                TranscriptLineMeta::CellLine {
+                    cell_index: 0,
+                    line_in_cell: 0
+                },
+                TranscriptLineMeta::CellLine {
+                    cell_index: 0,
+                    line_in_cell: 1
+                },
+                TranscriptLineMeta::Spacer,
+                TranscriptLineMeta::CellLine {
+                    cell_index: 1,
+                    line_in_cell: 0
+                },
+            ]
+        );
+    }
 }
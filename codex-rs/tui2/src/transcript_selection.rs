@@ -23,8 +23,17 @@
 //! The transcript supports click-and-drag selection. To avoid leaving a
 //! distracting 1-cell highlight on a simple click, the selection only becomes
 //! active once a drag updates the head point.
+//!
+//! Mouse columns are snapped to grapheme boundaries on the target line (see
+//! [`snap_column_to_grapheme_start`]), so an endpoint never lands on the right
+//! half of a wide glyph (CJK, emoji).
 
+use crate::tui::scrolling::TranscriptLineMeta;
 use crate::tui::scrolling::TranscriptScroll;
+use ratatui::layout::Rect;
+use ratatui::text::Line;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// Number of columns reserved for the transcript gutter (bullet/prefix space).
 ///
@@ -34,6 +43,155 @@ use crate::tui::scrolling::TranscriptScroll;
 /// absolute columns.
 pub(crate) const TRANSCRIPT_GUTTER_COLS: u16 = 2;
 
+/// Default gutter width when line numbers are enabled and no explicit width is
+/// configured: room for a 5-digit line number, a separating space, and the
+/// cell prefix.
+const LINE_NUMBER_GUTTER_COLS: u16 = TRANSCRIPT_GUTTER_COLS + 6;
+
+/// Configured layout of the transcript gutter.
+///
+/// The gutter is made of two parts:
+///
+/// - A *margin* on the far left, reserved by the app (e.g. for line numbers).
+/// - The [`TRANSCRIPT_GUTTER_COLS`]-wide bullet/prefix area that each history
+///   cell renders as part of its own lines.
+///
+/// Cells are wrapped and rendered into the area to the right of the margin, so
+/// wrapped lines (and everything derived from them, like copy and multi-click
+/// selection) keep using [`TRANSCRIPT_GUTTER_COLS`] as their content offset.
+/// Screen-space mapping must go through [`TranscriptGutter::base_x`] so mouse
+/// coordinates, highlighting, and the copy affordance agree on where content
+/// starts.
+///
+/// When the minimap is enabled, the rightmost
+/// [`crate::transcript_minimap::MINIMAP_COLS`] columns are reserved for it and
+/// excluded from the content area as well.
+///
+/// An optional maximum content width (`tui.max_content_width`) caps the content
+/// area on wide terminals. Content stays left-aligned, so [`Self::base_x`] is
+/// unaffected and only the right edge of the content area moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TranscriptGutter {
+    cols: u16,
+    line_numbers: bool,
+    minimap_cols: u16,
+    max_content_cols: Option<u16>,
+}
+
+impl Default for TranscriptGutter {
+    fn default() -> Self {
+        Self {
+            cols: TRANSCRIPT_GUTTER_COLS,
+            line_numbers: false,
+            minimap_cols: 0,
+            max_content_cols: None,
+        }
+    }
+}
+
+impl TranscriptGutter {
+    /// Build a gutter from the (optional) configured width.
+    ///
+    /// Widths narrower than the cell prefix are clamped to
+    /// [`TRANSCRIPT_GUTTER_COLS`].
+    pub(crate) fn new(cols: Option<u16>, line_numbers: bool) -> Self {
+        let default_cols = if line_numbers {
+            LINE_NUMBER_GUTTER_COLS
+        } else {
+            TRANSCRIPT_GUTTER_COLS
+        };
+        Self {
+            cols: cols.unwrap_or(default_cols).max(TRANSCRIPT_GUTTER_COLS),
+            line_numbers,
+            minimap_cols: 0,
+            max_content_cols: None,
+        }
+    }
+
+    /// Reserve the right edge of the transcript for the minimap.
+    pub(crate) fn with_minimap(mut self, enabled: bool) -> Self {
+        self.minimap_cols = if enabled {
+            crate::transcript_minimap::MINIMAP_COLS
+        } else {
+            0
+        };
+        self
+    }
+
+    /// Never wrap content wider than `cols` (including the cell prefix), however wide the
+    /// terminal is. Caps narrower than the prefix plus one column are raised to that.
+    pub(crate) fn with_max_content_width(mut self, cols: Option<u16>) -> Self {
+        self.max_content_cols = cols.map(|cols| cols.max(TRANSCRIPT_GUTTER_COLS + 1));
+        self
+    }
+
+    /// Total gutter width, including the cell prefix.
+    pub(crate) fn cols(self) -> u16 {
+        self.cols
+    }
+
+    /// Columns reserved to the left of the cell prefix.
+    pub(crate) fn margin_cols(self) -> u16 {
+        self.cols.saturating_sub(TRANSCRIPT_GUTTER_COLS)
+    }
+
+    /// Absolute x of the first content column (selection column 0) in `area`.
+    pub(crate) fn base_x(self, area: Rect) -> u16 {
+        area.x.saturating_add(self.cols)
+    }
+
+    /// The part of `area` that history cells are rendered into (everything to
+    /// the right of the margin and left of the minimap, up to the maximum
+    /// content width).
+    pub(crate) fn content_area(self, area: Rect) -> Rect {
+        let margin = self.margin_cols().min(area.width);
+        Rect {
+            x: area.x.saturating_add(margin),
+            y: area.y,
+            width: self.content_width(area.width),
+            height: area.height,
+        }
+    }
+
+    /// Width available to history cells when the transcript is `width` columns wide.
+    pub(crate) fn content_width(self, width: u16) -> u16 {
+        let available = width
+            .saturating_sub(self.margin_cols())
+            .saturating_sub(self.minimap_cols);
+        self.max_content_cols
+            .map_or(available, |max| available.min(max))
+    }
+
+    /// Columns on the right edge of `area` reserved for the minimap, if enabled.
+    pub(crate) fn minimap_area(self, area: Rect) -> Option<Rect> {
+        if self.minimap_cols == 0 || self.content_width(area.width) == 0 {
+            return None;
+        }
+        Some(Rect {
+            x: area.right() - self.minimap_cols,
+            width: self.minimap_cols,
+            ..area
+        })
+    }
+
+    /// Text to render in the margin for the (zero-based) wrapped line index.
+    ///
+    /// The label is right-aligned and leaves one column of padding before the
+    /// cell prefix. Returns `None` when line numbers are disabled or the margin
+    /// is too narrow to fit the number.
+    pub(crate) fn line_number_label(self, line_index: usize) -> Option<String> {
+        if !self.line_numbers {
+            return None;
+        }
+        let label_width = usize::from(self.margin_cols().saturating_sub(1));
+        let number = line_index.saturating_add(1).to_string();
+        if label_width == 0 || number.len() > label_width {
+            return None;
+        }
+        Some(format!("{number:>label_width$} "))
+    }
+}
+
 /// Content-relative selection within the inline transcript viewport.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub(crate) struct TranscriptSelection {
@@ -191,12 +349,13 @@ fn drag(
     point: TranscriptSelectionPoint,
     streaming: bool,
 ) -> bool {
-    let Some(anchor) = selection.anchor else {
+    if selection.anchor.is_none() {
         return false;
-    };
+    }
 
-    let should_lock_scroll =
-        streaming && matches!(*scroll, TranscriptScroll::ToBottom) && point != anchor;
+    // Any drag while following counts as reading: pause auto-follow even if the pointer has
+    // not left the anchor cell yet, so the content doesn't move out from under it.
+    let should_lock_scroll = streaming && matches!(*scroll, TranscriptScroll::ToBottom);
 
     selection.head = Some(point);
 
@@ -213,11 +372,238 @@ fn end(selection: &mut TranscriptSelection) {
     }
 }
 
+/// Snap a content-relative `column` on `line` back to the start of the grapheme that covers it.
+///
+/// `line` is a wrapped transcript line including the [`TRANSCRIPT_GUTTER_COLS`] prefix. Widths
+/// follow the same grapheme/unicode-width rules as the composer, so a click on the right half of a
+/// double-width glyph selects from the glyph's first column. Columns past the end of the line are
+/// returned unchanged.
+pub(crate) fn snap_column_to_grapheme_start(line: &Line<'_>, column: u16) -> u16 {
+    let target = usize::from(column) + usize::from(TRANSCRIPT_GUTTER_COLS);
+    let mut col = 0usize;
+    for span in &line.spans {
+        for grapheme in span.content.graphemes(true) {
+            let width = grapheme.width();
+            if width == 0 {
+                continue;
+            }
+            if target < col + width {
+                let start = col.saturating_sub(usize::from(TRANSCRIPT_GUTTER_COLS));
+                return u16::try_from(start).unwrap_or(column).min(column);
+            }
+            col += width;
+        }
+    }
+    column
+}
+
+/// Width-independent position of a selection endpoint.
+///
+/// [`TranscriptSelectionPoint`] is only meaningful for the width the transcript was wrapped at.
+/// Before a reflow each endpoint is converted into the owning cell plus the number of
+/// non-whitespace graphemes that precede it in that cell; wrapping only moves whitespace and
+/// gutter padding around, so the same content can be found again at the new width (the same idea
+/// as [`TranscriptScroll::anchor_for`] for the scroll position).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TranscriptContentAnchor {
+    cell_index: usize,
+    offset: usize,
+}
+
+/// Non-whitespace graphemes of a wrapped line, as `(content_column, width)`.
+///
+/// Graphemes in the [`TRANSCRIPT_GUTTER_COLS`] prefix (bullets, continuation padding) are skipped
+/// since the prefix is re-rendered on every wrapped line.
+fn content_graphemes(line: &Line<'_>) -> Vec<(usize, usize)> {
+    let gutter = usize::from(TRANSCRIPT_GUTTER_COLS);
+    let mut out = Vec::new();
+    let mut col = 0usize;
+    for span in &line.spans {
+        for grapheme in span.content.graphemes(true) {
+            let width = grapheme.width();
+            if width == 0 {
+                continue;
+            }
+            if col >= gutter && !grapheme.chars().all(char::is_whitespace) {
+                out.push((col - gutter, width));
+            }
+            col += width;
+        }
+    }
+    out
+}
+
+/// Convert `point` into a [`TranscriptContentAnchor`] using the wrapped `lines` it refers to.
+///
+/// Points on spacer rows anchor to the start of the following cell. Returns `None` when `point`
+/// is out of range.
+pub(crate) fn content_anchor_for(
+    lines: &[Line<'_>],
+    line_meta: &[TranscriptLineMeta],
+    point: TranscriptSelectionPoint,
+) -> Option<TranscriptContentAnchor> {
+    match *line_meta.get(point.line_index)? {
+        TranscriptLineMeta::Spacer => line_meta[point.line_index..]
+            .iter()
+            .find_map(TranscriptLineMeta::cell_line)
+            .map(|(cell_index, _)| TranscriptContentAnchor {
+                cell_index,
+                offset: 0,
+            }),
+        TranscriptLineMeta::CellLine { cell_index, .. } => {
+            let mut offset = 0;
+            for (line_index, meta) in line_meta.iter().enumerate().take(point.line_index) {
+                if meta.cell_line().is_some_and(|(cell, _)| cell == cell_index) {
+                    offset += lines
+                        .get(line_index)
+                        .map_or(0, |l| content_graphemes(l).len());
+                }
+            }
+            let column = usize::from(point.column);
+            offset += lines.get(point.line_index).map_or(0, |line| {
+                content_graphemes(line)
+                    .iter()
+                    .take_while(|(col, _)| *col < column)
+                    .count()
+            });
+            Some(TranscriptContentAnchor { cell_index, offset })
+        }
+    }
+}
+
+/// Resolve a [`TranscriptContentAnchor`] against the re-wrapped `lines`.
+///
+/// Offsets past the end of the cell clamp to the end of its last line. Returns `None` when the
+/// cell no longer exists.
+pub(crate) fn point_for_content_anchor(
+    lines: &[Line<'_>],
+    line_meta: &[TranscriptLineMeta],
+    anchor: TranscriptContentAnchor,
+) -> Option<TranscriptSelectionPoint> {
+    let mut remaining = anchor.offset;
+    let mut last = None;
+    for (line_index, meta) in line_meta.iter().enumerate() {
+        if !meta
+            .cell_line()
+            .is_some_and(|(cell, _)| cell == anchor.cell_index)
+        {
+            continue;
+        }
+        let graphemes = lines
+            .get(line_index)
+            .map(content_graphemes)
+            .unwrap_or_default();
+        if let Some(&(col, _)) = graphemes.get(remaining) {
+            return Some(TranscriptSelectionPoint::new(
+                line_index,
+                u16::try_from(col).unwrap_or(u16::MAX),
+            ));
+        }
+        remaining -= graphemes.len();
+        let end = graphemes
+            .last()
+            .map_or(0, |&(col, width)| col + width.saturating_sub(1));
+        last = Some(TranscriptSelectionPoint::new(
+            line_index,
+            u16::try_from(end).unwrap_or(u16::MAX),
+        ));
+    }
+    last
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn snaps_columns_inside_wide_glyphs_to_their_start() {
+        let line = Line::from("• a中b");
+
+        // Content columns: `a` = 0, `中` = 1..=2, `b` = 3.
+        assert_eq!(snap_column_to_grapheme_start(&line, 0), 0);
+        assert_eq!(snap_column_to_grapheme_start(&line, 1), 1);
+        assert_eq!(snap_column_to_grapheme_start(&line, 2), 1);
+        assert_eq!(snap_column_to_grapheme_start(&line, 3), 3);
+        // Past the end of the line the column is left alone.
+        assert_eq!(snap_column_to_grapheme_start(&line, 10), 10);
+    }
+
+    #[test]
+    fn max_content_width_caps_content_area() {
+        let area = Rect::new(0, 0, 200, 10);
+        let gutter = TranscriptGutter::default().with_max_content_width(Some(80));
+        assert_eq!(gutter.content_width(200), 80);
+        assert_eq!(gutter.content_width(60), 60);
+        assert_eq!(gutter.content_area(area), Rect::new(0, 0, 80, 10));
+        assert_eq!(gutter.base_x(area), TRANSCRIPT_GUTTER_COLS);
+
+        // The minimap stays on the terminal's right edge.
+        let gutter = gutter.with_minimap(true);
+        assert_eq!(gutter.content_width(200), 80);
+        assert_eq!(
+            gutter.minimap_area(area).map(|rect| rect.right()),
+            Some(area.right())
+        );
+    }
+
+    #[test]
+    fn content_anchor_survives_rewrap() {
+        let wide = [Line::from("• one two three")];
+        let wide_meta = [TranscriptLineMeta::CellLine {
+            cell_index: 0,
+            line_in_cell: 0,
+        }];
+        let narrow = [
+            Line::from("• one two"),
+            Line::from("  three"),
+            Line::from(""),
+        ];
+        let narrow_meta = [
+            TranscriptLineMeta::CellLine {
+                cell_index: 0,
+                line_in_cell: 0,
+            },
+            TranscriptLineMeta::CellLine {
+                cell_index: 0,
+                line_in_cell: 1,
+            },
+            TranscriptLineMeta::Spacer,
+        ];
+
+        // `h` in `three` sits at content column 9 when unwrapped.
+        let anchor = content_anchor_for(&wide, &wide_meta, TranscriptSelectionPoint::new(0, 9))
+            .expect("anchor");
+        assert_eq!(
+            point_for_content_anchor(&narrow, &narrow_meta, anchor),
+            Some(TranscriptSelectionPoint::new(1, 1))
+        );
+        let back = content_anchor_for(&narrow, &narrow_meta, TranscriptSelectionPoint::new(1, 1))
+            .expect("anchor");
+        assert_eq!(back, anchor);
+
+        // Offsets past the end clamp to the last glyph of the cell.
+        let past_end = content_anchor_for(
+            &wide,
+            &wide_meta,
+            TranscriptSelectionPoint::new(0, u16::MAX),
+        )
+        .expect("anchor");
+        assert_eq!(
+            point_for_content_anchor(&narrow, &narrow_meta, past_end),
+            Some(TranscriptSelectionPoint::new(1, 4))
+        );
+        // A cell that no longer exists cannot be resolved.
+        let missing = TranscriptContentAnchor {
+            cell_index: 3,
+            offset: 0,
+        };
+        assert_eq!(
+            point_for_content_anchor(&narrow, &narrow_meta, missing),
+            None
+        );
+    }
+
     #[test]
     fn selection_only_highlights_on_drag() {
         let anchor = TranscriptSelectionPoint::new(0, 1);
@@ -290,6 +676,21 @@ mod tests {
         assert!(outcome.lock_scroll);
     }
 
+    #[test]
+    fn drag_at_anchor_requests_scroll_lock_while_streaming() {
+        let point = TranscriptSelectionPoint::new(0, 1);
+
+        let mut selection = TranscriptSelection::default();
+        assert!(on_mouse_down(&mut selection, Some(point)));
+        let outcome = on_mouse_drag(
+            &mut selection,
+            &TranscriptScroll::ToBottom,
+            Some(point),
+            true,
+        );
+        assert!(outcome.lock_scroll);
+    }
+
     #[test]
     fn selection_helpers_noop_without_points_or_anchor() {
         let mut selection = TranscriptSelection::default();
@@ -346,6 +747,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn gutter_width_shifts_base_x_and_content_area() {
+        let area = Rect::new(3, 0, 40, 5);
+
+        let default = TranscriptGutter::default();
+        assert_eq!(default.base_x(area), 3 + TRANSCRIPT_GUTTER_COLS);
+        assert_eq!(default.content_area(area), area);
+
+        let wide = TranscriptGutter::new(Some(6), false);
+        assert_eq!(wide.base_x(area), 9);
+        assert_eq!(wide.content_area(area), Rect::new(7, 0, 36, 5));
+        assert_eq!(wide.content_width(area.width), 36);
+
+        // The cell prefix can't be shrunk away.
+        assert_eq!(TranscriptGutter::new(Some(0), false), default);
+    }
+
+    #[test]
+    fn minimap_reserves_the_right_edge() {
+        let area = Rect::new(3, 0, 40, 5);
+        assert_eq!(TranscriptGutter::default().minimap_area(area), None);
+
+        let gutter = TranscriptGutter::default().with_minimap(true);
+        assert_eq!(gutter.content_area(area), Rect::new(3, 0, 39, 5));
+        assert_eq!(gutter.minimap_area(area), Some(Rect::new(42, 0, 1, 5)));
+    }
+
+    #[test]
+    fn line_number_labels_fit_the_margin() {
+        let gutter = TranscriptGutter::new(None, true);
+        assert_eq!(gutter.cols(), LINE_NUMBER_GUTTER_COLS);
+        assert_eq!(gutter.line_number_label(0), Some("    1 ".to_string()));
+        assert_eq!(gutter.line_number_label(41), Some("   42 ".to_string()));
+
+        let narrow = TranscriptGutter::new(Some(4), true);
+        assert_eq!(narrow.line_number_label(8), Some("9 ".to_string()));
+        assert_eq!(narrow.line_number_label(9), None);
+
+        assert_eq!(
+            TranscriptGutter::new(Some(8), false).line_number_label(0),
+            None
+        );
+    }
+
     #[test]
     fn dragging_does_not_request_scroll_lock_when_not_at_bottom() {
         let anchor = TranscriptSelectionPoint::new(0, 1);
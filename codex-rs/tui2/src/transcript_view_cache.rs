@@ -22,6 +22,9 @@
 //! Notes:
 //! - All caches are invalidated on width changes because wrapping and layout
 //!   depend on the viewport width.
+//! - Only the newest cell is checked for in-place changes (a streamed cell growing, or a repeated
+//!   error bumping its count): its display lines are hashed with [`cell_content_hash`], and a
+//!   changed hash re-wraps just that cell. Earlier cells are finalized and never re-wrapped.
 //! - Rasterization is cached for base transcript content only; selection
 //!   highlight and copy affordances are applied after the rows are drawn, so
 //!   they do not pollute the cache.
@@ -68,13 +71,26 @@
 //!
 //! Cached rows are invalidated when:
 //! - the wrapped transcript is rebuilt (line indices shift)
+//! - the newest cell is re-wrapped (only rows from its first line onward are dropped)
 //! - the width changes (layout changes)
 //!
 //! The raster cache is bounded by `capacity` using an approximate LRU so it does not grow without
 //! bound during long sessions.
+//!
+//! ### Dirty row tracking
+//!
+//! While a response streams, most visible rows are identical from one frame to the next.
+//! [`TranscriptRowDiff`] remembers, per viewport row, which line was drawn there (a
+//! [`TranscriptRowKey`]: line index plus a content fingerprint) and the cells that were written.
+//! Rows whose key is unchanged are restored from that snapshot instead of being re-rendered, so
+//! only dirty rows go through the raster path. The snapshot is taken before selection highlight and
+//! other overlays are applied, and it is discarded whenever the viewport area or palette changes,
+//! so scrolling (which changes the line index shown on each row) and selection changes naturally
+//! repaint the affected rows.
 
 use crate::history_cell::HistoryCell;
 use crate::history_cell::UserHistoryCell;
+use crate::theme::Theme;
 use crate::transcript_render::TranscriptLines;
 use crate::tui::scrolling::TranscriptLineMeta;
 use ratatui::buffer::Buffer;
@@ -83,6 +99,9 @@ use ratatui::text::Line;
 use ratatui::widgets::WidgetRef;
 use std::collections::HashMap;
 use std::collections::VecDeque;
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::sync::Arc;
 
 /// Top-level cache for the inline transcript viewport.
@@ -142,6 +161,10 @@ impl TranscriptViewCache {
         if update == WrappedTranscriptUpdate::Rebuilt {
             self.raster.width = width;
             self.raster.clear();
+        } else if let WrappedTranscriptUpdate::Rewrapped { from_line } = update
+            && width == self.raster.width
+        {
+            self.raster.invalidate_from(from_line);
         } else if width != self.raster.width {
             // Keep the invariant that raster cache always matches the active wrapped width.
             self.raster.clear();
@@ -149,6 +172,31 @@ impl TranscriptViewCache {
         }
     }
 
+    /// Width the cached transcript was last wrapped at (`0` before the first wrap).
+    pub(crate) fn width(&self) -> u16 {
+        self.wrapped.width
+    }
+
+    /// Set the number of blank spacer rows between non-continuation cells.
+    ///
+    /// Changing the spacing shifts every line index after the first cell, so the wrapped and
+    /// raster caches are rebuilt on the next [`Self::ensure_wrapped`].
+    pub(crate) fn set_cell_spacing(&mut self, cell_spacing: u16) {
+        if self.wrapped.cell_spacing != cell_spacing {
+            self.wrapped = WrappedTranscriptCache::new();
+            self.wrapped.cell_spacing = cell_spacing;
+            self.raster.clear();
+        }
+    }
+
+    /// Set the theme user rows are rasterized with, dropping cached rows when it changes.
+    pub(crate) fn set_theme(&mut self, theme: Theme) {
+        if self.raster.theme != theme {
+            self.raster.theme = theme;
+            self.raster.clear();
+        }
+    }
+
     /// Return the cached flattened wrapped transcript lines.
     ///
     /// This is primarily used for:
@@ -222,6 +270,129 @@ impl TranscriptViewCache {
         self.raster
             .render_row_into(line_index, is_user_row, line, row_area, buf);
     }
+
+    /// Identify what would be drawn for `line_index`, for dirty row tracking.
+    ///
+    /// The fingerprint covers the wrapped line content and user-row styling, so a rebuilt
+    /// transcript that reuses a line index for different content still produces a different key.
+    pub(crate) fn row_key(&self, line_index: usize) -> TranscriptRowKey {
+        let mut hasher = DefaultHasher::new();
+        self.wrapped.transcript.lines[line_index].hash(&mut hasher);
+        self.is_user_row(line_index).hash(&mut hasher);
+        TranscriptRowKey {
+            line_index,
+            fingerprint: hasher.finish(),
+        }
+    }
+}
+
+/// Identity of the content drawn on a single transcript viewport row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TranscriptRowKey {
+    line_index: usize,
+    fingerprint: u64,
+}
+
+impl TranscriptRowKey {
+    /// Index of the wrapped transcript line this key identifies.
+    pub(crate) fn line_index(self) -> usize {
+        self.line_index
+    }
+}
+
+/// Tracks which transcript viewport rows changed since the previous frame.
+///
+/// The frame buffer starts empty every draw, so "clean" rows are restored by copying the cells
+/// recorded for them last frame rather than re-rendering the line (and its line-number label).
+pub(crate) struct TranscriptRowDiff {
+    /// Viewport area the recorded rows belong to.
+    area: Rect,
+    /// Terminal palette version the recorded rows were rendered with.
+    palette_version: u64,
+    /// Recorded row content, indexed by viewport row.
+    rows: Vec<Option<RecordedRow>>,
+}
+
+struct RecordedRow {
+    key: TranscriptRowKey,
+    cells: Vec<ratatui::buffer::Cell>,
+}
+
+impl TranscriptRowDiff {
+    /// Create a tracker with no recorded rows (the first frame repaints everything).
+    pub(crate) fn new() -> Self {
+        Self {
+            area: Rect::default(),
+            palette_version: crate::terminal_palette::palette_version(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Forget every recorded row so the next frame repaints the whole viewport.
+    pub(crate) fn invalidate(&mut self) {
+        self.rows.clear();
+    }
+
+    /// Prepare for a frame drawn into `area`, invalidating when the area or palette changed.
+    pub(crate) fn begin_frame(&mut self, area: Rect) {
+        self.begin_frame_with_palette(area, crate::terminal_palette::palette_version());
+    }
+
+    fn begin_frame_with_palette(&mut self, area: Rect, palette_version: u64) {
+        if area != self.area || palette_version != self.palette_version {
+            self.area = area;
+            self.palette_version = palette_version;
+            self.invalidate();
+        }
+        self.rows.resize_with(area.height as usize, || None);
+    }
+
+    /// Whether `row` must be re-rendered to show `key`.
+    fn is_dirty(&self, row: usize, key: TranscriptRowKey) -> bool {
+        !matches!(self.rows.get(row), Some(Some(recorded)) if recorded.key == key)
+    }
+
+    /// Return the viewport rows (by index) that are dirty for `keys`, in order.
+    pub(crate) fn dirty_rows(&self, keys: &[TranscriptRowKey]) -> Vec<usize> {
+        keys.iter()
+            .enumerate()
+            .filter(|(row, key)| self.is_dirty(*row, **key))
+            .map(|(row, _)| row)
+            .collect()
+    }
+
+    /// Copy the cells recorded for `row` into `buf`; callers restore only rows that
+    /// [`Self::dirty_rows`] reported clean.
+    ///
+    /// Returns `false` (leaving `buf` untouched) when nothing was recorded for the row.
+    pub(crate) fn restore_row(&self, row: usize, buf: &mut Buffer) -> bool {
+        let Some(Some(recorded)) = self.rows.get(row) else {
+            return false;
+        };
+        copy_row(self.row_area(row), buf, &recorded.cells);
+        true
+    }
+
+    /// Record the cells just rendered for `row` so later frames can restore them.
+    pub(crate) fn record_row(&mut self, row: usize, key: TranscriptRowKey, buf: &Buffer) {
+        let row_area = self.row_area(row);
+        let Some(slot) = self.rows.get_mut(row) else {
+            return;
+        };
+        let cells = (row_area.x..row_area.right())
+            .map(|x| buf[(x, row_area.y)].clone())
+            .collect();
+        *slot = Some(RecordedRow { key, cells });
+    }
+
+    fn row_area(&self, row: usize) -> Rect {
+        Rect {
+            x: self.area.x,
+            y: self.area.y.saturating_add(row as u16),
+            width: self.area.width,
+            height: 1,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -230,21 +401,69 @@ enum WrappedTranscriptUpdate {
     Unchanged,
     /// The cache appended additional cells without rebuilding.
     Appended,
+    /// The newest cached cell changed in place and was re-wrapped, possibly along with newly
+    /// appended cells. Lines before `from_line` are untouched.
+    Rewrapped { from_line: usize },
     /// The cache rebuilt from scratch (width change, truncation, or replacement).
     Rebuilt,
 }
 
+/// Hash of the lines a cell displays at `width`.
+///
+/// Stable across frames while the cell is unchanged, and different once a streamed cell gains
+/// content, so it tells the wrap cache exactly when the newest cell needs re-wrapping.
+pub(crate) fn cell_content_hash(cell: &dyn HistoryCell, width: u16) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    cell.display_lines(width).hash(&mut hasher);
+    hasher.finish()
+}
+
+/// What the wrap cache remembers about the newest cell so it can be re-wrapped in isolation.
+struct WrappedTail {
+    /// Pointer identity of the cell, so a replaced cell is re-wrapped even if its lines match.
+    cell_ptr: *const (),
+    /// [`cell_content_hash`] of the cell when it was wrapped.
+    content_hash: u64,
+    /// Index of the first flattened line (including any leading spacer) produced by the cell.
+    start_line: usize,
+    /// `has_emitted_lines` before the cell was appended.
+    had_emitted_lines: bool,
+}
+
+/// Where a cached cell's flattened lines start, keyed by the cell's pointer identity.
+struct WrappedCell {
+    /// Pointer identity of the cell, used to recognize it after cells before it were dropped.
+    cell_ptr: *const (),
+    /// Index of the first flattened line (including any leading spacer) produced by the cell.
+    start_line: usize,
+}
+
+/// How the front of the cell list changed since the last [`WrappedTranscriptCache::ensure`].
+enum FrontChange {
+    /// The cached cells still line up with `cells`.
+    Unchanged,
+    /// Cells right after the first one were dropped (folded into a collapsed summary); their lines
+    /// were removed and every line from `from_line` on moved up.
+    Folded { from_line: usize },
+    /// The cached cells no longer line up with `cells`; the cache must be rebuilt.
+    Mismatch,
+}
+
 /// Incremental memoization of wrapped transcript lines for a given width.
 ///
 /// This cache exists so callers doing tight-loop scroll math (mouse wheel, PgUp/PgDn) and render
 /// ticks do not repeatedly rebuild the wrapped transcript (`HistoryCell` → flattened `Line`s).
 ///
 /// It assumes the transcript is append-mostly: when new cells arrive, they are appended to the end
-/// of `cells` and existing cells do not mutate. If the underlying cell list is replaced or
-/// truncated, the cache rebuilds from scratch.
+/// of `cells`, and only the newest cell may change in place. Cells right after the first one may
+/// also be dropped while the first cell stays the same (a collapsed transcript folding more cells
+/// into its summary); their lines are removed without re-wrapping the rest. If the underlying cell
+/// list is otherwise replaced or truncated, the cache rebuilds from scratch.
 struct WrappedTranscriptCache {
     /// Width this cache was last built for.
     width: u16,
+    /// Blank spacer rows inserted between non-continuation cells.
+    cell_spacing: u16,
     /// Number of leading cells already incorporated into [`Self::transcript`].
     cell_count: usize,
     /// Pointer identity of the first cell at the time the cache was built.
@@ -267,6 +486,10 @@ struct WrappedTranscriptCache {
     /// We store this alongside the wrapped transcript so user-row styling can be derived cheaply
     /// from `TranscriptLineMeta::cell_index()` without re-inspecting the cell type every frame.
     is_user_cell: Vec<bool>,
+    /// Per-cell pointer identity and first line, aligned with [`Self::is_user_cell`].
+    wrapped_cells: Vec<WrappedCell>,
+    /// The newest wrapped cell, checked on every [`Self::ensure`] for in-place changes.
+    tail: Option<WrappedTail>,
 }
 
 impl WrappedTranscriptCache {
@@ -277,6 +500,7 @@ impl WrappedTranscriptCache {
     fn new() -> Self {
         Self {
             width: 0,
+            cell_spacing: crate::transcript_render::DEFAULT_CELL_SPACING,
             cell_count: 0,
             first_cell_ptr: None,
             transcript: TranscriptLines {
@@ -286,6 +510,8 @@ impl WrappedTranscriptCache {
             },
             has_emitted_lines: false,
             is_user_cell: Vec::new(),
+            wrapped_cells: Vec::new(),
+            tail: None,
         }
     }
 
@@ -293,10 +519,13 @@ impl WrappedTranscriptCache {
     ///
     /// This cache is intentionally single-entry and width-scoped:
     /// - when `width` is unchanged and `cells` has grown, append only the new cells
+    /// - when cells after the first one were dropped, remove only their lines
     /// - when `width` changes or the transcript is replaced/truncated, rebuild from scratch
     ///
-    /// The cache assumes history cells are append-only and immutable once inserted. If existing
-    /// cell contents can change without changing identity, callers must treat that as a rebuild.
+    /// History cells are assumed to be immutable once another cell follows them. The newest cell
+    /// may still change (streaming, merged repeats): when its pointer or [`cell_content_hash`]
+    /// differs from the cached one, only that cell is re-wrapped. Changes to older cells require a
+    /// fresh cache.
     fn ensure(&mut self, cells: &[Arc<dyn HistoryCell>], width: u16) -> WrappedTranscriptUpdate {
         if width == 0 {
             self.width = width;
@@ -307,12 +536,13 @@ impl WrappedTranscriptCache {
             self.transcript.joiner_before.clear();
             self.has_emitted_lines = false;
             self.is_user_cell.clear();
+            self.wrapped_cells.clear();
+            self.tail = None;
             return WrappedTranscriptUpdate::Rebuilt;
         }
 
         let current_first_ptr = cells.first().map(Arc::as_ptr);
         if self.width != width
-            || self.cell_count > cells.len()
             || (self.cell_count > 0
                 && current_first_ptr.is_some()
                 && self.first_cell_ptr != current_first_ptr)
@@ -321,29 +551,146 @@ impl WrappedTranscriptCache {
             return WrappedTranscriptUpdate::Rebuilt;
         }
 
-        if self.cell_count == cells.len() {
-            return WrappedTranscriptUpdate::Unchanged;
+        let mut rewrapped_from = match self.drop_folded_cells(cells) {
+            FrontChange::Unchanged => None,
+            FrontChange::Folded { from_line } => Some(from_line),
+            FrontChange::Mismatch => {
+                self.rebuild(cells, width);
+                return WrappedTranscriptUpdate::Rebuilt;
+            }
+        };
+        if self.cell_count > cells.len() {
+            self.rebuild(cells, width);
+            return WrappedTranscriptUpdate::Rebuilt;
+        }
+
+        let mut start_index = self.cell_count;
+        if let Some(tail) = &self.tail {
+            let tail_index = self.cell_count - 1;
+            let cell = &cells[tail_index];
+            if Arc::as_ptr(cell).cast::<()>() != tail.cell_ptr
+                || cell_content_hash(cell.as_ref(), width) != tail.content_hash
+            {
+                self.transcript.lines.truncate(tail.start_line);
+                self.transcript.meta.truncate(tail.start_line);
+                self.transcript.joiner_before.truncate(tail.start_line);
+                self.has_emitted_lines = tail.had_emitted_lines;
+                self.is_user_cell.truncate(tail_index);
+                self.wrapped_cells.truncate(tail_index);
+                rewrapped_from =
+                    Some(rewrapped_from.map_or(tail.start_line, |from| from.min(tail.start_line)));
+                start_index = tail_index;
+            }
+        }
+
+        if start_index == cells.len() {
+            return match rewrapped_from {
+                Some(from_line) => WrappedTranscriptUpdate::Rewrapped { from_line },
+                None => WrappedTranscriptUpdate::Unchanged,
+            };
         }
 
-        let old_cell_count = self.cell_count;
         self.cell_count = cells.len();
         self.first_cell_ptr = current_first_ptr;
+        self.append_cells(cells, start_index, width);
+
+        match rewrapped_from {
+            Some(from_line) => WrappedTranscriptUpdate::Rewrapped { from_line },
+            None => WrappedTranscriptUpdate::Appended,
+        }
+    }
+
+    /// Drop cached cells that were removed right after the first cell.
+    ///
+    /// A collapsed transcript keeps its summary as the first cell and folds the oldest kept cells
+    /// into it, so the second cell of `cells` shows up further down the cached list. The lines of
+    /// the cells before it are removed and later cell indices shift down; nothing is re-wrapped.
+    /// The cached tail is left for the regular tail check.
+    fn drop_folded_cells(&mut self, cells: &[Arc<dyn HistoryCell>]) -> FrontChange {
+        // With at most two cached cells, the second one is the tail, which the tail check
+        // re-wraps when it is replaced.
+        if self.cell_count <= 2 {
+            return FrontChange::Unchanged;
+        }
+        let Some(second) = cells.get(1) else {
+            return FrontChange::Mismatch;
+        };
+        let second_ptr = Arc::as_ptr(second).cast::<()>();
+        if self.wrapped_cells[1].cell_ptr == second_ptr {
+            return FrontChange::Unchanged;
+        }
+
+        let tail_index = self.cell_count - 1;
+        let Some(folded) = (2..=tail_index)
+            .find(|&idx| self.wrapped_cells[idx].cell_ptr == second_ptr)
+            .map(|idx| idx - 1)
+        else {
+            return FrontChange::Mismatch;
+        };
+        let kept_still_match = cells.len() >= self.cell_count - folded
+            && (folded + 1..tail_index).all(|idx| {
+                self.wrapped_cells[idx].cell_ptr == Arc::as_ptr(&cells[idx - folded]).cast::<()>()
+            });
+        // The spacer logic assumes the first cell emitted lines; otherwise a full rebuild decides
+        // where the first spacer goes.
+        let from_line = self.wrapped_cells[1].start_line;
+        if !kept_still_match || from_line == 0 {
+            return FrontChange::Mismatch;
+        }
+
+        let to_line = self.wrapped_cells[folded + 1].start_line;
+        let removed_lines = to_line - from_line;
+        self.transcript.lines.drain(from_line..to_line);
+        self.transcript.meta.drain(from_line..to_line);
+        self.transcript.joiner_before.drain(from_line..to_line);
+        for meta in &mut self.transcript.meta[from_line..] {
+            if let TranscriptLineMeta::CellLine { cell_index, .. } = meta {
+                *cell_index -= folded;
+            }
+        }
+        self.is_user_cell.drain(1..=folded);
+        self.wrapped_cells.drain(1..=folded);
+        for cell in &mut self.wrapped_cells[1..] {
+            cell.start_line -= removed_lines;
+        }
+        if let Some(tail) = &mut self.tail {
+            tail.start_line -= removed_lines;
+        }
+        self.cell_count -= folded;
+        FrontChange::Folded { from_line }
+    }
+
+    /// Wrap and append `cells[start_index..]`, remembering the last one as the new tail.
+    fn append_cells(&mut self, cells: &[Arc<dyn HistoryCell>], start_index: usize, width: u16) {
         let base_opts: crate::wrapping::RtOptions<'_> =
             crate::wrapping::RtOptions::new(width.max(1) as usize);
-        for (cell_index, cell) in cells.iter().enumerate().skip(old_cell_count) {
+        for (cell_index, cell) in cells.iter().enumerate().skip(start_index) {
+            let start_line = self.transcript.lines.len();
+            let had_emitted_lines = self.has_emitted_lines;
             self.is_user_cell
                 .push(cell.as_any().is::<UserHistoryCell>());
+            self.wrapped_cells.push(WrappedCell {
+                cell_ptr: Arc::as_ptr(cell).cast::<()>(),
+                start_line,
+            });
             crate::transcript_render::append_wrapped_transcript_cell(
                 &mut self.transcript,
                 &mut self.has_emitted_lines,
                 cell_index,
                 cell,
                 width,
+                self.cell_spacing,
                 &base_opts,
             );
+            if cell_index + 1 == cells.len() {
+                self.tail = Some(WrappedTail {
+                    cell_ptr: Arc::as_ptr(cell).cast::<()>(),
+                    content_hash: cell_content_hash(cell.as_ref(), width),
+                    start_line,
+                    had_emitted_lines,
+                });
+            }
         }
-
-        WrappedTranscriptUpdate::Appended
     }
 
     /// Rebuild the wrapped transcript cache from scratch.
@@ -360,21 +707,10 @@ impl WrappedTranscriptCache {
         self.has_emitted_lines = false;
         self.is_user_cell.clear();
         self.is_user_cell.reserve(cells.len());
-
-        let base_opts: crate::wrapping::RtOptions<'_> =
-            crate::wrapping::RtOptions::new(width.max(1) as usize);
-        for (cell_index, cell) in cells.iter().enumerate() {
-            self.is_user_cell
-                .push(cell.as_any().is::<UserHistoryCell>());
-            crate::transcript_render::append_wrapped_transcript_cell(
-                &mut self.transcript,
-                &mut self.has_emitted_lines,
-                cell_index,
-                cell,
-                width,
-                &base_opts,
-            );
-        }
+        self.wrapped_cells.clear();
+        self.wrapped_cells.reserve(cells.len());
+        self.tail = None;
+        self.append_cells(cells, 0, width);
     }
 }
 
@@ -400,6 +736,8 @@ struct TranscriptRasterCache {
     clock: u64,
     /// Version of the terminal palette used for the cached rows.
     palette_version: u64,
+    /// Theme supplying the user-row background baked into cached rows.
+    theme: Theme,
     /// Access log used for approximate LRU eviction.
     lru: VecDeque<(u64, u64)>,
     /// Cached rasterized rows by key.
@@ -425,6 +763,7 @@ impl TranscriptRasterCache {
             capacity: 0,
             clock: 0,
             palette_version: crate::terminal_palette::palette_version(),
+            theme: Theme::default(),
             lru: VecDeque::new(),
             rows: HashMap::new(),
         }
@@ -440,6 +779,14 @@ impl TranscriptRasterCache {
         self.clock = 0;
     }
 
+    /// Drop cached rows for `line_index` and later, keeping the rows before it.
+    ///
+    /// Used when the newest cell is re-wrapped: earlier line indices still map to the same
+    /// content, so their rasters remain valid.
+    fn invalidate_from(&mut self, line_index: usize) {
+        self.rows.retain(|key, _| key >> 1 < line_index as u64);
+    }
+
     /// Set the maximum number of cached rasterized rows.
     ///
     /// When set to 0, caching is disabled and any existing cached rows are dropped.
@@ -476,7 +823,7 @@ impl TranscriptRasterCache {
         }
 
         if self.capacity == 0 {
-            let cells = rasterize_line(line, row_area.width, is_user_row);
+            let cells = rasterize_line(line, row_area.width, is_user_row, &self.theme);
             copy_row(row_area, buf, &cells);
             return;
         }
@@ -490,7 +837,7 @@ impl TranscriptRasterCache {
             return;
         }
 
-        let cells = rasterize_line(line, row_area.width, is_user_row);
+        let cells = rasterize_line(line, row_area.width, is_user_row, &self.theme);
         copy_row(row_area, buf, &cells);
         self.rows.insert(
             key,
@@ -543,7 +890,8 @@ impl TranscriptRasterCache {
 /// We key by `line_index` (not by hashing line content) because:
 /// - it is effectively free in the draw loop
 /// - the wrapped transcript cache defines a stable `(index → Line)` mapping until the next rebuild
-/// - rebuilds clear the raster cache, so indices cannot alias across different transcripts
+/// - rebuilds clear the raster cache and re-wraps drop rows from the re-wrapped cell onward, so
+///   indices cannot alias across different transcripts
 ///
 /// `is_user_row` is included because user rows apply a row-wide base style that affects every cell.
 fn raster_key(line_index: usize, is_user_row: bool) -> u64 {
@@ -556,18 +904,19 @@ fn raster_key(line_index: usize, is_user_row: bool) -> u64 {
 /// rendering for the line (including grapheme segmentation) into a scratch buffer and then copies
 /// out the rendered cells.
 ///
-/// For user rows, we pre-fill the row with the base user style so the cached raster includes the
-/// full-width background, matching the viewport behavior.
+/// For user rows, we pre-fill the row with `theme`'s base user style so the cached raster includes
+/// the full-width background, matching the viewport behavior.
 fn rasterize_line(
     line: &Line<'static>,
     width: u16,
     is_user_row: bool,
+    theme: &Theme,
 ) -> Vec<ratatui::buffer::Cell> {
     let scratch_area = Rect::new(0, 0, width, 1);
     let mut scratch = Buffer::empty(scratch_area);
 
     if is_user_row {
-        let base_style = crate::style::user_message_style();
+        let base_style = crate::style::user_message_style(theme);
         for x in 0..width {
             scratch[(x, 0)].set_style(base_style);
         }
@@ -683,7 +1032,11 @@ mod tests {
         ];
 
         let width = 8;
-        let expected = crate::transcript_render::build_wrapped_transcript_lines(&cells, width);
+        let expected = crate::transcript_render::build_wrapped_transcript_lines(
+            &cells,
+            width,
+            crate::transcript_render::DEFAULT_CELL_SPACING,
+        );
 
         let mut cache = TranscriptViewCache::new();
         cache.ensure_wrapped(&cells, width);
@@ -701,6 +1054,159 @@ mod tests {
         );
     }
 
+    /// A cell whose content can grow in place, like an in-flight streamed message.
+    #[derive(Debug, Default)]
+    struct GrowingCell {
+        lines: std::sync::Mutex<Vec<Line<'static>>>,
+    }
+
+    impl GrowingCell {
+        fn push(&self, line: &'static str) {
+            self.lines
+                .lock()
+                .expect("lines lock")
+                .push(Line::from(line));
+        }
+    }
+
+    impl HistoryCell for GrowingCell {
+        fn display_lines(&self, _width: u16) -> Vec<Line<'static>> {
+            self.lines.lock().expect("lines lock").clone()
+        }
+    }
+
+    #[test]
+    fn cell_content_hash_is_stable_until_content_is_appended() {
+        let cell = GrowingCell::default();
+        cell.push("• streaming");
+
+        let first = cell_content_hash(&cell, 20);
+        assert_eq!(cell_content_hash(&cell, 20), first);
+
+        cell.push("  more output");
+        assert_ne!(cell_content_hash(&cell, 20), first);
+    }
+
+    #[test]
+    fn growing_tail_cell_is_rewrapped_without_touching_finalized_cells() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let growing = Arc::new(GrowingCell::default());
+        growing.push("• hello");
+        let cells: Vec<Arc<dyn HistoryCell>> = vec![
+            Arc::new(FakeCell::new(
+                vec![Line::from("• done")],
+                vec![None],
+                false,
+                calls.clone(),
+            )),
+            growing.clone(),
+        ];
+
+        let mut cache = TranscriptViewCache::new();
+        cache.set_raster_capacity(8);
+        cache.ensure_wrapped(&cells, 20);
+        let area = Rect::new(0, 0, 20, 1);
+        let mut buf = Buffer::empty(area);
+        for line_index in 0..cache.lines().len() {
+            cache.render_row_index_into(line_index, area, &mut buf);
+        }
+        assert_eq!(
+            cache.wrapped.ensure(&cells, 20),
+            WrappedTranscriptUpdate::Unchanged
+        );
+
+        growing.push("  world");
+        let tail_start = cache.wrapped.tail.as_ref().expect("tail").start_line;
+        cache.ensure_wrapped(&cells, 20);
+
+        let expected = crate::transcript_render::build_wrapped_transcript_lines(
+            &cells,
+            20,
+            crate::transcript_render::DEFAULT_CELL_SPACING,
+        );
+        assert_eq!(cache.lines(), expected.lines.as_slice());
+        assert_eq!(cache.line_meta(), expected.meta.as_slice());
+        assert_eq!(
+            calls.load(Ordering::Relaxed),
+            1,
+            "finalized cell not re-wrapped"
+        );
+        assert!(cache.raster.rows.contains_key(&raster_key(0, false)));
+        assert!(
+            !cache
+                .raster
+                .rows
+                .contains_key(&raster_key(tail_start, false))
+        );
+    }
+
+    #[test]
+    fn cells_folded_after_the_first_are_dropped_without_rewrapping() {
+        let calls: Vec<Arc<AtomicUsize>> = (0..5).map(|_| Arc::new(AtomicUsize::new(0))).collect();
+        let all: Vec<Arc<dyn HistoryCell>> = calls
+            .iter()
+            .enumerate()
+            .map(|(idx, calls)| {
+                Arc::new(FakeCell::new(
+                    vec![Line::from(format!("• cell {idx} wraps"))],
+                    vec![None],
+                    idx == 3,
+                    calls.clone(),
+                )) as Arc<dyn HistoryCell>
+            })
+            .collect();
+        let summary = all[0].clone();
+
+        let mut cache = TranscriptViewCache::new();
+        cache.set_raster_capacity(16);
+        let before = [summary.clone(), all[1].clone(), all[2].clone()];
+        cache.ensure_wrapped(&before, 10);
+        let area = Rect::new(0, 0, 10, 1);
+        let mut buf = Buffer::empty(area);
+        for line_index in 0..cache.lines().len() {
+            cache.render_row_index_into(line_index, area, &mut buf);
+        }
+
+        let after = [
+            summary.clone(),
+            all[2].clone(),
+            all[3].clone(),
+            all[4].clone(),
+        ];
+        let from_line = cache.wrapped.wrapped_cells[1].start_line;
+        cache.ensure_wrapped(&after, 10);
+
+        assert_eq!(
+            calls[0].load(Ordering::Relaxed),
+            1,
+            "summary not re-wrapped"
+        );
+        assert_eq!(
+            calls[2].load(Ordering::Relaxed),
+            1,
+            "kept cell not re-wrapped"
+        );
+        assert!(cache.raster.rows.contains_key(&raster_key(0, false)));
+        assert!(
+            !cache
+                .raster
+                .rows
+                .contains_key(&raster_key(from_line, false))
+        );
+
+        let expected = crate::transcript_render::build_wrapped_transcript_lines(
+            &after,
+            10,
+            crate::transcript_render::DEFAULT_CELL_SPACING,
+        );
+        assert_eq!(cache.lines(), expected.lines.as_slice());
+        assert_eq!(cache.line_meta(), expected.meta.as_slice());
+        assert_eq!(
+            cache.wrapped.transcript.joiner_before,
+            expected.joiner_before
+        );
+    }
+
     #[test]
     fn wrapped_cache_ensure_appends_only_new_cells_when_width_is_unchanged() {
         let calls0 = Arc::new(AtomicUsize::new(0));
@@ -797,7 +1303,11 @@ mod tests {
         assert_eq!(calls0.load(Ordering::Relaxed), 2);
         assert_eq!(calls1.load(Ordering::Relaxed), 2);
 
-        let expected = crate::transcript_render::build_wrapped_transcript_lines(&cells, 10);
+        let expected = crate::transcript_render::build_wrapped_transcript_lines(
+            &cells,
+            10,
+            crate::transcript_render::DEFAULT_CELL_SPACING,
+        );
         assert_eq!(cache.lines(), expected.lines.as_slice());
         assert_eq!(cache.line_meta(), expected.meta.as_slice());
         assert_eq!(
@@ -833,7 +1343,11 @@ mod tests {
         assert_eq!(calls0.load(Ordering::Relaxed), 2);
         assert_eq!(calls1.load(Ordering::Relaxed), 1);
 
-        let expected = crate::transcript_render::build_wrapped_transcript_lines(&cells[..1], 8);
+        let expected = crate::transcript_render::build_wrapped_transcript_lines(
+            &cells[..1],
+            8,
+            crate::transcript_render::DEFAULT_CELL_SPACING,
+        );
         assert_eq!(cache.lines(), expected.lines.as_slice());
         assert_eq!(cache.line_meta(), expected.meta.as_slice());
     }
@@ -938,7 +1452,7 @@ mod tests {
         let area = Rect::new(0, 0, width, 1);
         let mut scratch = Buffer::empty(area);
         if is_user_row {
-            let base_style = crate::style::user_message_style();
+            let base_style = crate::style::user_message_style(&Theme::default());
             for x in 0..width {
                 scratch[(x, 0)].set_style(base_style);
             }
@@ -952,10 +1466,10 @@ mod tests {
         let width = 12;
         let line = Line::from(vec!["hello".into(), " ".into(), "world".magenta()]);
 
-        let non_user = rasterize_line(&line, width, false);
+        let non_user = rasterize_line(&line, width, false, &Theme::default());
         assert_eq!(non_user, direct_render_cells(&line, width, false));
 
-        let user = rasterize_line(&line, width, true);
+        let user = rasterize_line(&line, width, true, &Theme::default());
         assert_eq!(user, direct_render_cells(&line, width, true));
     }
 
@@ -1018,6 +1532,7 @@ mod tests {
         let mut cache = TranscriptViewCache::new();
         let cells: Vec<Arc<dyn HistoryCell>> = vec![Arc::new(UserHistoryCell {
             message: "hello".to_string(),
+            theme: Theme::default(),
         })];
 
         cache.ensure_wrapped(&cells, 20);
@@ -1030,4 +1545,110 @@ mod tests {
         assert!(cache.is_user_row(0));
         assert!(cache.raster.rows.contains_key(&raster_key(0, true)));
     }
+
+    fn draw_frame(
+        cache: &mut TranscriptViewCache,
+        diff: &mut TranscriptRowDiff,
+        area: Rect,
+    ) -> Vec<usize> {
+        diff.begin_frame_with_palette(area, 0);
+        let mut buf = Buffer::empty(area);
+        let keys: Vec<TranscriptRowKey> = (0..cache.lines().len().min(area.height as usize))
+            .map(|line_index| cache.row_key(line_index))
+            .collect();
+        let dirty = diff.dirty_rows(&keys);
+        for (row, key) in keys.into_iter().enumerate() {
+            if dirty.binary_search(&row).is_ok() || !diff.restore_row(row, &mut buf) {
+                let row_area = Rect::new(area.x, area.y + row as u16, area.width, 1);
+                cache.render_row_index_into(key.line_index, row_area, &mut buf);
+                diff.record_row(row, key, &buf);
+            }
+        }
+        dirty
+    }
+
+    #[test]
+    fn row_diff_marks_only_appended_tail_rows_dirty() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut cells: Vec<Arc<dyn HistoryCell>> = vec![
+            Arc::new(FakeCell::new(
+                vec![Line::from("• one"), Line::from("two")],
+                vec![None, None],
+                false,
+                calls.clone(),
+            )),
+            Arc::new(FakeCell::new(
+                vec![Line::from("• three")],
+                vec![None],
+                false,
+                calls.clone(),
+            )),
+        ];
+        let area = Rect::new(0, 0, 20, 8);
+        let mut cache = TranscriptViewCache::new();
+        let mut diff = TranscriptRowDiff::new();
+
+        cache.ensure_wrapped(&cells, area.width);
+        assert_eq!(draw_frame(&mut cache, &mut diff, area), vec![0, 1, 2, 3]);
+        assert_eq!(draw_frame(&mut cache, &mut diff, area), Vec::<usize>::new());
+
+        // A streamed continuation extends the last cell without a spacer.
+        cells.push(Arc::new(FakeCell::new(
+            vec![Line::from("four"), Line::from("five")],
+            vec![None, None],
+            true,
+            calls,
+        )));
+        cache.ensure_wrapped(&cells, area.width);
+        assert_eq!(draw_frame(&mut cache, &mut diff, area), vec![4, 5]);
+    }
+
+    #[test]
+    fn row_diff_repaints_everything_after_area_change_or_scroll() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let cells: Vec<Arc<dyn HistoryCell>> = vec![Arc::new(FakeCell::new(
+            vec![Line::from("a"), Line::from("b"), Line::from("c")],
+            vec![None, None, None],
+            false,
+            calls,
+        ))];
+        let mut cache = TranscriptViewCache::new();
+        let mut diff = TranscriptRowDiff::new();
+        cache.ensure_wrapped(&cells, 10);
+
+        let area = Rect::new(0, 0, 10, 2);
+        draw_frame(&mut cache, &mut diff, area);
+        let keys = [cache.row_key(0), cache.row_key(1)];
+        assert_eq!(diff.dirty_rows(&keys), Vec::<usize>::new());
+
+        // Scrolling by one line changes the line shown on every row.
+        let scrolled = [cache.row_key(1), cache.row_key(2)];
+        assert_eq!(diff.dirty_rows(&scrolled), vec![0, 1]);
+
+        diff.begin_frame_with_palette(Rect::new(0, 0, 10, 3), 0);
+        assert_eq!(diff.dirty_rows(&keys), vec![0, 1]);
+    }
+
+    #[test]
+    fn row_diff_restores_recorded_cells() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let cells: Vec<Arc<dyn HistoryCell>> = vec![Arc::new(FakeCell::new(
+            vec![Line::from("hello")],
+            vec![None],
+            false,
+            calls,
+        ))];
+        let mut cache = TranscriptViewCache::new();
+        let mut diff = TranscriptRowDiff::new();
+        cache.ensure_wrapped(&cells, 10);
+
+        let area = Rect::new(0, 0, 10, 1);
+        draw_frame(&mut cache, &mut diff, area);
+
+        let mut rendered = Buffer::empty(area);
+        cache.render_row_index_into(0, area, &mut rendered);
+        let mut restored = Buffer::empty(area);
+        assert!(diff.restore_row(0, &mut restored));
+        assert_eq!(restored, rendered);
+    }
 }
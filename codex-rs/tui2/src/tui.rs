@@ -126,6 +126,8 @@ pub enum TuiEvent {
     Paste(String),
     Draw,
     Mouse(crossterm::event::MouseEvent),
+    /// The terminal window gained (`true`) or lost (`false`) focus.
+    FocusChanged(bool),
 }
 
 pub struct Tui {
@@ -181,6 +183,16 @@ impl Tui {
         self.enhanced_keys_supported
     }
 
+    /// Whether the session is currently drawing on the alternate screen.
+    pub fn is_alt_screen_active(&self) -> bool {
+        self.alt_screen_active.load(Ordering::Relaxed)
+    }
+
+    /// Whether the terminal/tab currently has focus (assumed true until told otherwise).
+    pub fn is_terminal_focused(&self) -> bool {
+        self.terminal_focused.load(Ordering::Relaxed)
+    }
+
     /// Emit a desktop notification now if the terminal is unfocused.
     /// Returns true if a notification was posted.
     pub fn notify(&mut self, message: impl AsRef<str>) -> bool {
@@ -270,10 +282,11 @@ impl Tui {
                                     Event::FocusGained => {
                                         terminal_focused.store(true, Ordering::Relaxed);
                                         crate::terminal_palette::requery_default_colors();
-                                        yield TuiEvent::Draw;
+                                        yield TuiEvent::FocusChanged(true);
                                     }
                                     Event::FocusLost => {
                                         terminal_focused.store(false, Ordering::Relaxed);
+                                        yield TuiEvent::FocusChanged(false);
                                     }
                                 }
                             }
@@ -12,6 +12,9 @@
 //! [“Actors with Tokio”](https://ryhl.io/blog/actors-with-tokio/), with a
 //! dedicated scheduler task and lightweight request handles.
 
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
 use std::time::Instant;
 
@@ -30,8 +33,13 @@ use super::frame_rate_limiter::FrameRateLimiter;
 #[derive(Clone, Debug)]
 pub struct FrameRequester {
     frame_schedule_tx: mpsc::UnboundedSender<Instant>,
+    /// Shared by all clones; see [`FrameRequester::set_low_power`].
+    low_power: Arc<AtomicBool>,
 }
 
+/// Shortest delay honored for [`FrameRequester::schedule_frame_in`] while in low-power mode.
+pub(crate) const LOW_POWER_MIN_FRAME_DELAY: Duration = Duration::from_secs(1);
+
 impl FrameRequester {
     /// Create a new FrameRequester and spawn its associated FrameScheduler task.
     ///
@@ -42,6 +50,7 @@ impl FrameRequester {
         tokio::spawn(scheduler.run());
         Self {
             frame_schedule_tx: tx,
+            low_power: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -51,9 +60,27 @@ impl FrameRequester {
     }
 
     /// Schedule a frame draw to occur after the specified duration.
+    ///
+    /// In low-power mode the delay is stretched to at least [`LOW_POWER_MIN_FRAME_DELAY`], so
+    /// periodic redraws (animations, follow-up ticks) back off while immediate draws requested
+    /// through [`Self::schedule_frame`] are unaffected.
     pub fn schedule_frame_in(&self, dur: Duration) {
+        let dur = if self.is_low_power() {
+            dur.max(LOW_POWER_MIN_FRAME_DELAY)
+        } else {
+            dur
+        };
         let _ = self.frame_schedule_tx.send(Instant::now() + dur);
     }
+
+    /// Enter or leave low-power mode (`tui.low_power_idle`) for every clone of this requester.
+    pub(crate) fn set_low_power(&self, low_power: bool) {
+        self.low_power.store(low_power, Ordering::Relaxed);
+    }
+
+    pub(crate) fn is_low_power(&self) -> bool {
+        self.low_power.load(Ordering::Relaxed)
+    }
 }
 
 #[cfg(test)]
@@ -63,6 +90,7 @@ impl FrameRequester {
         let (tx, _rx) = mpsc::unbounded_channel();
         FrameRequester {
             frame_schedule_tx: tx,
+            low_power: Arc::new(AtomicBool::new(false)),
         }
     }
 }
@@ -156,6 +184,42 @@ mod tests {
         assert!(second.is_err(), "unexpected extra draw received");
     }
 
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn low_power_backs_off_delayed_frames_but_not_immediate_ones() {
+        let (draw_tx, mut draw_rx) = broadcast::channel(16);
+        let requester = FrameRequester::new(draw_tx);
+        requester.set_low_power(true);
+
+        // A periodic animation frame is pushed out to the low-power minimum.
+        requester.schedule_frame_in(Duration::from_millis(32));
+        time::advance(Duration::from_millis(500)).await;
+        let early = draw_rx.recv().timeout(Duration::from_millis(10)).await;
+        assert!(early.is_err(), "periodic frame fired in low-power mode");
+
+        // Input still draws right away.
+        requester.schedule_frame();
+        time::advance(Duration::from_millis(1)).await;
+        let immediate = draw_rx
+            .recv()
+            .timeout(Duration::from_millis(50))
+            .await
+            .expect("timed out waiting for immediate draw");
+        assert!(immediate.is_ok(), "broadcast closed unexpectedly");
+
+        // Leaving low-power mode restores the requested delay.
+        time::advance(LOW_POWER_MIN_FRAME_DELAY).await;
+        while draw_rx.try_recv().is_ok() {}
+        requester.set_low_power(false);
+        requester.schedule_frame_in(Duration::from_millis(32));
+        time::advance(Duration::from_millis(40)).await;
+        let resumed = draw_rx
+            .recv()
+            .timeout(Duration::from_millis(50))
+            .await
+            .expect("timed out waiting for resumed draw");
+        assert!(resumed.is_ok(), "broadcast closed unexpectedly");
+    }
+
     #[tokio::test(flavor = "current_thread", start_paused = true)]
     async fn test_schedule_frame_in_triggers_at_delay() {
         let (draw_tx, mut draw_rx) = broadcast::channel(16);
@@ -24,6 +24,8 @@ pub(crate) use mouse::MouseScrollState;
 pub(crate) use mouse::ScrollConfig;
 pub(crate) use mouse::ScrollConfigOverrides;
 pub(crate) use mouse::ScrollDirection;
+pub(crate) use mouse::ScrollOverrideAdjustment;
+pub(crate) use mouse::ScrollTuning;
 pub(crate) use mouse::ScrollUpdate;
 
 /// Per-flattened-line metadata for the transcript view.
@@ -281,11 +283,53 @@ fn anchor_at_or_before(line_meta: &[TranscriptLineMeta], start: usize) -> Option
         .find_map(TranscriptLineMeta::cell_line)
 }
 
+/// An edge of the transcript that the viewport can rest against.
+///
+/// Used to give "rubber-band" feedback when a scroll attempt cannot move any further.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TranscriptEdge {
+    Top,
+    Bottom,
+}
+
+impl TranscriptEdge {
+    /// Return the edge the viewport is resting on, if any.
+    ///
+    /// `view_top` is the resolved top row (see [`TranscriptScroll::resolve_top`]) and
+    /// `visible_lines` the viewport height. Transcripts that fit entirely in the viewport have no
+    /// scrollable range, so they report no edge.
+    pub(crate) fn at(view_top: usize, total_lines: usize, visible_lines: usize) -> Option<Self> {
+        if visible_lines == 0 || total_lines <= visible_lines {
+            return None;
+        }
+        let max_start = total_lines - visible_lines;
+        if view_top == 0 {
+            Some(Self::Top)
+        } else if view_top >= max_start {
+            Some(Self::Bottom)
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn transcript_edge_detects_top_and_bottom() {
+        assert_eq!(TranscriptEdge::at(0, 10, 4), Some(TranscriptEdge::Top));
+        assert_eq!(TranscriptEdge::at(3, 10, 4), None);
+        assert_eq!(TranscriptEdge::at(6, 10, 4), Some(TranscriptEdge::Bottom));
+
+        // Nothing to scroll: no edge feedback.
+        assert_eq!(TranscriptEdge::at(0, 4, 4), None);
+        assert_eq!(TranscriptEdge::at(0, 0, 4), None);
+        assert_eq!(TranscriptEdge::at(0, 10, 0), None);
+    }
+
     fn meta(entries: &[TranscriptLineMeta]) -> Vec<TranscriptLineMeta> {
         entries.to_vec()
     }
@@ -17,7 +17,9 @@
 //!
 //! See `codex-rs/tui2/docs/scroll_input_model.md` for the data-derived constants and analysis.
 
+use codex_core::config::edit::ConfigEditsBuilder;
 use codex_core::config::types::ScrollInputMode;
+use codex_core::config::types::TrackpadAccelCurve;
 use codex_core::terminal::TerminalInfo;
 use codex_core::terminal::TerminalName;
 use std::time::Duration;
@@ -39,6 +41,14 @@ const MAX_EVENTS_PER_STREAM: usize = 256;
 const MAX_ACCUMULATED_LINES: i32 = 256;
 const MIN_LINES_PER_WHEEL_STREAM: i32 = 1;
 
+// Upper bounds for user overrides. Values past these are almost certainly typos: they make one
+// scroll event jump the whole transcript, or make scrolling and wheel detection never trigger.
+const MAX_EVENTS_PER_TICK_OVERRIDE: u16 = 64;
+const MAX_LINES_PER_TICK_OVERRIDE: u16 = 100;
+const MAX_TRACKPAD_ACCEL_EVENTS_OVERRIDE: u16 = 1_000;
+const MAX_TRACKPAD_ACCEL_MAX_OVERRIDE: u16 = 20;
+const MAX_STREAM_TIMING_OVERRIDE_MS: u64 = 1_000;
+
 fn default_wheel_tick_detect_max_ms_for_terminal(name: TerminalName) -> u64 {
     // This threshold is only used for the "promote to wheel-like" fast path in auto mode.
     // We keep it per-terminal because some terminals emit wheel ticks spread over tens of
@@ -133,6 +143,11 @@ pub(crate) struct ScrollConfig {
     /// Set to 1 to effectively disable acceleration.
     trackpad_accel_max: u16,
 
+    /// Trackpad acceleration: shape of the multiplier as a function of stream length.
+    ///
+    /// User-facing name: `tui.scroll_trackpad_accel_curve`.
+    trackpad_accel_curve: TrackpadAccelCurve,
+
     /// Force wheel/trackpad behavior, or infer it per stream.
     mode: ScrollInputMode,
 
@@ -168,19 +183,147 @@ pub(crate) struct ScrollConfigOverrides {
     pub(crate) trackpad_lines_per_tick: Option<u16>,
     pub(crate) trackpad_accel_events: Option<u16>,
     pub(crate) trackpad_accel_max: Option<u16>,
+    pub(crate) trackpad_accel_curve: Option<TrackpadAccelCurve>,
     pub(crate) mode: Option<ScrollInputMode>,
     pub(crate) wheel_tick_detect_max_ms: Option<u64>,
     pub(crate) wheel_like_max_duration_ms: Option<u64>,
     pub(crate) invert_direction: bool,
 }
 
+/// A live adjustment from the `/scroll` popup.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ScrollTuning {
+    EventsPerTick(i32),
+    WheelLinesPerTick(i32),
+    ToggleInvert,
+}
+
+/// A scroll override from `config.toml` that was out of range and replaced by the nearest
+/// supported value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ScrollOverrideAdjustment {
+    /// User-facing config key, e.g. `tui.scroll_events_per_tick`.
+    pub(crate) key: &'static str,
+    pub(crate) configured: u64,
+    pub(crate) applied: u64,
+}
+
+impl ScrollOverrideAdjustment {
+    /// One-line summary of several adjustments for the startup info message.
+    pub(crate) fn summary(adjustments: &[Self]) -> Option<String> {
+        if adjustments.is_empty() {
+            return None;
+        }
+        let items: Vec<String> = adjustments
+            .iter()
+            .map(|adjustment| {
+                format!(
+                    "{} = {} (using {})",
+                    adjustment.key, adjustment.configured, adjustment.applied
+                )
+            })
+            .collect();
+        Some(format!(
+            "Adjusted out-of-range scroll settings: {}.",
+            items.join(", ")
+        ))
+    }
+}
+
+fn clamp_override<T>(
+    key: &'static str,
+    value: Option<T>,
+    min: T,
+    max: T,
+    adjustments: &mut Vec<ScrollOverrideAdjustment>,
+) -> Option<T>
+where
+    T: Copy + Ord + Into<u64>,
+{
+    let value = value?;
+    let applied = value.clamp(min, max);
+    if applied != value {
+        adjustments.push(ScrollOverrideAdjustment {
+            key,
+            configured: value.into(),
+            applied: applied.into(),
+        });
+    }
+    Some(applied)
+}
+
+impl ScrollConfigOverrides {
+    /// Clamp numeric overrides into their supported ranges.
+    ///
+    /// Returns the clamped overrides along with one [`ScrollOverrideAdjustment`] per value that
+    /// changed, so callers can tell the user their configured value was not used as-is.
+    pub(crate) fn validated(self) -> (Self, Vec<ScrollOverrideAdjustment>) {
+        let mut adjustments = Vec::new();
+        let validated = Self {
+            events_per_tick: clamp_override(
+                "tui.scroll_events_per_tick",
+                self.events_per_tick,
+                1,
+                MAX_EVENTS_PER_TICK_OVERRIDE,
+                &mut adjustments,
+            ),
+            wheel_lines_per_tick: clamp_override(
+                "tui.scroll_wheel_lines",
+                self.wheel_lines_per_tick,
+                1,
+                MAX_LINES_PER_TICK_OVERRIDE,
+                &mut adjustments,
+            ),
+            trackpad_lines_per_tick: clamp_override(
+                "tui.scroll_trackpad_lines",
+                self.trackpad_lines_per_tick,
+                1,
+                MAX_LINES_PER_TICK_OVERRIDE,
+                &mut adjustments,
+            ),
+            trackpad_accel_events: clamp_override(
+                "tui.scroll_trackpad_accel_events",
+                self.trackpad_accel_events,
+                1,
+                MAX_TRACKPAD_ACCEL_EVENTS_OVERRIDE,
+                &mut adjustments,
+            ),
+            trackpad_accel_max: clamp_override(
+                "tui.scroll_trackpad_accel_max",
+                self.trackpad_accel_max,
+                1,
+                MAX_TRACKPAD_ACCEL_MAX_OVERRIDE,
+                &mut adjustments,
+            ),
+            wheel_tick_detect_max_ms: clamp_override(
+                "tui.scroll_wheel_tick_detect_max_ms",
+                self.wheel_tick_detect_max_ms,
+                0,
+                MAX_STREAM_TIMING_OVERRIDE_MS,
+                &mut adjustments,
+            ),
+            wheel_like_max_duration_ms: clamp_override(
+                "tui.scroll_wheel_like_max_duration_ms",
+                self.wheel_like_max_duration_ms,
+                0,
+                MAX_STREAM_TIMING_OVERRIDE_MS,
+                &mut adjustments,
+            ),
+            ..self
+        };
+        (validated, adjustments)
+    }
+}
+
 impl ScrollConfig {
     /// Derive scroll normalization defaults from detected terminal metadata.
     ///
     /// This uses [`TerminalInfo`] (in particular [`TerminalName`]) to pick an empirically derived
     /// `events_per_line` default. Users can override both `events_per_line` and the per-wheel-tick
-    /// multiplier via `config.toml` (see [`ScrollConfig`] docs).
+    /// multiplier via `config.toml` (see [`ScrollConfig`] docs). Out-of-range overrides are
+    /// clamped (see [`ScrollConfigOverrides::validated`]).
     pub(crate) fn from_terminal(terminal: &TerminalInfo, overrides: ScrollConfigOverrides) -> Self {
+        let (overrides, _) = overrides.validated();
         let mut events_per_tick = match terminal.name {
             TerminalName::AppleTerminal => 3,
             TerminalName::WarpTerminal => 9,
@@ -194,27 +337,27 @@ impl ScrollConfig {
         };
 
         if let Some(override_value) = overrides.events_per_tick {
-            events_per_tick = override_value.max(1);
+            events_per_tick = override_value;
         }
 
         let mut wheel_lines_per_tick = DEFAULT_WHEEL_LINES_PER_TICK;
         if let Some(override_value) = overrides.wheel_lines_per_tick {
-            wheel_lines_per_tick = override_value.max(1);
+            wheel_lines_per_tick = override_value;
         }
 
         let mut trackpad_lines_per_tick = DEFAULT_TRACKPAD_LINES_PER_TICK;
         if let Some(override_value) = overrides.trackpad_lines_per_tick {
-            trackpad_lines_per_tick = override_value.max(1);
+            trackpad_lines_per_tick = override_value;
         }
 
         let mut trackpad_accel_events = DEFAULT_TRACKPAD_ACCEL_EVENTS;
         if let Some(override_value) = overrides.trackpad_accel_events {
-            trackpad_accel_events = override_value.max(1);
+            trackpad_accel_events = override_value;
         }
 
         let mut trackpad_accel_max = DEFAULT_TRACKPAD_ACCEL_MAX;
         if let Some(override_value) = overrides.trackpad_accel_max {
-            trackpad_accel_max = override_value.max(1);
+            trackpad_accel_max = override_value;
         }
 
         let wheel_tick_detect_max_ms = overrides
@@ -233,6 +376,7 @@ impl ScrollConfig {
             trackpad_lines_per_tick,
             trackpad_accel_events,
             trackpad_accel_max,
+            trackpad_accel_curve: overrides.trackpad_accel_curve.unwrap_or_default(),
             mode: overrides.mode.unwrap_or(DEFAULT_SCROLL_MODE),
             wheel_tick_detect_max,
             wheel_like_max_duration,
@@ -270,6 +414,20 @@ impl ScrollConfig {
         self.trackpad_accel_max.max(1) as f32
     }
 
+    /// Acceleration multiplier for a trackpad stream of `event_count` events.
+    ///
+    /// Every curve is 1x for an empty stream and stays close to 1x for short swipes; they differ
+    /// only in how quickly long swipes approach `trackpad_accel_max`.
+    fn trackpad_accel_multiplier(self, event_count: f32) -> f32 {
+        let x = event_count / self.trackpad_accel_events_f32();
+        let multiplier = match self.trackpad_accel_curve {
+            TrackpadAccelCurve::Linear => 1.0 + x,
+            TrackpadAccelCurve::Quadratic => 1.0 + x * x,
+            TrackpadAccelCurve::Exponential => x.exp(),
+        };
+        multiplier.clamp(1.0, self.trackpad_accel_max_f32())
+    }
+
     fn apply_direction(self, direction: ScrollDirection) -> ScrollDirection {
         if self.invert_direction {
             direction.inverted()
@@ -279,6 +437,48 @@ impl ScrollConfig {
     }
 }
 
+impl ScrollConfig {
+    /// Apply a `/scroll` adjustment, keeping values within the ranges accepted from `config.toml`.
+    pub(crate) fn tune(&mut self, tuning: ScrollTuning) {
+        let nudge = |value: u16, delta: i32, max: u16| {
+            (i32::from(value) + delta).clamp(1, i32::from(max)) as u16
+        };
+        match tuning {
+            ScrollTuning::EventsPerTick(delta) => {
+                self.events_per_tick =
+                    nudge(self.events_per_tick, delta, MAX_EVENTS_PER_TICK_OVERRIDE);
+            }
+            ScrollTuning::WheelLinesPerTick(delta) => {
+                self.wheel_lines_per_tick = nudge(
+                    self.wheel_lines_per_tick,
+                    delta,
+                    MAX_LINES_PER_TICK_OVERRIDE,
+                );
+            }
+            ScrollTuning::ToggleInvert => self.invert_direction = !self.invert_direction,
+        }
+    }
+
+    /// One-line summary of the values `/scroll` can change.
+    pub(crate) fn tuning_summary(&self) -> String {
+        format!(
+            "events per tick: {} · wheel lines per tick: {} · inverted: {}",
+            self.events_per_tick,
+            self.wheel_lines_per_tick,
+            if self.invert_direction { "yes" } else { "no" }
+        )
+    }
+
+    /// Add the `config.toml` edits that persist the values `/scroll` can change.
+    pub(crate) fn with_tuning_edits(&self, builder: ConfigEditsBuilder) -> ConfigEditsBuilder {
+        builder.set_tui_scroll_tuning(
+            self.events_per_tick,
+            self.wheel_lines_per_tick,
+            self.invert_direction,
+        )
+    }
+}
+
 impl Default for ScrollConfig {
     fn default() -> Self {
         Self {
@@ -287,6 +487,7 @@ impl Default for ScrollConfig {
             trackpad_lines_per_tick: DEFAULT_TRACKPAD_LINES_PER_TICK,
             trackpad_accel_events: DEFAULT_TRACKPAD_ACCEL_EVENTS,
             trackpad_accel_max: DEFAULT_TRACKPAD_ACCEL_MAX,
+            trackpad_accel_curve: TrackpadAccelCurve::default(),
             mode: DEFAULT_SCROLL_MODE,
             wheel_tick_detect_max: Duration::from_millis(DEFAULT_WHEEL_TICK_DETECT_MAX_MS),
             wheel_like_max_duration: Duration::from_millis(DEFAULT_WHEEL_LIKE_MAX_DURATION_MS),
@@ -436,6 +637,11 @@ impl MouseScrollState {
         }
     }
 
+    /// Whether no scroll stream is in progress (so no follow-up ticks are pending).
+    pub(crate) fn is_idle(&self) -> bool {
+        self.stream.is_none()
+    }
+
     /// Check whether an active stream has ended based on the current time.
     pub(crate) fn on_tick(&mut self) -> ScrollUpdate {
         self.on_tick_at(Instant::now())
@@ -490,6 +696,7 @@ impl MouseScrollState {
         let lines = Self::flush_lines_at(&mut self.last_redraw_at, self.carry_lines, now, stream);
 
         // Preserve sub-line fractional scroll for trackpad-like streams across stream boundaries.
+        // (`finalize_kind` marks wheel and precise streams as `Wheel`, so they never carry.)
         if stream.kind != ScrollStreamKind::Wheel && stream.config.mode != ScrollInputMode::Wheel {
             self.carry_lines =
                 stream.desired_lines_f32(self.carry_lines) - stream.applied_lines as f32;
@@ -542,9 +749,14 @@ impl MouseScrollState {
     /// - **Trackpad coalescing**: if whole lines are pending but we haven't hit
     ///   [`REDRAW_CADENCE`] yet, we schedule an earlier tick so the viewport updates promptly.
     ///
-    /// Returning `None` means no stream is active (or it is already past the gap threshold).
+    /// Returning `None` means no stream is active (or it is already past the gap threshold). In
+    /// precise mode every event is applied immediately and nothing carries over, so no follow-up
+    /// tick is ever needed; the stale stream is simply closed by the next event.
     fn next_tick_in(&self, now: Instant) -> Option<Duration> {
         let stream = self.stream.as_ref()?;
+        if stream.config.mode == ScrollInputMode::Precise {
+            return None;
+        }
         let gap = now.duration_since(stream.last);
         if gap > STREAM_GAP {
             return None;
@@ -683,7 +895,9 @@ impl ScrollStream {
     /// the expected multi-line notch behavior.
     fn finalize_kind(&mut self) {
         match self.config.mode {
-            ScrollInputMode::Wheel => self.kind = ScrollStreamKind::Wheel,
+            ScrollInputMode::Wheel | ScrollInputMode::Precise => {
+                self.kind = ScrollStreamKind::Wheel;
+            }
             ScrollInputMode::Trackpad => self.kind = ScrollStreamKind::Trackpad,
             ScrollInputMode::Auto => {
                 if self.kind != ScrollStreamKind::Unknown {
@@ -711,9 +925,11 @@ impl ScrollStream {
     /// In auto mode, streams are wheel-like only after we promote them (or after the 1-event
     /// fallback triggers on finalization). While `kind` is still unknown, we treat the stream as
     /// trackpad-like to avoid overshooting.
+    ///
+    /// Precise mode counts as wheel-like so every event flushes immediately with no carry.
     fn is_wheel_like(&self) -> bool {
         match self.config.mode {
-            ScrollInputMode::Wheel => true,
+            ScrollInputMode::Wheel | ScrollInputMode::Precise => true,
             ScrollInputMode::Trackpad => false,
             ScrollInputMode::Auto => matches!(self.kind, ScrollStreamKind::Wheel),
         }
@@ -724,6 +940,7 @@ impl ScrollStream {
     /// In auto mode, unknown streams use the trackpad factor until promoted.
     fn effective_lines_per_tick_f32(&self) -> f32 {
         match self.config.mode {
+            ScrollInputMode::Precise => 1.0,
             ScrollInputMode::Wheel => self.config.wheel_lines_per_tick_f32(),
             ScrollInputMode::Trackpad => self.config.trackpad_lines_per_tick_f32(),
             ScrollInputMode::Auto => match self.kind {
@@ -741,11 +958,14 @@ impl ScrollStream {
     ///
     /// - Wheel-like: `lines = events * (wheel_lines_per_tick / events_per_tick)`
     /// - Trackpad-like: `lines = events * (trackpad_lines_per_tick / min(events_per_tick, 3))`
+    /// - Precise: `lines = events`
     ///
     /// For trackpad-like streams we also add `carry_lines` (fractional remainder from previous
     /// streams) and then apply bounded acceleration. The returned value is clamped as a guardrail.
     fn desired_lines_f32(&self, carry_lines: f32) -> f32 {
-        let events_per_tick = if self.is_wheel_like() {
+        let events_per_tick = if self.config.mode == ScrollInputMode::Precise {
+            1.0
+        } else if self.is_wheel_like() {
             self.config.events_per_tick_f32()
         } else {
             self.config.trackpad_events_per_tick_f32()
@@ -767,8 +987,7 @@ impl ScrollStream {
             // Trackpad acceleration: keep small swipes precise, but speed up large/fast swipes so
             // they can cover more content. This is intentionally simple and bounded.
             let event_count = self.accumulated_events.abs() as f32;
-            let accel = (1.0 + (event_count / self.config.trackpad_accel_events_f32()))
-                .clamp(1.0, self.config.trackpad_accel_max_f32());
+            let accel = self.config.trackpad_accel_multiplier(event_count);
             total = (total * accel).clamp(
                 -(MAX_ACCUMULATED_LINES as f32),
                 MAX_ACCUMULATED_LINES as f32,
@@ -819,6 +1038,80 @@ mod tests {
         assert_eq!(unknown.events_per_tick, DEFAULT_EVENTS_PER_TICK);
     }
 
+    #[test]
+    fn out_of_range_overrides_are_clamped_and_reported() {
+        let (overrides, adjustments) = ScrollConfigOverrides {
+            events_per_tick: Some(0),
+            wheel_lines_per_tick: Some(5),
+            trackpad_accel_max: Some(u16::MAX),
+            wheel_like_max_duration_ms: Some(60_000),
+            ..ScrollConfigOverrides::default()
+        }
+        .validated();
+
+        assert_eq!(overrides.events_per_tick, Some(1));
+        assert_eq!(overrides.wheel_lines_per_tick, Some(5));
+        assert_eq!(
+            overrides.trackpad_accel_max,
+            Some(MAX_TRACKPAD_ACCEL_MAX_OVERRIDE)
+        );
+        assert_eq!(
+            overrides.wheel_like_max_duration_ms,
+            Some(MAX_STREAM_TIMING_OVERRIDE_MS)
+        );
+        assert_eq!(
+            adjustments,
+            vec![
+                ScrollOverrideAdjustment {
+                    key: "tui.scroll_events_per_tick",
+                    configured: 0,
+                    applied: 1,
+                },
+                ScrollOverrideAdjustment {
+                    key: "tui.scroll_trackpad_accel_max",
+                    configured: u64::from(u16::MAX),
+                    applied: u64::from(MAX_TRACKPAD_ACCEL_MAX_OVERRIDE),
+                },
+                ScrollOverrideAdjustment {
+                    key: "tui.scroll_wheel_like_max_duration_ms",
+                    configured: 60_000,
+                    applied: MAX_STREAM_TIMING_OVERRIDE_MS,
+                },
+            ]
+        );
+        assert_eq!(
+            ScrollOverrideAdjustment::summary(&adjustments[..1]).as_deref(),
+            Some(
+                "Adjusted out-of-range scroll settings: tui.scroll_events_per_tick = 0 (using 1)."
+            )
+        );
+        assert_eq!(ScrollOverrideAdjustment::summary(&[]), None);
+
+        // `from_terminal` applies the same clamping on its own.
+        let config = ScrollConfig::from_terminal(
+            &terminal_info_named(TerminalName::Unknown),
+            ScrollConfigOverrides {
+                events_per_tick: Some(0),
+                ..ScrollConfigOverrides::default()
+            },
+        );
+        assert_eq!(config.events_per_tick, 1);
+    }
+
+    #[test]
+    fn in_range_overrides_are_not_reported() {
+        let (overrides, adjustments) = ScrollConfigOverrides {
+            events_per_tick: Some(9),
+            trackpad_accel_events: Some(30),
+            wheel_tick_detect_max_ms: Some(0),
+            ..ScrollConfigOverrides::default()
+        }
+        .validated();
+
+        assert_eq!(overrides.events_per_tick, Some(9));
+        assert_eq!(adjustments, Vec::new());
+    }
+
     #[test]
     fn wheel_tick_scrolls_three_lines_even_when_terminal_emits_three_events() {
         let config = ScrollConfig::from_terminal(
@@ -982,6 +1275,58 @@ mod tests {
         assert!(total_lines >= 30, "total_lines={total_lines}");
     }
 
+    #[test]
+    fn exponential_accel_curve_covers_more_than_linear_for_large_swipes() {
+        let lines_for = |curve: TrackpadAccelCurve, events: u64| {
+            let config = ScrollConfig::from_terminal(
+                &terminal_info_named(TerminalName::Ghostty),
+                ScrollConfigOverrides {
+                    events_per_tick: Some(3),
+                    trackpad_accel_events: Some(30),
+                    trackpad_accel_max: Some(10),
+                    trackpad_accel_curve: Some(curve),
+                    mode: Some(ScrollInputMode::Trackpad),
+                    ..ScrollConfigOverrides::default()
+                },
+            );
+            let base = Instant::now();
+            let mut state = MouseScrollState::new_at(base);
+            let mut total_lines = 0;
+            for idx in 0..events {
+                total_lines += state
+                    .on_scroll_event_at(
+                        base + Duration::from_millis((idx + 1) * (REDRAW_CADENCE_MS + 1)),
+                        ScrollDirection::Down,
+                        config,
+                    )
+                    .lines;
+            }
+            total_lines
+                + state
+                    .on_tick_at(
+                        base + Duration::from_millis(events * (REDRAW_CADENCE_MS + 1)) + STREAM_GAP,
+                    )
+                    .lines
+        };
+
+        let linear = lines_for(TrackpadAccelCurve::Linear, 60);
+        let exponential = lines_for(TrackpadAccelCurve::Exponential, 60);
+        assert!(
+            exponential > linear,
+            "linear={linear} exponential={exponential}"
+        );
+
+        // A single event is barely accelerated, whatever the curve.
+        assert_eq!(
+            lines_for(TrackpadAccelCurve::Exponential, 1),
+            lines_for(TrackpadAccelCurve::Linear, 1)
+        );
+        assert_eq!(
+            lines_for(TrackpadAccelCurve::Quadratic, 1),
+            lines_for(TrackpadAccelCurve::Linear, 1)
+        );
+    }
+
     #[test]
     fn direction_flip_closes_previous_stream() {
         let config = ScrollConfig::from_terminal(
@@ -1023,6 +1368,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn runtime_tuning_updates_the_in_memory_config() {
+        let mut config = ScrollConfig::default();
+        config.tune(ScrollTuning::EventsPerTick(2));
+        config.tune(ScrollTuning::WheelLinesPerTick(-1));
+        config.tune(ScrollTuning::ToggleInvert);
+        assert_eq!(config.events_per_tick, DEFAULT_EVENTS_PER_TICK + 2);
+        assert_eq!(
+            config.wheel_lines_per_tick,
+            DEFAULT_WHEEL_LINES_PER_TICK - 1
+        );
+        assert!(config.invert_direction);
+
+        // Values stay within the ranges accepted from `config.toml`.
+        config.tune(ScrollTuning::EventsPerTick(-1000));
+        config.tune(ScrollTuning::WheelLinesPerTick(1000));
+        assert_eq!(config.events_per_tick, 1);
+        assert_eq!(config.wheel_lines_per_tick, MAX_LINES_PER_TICK_OVERRIDE);
+        assert_eq!(
+            config.tuning_summary(),
+            format!(
+                "events per tick: 1 · wheel lines per tick: {MAX_LINES_PER_TICK_OVERRIDE} · inverted: yes"
+            )
+        );
+    }
+
+    #[test]
+    fn persisting_tuning_writes_scroll_keys() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let mut config = ScrollConfig::default();
+        config.events_per_tick = 5;
+        config.wheel_lines_per_tick = 4;
+        config.tune(ScrollTuning::ToggleInvert);
+
+        config
+            .with_tuning_edits(ConfigEditsBuilder::new(codex_home.path()))
+            .apply_blocking()
+            .expect("persist");
+
+        let written =
+            std::fs::read_to_string(codex_home.path().join("config.toml")).expect("config.toml");
+        let parsed: toml::Value = toml::from_str(&written).expect("valid toml");
+        let tui = parsed.get("tui").expect("tui table");
+        assert_eq!(
+            tui.get("scroll_events_per_tick"),
+            Some(&toml::Value::Integer(5))
+        );
+        assert_eq!(
+            tui.get("scroll_wheel_lines"),
+            Some(&toml::Value::Integer(4))
+        );
+        assert_eq!(tui.get("scroll_invert"), Some(&toml::Value::Boolean(true)));
+    }
+
     #[test]
     fn continuous_stream_coalesces_redraws() {
         let config = ScrollConfig::from_terminal(
@@ -1102,4 +1501,82 @@ mod tests {
             }
         );
     }
+
+    fn precise_config() -> ScrollConfig {
+        ScrollConfig::from_terminal(
+            &terminal_info_named(TerminalName::WarpTerminal),
+            ScrollConfigOverrides {
+                events_per_tick: Some(9),
+                wheel_lines_per_tick: Some(5),
+                mode: Some(ScrollInputMode::Precise),
+                ..ScrollConfigOverrides::default()
+            },
+        )
+    }
+
+    #[test]
+    fn precise_mode_scrolls_one_line_per_event_without_carry() {
+        let config = precise_config();
+        let base = Instant::now();
+        let mut state = MouseScrollState::new_at(base);
+
+        let updates: Vec<ScrollUpdate> = (1..=3u64)
+            .map(|ms| {
+                state.on_scroll_event_at(
+                    base + Duration::from_millis(ms),
+                    ScrollDirection::Down,
+                    config,
+                )
+            })
+            .collect();
+        assert_eq!(
+            updates,
+            vec![
+                ScrollUpdate {
+                    lines: 1,
+                    next_tick_in: None,
+                };
+                3
+            ]
+        );
+
+        // Closing the stream flushes nothing extra and leaves no fractional carry behind.
+        let tick = state.on_tick_at(base + Duration::from_millis(3) + STREAM_GAP * 2);
+        assert_eq!(tick, ScrollUpdate::default());
+        let next = state.on_scroll_event_at(
+            base + Duration::from_millis(3) + STREAM_GAP * 3,
+            ScrollDirection::Down,
+            config,
+        );
+        assert_eq!(next.lines, 1);
+        assert_eq!(state.carry_lines, 0.0);
+    }
+
+    #[test]
+    fn precise_mode_direction_flip_applies_each_event_immediately() {
+        let config = precise_config();
+        let base = Instant::now();
+        let mut state = MouseScrollState::new_at(base);
+
+        let lines: Vec<i32> = [
+            ScrollDirection::Up,
+            ScrollDirection::Up,
+            ScrollDirection::Down,
+            ScrollDirection::Up,
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(idx, direction)| {
+            state
+                .on_scroll_event_at(
+                    base + Duration::from_millis(idx as u64 + 1),
+                    direction,
+                    config,
+                )
+                .lines
+        })
+        .collect();
+
+        assert_eq!(lines, vec![-1, -1, 1, -1]);
+    }
 }
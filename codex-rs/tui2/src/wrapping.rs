@@ -2,10 +2,41 @@ use ratatui::text::Line;
 use ratatui::text::Span;
 use std::borrow::Cow;
 use std::ops::Range;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use textwrap::Options;
 
+use codex_core::config::types::WrapAlgorithm;
+
 use crate::render::line_utils::push_owned_lines;
 
+static OPTIMAL_FIT: AtomicBool = AtomicBool::new(false);
+
+/// Installs the configured `tui.wrap_algorithm` for this process.
+///
+/// Called once at startup, before anything is wrapped, so cached wrap results never mix
+/// algorithms.
+pub(crate) fn set_wrap_algorithm(algorithm: WrapAlgorithm) {
+    OPTIMAL_FIT.store(algorithm == WrapAlgorithm::OptimalFit, Ordering::Relaxed);
+}
+
+/// The textwrap algorithm used for the composer and transcript; `FirstFit` until
+/// [`set_wrap_algorithm`] runs.
+pub(crate) fn wrap_algorithm() -> textwrap::WrapAlgorithm {
+    textwrap_algorithm(if OPTIMAL_FIT.load(Ordering::Relaxed) {
+        WrapAlgorithm::OptimalFit
+    } else {
+        WrapAlgorithm::FirstFit
+    })
+}
+
+fn textwrap_algorithm(algorithm: WrapAlgorithm) -> textwrap::WrapAlgorithm {
+    match algorithm {
+        WrapAlgorithm::FirstFit => textwrap::WrapAlgorithm::FirstFit,
+        WrapAlgorithm::OptimalFit => textwrap::WrapAlgorithm::new_optimal_fit(),
+    }
+}
+
 pub(crate) fn wrap_ranges<'a, O>(text: &str, width_or_options: O) -> Vec<Range<usize>>
 where
     O: Into<Options<'a>>,
@@ -91,7 +122,7 @@ impl<'a> RtOptions<'a> {
             subsequent_indent: Line::default(),
             break_words: true,
             word_separator: textwrap::WordSeparator::new(),
-            wrap_algorithm: textwrap::WrapAlgorithm::FirstFit,
+            wrap_algorithm: wrap_algorithm(),
             word_splitter: textwrap::WordSplitter::HyphenSplitter,
         }
     }
@@ -503,6 +534,62 @@ mod tests {
             .collect::<String>()
     }
 
+    /// Asserts that `ranges` are in order, non-overlapping, and together cover every
+    /// non-space byte of `text`.
+    fn assert_ranges_cover(text: &str, ranges: &[Range<usize>]) {
+        let mut covered = vec![false; text.len()];
+        let mut prev_end = 0;
+        for range in ranges {
+            assert!(range.start >= prev_end, "{range:?} overlaps previous line");
+            assert!(range.start < range.end, "{range:?} is empty");
+            covered[range.clone()].fill(true);
+            prev_end = range.end;
+        }
+        for (idx, ch) in text.char_indices() {
+            if ch != ' ' {
+                assert!(
+                    covered[idx],
+                    "byte {idx} ({ch:?}) not covered by {ranges:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn both_wrap_algorithms_yield_covering_ranges() {
+        let text = "The quick brown fox jumps over the lazy dog, then naps in a sunny patch \
+                    of grass while a surprisingly loud woodpecker hammers away overhead.";
+        for algorithm in [WrapAlgorithm::FirstFit, WrapAlgorithm::OptimalFit] {
+            for width in [8, 13, 21, 34] {
+                let opts = Options::new(width).wrap_algorithm(textwrap_algorithm(algorithm));
+
+                let trimmed = wrap_ranges_trim(text, opts.clone());
+                assert_ranges_cover(text, &trimmed);
+                for range in &trimmed {
+                    assert!(range.len() <= width, "{algorithm:?} at width {width}");
+                }
+
+                // The composer's ranges keep trailing spaces plus one sentinel byte for the
+                // cursor, so each line ends exactly one byte past where the next one starts.
+                let ranges = wrap_ranges(text, opts);
+                assert_eq!(
+                    ranges.len(),
+                    trimmed.len(),
+                    "{algorithm:?} at width {width}"
+                );
+                assert_eq!(ranges.first().map(|r| r.start), Some(0));
+                assert_eq!(ranges.last().map(|r| r.end), Some(text.len() + 1));
+                for pair in ranges.windows(2) {
+                    assert_eq!(
+                        pair[0].end,
+                        pair[1].start + 1,
+                        "{algorithm:?} at width {width}"
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     fn trivial_unstyled_no_indents_wide_width() {
         let line = Line::from("hello");